@@ -0,0 +1,721 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Business-day arithmetic and recurrence rules on [`NaiveDate`]s.
+//!
+//! A business day is a day that is neither part of the [`Weekend`] nor contained in a
+//! [`HolidaySet`]. Nothing in this module allocates; the holiday set is supplied by the caller.
+//!
+//! # Example
+//!
+//! ```
+//! use chrono::calendar::{add_business_days, business_days_between, Weekend};
+//! use chrono::NaiveDate;
+//!
+//! let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+//! // Monday 2024-12-23, with Christmas and Boxing Day as holidays.
+//! let holidays = [ymd(2024, 12, 25), ymd(2024, 12, 26)];
+//! let weekend = Weekend::SATURDAY_SUNDAY;
+//!
+//! let due = add_business_days(ymd(2024, 12, 23), 3, weekend, &holidays[..]);
+//! assert_eq!(due, Some(ymd(2024, 12, 30)));
+//! assert_eq!(business_days_between(ymd(2024, 12, 23), ymd(2024, 12, 30), weekend, &holidays[..]), 3);
+//! ```
+//!
+//! A [`RecurrenceOrdinal`] is the "nth weekday of the month" part of an iCalendar recurrence
+//! rule, such as `3TH` for the third Thursday or `-1MO` for the last Monday.
+
+use core::cmp::Ordering;
+use core::{fmt, str};
+
+use crate::{Datelike, Days, NaiveDate, Weekday};
+
+/// A set of weekdays that are not business days.
+///
+/// This is a small bitset with one bit for every [`Weekday`].
+///
+/// # Example
+///
+/// ```
+/// use chrono::calendar::Weekend;
+/// use chrono::Weekday;
+///
+/// let weekend = Weekend::NONE.with(Weekday::Fri).with(Weekday::Sat);
+/// assert_eq!(weekend, Weekend::FRIDAY_SATURDAY);
+/// assert!(weekend.contains(Weekday::Fri));
+/// assert!(!weekend.contains(Weekday::Sun));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Weekend(u8);
+
+impl Weekend {
+    /// A weekend without any days.
+    pub const NONE: Weekend = Weekend(0);
+
+    /// A weekend on Saturday and Sunday.
+    pub const SATURDAY_SUNDAY: Weekend = Weekend::NONE.with(Weekday::Sat).with(Weekday::Sun);
+
+    /// A weekend on Friday and Saturday.
+    pub const FRIDAY_SATURDAY: Weekend = Weekend::NONE.with(Weekday::Fri).with(Weekday::Sat);
+
+    /// Makes a new `Weekend` from the given weekdays.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::calendar::Weekend;
+    /// use chrono::Weekday;
+    ///
+    /// assert_eq!(Weekend::from_weekdays(&[Weekday::Sat, Weekday::Sun]), Weekend::SATURDAY_SUNDAY);
+    /// ```
+    #[must_use]
+    pub const fn from_weekdays(weekdays: &[Weekday]) -> Weekend {
+        let mut weekend = Weekend::NONE;
+        let mut i = 0;
+        while i < weekdays.len() {
+            weekend = weekend.with(weekdays[i]);
+            i += 1;
+        }
+        weekend
+    }
+
+    /// Returns a copy of this weekend that also includes `weekday`.
+    #[inline]
+    #[must_use]
+    pub const fn with(self, weekday: Weekday) -> Weekend {
+        Weekend(self.0 | Weekend::bit(weekday))
+    }
+
+    /// Returns a copy of this weekend that doesn't include `weekday`.
+    #[inline]
+    #[must_use]
+    pub const fn without(self, weekday: Weekday) -> Weekend {
+        Weekend(self.0 & !Weekend::bit(weekday))
+    }
+
+    /// Returns `true` if `weekday` is part of this weekend.
+    #[inline]
+    #[must_use]
+    pub const fn contains(&self, weekday: Weekday) -> bool {
+        self.0 & Weekend::bit(weekday) != 0
+    }
+
+    /// Returns the number of weekdays that are part of this weekend.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// Returns `true` if this weekend doesn't contain any weekdays.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    const fn bit(weekday: Weekday) -> u8 {
+        1 << weekday.num_days_from_monday()
+    }
+}
+
+impl fmt::Debug for Weekend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut set = f.debug_set();
+        let mut weekday = Weekday::Mon;
+        for _ in 0..7 {
+            if self.contains(weekday) {
+                set.entry(&weekday);
+            }
+            weekday = weekday.succ();
+        }
+        set.finish()
+    }
+}
+
+/// A set of dates that are not business days.
+///
+/// This is implemented for slices and arrays of dates, for `BTreeSet<NaiveDate>` with the `alloc`
+/// feature, and for `HashSet<NaiveDate>` with the `std` feature.
+pub trait HolidaySet {
+    /// Returns `true` if `date` is a holiday.
+    fn is_holiday(&self, date: NaiveDate) -> bool;
+
+    /// Calls `f` once for every holiday from `first` to `last` inclusive, in any order.
+    ///
+    /// Business-day arithmetic uses this to count the holidays in a range of whole weeks. The
+    /// default implementation calls [`is_holiday`](HolidaySet::is_holiday) for every day in the
+    /// range; sets that can iterate over their dates should override it.
+    fn holidays_between(&self, first: NaiveDate, last: NaiveDate, f: &mut dyn FnMut(NaiveDate)) {
+        let mut date = first;
+        while date <= last {
+            if self.is_holiday(date) {
+                f(date);
+            }
+            date = match date.succ_opt() {
+                Some(next) => next,
+                None => break,
+            };
+        }
+    }
+}
+
+impl<H: HolidaySet + ?Sized> HolidaySet for &H {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        (**self).is_holiday(date)
+    }
+
+    fn holidays_between(&self, first: NaiveDate, last: NaiveDate, f: &mut dyn FnMut(NaiveDate)) {
+        (**self).holidays_between(first, last, f)
+    }
+}
+
+impl HolidaySet for [NaiveDate] {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.contains(&date)
+    }
+
+    fn holidays_between(&self, first: NaiveDate, last: NaiveDate, f: &mut dyn FnMut(NaiveDate)) {
+        for (i, &date) in self.iter().enumerate() {
+            // A slice may list a date more than once, only report the first.
+            if first <= date && date <= last && !self[..i].contains(&date) {
+                f(date);
+            }
+        }
+    }
+}
+
+impl<const N: usize> HolidaySet for [NaiveDate; N] {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.contains(&date)
+    }
+
+    fn holidays_between(&self, first: NaiveDate, last: NaiveDate, f: &mut dyn FnMut(NaiveDate)) {
+        self[..].holidays_between(first, last, f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl HolidaySet for alloc::collections::BTreeSet<NaiveDate> {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.contains(&date)
+    }
+
+    fn holidays_between(&self, first: NaiveDate, last: NaiveDate, f: &mut dyn FnMut(NaiveDate)) {
+        if first <= last {
+            self.range(first..=last).for_each(|&date| f(date));
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S: std::hash::BuildHasher> HolidaySet for std::collections::HashSet<NaiveDate, S> {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.contains(&date)
+    }
+
+    fn holidays_between(&self, first: NaiveDate, last: NaiveDate, f: &mut dyn FnMut(NaiveDate)) {
+        self.iter().filter(|&&date| first <= date && date <= last).for_each(|&date| f(date));
+    }
+}
+
+/// Business-day arithmetic for a given weekend and set of holidays.
+///
+/// # Example
+///
+/// ```
+/// use chrono::calendar::{BusinessDays, Weekend};
+/// use chrono::NaiveDate;
+///
+/// let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+/// let holidays = [ymd(2024, 5, 27)];
+/// let business_days = BusinessDays::new(Weekend::SATURDAY_SUNDAY, &holidays);
+///
+/// assert!(business_days.is_business_day(ymd(2024, 5, 24)));
+/// assert!(!business_days.is_business_day(ymd(2024, 5, 27)));
+/// // Skips the weekend and the holiday on Monday.
+/// assert_eq!(business_days.add(ymd(2024, 5, 24), 1), Some(ymd(2024, 5, 28)));
+/// assert_eq!(business_days.add(ymd(2024, 5, 28), -1), Some(ymd(2024, 5, 24)));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct BusinessDays<'a, H: HolidaySet + ?Sized> {
+    weekend: Weekend,
+    holidays: &'a H,
+}
+
+impl<'a, H: HolidaySet + ?Sized> BusinessDays<'a, H> {
+    /// Makes a new `BusinessDays` from the given weekend and holidays.
+    pub const fn new(weekend: Weekend, holidays: &'a H) -> Self {
+        BusinessDays { weekend, holidays }
+    }
+
+    /// Returns `true` if `date` is neither part of the weekend nor a holiday.
+    #[must_use]
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !self.weekend.contains(date.weekday()) && !self.holidays.is_holiday(date)
+    }
+
+    /// Add `n` business days to `date`.
+    ///
+    /// Every day after `date` that is a business day counts as one, so the result is always a
+    /// business day unless `n` is zero. A negative `n` subtracts business days. `date` itself
+    /// doesn't need to be a business day.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the resulting date would be out of range, or if `n` is not zero and
+    /// every day of the week is part of the weekend.
+    #[must_use]
+    pub fn add(&self, date: NaiveDate, n: i64) -> Option<NaiveDate> {
+        if n == 0 {
+            return Some(date);
+        }
+        let per_week = match 7 - self.weekend.len() {
+            0 => return None,
+            days => u64::from(days),
+        };
+        let mut date = date;
+        let mut remaining = n.unsigned_abs();
+        // Whole weeks never contain more business days than are remaining, so they can be
+        // skipped at once. Only the last few days are stepped through one at a time.
+        while remaining >= per_week {
+            let days = Days::new((remaining / per_week).saturating_mul(7));
+            let next =
+                if n > 0 { date.checked_add_days(days)? } else { date.checked_sub_days(days)? };
+            remaining -= self.between(date, next).unsigned_abs();
+            date = next;
+        }
+        while remaining > 0 {
+            date = if n > 0 { date.succ_opt()? } else { date.pred_opt()? };
+            if self.is_business_day(date) {
+                remaining -= 1;
+            }
+        }
+        Some(date)
+    }
+
+    /// Returns the number of business days from `a` to `b`.
+    ///
+    /// If `b` is after `a` this counts the business days in `a < date <= b`. If `b` is before `a`
+    /// it counts the business days in `b <= date < a`, and returns the negated count. So if `b` is
+    /// a business day, adding the result to `a` with [`BusinessDays::add`] returns `b`.
+    #[must_use]
+    pub fn between(&self, a: NaiveDate, b: NaiveDate) -> i64 {
+        match a.cmp(&b) {
+            Ordering::Less => {
+                self.count(a.succ_opt().expect("a date before `b` has a successor"), b)
+            }
+            Ordering::Greater => {
+                -self.count(b, a.pred_opt().expect("a date after `b` has a predecessor"))
+            }
+            Ordering::Equal => 0,
+        }
+    }
+
+    /// Returns the number of business days from `first` to `last` inclusive.
+    fn count(&self, first: NaiveDate, last: NaiveDate) -> i64 {
+        let days = last.signed_duration_since(first).num_days() + 1;
+        let mut count = days / 7 * i64::from(7 - self.weekend.len());
+        let mut weekday = first.weekday();
+        for _ in 0..days % 7 {
+            if !self.weekend.contains(weekday) {
+                count += 1;
+            }
+            weekday = weekday.succ();
+        }
+        let weekend = self.weekend;
+        self.holidays.holidays_between(first, last, &mut |date| {
+            if !weekend.contains(date.weekday()) {
+                count -= 1;
+            }
+        });
+        count
+    }
+}
+
+/// Add `n` business days to `date`, skipping days in `weekend` and `holidays`.
+///
+/// A negative `n` subtracts business days. See [`BusinessDays::add`] for details.
+///
+/// # Errors
+///
+/// Returns `None` if the resulting date would be out of range, or if `n` is not zero and every day
+/// of the week is part of the weekend.
+///
+/// # Example
+///
+/// ```
+/// use chrono::calendar::{add_business_days, Weekend};
+/// use chrono::NaiveDate;
+///
+/// let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+/// let friday = ymd(2024, 5, 24);
+/// assert_eq!(add_business_days(friday, 1, Weekend::SATURDAY_SUNDAY, &[]), Some(ymd(2024, 5, 27)));
+/// assert_eq!(add_business_days(friday, -5, Weekend::SATURDAY_SUNDAY, &[]), Some(ymd(2024, 5, 17)));
+/// ```
+#[must_use]
+pub fn add_business_days<H: HolidaySet + ?Sized>(
+    date: NaiveDate,
+    n: i64,
+    weekend: Weekend,
+    holidays: &H,
+) -> Option<NaiveDate> {
+    BusinessDays::new(weekend, holidays).add(date, n)
+}
+
+/// Returns the number of business days from `a` to `b`, skipping days in `weekend` and
+/// `holidays`.
+///
+/// The result is negative if `b` is before `a`. See [`BusinessDays::between`] for details.
+#[must_use]
+pub fn business_days_between<H: HolidaySet + ?Sized>(
+    a: NaiveDate,
+    b: NaiveDate,
+    weekend: Weekend,
+    holidays: &H,
+) -> i64 {
+    BusinessDays::new(weekend, holidays).between(a, b)
+}
+
+/// The `n`th occurrence of a weekday within a month, such as the third Thursday or the last
+/// Monday.
+///
+/// This is the value of a `BYDAY` rule part with an ordinal in an iCalendar (RFC 5545) monthly
+/// recurrence, and can be parsed from strings like `"3TH"`, `"+2FR"` or `"-1MO"`. A negative
+/// ordinal counts from the end of the month, so `-1` is the last occurrence.
+///
+/// # Example
+///
+/// ```
+/// use chrono::calendar::RecurrenceOrdinal;
+/// use chrono::{NaiveDate, Weekday};
+///
+/// let last_monday: RecurrenceOrdinal = "-1MO".parse().unwrap();
+/// assert_eq!(last_monday.ordinal(), -1);
+/// assert_eq!(last_monday.weekday(), Weekday::Mon);
+/// assert_eq!(last_monday.resolve(2024, 5), NaiveDate::from_ymd_opt(2024, 5, 27));
+///
+/// let third_thursday = "3TH".parse::<RecurrenceOrdinal>().unwrap().resolver();
+/// assert_eq!(third_thursday(2024, 11), NaiveDate::from_ymd_opt(2024, 11, 21));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct RecurrenceOrdinal {
+    ordinal: i8,
+    weekday: Weekday,
+}
+
+impl RecurrenceOrdinal {
+    /// Makes a new `RecurrenceOrdinal` for the `ordinal`th `weekday` of a month.
+    ///
+    /// Returns `None` if `ordinal` is zero or its magnitude is larger than 5, because a month
+    /// never has more than five occurrences of a weekday.
+    #[must_use]
+    pub const fn new(ordinal: i8, weekday: Weekday) -> Option<RecurrenceOrdinal> {
+        match ordinal {
+            -5..=-1 | 1..=5 => Some(RecurrenceOrdinal { ordinal, weekday }),
+            _ => None,
+        }
+    }
+
+    /// Returns the ordinal, which is negative if it counts from the end of the month.
+    #[must_use]
+    pub const fn ordinal(&self) -> i8 {
+        self.ordinal
+    }
+
+    /// Returns the weekday.
+    #[must_use]
+    pub const fn weekday(&self) -> Weekday {
+        self.weekday
+    }
+
+    /// Returns the date of this occurrence of the weekday in the given month.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the month does not have that many occurrences of the weekday (for
+    /// example the 5th Monday of April 2023), if the value for `month` is invalid, or if `year`
+    /// is out of range for `NaiveDate`.
+    #[must_use]
+    pub fn resolve(&self, year: i32, month: u32) -> Option<NaiveDate> {
+        if self.ordinal > 0 {
+            return NaiveDate::nth_weekday_of_month(year, month, self.weekday, self.ordinal as u8);
+        }
+        let last = NaiveDate::last_weekday_of_month(year, month, self.weekday)?;
+        let day = last.day() as i32 + 7 * (self.ordinal as i32 + 1);
+        if day < 1 {
+            return None;
+        }
+        NaiveDate::from_ymd_opt(year, month, day as u32)
+    }
+
+    /// Returns a closure that resolves this occurrence of the weekday for a given year and month.
+    ///
+    /// See [`RecurrenceOrdinal::resolve`].
+    pub fn resolver(self) -> impl Fn(i32, u32) -> Option<NaiveDate> {
+        move |year, month| self.resolve(year, month)
+    }
+}
+
+/// Parses a `RecurrenceOrdinal` from an optional sign, an ordinal from 1 to 5 and a two-letter
+/// weekday code from `MO` to `SU`, like the `BYDAY` rule part of iCalendar.
+///
+/// The weekday code is case-insensitive.
+///
+/// # Example
+///
+/// ```
+/// use chrono::calendar::RecurrenceOrdinal;
+/// use chrono::Weekday;
+///
+/// assert_eq!("3TH".parse(), Ok(RecurrenceOrdinal::new(3, Weekday::Thu).unwrap()));
+/// assert_eq!("+2fr".parse(), Ok(RecurrenceOrdinal::new(2, Weekday::Fri).unwrap()));
+/// assert_eq!("-1MO".parse(), Ok(RecurrenceOrdinal::new(-1, Weekday::Mon).unwrap()));
+/// assert!("MO".parse::<RecurrenceOrdinal>().is_err());
+/// assert!("6MO".parse::<RecurrenceOrdinal>().is_err());
+/// ```
+impl str::FromStr for RecurrenceOrdinal {
+    type Err = ParseRecurrenceOrdinalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, s) = match s.as_bytes() {
+            [b'-', rest @ ..] => (true, rest),
+            [b'+', rest @ ..] => (false, rest),
+            rest => (false, rest),
+        };
+        let (ordinal, code) = match s {
+            [n @ b'1'..=b'5', a, b] => {
+                ((n - b'0') as i8, [a.to_ascii_uppercase(), b.to_ascii_uppercase()])
+            }
+            _ => return Err(ParseRecurrenceOrdinalError { _dummy: () }),
+        };
+        let weekday = match &code {
+            b"MO" => Weekday::Mon,
+            b"TU" => Weekday::Tue,
+            b"WE" => Weekday::Wed,
+            b"TH" => Weekday::Thu,
+            b"FR" => Weekday::Fri,
+            b"SA" => Weekday::Sat,
+            b"SU" => Weekday::Sun,
+            _ => return Err(ParseRecurrenceOrdinalError { _dummy: () }),
+        };
+        let ordinal = if negative { -ordinal } else { ordinal };
+        Ok(RecurrenceOrdinal { ordinal, weekday })
+    }
+}
+
+/// An error resulting from reading a `RecurrenceOrdinal` value with `FromStr`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParseRecurrenceOrdinalError {
+    _dummy: (),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseRecurrenceOrdinalError {}
+
+impl fmt::Display for ParseRecurrenceOrdinalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ParseRecurrenceOrdinalError {{ .. }}")
+    }
+}
+
+impl fmt::Debug for ParseRecurrenceOrdinalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ParseRecurrenceOrdinalError {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_business_days, BusinessDays, HolidaySet, RecurrenceOrdinal, Weekend};
+    use crate::{Datelike, NaiveDate, Weekday};
+    #[cfg(feature = "std")]
+    use std::collections::HashSet;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_weekend() {
+        assert!(Weekend::NONE.is_empty());
+        assert_eq!(Weekend::SATURDAY_SUNDAY.len(), 2);
+        assert!(Weekend::SATURDAY_SUNDAY.contains(Weekday::Sat));
+        assert!(Weekend::SATURDAY_SUNDAY.contains(Weekday::Sun));
+        assert!(!Weekend::SATURDAY_SUNDAY.contains(Weekday::Fri));
+        assert_eq!(Weekend::SATURDAY_SUNDAY.without(Weekday::Sat).with(Weekday::Fri).len(), 2);
+        assert_eq!(format!("{:?}", Weekend::FRIDAY_SATURDAY), "{Fri, Sat}");
+    }
+
+    #[test]
+    fn test_add_business_days() {
+        let weekend = Weekend::SATURDAY_SUNDAY;
+        let wednesday = ymd(2024, 5, 22);
+        assert_eq!(add_business_days(wednesday, 0, weekend, &[]), Some(wednesday));
+        assert_eq!(add_business_days(wednesday, 2, weekend, &[]), Some(ymd(2024, 5, 24)));
+        assert_eq!(add_business_days(wednesday, 3, weekend, &[]), Some(ymd(2024, 5, 27)));
+        assert_eq!(add_business_days(wednesday, 10, weekend, &[]), Some(ymd(2024, 6, 5)));
+        // Starting on a weekend.
+        assert_eq!(add_business_days(ymd(2024, 5, 25), 1, weekend, &[]), Some(ymd(2024, 5, 27)));
+        // A different weekend.
+        let weekend = Weekend::FRIDAY_SATURDAY;
+        assert_eq!(add_business_days(wednesday, 2, weekend, &[]), Some(ymd(2024, 5, 26)));
+    }
+
+    #[test]
+    fn test_add_business_days_holiday_next_to_weekend() {
+        let weekend = Weekend::SATURDAY_SUNDAY;
+        // Friday before and Monday after a weekend.
+        let holidays = [ymd(2024, 5, 24), ymd(2024, 5, 27)];
+        let thursday = ymd(2024, 5, 23);
+        assert_eq!(add_business_days(thursday, 1, weekend, &holidays), Some(ymd(2024, 5, 28)));
+        assert_eq!(add_business_days(ymd(2024, 5, 28), -1, weekend, &holidays), Some(thursday));
+
+        #[cfg(feature = "std")]
+        {
+            use super::business_days_between;
+
+            let holidays: HashSet<_> = holidays.iter().copied().collect();
+            assert_eq!(add_business_days(thursday, 1, weekend, &holidays), Some(ymd(2024, 5, 28)));
+            assert_eq!(business_days_between(thursday, ymd(2024, 5, 28), weekend, &holidays), 1);
+        }
+    }
+
+    #[test]
+    fn test_add_business_days_negative() {
+        let weekend = Weekend::SATURDAY_SUNDAY;
+        let monday = ymd(2024, 5, 27);
+        assert_eq!(add_business_days(monday, -1, weekend, &[]), Some(ymd(2024, 5, 24)));
+        assert_eq!(add_business_days(monday, -5, weekend, &[]), Some(ymd(2024, 5, 20)));
+        assert_eq!(add_business_days(monday, -6, weekend, &[]), Some(ymd(2024, 5, 17)));
+        let holidays = [ymd(2024, 5, 24)];
+        assert_eq!(add_business_days(monday, -1, weekend, &holidays[..]), Some(ymd(2024, 5, 23)));
+    }
+
+    #[test]
+    fn test_add_business_days_limits() {
+        let weekend = Weekend::NONE;
+        assert_eq!(add_business_days(NaiveDate::MAX, 1, weekend, &[]), None);
+        assert_eq!(add_business_days(NaiveDate::MIN, -1, weekend, &[]), None);
+        assert_eq!(add_business_days(NaiveDate::MAX, -1, weekend, &[]), NaiveDate::MAX.pred_opt());
+
+        let every_day = Weekend::from_weekdays(&[
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ]);
+        assert_eq!(add_business_days(ymd(2024, 5, 27), 1, every_day, &[]), None);
+        assert_eq!(add_business_days(ymd(2024, 5, 27), 0, every_day, &[]), Some(ymd(2024, 5, 27)));
+    }
+
+    #[test]
+    fn test_business_days_between() {
+        let weekend = Weekend::SATURDAY_SUNDAY;
+        let holidays = [ymd(2024, 5, 27)];
+        let business_days = BusinessDays::new(weekend, &holidays);
+        let friday = ymd(2024, 5, 24);
+        assert_eq!(business_days.between(friday, friday), 0);
+        assert_eq!(business_days.between(friday, ymd(2024, 5, 27)), 0);
+        assert_eq!(business_days.between(friday, ymd(2024, 5, 28)), 1);
+        assert_eq!(business_days.between(ymd(2024, 5, 28), friday), -1);
+        assert_eq!(business_days.between(ymd(2024, 5, 20), ymd(2024, 6, 3)), 9);
+        assert_eq!(business_days.between(ymd(2024, 6, 3), ymd(2024, 5, 20)), -9);
+
+        let start = ymd(2024, 5, 1);
+        for n in -30..=30 {
+            let end = business_days.add(start, n).unwrap();
+            assert_eq!(business_days.between(start, end), n);
+        }
+    }
+
+    #[test]
+    fn test_business_days_whole_weeks() {
+        // Compare with counting one day at a time, including a duplicated holiday, a holiday on
+        // the weekend and holidays that are only found through `is_holiday`.
+        struct FirstOfMonth;
+        impl HolidaySet for FirstOfMonth {
+            fn is_holiday(&self, date: NaiveDate) -> bool {
+                date.day() == 1
+            }
+        }
+        let holidays = [ymd(2024, 5, 27), ymd(2024, 5, 27), ymd(2024, 6, 1), ymd(2024, 7, 4)];
+        let start = ymd(2024, 5, 15);
+        for weekend in [Weekend::NONE, Weekend::SATURDAY_SUNDAY, Weekend::FRIDAY_SATURDAY] {
+            let sets: [&dyn HolidaySet; 2] = [&holidays, &FirstOfMonth];
+            for business_days in sets.map(|set| BusinessDays::new(weekend, set)) {
+                let mut date = start;
+                for n in 1..=100 {
+                    date = date.succ_opt().unwrap();
+                    while !business_days.is_business_day(date) {
+                        assert_eq!(business_days.between(start, date), n - 1);
+                        date = date.succ_opt().unwrap();
+                    }
+                    assert_eq!(business_days.add(start, n), Some(date));
+                    assert_eq!(business_days.add(date, -n), Some(start));
+                    assert_eq!(business_days.between(start, date), n);
+                    assert_eq!(business_days.between(date, start), -n);
+                }
+            }
+        }
+
+        // Large counts don't step through every day.
+        let weekend = Weekend::SATURDAY_SUNDAY;
+        let monday = ymd(2024, 5, 27);
+        let far = add_business_days(monday, 50_000_000, weekend, &[]).unwrap();
+        assert_eq!(far, ymd(2024, 5, 27) + crate::Days::new(70_000_000));
+        assert_eq!(BusinessDays::new(weekend, &[]).between(monday, far), 50_000_000);
+        assert_eq!(add_business_days(monday, i64::MAX, weekend, &[]), None);
+        assert_eq!(add_business_days(monday, i64::MIN, Weekend::NONE, &[]), None);
+    }
+
+    #[test]
+    fn test_recurrence_ordinal_parse() {
+        let parse = |s: &str| s.parse::<RecurrenceOrdinal>().ok();
+        let ordinal = |n, weekday| RecurrenceOrdinal::new(n, weekday);
+
+        assert_eq!(parse("-1MO"), ordinal(-1, Weekday::Mon));
+        assert_eq!(parse("3TH"), ordinal(3, Weekday::Thu));
+        assert_eq!(parse("+2FR"), ordinal(2, Weekday::Fri));
+        assert_eq!(parse("5su"), ordinal(5, Weekday::Sun));
+        assert_eq!(parse("-5We"), ordinal(-5, Weekday::Wed));
+        for s in ["", "MO", "0MO", "6MO", "-0TU", "+-1TU", "1MON", "1XX", "10MO", "1 MO", "-1"] {
+            assert_eq!(parse(s), None, "{:?}", s);
+        }
+
+        assert_eq!(RecurrenceOrdinal::new(0, Weekday::Mon), None);
+        assert_eq!(RecurrenceOrdinal::new(6, Weekday::Mon), None);
+        assert_eq!(RecurrenceOrdinal::new(-6, Weekday::Mon), None);
+    }
+
+    #[test]
+    fn test_recurrence_ordinal_resolve() {
+        let resolve =
+            |s: &str, year, month| s.parse::<RecurrenceOrdinal>().unwrap().resolve(year, month);
+
+        // last Monday of May 2024 (Memorial Day), and of a month ending on a Monday
+        assert_eq!(resolve("-1MO", 2024, 5), Some(ymd(2024, 5, 27)));
+        assert_eq!(resolve("-1MO", 2024, 9), Some(ymd(2024, 9, 30)));
+        assert_eq!(resolve("-2MO", 2024, 9), Some(ymd(2024, 9, 23)));
+        // third Thursday
+        assert_eq!(resolve("3TH", 2024, 11), Some(ymd(2024, 11, 21)));
+        assert_eq!(resolve("3TH", 2024, 2), Some(ymd(2024, 2, 15)));
+        // fifth occurrences only exist in some months
+        assert_eq!(resolve("5MO", 2024, 9), Some(ymd(2024, 9, 30)));
+        assert_eq!(resolve("-5MO", 2024, 9), Some(ymd(2024, 9, 2)));
+        assert_eq!(resolve("5MO", 2023, 4), None);
+        assert_eq!(resolve("-5MO", 2023, 4), None);
+        assert_eq!(resolve("5TH", 2021, 2), None);
+        assert_eq!(resolve("-4TH", 2021, 2), Some(ymd(2021, 2, 4)));
+        // invalid month
+        assert_eq!(resolve("1MO", 2024, 13), None);
+        assert_eq!(resolve("-1MO", 2024, 0), None);
+
+        let last_friday = "-1FR".parse::<RecurrenceOrdinal>().unwrap().resolver();
+        let dates: Vec<_> = (1..=3).map(|month| last_friday(2024, month)).collect();
+        assert_eq!(dates, [Some(ymd(2024, 1, 26)), Some(ymd(2024, 2, 23)), Some(ymd(2024, 3, 29))]);
+    }
+}
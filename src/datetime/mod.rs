@@ -9,6 +9,8 @@ use core::borrow::Borrow;
 use core::cmp::Ordering;
 use core::fmt::Write;
 use core::ops::{Add, AddAssign, Sub, SubAssign};
+#[cfg(feature = "alloc")]
+use core::slice;
 use core::time::Duration;
 use core::{fmt, hash, str};
 #[cfg(feature = "std")]
@@ -17,15 +19,19 @@ use std::time::{SystemTime, UNIX_EPOCH};
 #[cfg(all(feature = "unstable-locales", feature = "alloc"))]
 use crate::format::Locale;
 use crate::format::{
-    parse, parse_and_remainder, parse_rfc3339, Fixed, Item, ParseError, ParseResult, Parsed,
-    StrftimeItems, TOO_LONG,
+    parse, parse_and_remainder, parse_iso8601_basic, parse_rfc2822, parse_rfc3339, write_items,
+    Fixed, Item, ParseError, ParseResult, Parsed, StrftimeItems, TOO_LONG,
 };
 #[cfg(feature = "alloc")]
-use crate::format::{write_rfc2822, write_rfc3339, DelayedFormat, SecondsFormat};
+use crate::format::{
+    write_rfc2822, write_rfc3339, write_rfc3339_with_offset_format, CompiledFormat, DelayedFormat,
+    LocaleTable, OffsetFormat, SecondsFormat, BAD_FORMAT,
+};
 use crate::naive::{Days, IsoWeek, NaiveDate, NaiveDateTime, NaiveTime};
 #[cfg(feature = "clock")]
 use crate::offset::Local;
-use crate::offset::{FixedOffset, LocalResult, Offset, TimeZone, Utc};
+use crate::offset::{FixedOffset, LocalResult, MappedLocalTime, Offset, TimeZone, Utc};
+use crate::round::{round_to_interval, RoundDirection, RoundingError};
 #[allow(deprecated)]
 use crate::Date;
 use crate::{expect, try_opt};
@@ -363,9 +369,56 @@ impl<Tz: TimeZone> DateTime<Tz> {
         try_opt!(timestamp.checked_mul(1_000_000_000)).checked_add(subsec_nanos)
     }
 
+    /// Returns the number of non-leap-nanoseconds since January 1, 1970 UTC, as an `i128`.
+    ///
+    /// Unlike [`timestamp_nanos_opt`](DateTime::timestamp_nanos_opt) this can represent the full
+    /// range of `DateTime`, so it never overflows.
+    ///
+    /// The reverse operation is [`DateTime::from_timestamp_nanos_i128`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(3000, 1, 1, 0, 0, 0).unwrap();
+    /// assert_eq!(dt.timestamp_nanos_opt(), None);
+    /// assert_eq!(dt.timestamp_nanos_i128(), 32_503_680_000_000_000_000);
+    /// assert_eq!(DateTime::from_timestamp_nanos_i128(dt.timestamp_nanos_i128()), Some(dt));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn timestamp_nanos_i128(&self) -> i128 {
+        self.timestamp() as i128 * 1_000_000_000 + self.timestamp_subsec_nanos() as i128
+    }
+
+    /// Returns the number of non-leap-microseconds since January 1, 1970 UTC, as an `i128`.
+    ///
+    /// This is the same value as [`timestamp_micros`](DateTime::timestamp_micros), widened so it
+    /// can be combined with [`timestamp_nanos_i128`](DateTime::timestamp_nanos_i128) without
+    /// casts. It never overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(3000, 1, 1, 0, 0, 0).unwrap();
+    /// assert_eq!(dt.timestamp_micros_i128(), 32_503_680_000_000_000);
+    /// assert_eq!(dt.timestamp_micros_i128() * 1_000, dt.timestamp_nanos_i128());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn timestamp_micros_i128(&self) -> i128 {
+        self.timestamp() as i128 * 1_000_000 + self.timestamp_subsec_micros() as i128
+    }
+
     /// Returns the number of milliseconds since the last second boundary.
     ///
     /// In event of a leap second this may exceed 999.
+    ///
+    /// Because offsets are whole seconds, this does not depend on the time zone and is the same as
+    /// [`NaiveDateTime::subsec_millis`] on both `naive_utc()` and `naive_local()`.
     #[inline]
     #[must_use]
     pub const fn timestamp_subsec_millis(&self) -> u32 {
@@ -375,6 +428,9 @@ impl<Tz: TimeZone> DateTime<Tz> {
     /// Returns the number of microseconds since the last second boundary.
     ///
     /// In event of a leap second this may exceed 999,999.
+    ///
+    /// Because offsets are whole seconds, this does not depend on the time zone and is the same as
+    /// [`NaiveDateTime::subsec_micros`] on both `naive_utc()` and `naive_local()`.
     #[inline]
     #[must_use]
     pub const fn timestamp_subsec_micros(&self) -> u32 {
@@ -384,6 +440,9 @@ impl<Tz: TimeZone> DateTime<Tz> {
     /// Returns the number of nanoseconds since the last second boundary
     ///
     /// In event of a leap second this may exceed 999,999,999.
+    ///
+    /// Because offsets are whole seconds, this does not depend on the time zone and is the same as
+    /// [`NaiveDateTime::subsec_nanos`] on both `naive_utc()` and `naive_local()`.
     #[inline]
     #[must_use]
     pub const fn timestamp_subsec_nanos(&self) -> u32 {
@@ -415,10 +474,12 @@ impl<Tz: TimeZone> DateTime<Tz> {
 
     /// Fix the offset from UTC to its current value, dropping the associated timezone information.
     /// This it useful for converting a generic `DateTime<Tz: Timezone>` to `DateTime<FixedOffset>`.
+    ///
+    /// The stored offset is reused, so this doesn't look up the offset in the time zone again.
     #[inline]
     #[must_use]
     pub fn fixed_offset(&self) -> DateTime<FixedOffset> {
-        self.with_timezone(&self.offset().fix())
+        DateTime { datetime: self.datetime, offset: self.offset.fix() }
     }
 
     /// Turn this `DateTime` into a `DateTime<Utc>`, dropping the offset and associated timezone
@@ -444,6 +505,10 @@ impl<Tz: TimeZone> DateTime<Tz> {
 
     /// Adds given `Months` to the current date and time.
     ///
+    /// This works on the local date and keeps the local wall clock time, after which the offset is
+    /// determined again for the new local date and time. So across a daylight saving time
+    /// transition the result has a different offset than `self`.
+    ///
     /// Uses the last day of the month if the day does not exist in the resulting month.
     ///
     /// See [`NaiveDate::checked_add_months`] for more details on behavior.
@@ -455,6 +520,17 @@ impl<Tz: TimeZone> DateTime<Tz> {
     ///   daylight saving time transition.
     /// - The resulting UTC datetime would be out of range.
     /// - The resulting local datetime would be out of range (unless `months` is zero).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, Months, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(3600).unwrap();
+    /// let dt = tz.with_ymd_and_hms(2023, 1, 31, 9, 0, 0).unwrap();
+    /// assert_eq!(dt.checked_add_months(Months::new(1)), tz.with_ymd_and_hms(2023, 2, 28, 9, 0, 0).single());
+    /// assert_eq!(dt.checked_add_months(Months::new(2)), tz.with_ymd_and_hms(2023, 3, 31, 9, 0, 0).single());
+    /// ```
     #[must_use]
     pub fn checked_add_months(self, months: Months) -> Option<DateTime<Tz>> {
         // `NaiveDate::checked_add_months` has a fast path for `Months(0)` that does not validate
@@ -479,6 +555,93 @@ impl<Tz: TimeZone> DateTime<Tz> {
         Some(tz.from_utc_datetime(&datetime))
     }
 
+    /// Adds given `TimeDelta` to the current date and time, saturating at the bounds.
+    ///
+    /// If the result would be out of range, this returns the UTC datetime [`MAX_UTC`] or
+    /// [`MIN_UTC`] in the time zone of `self`.
+    ///
+    /// [`MAX_UTC`]: DateTime::MAX_UTC
+    /// [`MIN_UTC`]: DateTime::MIN_UTC
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// assert_eq!(
+    ///     dt.saturating_add_signed(TimeDelta::days(1)),
+    ///     Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap()
+    /// );
+    /// assert_eq!(dt.saturating_add_signed(TimeDelta::max_value()), DateTime::<Utc>::MAX_UTC);
+    /// assert_eq!(dt.saturating_add_signed(TimeDelta::min_value()), DateTime::<Utc>::MIN_UTC);
+    /// ```
+    #[must_use]
+    pub fn saturating_add_signed(self, rhs: TimeDelta) -> DateTime<Tz> {
+        let datetime = match self.datetime.checked_add_signed(rhs) {
+            Some(datetime) => datetime,
+            None if rhs < TimeDelta::zero() => NaiveDateTime::MIN,
+            None => NaiveDateTime::MAX,
+        };
+        self.timezone().from_utc_datetime(&datetime)
+    }
+
+    /// Subtracts given `TimeDelta` from the current date and time, saturating at the bounds.
+    ///
+    /// If the result would be out of range, this returns the UTC datetime [`MIN_UTC`] or
+    /// [`MAX_UTC`] in the time zone of `self`.
+    ///
+    /// [`MAX_UTC`]: DateTime::MAX_UTC
+    /// [`MIN_UTC`]: DateTime::MIN_UTC
+    #[must_use]
+    pub fn saturating_sub_signed(self, rhs: TimeDelta) -> DateTime<Tz> {
+        let datetime = match self.datetime.checked_sub_signed(rhs) {
+            Some(datetime) => datetime,
+            None if rhs < TimeDelta::zero() => NaiveDateTime::MAX,
+            None => NaiveDateTime::MIN,
+        };
+        self.timezone().from_utc_datetime(&datetime)
+    }
+
+    /// Adds given `TimeDelta` to the current date and time, returning the part of `rhs` that could
+    /// not be applied.
+    ///
+    /// If the result would be out of range, this returns the UTC datetime [`MAX_UTC`] or
+    /// [`MIN_UTC`] in the time zone of `self`, together with the remaining duration past that
+    /// bound. The remainder has the same sign as `rhs`, and is zero if the result is in range.
+    ///
+    /// [`MAX_UTC`]: DateTime::MAX_UTC
+    /// [`MIN_UTC`]: DateTime::MIN_UTC
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// assert_eq!(
+    ///     dt.overflowing_add_signed(TimeDelta::days(1)),
+    ///     (Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap(), TimeDelta::zero())
+    /// );
+    ///
+    /// let max = DateTime::<Utc>::MAX_UTC;
+    /// assert_eq!(max.overflowing_add_signed(TimeDelta::days(1)), (max, TimeDelta::days(1)));
+    /// ```
+    #[must_use]
+    pub fn overflowing_add_signed(self, rhs: TimeDelta) -> (DateTime<Tz>, TimeDelta) {
+        let (datetime, remainder) = match self.datetime.checked_add_signed(rhs) {
+            Some(datetime) => (datetime, TimeDelta::zero()),
+            None => {
+                let limit =
+                    if rhs < TimeDelta::zero() { NaiveDateTime::MIN } else { NaiveDateTime::MAX };
+                // The span of representable datetimes fits in a `TimeDelta`, so this can't
+                // overflow.
+                (limit, rhs - limit.signed_duration_since(self.datetime))
+            }
+        };
+        (self.timezone().from_utc_datetime(&datetime), remainder)
+    }
+
     /// Subtracts given `Months` from the current date and time.
     ///
     /// Uses the last day of the month if the day does not exist in the resulting month.
@@ -505,6 +668,11 @@ impl<Tz: TimeZone> DateTime<Tz> {
 
     /// Add a duration in [`Days`] to the date part of the `DateTime`.
     ///
+    /// This moves by calendar days in the local time zone and keeps the local time, after which
+    /// the offset is determined again. So across a daylight saving time transition "tomorrow at
+    /// 09:00" is still at 09:00 local time, while adding `TimeDelta::days(1)` always adds exactly
+    /// 24 hours and can give 08:00 or 10:00 local time.
+    ///
     /// # Errors
     ///
     /// Returns `None` if:
@@ -528,6 +696,9 @@ impl<Tz: TimeZone> DateTime<Tz> {
 
     /// Subtract a duration in [`Days`] from the date part of the `DateTime`.
     ///
+    /// Like [`checked_add_days`](DateTime::checked_add_days) this moves by calendar days in the
+    /// local time zone, which is not the same as subtracting multiples of 24 hours.
+    ///
     /// # Errors
     ///
     /// Returns `None` if:
@@ -546,6 +717,197 @@ impl<Tz: TimeZone> DateTime<Tz> {
             .filter(|dt| dt >= &DateTime::<Utc>::MIN_UTC)
     }
 
+    /// Returns the local date, if it is within the range of `NaiveDate`.
+    fn local_date(&self) -> Option<NaiveDate> {
+        let date = self.overflowing_naive_local().date();
+        Some(date).filter(|date| (NaiveDate::MIN..=NaiveDate::MAX).contains(date))
+    }
+
+    /// Returns the first instant of the local day.
+    ///
+    /// This is usually midnight. If local midnight does not exist because of a transition in the
+    /// time zone, such as the start of daylight saving time, this is the earliest instant that
+    /// falls on the day. If local midnight is ambiguous the earliest instant is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the result would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(5 * 3600).unwrap();
+    /// let dt = tz.with_ymd_and_hms(2024, 5, 15, 12, 34, 56).unwrap();
+    /// assert_eq!(dt.beginning_of_day().unwrap().to_string(), "2024-05-15 00:00:00 +05:00");
+    /// assert_eq!(dt.end_of_day().unwrap().to_string(), "2024-05-15 23:59:59.999999999 +05:00");
+    /// ```
+    #[must_use]
+    pub fn beginning_of_day(&self) -> Option<DateTime<Tz>> {
+        local_day_start(&self.timezone(), self.local_date()?)
+    }
+
+    /// Returns the last instant of the local day, one nanosecond before the next day starts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the result would be out of range.
+    #[must_use]
+    pub fn end_of_day(&self) -> Option<DateTime<Tz>> {
+        local_day_end(&self.timezone(), self.local_date()?)
+    }
+
+    /// Returns the first instant of the local week that starts on the [`Weekday`] `start`.
+    ///
+    /// Local time zone transitions are handled as in [`beginning_of_day`].
+    ///
+    /// [`beginning_of_day`]: DateTime::beginning_of_day
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the result would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc, Weekday};
+    ///
+    /// // 2024-05-15 is a Wednesday.
+    /// let dt = Utc.with_ymd_and_hms(2024, 5, 15, 12, 34, 56).unwrap();
+    /// let start = dt.beginning_of_week(Weekday::Mon).unwrap();
+    /// assert_eq!(start.to_string(), "2024-05-13 00:00:00 UTC");
+    /// let end = dt.end_of_week(Weekday::Mon).unwrap();
+    /// assert_eq!(end.to_string(), "2024-05-19 23:59:59.999999999 UTC");
+    /// ```
+    #[must_use]
+    pub fn beginning_of_week(&self, start: Weekday) -> Option<DateTime<Tz>> {
+        let date = self.local_date()?;
+        let first = date.checked_sub_days(Days::new(date.weekday().days_since(start).into()))?;
+        local_day_start(&self.timezone(), first)
+    }
+
+    /// Returns the last instant of the local week that starts on the [`Weekday`] `start`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the result would be out of range.
+    #[must_use]
+    pub fn end_of_week(&self, start: Weekday) -> Option<DateTime<Tz>> {
+        let date = self.local_date()?;
+        let last =
+            date.checked_add_days(Days::new(start.pred().days_since(date.weekday()).into()))?;
+        local_day_end(&self.timezone(), last)
+    }
+
+    /// Returns the first instant of the local month.
+    ///
+    /// Local time zone transitions are handled as in [`beginning_of_day`].
+    ///
+    /// [`beginning_of_day`]: DateTime::beginning_of_day
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the result would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2024, 2, 15, 12, 34, 56).unwrap();
+    /// assert_eq!(dt.beginning_of_month().unwrap().to_string(), "2024-02-01 00:00:00 UTC");
+    /// assert_eq!(dt.end_of_month().unwrap().to_string(), "2024-02-29 23:59:59.999999999 UTC");
+    /// ```
+    #[must_use]
+    pub fn beginning_of_month(&self) -> Option<DateTime<Tz>> {
+        let date = self.local_date()?;
+        local_day_start(&self.timezone(), date.first_day_of_month())
+    }
+
+    /// Returns the last instant of the local month.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the result would be out of range.
+    #[must_use]
+    pub fn end_of_month(&self) -> Option<DateTime<Tz>> {
+        let date = self.local_date()?;
+        local_day_end(&self.timezone(), date.last_day_of_month())
+    }
+
+    /// Returns the first instant of the local quarter.
+    ///
+    /// Local time zone transitions are handled as in [`beginning_of_day`].
+    ///
+    /// [`beginning_of_day`]: DateTime::beginning_of_day
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the result would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2024, 5, 15, 12, 34, 56).unwrap();
+    /// assert_eq!(dt.beginning_of_quarter().unwrap().to_string(), "2024-04-01 00:00:00 UTC");
+    /// assert_eq!(dt.end_of_quarter().unwrap().to_string(), "2024-06-30 23:59:59.999999999 UTC");
+    /// ```
+    #[must_use]
+    pub fn beginning_of_quarter(&self) -> Option<DateTime<Tz>> {
+        let date = self.local_date()?;
+        local_day_start(&self.timezone(), date.first_day_of_quarter())
+    }
+
+    /// Returns the last instant of the local quarter.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the result would be out of range.
+    #[must_use]
+    pub fn end_of_quarter(&self) -> Option<DateTime<Tz>> {
+        let date = self.local_date()?;
+        local_day_end(&self.timezone(), date.last_day_of_quarter())
+    }
+
+    /// Returns the first instant of the local year.
+    ///
+    /// Local time zone transitions are handled as in [`beginning_of_day`].
+    ///
+    /// [`beginning_of_day`]: DateTime::beginning_of_day
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the result would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2024, 5, 15, 12, 34, 56).unwrap();
+    /// assert_eq!(dt.beginning_of_year().unwrap().to_string(), "2024-01-01 00:00:00 UTC");
+    /// assert_eq!(dt.end_of_year().unwrap().to_string(), "2024-12-31 23:59:59.999999999 UTC");
+    /// ```
+    #[must_use]
+    pub fn beginning_of_year(&self) -> Option<DateTime<Tz>> {
+        let date = self.local_date()?;
+        local_day_start(&self.timezone(), date.first_day_of_year())
+    }
+
+    /// Returns the last instant of the local year.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the result would be out of range.
+    #[must_use]
+    pub fn end_of_year(&self) -> Option<DateTime<Tz>> {
+        let date = self.local_date()?;
+        local_day_end(&self.timezone(), date.last_day_of_year())
+    }
+
     /// Subtracts another `DateTime` from the current date and time.
     /// This does not overflow or underflow at all.
     #[inline]
@@ -557,6 +919,86 @@ impl<Tz: TimeZone> DateTime<Tz> {
         self.datetime.signed_duration_since(rhs.borrow().datetime)
     }
 
+    /// Returns the absolute difference between two `DateTime`s, which is never negative.
+    ///
+    /// This is the same as `self.signed_duration_since(other).abs()`, and can be used to find out
+    /// which of several datetimes is closest to `self`. The difference between any two `DateTime`s
+    /// fits in a `TimeDelta`, so this can't overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeDelta, TimeZone, Utc};
+    ///
+    /// let a = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+    /// let b = FixedOffset::east_opt(3600).unwrap().with_ymd_and_hms(2024, 3, 1, 10, 0, 0).unwrap();
+    /// assert_eq!(a.abs_diff(b), TimeDelta::hours(3));
+    /// assert_eq!(b.abs_diff(a), TimeDelta::hours(3));
+    /// ```
+    #[must_use]
+    pub fn abs_diff<Tz2: TimeZone>(&self, other: impl Borrow<DateTime<Tz2>>) -> TimeDelta {
+        self.datetime.signed_duration_since(other.borrow().datetime).abs()
+    }
+
+    /// Subtracts another `DateTime` from the current date and time, counting the leap seconds in
+    /// between.
+    ///
+    /// Unlike [`signed_duration_since`](DateTime::signed_duration_since), which assumes every day
+    /// has 86,400 seconds, this adds one second for every historical leap second that occurred
+    /// between the two instants, as in a TAI-style difference. An instant within a leap second is
+    /// represented by chrono as `23:59:59` with a nanosecond of 1,000,000,000 or more, see the
+    /// [leap second handling](crate::NaiveTime#leap-second-handling) of `NaiveTime`.
+    ///
+    /// The leap seconds are taken from a table embedded in chrono, which has to be updated when
+    /// new leap seconds are announced. A leap second that is not in the table is only counted if
+    /// one of the instants is within it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeDelta, TimeZone, Utc};
+    ///
+    /// // A leap second was inserted at the end of 2016-12-31.
+    /// let before = Utc.with_ymd_and_hms(2016, 12, 31, 23, 59, 0).unwrap();
+    /// let after = Utc.with_ymd_and_hms(2017, 1, 1, 0, 1, 0).unwrap();
+    /// assert_eq!(after.signed_duration_since(before), TimeDelta::seconds(120));
+    /// assert_eq!(after.duration_since_including_leap(&before), TimeDelta::seconds(121));
+    /// assert_eq!(before.duration_since_including_leap(&after), TimeDelta::seconds(-121));
+    /// ```
+    #[cfg(feature = "leap-seconds")]
+    #[must_use]
+    pub fn duration_since_including_leap<Tz2: TimeZone>(&self, other: &DateTime<Tz2>) -> TimeDelta {
+        use crate::leap_seconds::leap_seconds_before;
+
+        let (lhs, rhs) = (self.timestamp(), other.timestamp());
+        let leaps = leap_seconds_before(lhs) - leap_seconds_before(rhs);
+        // The nanoseconds include the leap second if either instant is within one.
+        let nanos =
+            i64::from(self.timestamp_subsec_nanos()) - i64::from(other.timestamp_subsec_nanos());
+        TimeDelta::seconds(lhs - rhs + leaps) + TimeDelta::nanoseconds(nanos)
+    }
+
+    /// Describes this date and time relative to `now` as an English phrase, such as
+    /// `"3 hours ago"` or `"in 2 days"`.
+    ///
+    /// This is the same as `self.signed_duration_since(now).humanize()`, see
+    /// [`TimeDelta::humanize`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeDelta, TimeZone, Utc};
+    ///
+    /// let now = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+    /// assert_eq!((now - TimeDelta::hours(3)).humanize_since(now), "3 hours ago");
+    /// assert_eq!((now + TimeDelta::days(2)).humanize_since(now), "in 2 days");
+    /// ```
+    #[cfg(feature = "humanize")]
+    #[must_use]
+    pub fn humanize_since<Tz2: TimeZone>(&self, now: DateTime<Tz2>) -> String {
+        self.clone().signed_duration_since(now).humanize()
+    }
+
     /// Returns a view to the naive UTC datetime.
     #[inline]
     #[must_use]
@@ -564,6 +1006,39 @@ impl<Tz: TimeZone> DateTime<Tz> {
         self.datetime
     }
 
+    /// Splits the `DateTime` into its components: a `NaiveDateTime` in UTC and an `Offset`.
+    ///
+    /// This is the inverse of [`DateTime::from_naive_utc_and_offset`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+    /// let dt = tz.with_ymd_and_hms(2024, 5, 6, 7, 8, 9).unwrap();
+    /// let (naive_utc, offset) = dt.into_naive_utc_and_offset();
+    /// let expected = NaiveDate::from_ymd_opt(2024, 5, 6).unwrap().and_hms_opt(5, 8, 9).unwrap();
+    /// assert_eq!(naive_utc, expected);
+    /// assert_eq!(offset, tz);
+    /// assert_eq!(DateTime::<FixedOffset>::from_naive_utc_and_offset(naive_utc, offset), dt);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn into_naive_utc_and_offset(self) -> (NaiveDateTime, Tz::Offset) {
+        (self.datetime, self.offset)
+    }
+
+    /// Returns the components of the `DateTime` without consuming it: a `NaiveDateTime` in UTC
+    /// and a reference to the `Offset`.
+    ///
+    /// See also [`DateTime::into_naive_utc_and_offset`].
+    #[inline]
+    #[must_use]
+    pub const fn as_naive_utc_and_offset(&self) -> (NaiveDateTime, &Tz::Offset) {
+        (self.datetime, &self.offset)
+    }
+
     /// Returns a view to the naive local datetime.
     ///
     /// # Panics
@@ -592,14 +1067,19 @@ impl<Tz: TimeZone> DateTime<Tz> {
 
     /// Retrieve the elapsed years from now to the given [`DateTime`].
     ///
+    /// The years are counted using the local dates and times. If the local date of `base` is
+    /// February 29, the anniversary in a common year is taken to be February 28, see
+    /// [`NaiveDate::years_since`].
+    ///
     /// # Errors
     ///
-    /// Returns `None` if `base < self`.
+    /// Returns `None` if `base > self`.
     #[must_use]
     pub fn years_since(&self, base: Self) -> Option<u32> {
-        let mut years = self.year() - base.year();
-        let earlier_time =
-            (self.month(), self.day(), self.time()) < (base.month(), base.day(), base.time());
+        let (date, base_date) = (self.date_naive(), base.date_naive());
+        let mut years = date.year() - base_date.year();
+        let earlier_time = (date.anniversary_in(date.leap_year()), self.time())
+            < (base_date.anniversary_in(date.leap_year()), base.time());
 
         years -= match earlier_time {
             true => 1,
@@ -612,6 +1092,154 @@ impl<Tz: TimeZone> DateTime<Tz> {
         }
     }
 
+    /// Clamps the `DateTime` to the range `min..=max`.
+    ///
+    /// Returns `min` if `self` is before it and `max` if `self` is after it, converted to the time
+    /// zone of `self`. Otherwise `self` is returned unchanged. Unlike [`Ord::clamp`], the bounds
+    /// can be in a different time zone.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if `min > max`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let min = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// let max = Utc.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap();
+    /// let dt = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+    /// assert_eq!(dt.clamp_between(&min, &max), max);
+    /// ```
+    #[must_use]
+    pub fn clamp_between<Tz2: TimeZone>(self, min: &DateTime<Tz2>, max: &DateTime<Tz2>) -> Self {
+        debug_assert!(min <= max, "`min` must not be greater than `max`");
+        if self < *min {
+            min.with_timezone(&self.timezone())
+        } else if self > *max {
+            max.with_timezone(&self.timezone())
+        } else {
+            self
+        }
+    }
+
+    /// Returns `true` if the `DateTime` is between `start` and `end`.
+    ///
+    /// Whether `start` and `end` themselves are part of the range is controlled with
+    /// `inclusivity`. If `start > end` the range is empty and this always returns `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Inclusivity, TimeZone, Utc};
+    ///
+    /// let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+    /// assert!(start.is_between(&start, &end, Inclusivity::StartInclusive));
+    /// assert!(!end.is_between(&start, &end, Inclusivity::StartInclusive));
+    /// ```
+    #[must_use]
+    pub fn is_between<Tz2: TimeZone>(
+        &self,
+        start: &DateTime<Tz2>,
+        end: &DateTime<Tz2>,
+        inclusivity: Inclusivity,
+    ) -> bool {
+        let (after_start, before_end) = match inclusivity {
+            Inclusivity::Inclusive => (self >= start, self <= end),
+            Inclusivity::Exclusive => (self > start, self < end),
+            Inclusivity::StartInclusive => (self >= start, self < end),
+            Inclusivity::EndInclusive => (self > start, self <= end),
+        };
+        after_start && before_end
+    }
+
+    /// Rounds to the nearest multiple of `interval`, counted from the Unix epoch.
+    ///
+    /// Halfway values are rounded up. Multiples are counted from 1970-01-01 00:00:00 UTC, and not
+    /// from local midnight, so intervals that don't evenly divide a day (such as 7 minutes) don't
+    /// drift from one day to the next. Because the calculation is done on the UTC instant, the
+    /// result is well-defined across daylight saving time transitions.
+    ///
+    /// Rounding by calendar units that depend on the local time, such as days in a time zone with
+    /// a nonzero offset, is better served by
+    /// [`DurationRound::duration_round`](crate::DurationRound::duration_round).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RoundingError::DurationExceedsLimit`] if `interval` is zero or negative, and
+    /// [`RoundingError::TimestampExceedsLimit`] if the result would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeDelta, TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2024, 3, 1, 12, 7, 30).unwrap();
+    /// let interval = TimeDelta::try_minutes(15).unwrap();
+    /// assert_eq!(dt.round_to_nearest(interval).unwrap().to_string(), "2024-03-01 12:15:00 UTC");
+    /// assert_eq!(dt.floor_to_nearest(interval).unwrap().to_string(), "2024-03-01 12:00:00 UTC");
+    /// assert_eq!(dt.ceil_to_nearest(interval).unwrap().to_string(), "2024-03-01 12:15:00 UTC");
+    /// ```
+    pub fn round_to_nearest(&self, interval: TimeDelta) -> Result<DateTime<Tz>, RoundingError> {
+        round_to_interval(self, interval, RoundDirection::Nearest)
+    }
+
+    /// Rounds down to the preceding multiple of `interval`, counted from the Unix epoch.
+    ///
+    /// Values that are already a multiple of `interval` are returned unchanged.
+    /// See [`DateTime::round_to_nearest`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RoundingError::DurationExceedsLimit`] if `interval` is zero or negative, and
+    /// [`RoundingError::TimestampExceedsLimit`] if the result would be out of range.
+    pub fn floor_to_nearest(&self, interval: TimeDelta) -> Result<DateTime<Tz>, RoundingError> {
+        round_to_interval(self, interval, RoundDirection::Floor)
+    }
+
+    /// Rounds up to the following multiple of `interval`, counted from the Unix epoch.
+    ///
+    /// Values that are already a multiple of `interval` are returned unchanged.
+    /// See [`DateTime::round_to_nearest`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RoundingError::DurationExceedsLimit`] if `interval` is zero or negative, and
+    /// [`RoundingError::TimestampExceedsLimit`] if the result would be out of range.
+    pub fn ceil_to_nearest(&self, interval: TimeDelta) -> Result<DateTime<Tz>, RoundingError> {
+        round_to_interval(self, interval, RoundDirection::Ceil)
+    }
+
+    /// Truncates to the start of the local day in the time zone of this `DateTime`.
+    ///
+    /// Unlike [`DurationRound::duration_trunc`](crate::DurationRound::duration_trunc) with a
+    /// `TimeDelta` of one day, which counts whole days of 24 hours from the Unix epoch, this
+    /// follows the local calendar day. Days with a time zone transition, such as the start or end
+    /// of daylight saving time, can be 23 or 25 hours long.
+    ///
+    /// The result is the earliest valid local midnight. If local midnight does not exist because
+    /// of a transition, this is the earliest instant that falls on the day. This is the same as
+    /// [`DateTime::beginning_of_day`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RoundingError::TimestampExceedsLimit`] if the result would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(5 * 3600).unwrap();
+    /// let dt = tz.with_ymd_and_hms(2024, 5, 15, 2, 34, 56).unwrap();
+    /// assert_eq!(dt.duration_trunc_to_day_in_tz().unwrap().to_string(), "2024-05-15 00:00:00 +05:00");
+    /// ```
+    pub fn duration_trunc_to_day_in_tz(&self) -> Result<DateTime<Tz>, RoundingError> {
+        self.beginning_of_day().ok_or(RoundingError::TimestampExceedsLimit)
+    }
+
     /// Returns an RFC 2822 date and time string such as `Tue, 1 Jul 2003 10:52:37 +0200`.
     ///
     /// # Panics
@@ -643,9 +1271,17 @@ impl<Tz: TimeZone> DateTime<Tz> {
     /// Return an RFC 3339 and ISO 8601 date and time string with subseconds
     /// formatted as per `SecondsFormat`.
     ///
-    /// If `use_z` is true and the timezone is UTC (offset 0), uses `Z` as
-    /// per [`Fixed::TimezoneOffsetColonZ`]. If `use_z` is false, uses
-    /// [`Fixed::TimezoneOffsetColon`]
+    /// If `use_z` is true and the offset is zero, uses `Z` as per
+    /// [`Fixed::TimezoneOffsetColonZ`]. If `use_z` is false, uses
+    /// [`Fixed::TimezoneOffsetColon`], so the offset is always written in numeric form.
+    ///
+    /// This only depends on the offset, not on the time zone type, so a `DateTime<FixedOffset>`
+    /// with a zero offset is formatted the same as a `DateTime<Utc>`:
+    ///
+    /// offset   | `use_z = true` | `use_z = false`
+    /// -------- | -------------- | ---------------
+    /// zero     | `Z`            | `+00:00`
+    /// non-zero | `+05:00`       | `+05:00`
     ///
     /// # Examples
     ///
@@ -680,12 +1316,70 @@ impl<Tz: TimeZone> DateTime<Tz> {
         result
     }
 
+    /// Return an RFC 3339 and ISO 8601 date and time string with subseconds formatted as per
+    /// `secform`, and the offset formatted as per `offset_format`.
+    ///
+    /// This is like [`to_rfc3339_opts`](DateTime::to_rfc3339_opts), but for consumers that
+    /// expect the offset in a different form than `+HH:MM`. Note that RFC 3339 itself only allows
+    /// an offset with a colon and minutes, such as produced by `to_rfc3339_opts`.
+    ///
+    /// See [`OffsetFormat`] for the available options. A precision of
+    /// [`OffsetPrecision::OptionalSeconds`](crate::format::OffsetPrecision::OptionalSeconds)
+    /// only writes the seconds of the offset if they are not zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::format::{Colons, OffsetFormat, OffsetPrecision, Pad};
+    /// use chrono::{FixedOffset, SecondsFormat, TimeZone};
+    ///
+    /// let offset = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+    /// let dt = offset.with_ymd_and_hms(2018, 1, 26, 10, 30, 9).unwrap();
+    /// let no_colon = OffsetFormat {
+    ///     precision: OffsetPrecision::Minutes,
+    ///     colons: Colons::None,
+    ///     allow_zulu: false,
+    ///     padding: Pad::Zero,
+    /// };
+    /// assert_eq!(dt.to_rfc3339_opts_ext(SecondsFormat::Secs, no_colon), "2018-01-26T10:30:09+0530");
+    ///
+    /// let hours_only = OffsetFormat { precision: OffsetPrecision::Hours, ..no_colon };
+    /// assert_eq!(dt.to_rfc3339_opts_ext(SecondsFormat::Secs, hours_only), "2018-01-26T10:30:09+05");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_rfc3339_opts_ext(
+        &self,
+        secform: SecondsFormat,
+        offset_format: OffsetFormat,
+    ) -> String {
+        let mut result = String::with_capacity(38);
+        write_rfc3339_with_offset_format(
+            &mut result,
+            self.naive_local(),
+            self.offset.fix(),
+            secform,
+            offset_format,
+        )
+        .expect("writing rfc3339 datetime to string should never fail");
+        result
+    }
+
     /// Set the time to a new fixed time on the existing date.
     ///
+    /// The local date is kept and the offset is resolved again for the new local time, so the
+    /// result may have a different offset than `self` if a daylight saving time transition lies in
+    /// between. This is a shorter form of
+    /// `self.date_naive().and_time(time).and_local_timezone(self.timezone())`.
+    ///
     /// # Errors
     ///
-    /// Returns `LocalResult::None` if the datetime is at the edge of the representable range for a
-    /// `DateTime`, and `with_time` would push the value in UTC out of range.
+    /// Returns [`MappedLocalTime::None`] if the new local time falls in a gap of the time zone,
+    /// such as when the clocks skip forward, and [`MappedLocalTime::Ambiguous`] if it falls in a
+    /// fold where the clocks are turned back.
+    ///
+    /// Also returns `MappedLocalTime::None` if the datetime is at the edge of the representable
+    /// range for a `DateTime`, and `with_time` would push the value in UTC out of range.
     ///
     /// # Example
     ///
@@ -702,10 +1396,144 @@ impl<Tz: TimeZone> DateTime<Tz> {
     /// # }
     /// ```
     #[must_use]
-    pub fn with_time(&self, time: NaiveTime) -> LocalResult<Self> {
+    pub fn with_time(&self, time: NaiveTime) -> MappedLocalTime<Self> {
         self.timezone().from_local_datetime(&self.overflowing_naive_local().date().and_time(time))
     }
 
+    /// Makes a new `DateTime` with the year number changed, while keeping the same month, day and
+    /// local time.
+    ///
+    /// This works like [`Datelike::with_year`], but the offset is resolved as a
+    /// [`MappedLocalTime`], so an ambiguous local time returns both candidates instead of `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MappedLocalTime::None`] if:
+    /// - The resulting date does not exist (February 29 in a non-leap year).
+    /// - The local time at the resulting date falls in a gap of the time zone, such as when the
+    ///   clocks skip forward.
+    /// - The resulting datetime would be out of range.
+    ///
+    /// Returns [`MappedLocalTime::Ambiguous`] if the local time at the resulting date falls in a
+    /// fold, where the clocks are turned back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, MappedLocalTime, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(3600).unwrap();
+    /// let dt = tz.with_ymd_and_hms(2024, 2, 29, 12, 0, 0).unwrap();
+    /// assert_eq!(dt.with_year_local(2028), tz.with_ymd_and_hms(2028, 2, 29, 12, 0, 0));
+    /// assert_eq!(dt.with_year_local(2025), MappedLocalTime::None);
+    /// ```
+    #[must_use]
+    pub fn with_year_local(&self, year: i32) -> MappedLocalTime<Self> {
+        map_local_mapped(self, |dt| match dt.year() == year {
+            true => Some(dt),
+            false => dt.with_year(year),
+        })
+    }
+
+    /// Makes a new `DateTime` with the month number (starting from 1) changed, while keeping the
+    /// same day and local time.
+    ///
+    /// This works like [`Datelike::with_month`], but the offset is resolved as a
+    /// [`MappedLocalTime`], so an ambiguous local time returns both candidates instead of `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MappedLocalTime::None`] if:
+    /// - The resulting date does not exist (for example `month(4)` when day of the month is 31).
+    /// - The value for `month` is invalid.
+    /// - The local time at the resulting date falls in a gap of the time zone, such as when the
+    ///   clocks skip forward.
+    /// - The resulting datetime would be out of range.
+    ///
+    /// Returns [`MappedLocalTime::Ambiguous`] if the local time at the resulting date falls in a
+    /// fold, where the clocks are turned back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, MappedLocalTime, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(3600).unwrap();
+    /// let dt = tz.with_ymd_and_hms(2024, 1, 31, 12, 0, 0).unwrap();
+    /// assert_eq!(dt.with_month_local(3), tz.with_ymd_and_hms(2024, 3, 31, 12, 0, 0));
+    /// assert_eq!(dt.with_month_local(4), MappedLocalTime::None);
+    /// ```
+    #[must_use]
+    pub fn with_month_local(&self, month: u32) -> MappedLocalTime<Self> {
+        map_local_mapped(self, |datetime| datetime.with_month(month))
+    }
+
+    /// Makes a new `DateTime` with the day of month (starting from 1) changed, while keeping the
+    /// same local time.
+    ///
+    /// This works like [`Datelike::with_day`], but the offset is resolved as a
+    /// [`MappedLocalTime`], so an ambiguous local time returns both candidates instead of `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MappedLocalTime::None`] if:
+    /// - The resulting date does not exist (for example `day(31)` in April).
+    /// - The value for `day` is invalid.
+    /// - The local time at the resulting date falls in a gap of the time zone, such as when the
+    ///   clocks skip forward.
+    /// - The resulting datetime would be out of range.
+    ///
+    /// Returns [`MappedLocalTime::Ambiguous`] if the local time at the resulting date falls in a
+    /// fold, where the clocks are turned back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, MappedLocalTime, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(3600).unwrap();
+    /// let dt = tz.with_ymd_and_hms(2024, 4, 1, 12, 0, 0).unwrap();
+    /// assert_eq!(dt.with_day_local(30), tz.with_ymd_and_hms(2024, 4, 30, 12, 0, 0));
+    /// assert_eq!(dt.with_day_local(31), MappedLocalTime::None);
+    /// ```
+    #[must_use]
+    pub fn with_day_local(&self, day: u32) -> MappedLocalTime<Self> {
+        map_local_mapped(self, |datetime| datetime.with_day(day))
+    }
+
+    /// Parses a string from a user-specified format into a `DateTime` in the given time zone.
+    ///
+    /// Unlike [`DateTime::parse_from_str`] the input string does not need to contain an offset.
+    /// The parsed local date and time are interpreted in `tz`. If the string does contain an
+    /// offset, it must match the offset of `tz` at that local time.
+    ///
+    /// See the [`format::strftime` module](crate::format::strftime) for supported format
+    /// sequences.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError`] if the string does not match the format, or if the local date and
+    /// time does not exist in `tz` (in a DST transition gap) or is ambiguous (in a fold), or if
+    /// a parsed offset does not match the offset of `tz`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+    ///
+    /// let dt = DateTime::parse_from_str_with_timezone("2024-01-01 12:00", "%Y-%m-%d %H:%M", &Utc);
+    /// assert_eq!(dt, Ok(Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap()));
+    ///
+    /// let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+    /// let dt = DateTime::parse_from_str_with_timezone("2024-01-01 12:00", "%Y-%m-%d %H:%M", &tz);
+    /// assert_eq!(dt, Ok(tz.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap()));
+    /// ```
+    pub fn parse_from_str_with_timezone(s: &str, fmt: &str, tz: &Tz) -> ParseResult<DateTime<Tz>> {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, StrftimeItems::new(fmt))?;
+        parsed.to_datetime_with_timezone(tz)
+    }
+
     /// The minimum possible `DateTime<Utc>`.
     pub const MIN_UTC: DateTime<Utc> = DateTime { datetime: NaiveDateTime::MIN, offset: Utc };
     /// The maximum possible `DateTime<Utc>`.
@@ -796,7 +1624,8 @@ impl DateTime<Utc> {
     /// # Errors
     ///
     /// Returns `None` if the number of microseconds would be out of range for a `NaiveDateTime`
-    /// (more than ca. 262,000 years away from common era)
+    /// (more than ca. 262,000 years away from common era). An `i64` with microsecond precision
+    /// spans ca. 292,000 years in both directions, so not every value is valid.
     ///
     /// # Example
     ///
@@ -813,6 +1642,8 @@ impl DateTime<Utc> {
     /// let dt = DateTime::from_timestamp_micros(timestamp_micros);
     /// assert!(dt.is_some());
     /// assert_eq!(timestamp_micros, dt.expect("invalid timestamp").timestamp_micros());
+    ///
+    /// assert_eq!(DateTime::from_timestamp_micros(i64::MAX), None);
     /// ```
     #[inline]
     #[must_use]
@@ -822,10 +1653,10 @@ impl DateTime<Utc> {
         Self::from_timestamp(secs, nsecs)
     }
 
-    /// Creates a new [`DateTime<Utc>`] from the number of non-leap microseconds
+    /// Creates a new [`DateTime<Utc>`] from the number of non-leap nanoseconds
     /// since January 1, 1970 0:00:00.000 UTC (aka "UNIX timestamp").
     ///
-    /// This is guaranteed to round-trip with [`timestamp_nanos`](DateTime::timestamp_nanos).
+    /// This is guaranteed to round-trip with [`timestamp_nanos_opt`](DateTime::timestamp_nanos_opt).
     ///
     /// If you need to create a `DateTime` with a [`TimeZone`] different from [`Utc`], use
     /// [`TimeZone::timestamp_nanos`] or [`DateTime::with_timezone`].
@@ -857,10 +1688,56 @@ impl DateTime<Utc> {
         expect(Self::from_timestamp(secs, nsecs), "timestamp in nanos is always in range")
     }
 
+    /// Creates a new [`DateTime<Utc>`] from the number of non-leap nanoseconds
+    /// since January 1, 1970 0:00:00 UTC (aka "UNIX timestamp"), as an `i128`.
+    ///
+    /// The result round-trips with [`timestamp_nanos_i128`](DateTime::timestamp_nanos_i128).
+    /// The reverse only holds for values that are not in a
+    /// [leap second](NaiveTime#leap-second-handling): `timestamp_nanos_i128` counts a leap second
+    /// as part of the next second, and this method never creates a leap second.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the number of nanoseconds would be out of range for a `NaiveDateTime`
+    /// (more than ca. 262,000 years away from common era)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, TimeZone, Utc};
+    ///
+    /// let dt = DateTime::from_timestamp_nanos_i128(-93_724_128_000_000_000_000).unwrap();
+    /// assert_eq!(dt, Utc.with_ymd_and_hms(-1000, 1, 1, 0, 0, 0).unwrap());
+    /// assert_eq!(DateTime::from_timestamp_nanos_i128(i128::MAX), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_timestamp_nanos_i128(nanos: i128) -> Option<Self> {
+        let secs = nanos.div_euclid(1_000_000_000);
+        let nsecs = nanos.rem_euclid(1_000_000_000) as u32;
+        if secs < i64::MIN as i128 || secs > i64::MAX as i128 {
+            return None;
+        }
+        Self::from_timestamp(secs as i64, nsecs)
+    }
+
     /// The Unix Epoch, 1970-01-01 00:00:00 UTC.
     pub const UNIX_EPOCH: Self = Self { datetime: NaiveDateTime::UNIX_EPOCH, offset: Utc };
 }
 
+/// Which endpoints are part of the range checked by [`DateTime::is_between`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Inclusivity {
+    /// Both endpoints are included: `start <= dt <= end`.
+    Inclusive,
+    /// Both endpoints are excluded: `start < dt < end`.
+    Exclusive,
+    /// Only the start is included: `start <= dt < end`.
+    StartInclusive,
+    /// Only the end is included: `start < dt <= end`.
+    EndInclusive,
+}
+
 impl Default for DateTime<Utc> {
     fn default() -> Self {
         Utc.from_utc_datetime(&NaiveDateTime::default())
@@ -949,12 +1826,60 @@ impl From<DateTime<Local>> for DateTime<FixedOffset> {
 }
 
 /// Maps the local datetime to other datetime with given conversion function.
-fn map_local<Tz: TimeZone, F>(dt: &DateTime<Tz>, mut f: F) -> Option<DateTime<Tz>>
+fn map_local<Tz: TimeZone, F>(dt: &DateTime<Tz>, f: F) -> Option<DateTime<Tz>>
+where
+    F: FnMut(NaiveDateTime) -> Option<NaiveDateTime>,
+{
+    map_local_mapped(dt, f).single()
+}
+
+/// Like [`map_local`], but keeps both results if the new local time is ambiguous.
+fn map_local_mapped<Tz: TimeZone, F>(dt: &DateTime<Tz>, mut f: F) -> MappedLocalTime<DateTime<Tz>>
 where
     F: FnMut(NaiveDateTime) -> Option<NaiveDateTime>,
 {
-    f(dt.overflowing_naive_local())
-        .and_then(|datetime| dt.timezone().from_local_datetime(&datetime).single())
+    match f(dt.overflowing_naive_local()) {
+        Some(datetime) => dt.timezone().from_local_datetime(&datetime).and_then(|dt| {
+            Some(dt).filter(|dt| dt >= &DateTime::<Utc>::MIN_UTC && dt <= &DateTime::<Utc>::MAX_UTC)
+        }),
+        None => MappedLocalTime::None,
+    }
+}
+
+/// Returns the first instant of the local `date` in `tz`.
+///
+/// If local midnight falls in a gap, this is the instant of the transition at the end of the gap.
+fn local_day_start<Tz: TimeZone>(tz: &Tz, date: NaiveDate) -> Option<DateTime<Tz>> {
+    let midnight = date.and_time(NaiveTime::MIN);
+    let dt = match tz.from_local_datetime(&midnight) {
+        LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => dt,
+        LocalResult::None => {
+            // Search for the first instant with a local time at or after midnight. Offsets are
+            // less than a day, so it is within a day of midnight interpreted as UTC.
+            let reached = |utc: &NaiveDateTime| {
+                utc.overflowing_add_offset(tz.offset_from_utc_datetime(utc).fix()) >= midnight
+            };
+            let mut before = midnight.checked_sub_signed(TimeDelta::days(1))?;
+            let mut after = midnight.checked_add_signed(TimeDelta::days(1))?;
+            while after - before > TimeDelta::nanoseconds(1) {
+                let mid = before + (after - before) / 2;
+                match reached(&mid) {
+                    true => after = mid,
+                    false => before = mid,
+                }
+            }
+            tz.from_utc_datetime(&after)
+        }
+    };
+    Some(dt).filter(|dt| dt >= &DateTime::<Utc>::MIN_UTC && dt <= &DateTime::<Utc>::MAX_UTC)
+}
+
+/// Returns the last instant of the local `date` in `tz`, one nanosecond before the next day starts.
+fn local_day_end<Tz: TimeZone>(tz: &Tz, date: NaiveDate) -> Option<DateTime<Tz>> {
+    date.succ_opt()
+        .and_then(|next| local_day_start(tz, next))
+        .and_then(|dt| dt.checked_sub_signed(TimeDelta::nanoseconds(1)))
+        .or_else(|| tz.from_local_datetime(&date.and_time(NaiveTime::MAX)).latest())
         .filter(|dt| dt >= &DateTime::<Utc>::MIN_UTC && dt <= &DateTime::<Utc>::MAX_UTC)
 }
 
@@ -972,7 +1897,13 @@ impl DateTime<FixedOffset> {
     ///
     /// - A 2-digit year is interpreted to be a year in 1950-2049.
     /// - The standard allows comments and whitespace between many of the tokens. See [4.3] and
-    ///   [Appendix A.5]
+    ///   [Appendix A.5]. Comments in parentheses and folding whitespace are accepted between any
+    ///   two tokens, and after the time zone. Use [`DateTime::parse_from_rfc2822_strict`] to only
+    ///   accept comments after the time zone.
+    /// - The obsolete time zone names `UT`, `GMT`, `EST`, `EDT`, `CST`, `CDT`, `MST`, `MDT`, `PST`
+    ///   and `PDT` are parsed as their defined offsets.
+    /// - A `-0000` offset, which indicates a time in UTC without information about the local time
+    ///   zone, is parsed as a zero offset.
     /// - Single letter 'military' time zone names are parsed as a `-0000` offset.
     ///   They were defined with the wrong sign in RFC 822 and corrected in RFC 2822. But because
     ///   the meaning is now ambiguous, the standard says they should be be considered as `-0000`
@@ -998,6 +1929,31 @@ impl DateTime<FixedOffset> {
         parsed.to_datetime()
     }
 
+    /// Parses an RFC 2822 date-and-time string into a `DateTime<FixedOffset>` value, without
+    /// accepting comments between the tokens.
+    ///
+    /// This is the same as [`DateTime::parse_from_rfc2822`], except that the tokens may only be
+    /// separated by whitespace, and comments in parentheses are only accepted after the time zone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{DateTime, FixedOffset, TimeZone};
+    /// assert_eq!(
+    ///     DateTime::parse_from_rfc2822_strict("Mon, 1 Jan 2024 00:00:00 GMT (UTC)").unwrap(),
+    ///     FixedOffset::east_opt(0).unwrap().with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+    /// );
+    /// assert!(DateTime::parse_from_rfc2822_strict("Mon, 1 Jan (comment) 2024 00:00:00 GMT").is_err());
+    /// ```
+    pub fn parse_from_rfc2822_strict(s: &str) -> ParseResult<DateTime<FixedOffset>> {
+        let mut parsed = Parsed::new();
+        let (s, _) = parse_rfc2822(&mut parsed, s, false)?;
+        if !s.is_empty() {
+            return Err(TOO_LONG);
+        }
+        parsed.to_datetime()
+    }
+
     /// Parses an RFC 3339 date-and-time string into a `DateTime<FixedOffset>` value.
     ///
     /// Parses all valid RFC 3339 values (as well as the subset of valid ISO 8601 values that are
@@ -1011,6 +1967,21 @@ impl DateTime<FixedOffset> {
     /// instances (rather than periods, ranges, dates, or times). Some valid ISO 8601 values are
     /// also simultaneously valid RFC 3339 values, but not all RFC 3339 values are valid ISO 8601
     /// values (or the other way around).
+    ///
+    /// As allowed by ISO 8601, a comma is accepted in place of the dot before the fractional
+    /// seconds, as in `2024-01-01T00:00:00,5Z`.
+    ///
+    /// The date and time may be separated by `T`, `t` or a space, which RFC 3339 allows
+    /// applications to accept for readability. A zero offset may be written as `Z` or `z`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::DateTime;
+    /// let dt = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z").unwrap();
+    /// assert_eq!(DateTime::parse_from_rfc3339("2024-01-01t00:00:00z"), Ok(dt));
+    /// assert_eq!(DateTime::parse_from_rfc3339("2024-01-01 00:00:00Z"), Ok(dt));
+    /// ```
     pub fn parse_from_rfc3339(s: &str) -> ParseResult<DateTime<FixedOffset>> {
         let mut parsed = Parsed::new();
         let (s, _) = parse_rfc3339(&mut parsed, s)?;
@@ -1020,6 +1991,34 @@ impl DateTime<FixedOffset> {
         parsed.to_datetime()
     }
 
+    /// Parses an ISO 8601 date-and-time string in the basic format, without separators, into a
+    /// `DateTime<FixedOffset>` value.
+    ///
+    /// The expected format is `YYYYMMDDTHHMMSS`, optionally followed by fractional seconds, and
+    /// then an offset of either `Z` or `±HHMM`, such as `20240115T093000Z` or
+    /// `20240115T093000.250+0530`. The date and time fields must have exactly the number of digits
+    /// shown, so the year must be in the range 0000 to 9999, and no white space is accepted. Use
+    /// [`DateTime::parse_from_rfc3339`] for the extended format with separators.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::DateTime;
+    ///
+    /// let basic = DateTime::parse_from_iso8601_basic("20240115T093000.5+0530").unwrap();
+    /// let extended = DateTime::parse_from_rfc3339("2024-01-15T09:30:00.5+05:30").unwrap();
+    /// assert_eq!(basic, extended);
+    /// assert_eq!(basic.offset(), extended.offset());
+    /// ```
+    pub fn parse_from_iso8601_basic(s: &str) -> ParseResult<DateTime<FixedOffset>> {
+        let mut parsed = Parsed::new();
+        let (s, _) = parse_iso8601_basic(&mut parsed, s)?;
+        if !s.is_empty() {
+            return Err(TOO_LONG);
+        }
+        parsed.to_datetime()
+    }
+
     /// Parses a string from a user-specified format into a `DateTime<FixedOffset>` value.
     ///
     /// Note that this method *requires a timezone* in the input string. See
@@ -1091,6 +2090,29 @@ impl DateTime<FixedOffset> {
         let remainder = parse_and_remainder(&mut parsed, s, StrftimeItems::new(fmt))?;
         parsed.to_datetime().map(|d| (d, remainder))
     }
+
+    /// Changes the offset from UTC, keeping the same instant.
+    ///
+    /// The UTC date and time and the [`timestamp`](DateTime::timestamp) are unchanged, only the
+    /// local date and time are shifted to the new offset. This gives the same result as
+    /// `self.with_timezone(&offset)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, FixedOffset, TimeZone, Timelike};
+    ///
+    /// let utc = FixedOffset::east_opt(0).unwrap().with_ymd_and_hms(2024, 3, 1, 22, 0, 0).unwrap();
+    /// let ist = utc.with_offset(FixedOffset::east_opt(5 * 3600 + 1800).unwrap());
+    /// assert_eq!(ist, utc);
+    /// assert_eq!(ist.timestamp(), utc.timestamp());
+    /// assert_eq!((ist.day(), ist.hour(), ist.minute()), (2, 3, 30));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_offset(self, offset: FixedOffset) -> DateTime<FixedOffset> {
+        DateTime { datetime: self.datetime, offset }
+    }
 }
 
 impl<Tz: TimeZone> DateTime<Tz>
@@ -1110,6 +2132,33 @@ where
         DelayedFormat::new_with_offset(Some(local.date()), Some(local.time()), &self.offset, items)
     }
 
+    /// Writes the combined date and time formatted with the specified formatting items into `w`.
+    ///
+    /// This writes directly into `w` without allocating an intermediate `String`, which is useful
+    /// to reuse a buffer. The items can be parsed once with [`StrftimeItems::parse`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use chrono::format::StrftimeItems;
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let items = StrftimeItems::new("%Y-%m-%d %H:%M:%S %z").parse().unwrap();
+    /// let dt = FixedOffset::east_opt(3600).unwrap().with_ymd_and_hms(2024, 1, 15, 9, 30, 0).unwrap();
+    ///
+    /// let mut buf = String::with_capacity(32);
+    /// dt.format_into(&mut buf, &items).unwrap();
+    /// assert_eq!(buf, "2024-01-15 09:30:00 +0100");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn format_into(&self, w: &mut impl fmt::Write, items: &[Item<'_>]) -> fmt::Result {
+        let local = self.overflowing_naive_local();
+        let off = (&self.offset as &dyn fmt::Display, self.offset.fix());
+        write_items(w, Some(local.date()), Some(local.time()), Some(off), items.iter())
+    }
+
     /// Formats the combined date and time per the specified format string.
     ///
     /// See the [`crate::format::strftime`] module for the supported escape sequences.
@@ -1129,6 +2178,66 @@ where
         self.format_with_items(StrftimeItems::new(fmt))
     }
 
+    /// Formats the combined date and time per the specified format string into a `String`,
+    /// returning an error if the format string is invalid.
+    ///
+    /// [`format`](DateTime::format) only detects an invalid format string when the result is
+    /// displayed, where the error causes a panic in `to_string()` or `format!`. This validates
+    /// the format string before formatting, so the error can be handled.
+    ///
+    /// See the [`crate::format::strftime`] module for the supported escape sequences.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] of kind [`BadFormat`](crate::format::ParseErrorKind::BadFormat)
+    /// if the format string contains an invalid or unrecognized formatting specifier.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::ParseErrorKind;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2017, 04, 02, 12, 50, 32).unwrap();
+    /// assert_eq!(dt.try_format("%d/%m/%Y %H:%M"), Ok("02/04/2017 12:50".to_owned()));
+    /// assert_eq!(dt.try_format("%Q").unwrap_err().kind(), ParseErrorKind::BadFormat);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn try_format(&self, fmt: &str) -> Result<String, ParseError> {
+        let items = StrftimeItems::new(fmt).parse()?;
+        let mut result = String::new();
+        self.format_into(&mut result, &items).map_err(|_| BAD_FORMAT)?;
+        Ok(result)
+    }
+
+    /// Formats the combined date and time with a format string that was parsed in advance.
+    ///
+    /// This is equivalent to [`format`](DateTime::format) with the same format string, but
+    /// doesn't parse the format string again for every call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::CompiledFormat;
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let format = CompiledFormat::compile("%d/%m/%Y %H:%M %z").unwrap();
+    /// let tz = FixedOffset::east_opt(3600).unwrap();
+    /// for day in 1..=3 {
+    ///     let dt = tz.with_ymd_and_hms(2017, 4, day, 12, 50, 32).unwrap();
+    ///     assert_eq!(dt.format_compiled(&format).to_string(), format!("0{}/04/2017 12:50 +0100", day));
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    #[must_use]
+    pub fn format_compiled<'a>(
+        &self,
+        format: &'a CompiledFormat,
+    ) -> DelayedFormat<slice::Iter<'a, Item<'static>>> {
+        self.format_with_items(format.items().iter())
+    }
+
     /// Formats the combined date and time with the specified formatting items and locale.
     #[cfg(all(feature = "unstable-locales", feature = "alloc"))]
     #[inline]
@@ -1167,6 +2276,63 @@ where
     ) -> DelayedFormat<StrftimeItems<'a>> {
         self.format_localized_with_items(StrftimeItems::new_with_locale(fmt, locale), locale)
     }
+
+    /// Formats the combined date and time with the specified formatting items, taking the names
+    /// of months and weekdays from a custom [`LocaleTable`].
+    ///
+    /// This makes it possible to format with a locale that chrono does not bundle.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the items can't be formatted, for example because the items contain an
+    /// [`Item::Error`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{LocaleTable, StrftimeItems};
+    /// use chrono::{Month, TimeZone, Utc, Weekday};
+    ///
+    /// struct Esperanto;
+    ///
+    /// impl LocaleTable for Esperanto {
+    ///     fn short_month_name(&self, month: Month) -> &str {
+    ///         &self.long_month_name(month)[..3]
+    ///     }
+    ///     fn long_month_name(&self, month: Month) -> &str {
+    ///         ["januaro", "februaro", "marto", "aprilo", "majo", "junio", "julio", "aŭgusto",
+    ///          "septembro", "oktobro", "novembro", "decembro"][month.number_from_month() as usize - 1]
+    ///     }
+    ///     fn short_weekday_name(&self, weekday: Weekday) -> &str {
+    ///         &self.long_weekday_name(weekday)[..3]
+    ///     }
+    ///     fn long_weekday_name(&self, weekday: Weekday) -> &str {
+    ///         ["lundo", "mardo", "merkredo", "ĵaŭdo", "vendredo", "sabato", "dimanĉo"]
+    ///             [weekday.num_days_from_monday() as usize]
+    ///     }
+    ///     fn am_pm(&self, pm: bool) -> &str {
+    ///         if pm { "ptm" } else { "atm" }
+    ///     }
+    /// }
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2024, 3, 1, 15, 30, 0).unwrap();
+    /// let formatted = dt.format_with_locale_table(StrftimeItems::new("%A %e %B %I:%M %p"), &Esperanto);
+    /// assert_eq!(formatted.unwrap(), "vendredo  1 marto 03:30 ptm");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn format_with_locale_table<'a, I, B>(
+        &self,
+        items: I,
+        table: &dyn LocaleTable,
+    ) -> Result<String, fmt::Error>
+    where
+        I: Iterator<Item = B> + Clone,
+        B: Borrow<Item<'a>>,
+    {
+        let mut result = String::new();
+        self.format_with_items(items).write_to_with_locale_table(&mut result, table)?;
+        Ok(result)
+    }
 }
 
 impl<Tz: TimeZone> Datelike for DateTime<Tz> {
@@ -1212,6 +2378,9 @@ impl<Tz: TimeZone> Datelike for DateTime<Tz> {
     ///
     /// See also the [`NaiveDate::with_year`] method.
     ///
+    /// Use [`DateTime::with_year_local`] to tell a nonexistent local time apart from an ambiguous
+    /// one, and to choose between the candidates of an ambiguous local time.
+    ///
     /// # Errors
     ///
     /// Returns `None` if:
@@ -1233,6 +2402,9 @@ impl<Tz: TimeZone> Datelike for DateTime<Tz> {
     ///
     /// See also the [`NaiveDate::with_month`] method.
     ///
+    /// Use [`DateTime::with_month_local`] to tell a nonexistent local time apart from an ambiguous
+    /// one, and to choose between the candidates of an ambiguous local time.
+    ///
     /// # Errors
     ///
     /// Returns `None` if:
@@ -1265,6 +2437,9 @@ impl<Tz: TimeZone> Datelike for DateTime<Tz> {
     ///
     /// See also the [`NaiveDate::with_day`] method.
     ///
+    /// Use [`DateTime::with_day_local`] to tell a nonexistent local time apart from an ambiguous
+    /// one, and to choose between the candidates of an ambiguous local time.
+    ///
     /// # Errors
     ///
     /// Returns `None` if:
@@ -1409,6 +2584,22 @@ impl<Tz: TimeZone> Timelike for DateTime<Tz> {
 }
 
 impl<Tz: TimeZone, Tz2: TimeZone> PartialEq<DateTime<Tz2>> for DateTime<Tz> {
+    /// Compare two DateTimes based on their true time, ignoring time zones
+    ///
+    /// Two `DateTime`s are equal if they denote the same instant, even if their time zones or
+    /// offsets differ.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    ///
+    /// let utc = Utc.with_ymd_and_hms(2015, 5, 15, 2, 0, 0).unwrap();
+    /// let fixed = FixedOffset::east_opt(9 * 3600).unwrap().with_ymd_and_hms(2015, 5, 15, 11, 0, 0).unwrap();
+    ///
+    /// assert_eq!(utc, fixed);
+    /// assert_ne!(utc.naive_local(), fixed.naive_local());
+    /// ```
     fn eq(&self, other: &DateTime<Tz2>) -> bool {
         self.datetime == other.datetime
     }
@@ -1858,11 +3049,18 @@ impl From<SystemTime> for DateTime<Local> {
     }
 }
 
+/// Converts a `DateTime` to a `SystemTime`.
+///
+/// `SystemTime` has no representation for leap seconds. A `DateTime` during a leap second (with a
+/// nanosecond value of 1,000,000,000 or more) is clamped to the last nanosecond of the preceding
+/// second, so `23:59:60.5` becomes `23:59:59.999999999`. This keeps the conversion monotonic,
+/// and the result is never later than the start of the next second.
 #[cfg(feature = "std")]
 impl<Tz: TimeZone> From<DateTime<Tz>> for SystemTime {
     fn from(dt: DateTime<Tz>) -> SystemTime {
         let sec = dt.timestamp();
-        let nsec = dt.timestamp_subsec_nanos();
+        // Clamp a leap second into the preceding second.
+        let nsec = dt.timestamp_subsec_nanos().min(999_999_999);
         if sec < 0 {
             // unlikely but should be handled
             UNIX_EPOCH - Duration::new(-sec as u64, 0) + Duration::new(0, nsec)
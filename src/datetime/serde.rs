@@ -6,6 +6,7 @@ use crate::format::{write_rfc3339, SecondsFormat};
 #[cfg(feature = "clock")]
 use crate::offset::Local;
 use crate::offset::{FixedOffset, Offset, TimeZone, Utc};
+use crate::serde::invalid_ts;
 
 #[doc(hidden)]
 #[derive(Debug)]
@@ -1206,6 +1207,462 @@ pub mod ts_seconds_option {
     }
 }
 
+/// Converts a number of seconds since the epoch with a fractional part to a `DateTime`.
+///
+/// Rounds to the nearest nanosecond.
+fn datetime_from_f64(value: f64) -> Option<DateTime<Utc>> {
+    // Also rejects NaN. The range of `DateTime` is about ±8.2e12 seconds.
+    if !(value > -1e17 && value < 1e17) {
+        return None;
+    }
+    let mut secs = value as i64;
+    if secs as f64 > value {
+        secs -= 1;
+    }
+    let mut nanos = ((value - secs as f64) * 1e9 + 0.5) as u32;
+    if nanos >= 1_000_000_000 {
+        secs += 1;
+        nanos -= 1_000_000_000;
+    }
+    DateTime::from_timestamp(secs, nanos)
+}
+
+/// Converts a `DateTime` to a number of seconds since the epoch with a fractional part.
+fn datetime_to_f64(dt: &DateTime<Utc>) -> f64 {
+    dt.timestamp() as f64 + f64::from(dt.timestamp_subsec_nanos()) / 1e9
+}
+
+/// Formats a `DateTime` as a decimal number of seconds since the epoch, such as
+/// `1609459200.123456789`.
+///
+/// Trailing zeros of the fraction are omitted, and so is the decimal point if the fraction is zero.
+struct DecimalTimestamp<'a>(&'a DateTime<Utc>);
+
+impl fmt::Display for DecimalTimestamp<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // A leap second is folded into the next second, like `timestamp_nanos_i128` does.
+        let total = self.0.timestamp_nanos_i128();
+        let sign = if total < 0 { "-" } else { "" };
+        let secs = total.unsigned_abs() / 1_000_000_000;
+        let mut nanos = (total.unsigned_abs() % 1_000_000_000) as u32;
+        write!(f, "{}{}", sign, secs)?;
+        if nanos == 0 {
+            return Ok(());
+        }
+        let mut width = 9;
+        while nanos % 10 == 0 {
+            nanos /= 10;
+            width -= 1;
+        }
+        write!(f, ".{:0width$}", nanos, width = width)
+    }
+}
+
+/// Parses a decimal number of seconds since the epoch, with at most 9 fractional digits.
+fn datetime_from_decimal_str(s: &str) -> Option<DateTime<Utc>> {
+    let (negative, s) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (int, frac) = match s.find('.') {
+        Some(i) => (&s[..i], Some(&s[i + 1..])),
+        None => (s, None),
+    };
+    if int.is_empty() || !int.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let mut secs = int.parse::<i64>().ok()?;
+    let mut nanos = 0;
+    if let Some(frac) = frac {
+        if frac.is_empty() || frac.len() > 9 || !frac.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        nanos = frac.parse::<u32>().ok()? * 10u32.pow(9 - frac.len() as u32);
+    }
+    if negative {
+        secs = -secs;
+        if nanos > 0 {
+            secs = secs.checked_sub(1)?;
+            nanos = 1_000_000_000 - nanos;
+        }
+    }
+    DateTime::from_timestamp(secs, nanos)
+}
+
+struct SecondsF64TimestampVisitor;
+
+impl<'de> de::Visitor<'de> for SecondsF64TimestampVisitor {
+    type Value = DateTime<Utc>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a unix timestamp in fractional seconds")
+    }
+
+    /// Deserialize a timestamp in fractional seconds since the epoch
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        datetime_from_f64(value).ok_or_else(|| invalid_ts(value))
+    }
+
+    /// Deserialize a timestamp in seconds since the epoch
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        DateTime::from_timestamp(value, 0).ok_or_else(|| invalid_ts(value))
+    }
+
+    /// Deserialize a timestamp in seconds since the epoch
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        i64::try_from(value)
+            .ok()
+            .and_then(|value| DateTime::from_timestamp(value, 0))
+            .ok_or_else(|| invalid_ts(value))
+    }
+}
+
+struct SecondsDecimalStrTimestampVisitor;
+
+impl<'de> de::Visitor<'de> for SecondsDecimalStrTimestampVisitor {
+    type Value = DateTime<Utc>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a unix timestamp in seconds as a decimal string")
+    }
+
+    /// Deserialize a timestamp in decimal seconds since the epoch
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        datetime_from_decimal_str(value).ok_or_else(|| invalid_ts(value))
+    }
+}
+
+/// Ser/de to/from timestamps in seconds with a fractional part, as an `f64`
+///
+/// Intended for use with `serde`'s `with` attribute.
+///
+/// An `f64` has 53 bits of precision, so for current dates the timestamp is only accurate to
+/// about a microsecond, and the nanoseconds of a `DateTime` don't survive a round-trip. Use
+/// [`ts_seconds_decimal_str`](crate::serde::ts_seconds_decimal_str) or
+/// [`ts_nanoseconds`](crate::serde::ts_nanoseconds) if the value must be exact.
+///
+/// Deserializing also accepts integers, and rounds to the nearest nanosecond.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{DateTime, Utc, NaiveDate};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::ts_seconds_f64;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "ts_seconds_f64")]
+///     time: DateTime<Utc>,
+/// }
+///
+/// let time = NaiveDate::from_ymd_opt(2018, 5, 17)
+///     .unwrap()
+///     .and_hms_milli_opt(02, 04, 59, 500)
+///     .unwrap()
+///     .and_utc();
+/// let my_s = S { time: time.clone() };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":1526522699.5}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, time);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod ts_seconds_f64 {
+    use serde::{de, ser};
+
+    use super::{datetime_to_f64, SecondsF64TimestampVisitor};
+    use crate::{DateTime, Utc};
+
+    /// Serialize a UTC datetime into an `f64` number of seconds since the epoch
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    pub fn serialize<S>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_f64(datetime_to_f64(dt))
+    }
+
+    /// Deserialize a `DateTime` from an `f64` seconds timestamp
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    pub fn deserialize<'de, D>(d: D) -> Result<DateTime<Utc>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_f64(SecondsF64TimestampVisitor)
+    }
+}
+
+/// Ser/de to/from optional timestamps in seconds with a fractional part, as an `f64`
+///
+/// Intended for use with `serde`'s `with` attribute.
+///
+/// See [`ts_seconds_f64`](crate::serde::ts_seconds_f64) for the precision caveats.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{DateTime, Utc};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::ts_seconds_f64_option;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "ts_seconds_f64_option")]
+///     time: Option<DateTime<Utc>>,
+/// }
+///
+/// let time = Some(DateTime::from_timestamp(1526522699, 250_000_000).unwrap());
+/// let my_s = S { time: time.clone() };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":1526522699.25}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, time);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod ts_seconds_f64_option {
+    use core::fmt;
+    use serde::{de, ser};
+
+    use super::{datetime_to_f64, SecondsF64TimestampVisitor};
+    use crate::{DateTime, Utc};
+
+    /// Serialize a UTC datetime into an `f64` number of seconds since the epoch or none
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    pub fn serialize<S>(opt: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        match *opt {
+            Some(ref dt) => serializer.serialize_some(&datetime_to_f64(dt)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize a `DateTime` from an `f64` seconds timestamp or none
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    pub fn deserialize<'de, D>(d: D) -> Result<Option<DateTime<Utc>>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_option(OptionSecondsF64TimestampVisitor)
+    }
+
+    struct OptionSecondsF64TimestampVisitor;
+
+    impl<'de> de::Visitor<'de> for OptionSecondsF64TimestampVisitor {
+        type Value = Option<DateTime<Utc>>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a unix timestamp in fractional seconds or none")
+        }
+
+        /// Deserialize a timestamp in fractional seconds since the epoch
+        fn visit_some<D>(self, d: D) -> Result<Self::Value, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_f64(SecondsF64TimestampVisitor).map(Some)
+        }
+
+        /// Deserialize a timestamp in fractional seconds since the epoch
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+
+        /// Deserialize a timestamp in fractional seconds since the epoch
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+    }
+}
+
+/// Ser/de to/from timestamps in seconds with a fractional part, as a decimal string
+///
+/// Intended for use with `serde`'s `with` attribute.
+///
+/// The timestamp is serialized as a string such as `"1609459200.123456789"`, so unlike
+/// [`ts_seconds_f64`](crate::serde::ts_seconds_f64) no precision is lost. Trailing zeros of
+/// the fraction are omitted. Deserializing accepts up to 9 fractional digits.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{DateTime, Utc, NaiveDate};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::ts_seconds_decimal_str;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "ts_seconds_decimal_str")]
+///     time: DateTime<Utc>,
+/// }
+///
+/// let time = NaiveDate::from_ymd_opt(2018, 5, 17)
+///     .unwrap()
+///     .and_hms_nano_opt(02, 04, 59, 918355733)
+///     .unwrap()
+///     .and_utc();
+/// let my_s = S { time: time.clone() };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":"1526522699.918355733"}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, time);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod ts_seconds_decimal_str {
+    use serde::{de, ser};
+
+    use super::{DecimalTimestamp, SecondsDecimalStrTimestampVisitor};
+    use crate::{DateTime, Utc};
+
+    /// Serialize a UTC datetime into a decimal string of seconds since the epoch
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    pub fn serialize<S>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.collect_str(&DecimalTimestamp(dt))
+    }
+
+    /// Deserialize a `DateTime` from a decimal string of seconds since the epoch
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    pub fn deserialize<'de, D>(d: D) -> Result<DateTime<Utc>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_str(SecondsDecimalStrTimestampVisitor)
+    }
+}
+
+/// Ser/de to/from optional timestamps in seconds with a fractional part, as a decimal string
+///
+/// Intended for use with `serde`'s `with` attribute.
+///
+/// See [`ts_seconds_decimal_str`](crate::serde::ts_seconds_decimal_str) for the format.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{DateTime, Utc};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::ts_seconds_decimal_str_option;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "ts_seconds_decimal_str_option")]
+///     time: Option<DateTime<Utc>>,
+/// }
+///
+/// let time = Some(DateTime::from_timestamp(-1, 500_000_000).unwrap());
+/// let my_s = S { time: time.clone() };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":"-0.5"}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, time);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod ts_seconds_decimal_str_option {
+    use core::fmt;
+    use serde::{de, ser};
+
+    use super::{DecimalTimestamp, SecondsDecimalStrTimestampVisitor};
+    use crate::{DateTime, Utc};
+
+    /// Serialize a UTC datetime into a decimal string of seconds since the epoch or none
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    pub fn serialize<S>(opt: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        struct Wrapper<'a>(&'a DateTime<Utc>);
+
+        impl ser::Serialize for Wrapper<'_> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ser::Serializer,
+            {
+                serializer.collect_str(&DecimalTimestamp(self.0))
+            }
+        }
+
+        match *opt {
+            Some(ref dt) => serializer.serialize_some(&Wrapper(dt)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize a `DateTime` from a decimal string of seconds since the epoch or none
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    pub fn deserialize<'de, D>(d: D) -> Result<Option<DateTime<Utc>>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_option(OptionSecondsDecimalStrTimestampVisitor)
+    }
+
+    struct OptionSecondsDecimalStrTimestampVisitor;
+
+    impl<'de> de::Visitor<'de> for OptionSecondsDecimalStrTimestampVisitor {
+        type Value = Option<DateTime<Utc>>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a unix timestamp in seconds as a decimal string or none")
+        }
+
+        /// Deserialize a timestamp in decimal seconds since the epoch
+        fn visit_some<D>(self, d: D) -> Result<Self::Value, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_str(SecondsDecimalStrTimestampVisitor).map(Some)
+        }
+
+        /// Deserialize a timestamp in decimal seconds since the epoch
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+
+        /// Deserialize a timestamp in decimal seconds since the epoch
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(feature = "clock")]
@@ -1292,6 +1749,155 @@ mod tests {
         assert!(serde_json::from_str::<DateTime<FixedOffset>>(r#""2014-07-32T12:34:06Z""#).is_err());
     }
 
+    #[test]
+    fn test_serde_ts_seconds_decimal_str() {
+        use super::ts_seconds_decimal_str;
+        use serde_derive::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct S {
+            #[serde(with = "ts_seconds_decimal_str")]
+            time: DateTime<Utc>,
+        }
+
+        for (secs, nanos, expected) in [
+            (1_609_459_200, 123_456_789, "1609459200.123456789"),
+            (1_609_459_200, 0, "1609459200"),
+            (1_609_459_200, 100_000_000, "1609459200.1"),
+            (0, 1, "0.000000001"),
+            (0, 0, "0"),
+            (-1, 999_999_999, "-0.000000001"),
+            (-1, 500_000_000, "-0.5"),
+            (-1, 0, "-1"),
+            (-1_609_459_201, 876_543_211, "-1609459200.123456789"),
+        ] {
+            let time = DateTime::from_timestamp(secs, nanos).unwrap();
+            let json = serde_json::to_string(&S { time }).unwrap();
+            assert_eq!(json, format!(r#"{{"time":"{}"}}"#, expected));
+            assert_eq!(serde_json::from_str::<S>(&json).unwrap(), S { time });
+        }
+
+        // leap seconds are written as the following second
+        for (secs, nanos, expected) in [
+            (59, 1_500_000_000, "60.5"),
+            (59, 1_000_000_000, "60"),
+            (-1, 1_500_000_000, "0.5"),
+            (-61, 1_250_000_000, "-59.75"),
+        ] {
+            let time = DateTime::from_timestamp(secs, nanos).unwrap();
+            let json = serde_json::to_string(&S { time }).unwrap();
+            assert_eq!(json, format!(r#"{{"time":"{}"}}"#, expected));
+        }
+
+        for (s, secs, nanos) in
+            [("+1.5", 1, 500_000_000), ("1.000", 1, 0), ("-0", 0, 0), ("-0.0", 0, 0)]
+        {
+            let decoded: S = serde_json::from_str(&format!(r#"{{"time":"{}"}}"#, s)).unwrap();
+            assert_eq!(decoded.time, DateTime::from_timestamp(secs, nanos).unwrap(), "{}", s);
+        }
+
+        for s in [
+            "",
+            "-",
+            ".5",
+            "1.",
+            "1.1234567890",
+            "1e3",
+            " 1",
+            "1 ",
+            "--1",
+            "1.-5",
+            "9999999999999999",
+        ] {
+            let json = format!(r#"{{"time":"{}"}}"#, s);
+            assert!(serde_json::from_str::<S>(&json).is_err(), "{}", s);
+        }
+        assert!(serde_json::from_str::<S>(r#"{"time":1}"#).is_err());
+
+        let max = DateTime::<Utc>::MAX_UTC;
+        let min = DateTime::<Utc>::MIN_UTC;
+        for time in [max, min] {
+            let json = serde_json::to_string(&S { time }).unwrap();
+            assert_eq!(serde_json::from_str::<S>(&json).unwrap(), S { time });
+        }
+    }
+
+    #[test]
+    fn test_serde_ts_seconds_decimal_str_option() {
+        use super::ts_seconds_decimal_str_option;
+        use serde_derive::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct S {
+            #[serde(with = "ts_seconds_decimal_str_option")]
+            time: Option<DateTime<Utc>>,
+        }
+
+        let time = DateTime::from_timestamp(1_609_459_200, 123_456_789);
+        let json = serde_json::to_string(&S { time }).unwrap();
+        assert_eq!(json, r#"{"time":"1609459200.123456789"}"#);
+        assert_eq!(serde_json::from_str::<S>(&json).unwrap(), S { time });
+
+        let json = serde_json::to_string(&S { time: None }).unwrap();
+        assert_eq!(json, r#"{"time":null}"#);
+        assert_eq!(serde_json::from_str::<S>(&json).unwrap(), S { time: None });
+    }
+
+    #[test]
+    fn test_serde_ts_seconds_f64() {
+        use super::{ts_seconds_f64, ts_seconds_f64_option};
+        use serde_derive::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct S {
+            #[serde(with = "ts_seconds_f64")]
+            time: DateTime<Utc>,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct O {
+            #[serde(with = "ts_seconds_f64_option")]
+            time: Option<DateTime<Utc>>,
+        }
+
+        let time = DateTime::from_timestamp(1_609_459_200, 123_456_789).unwrap();
+        let json = serde_json::to_string(&S { time }).unwrap();
+        let decoded: S = serde_json::from_str(&json).unwrap();
+        // An `f64` can't represent the nanoseconds exactly.
+        let error = decoded.time.signed_duration_since(time).num_nanoseconds().unwrap();
+        assert!(error.abs() < 1_000, "{}", error);
+
+        // exactly representable values round-trip
+        for (secs, nanos, expected) in [
+            (1_609_459_200, 500_000_000, "1609459200.5"),
+            (0, 0, "0.0"),
+            (-1, 750_000_000, "-0.25"),
+        ] {
+            let time = DateTime::from_timestamp(secs, nanos).unwrap();
+            let json = serde_json::to_string(&S { time }).unwrap();
+            assert_eq!(json, format!(r#"{{"time":{}}}"#, expected));
+            assert_eq!(serde_json::from_str::<S>(&json).unwrap(), S { time });
+        }
+
+        let decoded: S = serde_json::from_str(r#"{"time":1609459200}"#).unwrap();
+        assert_eq!(decoded.time, DateTime::from_timestamp(1_609_459_200, 0).unwrap());
+        let decoded: S = serde_json::from_str(r#"{"time":-1.5}"#).unwrap();
+        assert_eq!(decoded.time, DateTime::from_timestamp(-2, 500_000_000).unwrap());
+        let decoded: S = serde_json::from_str(r#"{"time":0.9999999999}"#).unwrap();
+        assert_eq!(decoded.time, DateTime::from_timestamp(1, 0).unwrap());
+        assert!(serde_json::from_str::<S>(r#"{"time":1e20}"#).is_err());
+        assert!(serde_json::from_str::<S>(r#"{"time":-1e20}"#).is_err());
+        assert!(serde_json::from_str::<S>(r#"{"time":"1.5"}"#).is_err());
+
+        let json = serde_json::to_string(&O { time: Some(time) }).unwrap();
+        let decoded: O = serde_json::from_str(&json).unwrap();
+        let error = decoded.time.unwrap().signed_duration_since(time).num_nanoseconds().unwrap();
+        assert!(error.abs() < 1_000, "{}", error);
+        let json = serde_json::to_string(&O { time: None }).unwrap();
+        assert_eq!(json, r#"{"time":null}"#);
+        assert_eq!(serde_json::from_str::<O>(&json).unwrap(), O { time: None });
+    }
+
     #[test]
     fn test_serde_bincode() {
         // Bincode is relevant to test separately from JSON because
@@ -1,126 +1,50 @@
-use super::DateTime;
+use super::{DateTime, Inclusivity};
+use crate::format::ParseErrorKind;
 use crate::naive::{NaiveDate, NaiveTime};
-use crate::offset::{FixedOffset, TimeZone, Utc};
+use crate::offset::dst_tester::{DstTester, TransitionTester};
 #[cfg(feature = "clock")]
-use crate::offset::{Local, Offset};
-use crate::{Datelike, Days, MappedLocalTime, Months, NaiveDateTime, TimeDelta, Timelike, Weekday};
+use crate::offset::Local;
+use crate::offset::{FixedOffset, Offset, TimeZone, Utc};
+use crate::{
+    Datelike, Days, DurationRound, MappedLocalTime, Months, NaiveDateTime, RoundingError,
+    SubsecRound, TimeDelta, Timelike, Weekday,
+};
+use core::cmp::Ordering;
 
-#[derive(Clone)]
-struct DstTester;
-
-impl DstTester {
-    fn winter_offset() -> FixedOffset {
-        FixedOffset::east_opt(8 * 60 * 60).unwrap()
-    }
-    fn summer_offset() -> FixedOffset {
-        FixedOffset::east_opt(9 * 60 * 60).unwrap()
-    }
-
-    const TO_WINTER_MONTH_DAY: (u32, u32) = (4, 15);
-    const TO_SUMMER_MONTH_DAY: (u32, u32) = (9, 15);
-
-    fn transition_start_local() -> NaiveTime {
-        NaiveTime::from_hms_opt(2, 0, 0).unwrap()
-    }
-}
-
-impl TimeZone for DstTester {
-    type Offset = FixedOffset;
-
-    fn from_offset(_: &Self::Offset) -> Self {
-        DstTester
-    }
-
-    fn offset_from_local_date(&self, _: &NaiveDate) -> crate::MappedLocalTime<Self::Offset> {
-        unimplemented!()
-    }
-
-    fn offset_from_local_datetime(
-        &self,
-        local: &NaiveDateTime,
-    ) -> crate::MappedLocalTime<Self::Offset> {
-        let local_to_winter_transition_start = NaiveDate::from_ymd_opt(
-            local.year(),
-            DstTester::TO_WINTER_MONTH_DAY.0,
-            DstTester::TO_WINTER_MONTH_DAY.1,
-        )
-        .unwrap()
-        .and_time(DstTester::transition_start_local());
-
-        let local_to_winter_transition_end = NaiveDate::from_ymd_opt(
-            local.year(),
-            DstTester::TO_WINTER_MONTH_DAY.0,
-            DstTester::TO_WINTER_MONTH_DAY.1,
-        )
-        .unwrap()
-        .and_time(DstTester::transition_start_local() - TimeDelta::try_hours(1).unwrap());
-
-        let local_to_summer_transition_start = NaiveDate::from_ymd_opt(
-            local.year(),
-            DstTester::TO_SUMMER_MONTH_DAY.0,
-            DstTester::TO_SUMMER_MONTH_DAY.1,
-        )
-        .unwrap()
-        .and_time(DstTester::transition_start_local());
-
-        let local_to_summer_transition_end = NaiveDate::from_ymd_opt(
-            local.year(),
-            DstTester::TO_SUMMER_MONTH_DAY.0,
-            DstTester::TO_SUMMER_MONTH_DAY.1,
-        )
-        .unwrap()
-        .and_time(DstTester::transition_start_local() + TimeDelta::try_hours(1).unwrap());
-
-        if *local < local_to_winter_transition_end || *local >= local_to_summer_transition_end {
-            MappedLocalTime::Single(DstTester::summer_offset())
-        } else if *local >= local_to_winter_transition_start
-            && *local < local_to_summer_transition_start
-        {
-            MappedLocalTime::Single(DstTester::winter_offset())
-        } else if *local >= local_to_winter_transition_end
-            && *local < local_to_winter_transition_start
-        {
-            MappedLocalTime::Ambiguous(DstTester::winter_offset(), DstTester::summer_offset())
-        } else if *local >= local_to_summer_transition_start
-            && *local < local_to_summer_transition_end
-        {
-            MappedLocalTime::None
-        } else {
-            panic!("Unexpected local time {}", local)
-        }
-    }
-
-    fn offset_from_utc_date(&self, _: &NaiveDate) -> Self::Offset {
-        unimplemented!()
-    }
+#[test]
+fn test_round_to_nearest_dst() {
+    // The transition to winter time happens at 2023-04-14 17:00:00 UTC, when the local time jumps
+    // back from 02:00 to 01:00.
+    let interval = TimeDelta::try_hours(1).unwrap();
+    let summer = DstTester.from_utc_datetime(
+        &NaiveDate::from_ymd_opt(2023, 4, 14).unwrap().and_hms_opt(16, 40, 0).unwrap(),
+    );
+    assert_eq!(summer.offset(), &DstTester::summer_offset());
+    let rounded = summer.round_to_nearest(interval).unwrap();
+    assert_eq!(rounded.to_string(), "2023-04-15 01:00:00 +08:00");
+    assert_eq!(rounded.signed_duration_since(&summer), TimeDelta::try_minutes(20).unwrap());
+    assert_eq!(
+        summer.floor_to_nearest(interval).unwrap().to_string(),
+        "2023-04-15 01:00:00 +09:00"
+    );
 
-    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> Self::Offset {
-        let utc_to_winter_transition = NaiveDate::from_ymd_opt(
-            utc.year(),
-            DstTester::TO_WINTER_MONTH_DAY.0,
-            DstTester::TO_WINTER_MONTH_DAY.1,
-        )
-        .unwrap()
-        .and_time(DstTester::transition_start_local())
-            - DstTester::summer_offset();
+    let winter = DstTester.from_utc_datetime(
+        &NaiveDate::from_ymd_opt(2023, 4, 14).unwrap().and_hms_opt(17, 40, 0).unwrap(),
+    );
+    assert_eq!(winter.offset(), &DstTester::winter_offset());
+    assert_eq!(
+        winter.round_to_nearest(interval).unwrap().to_string(),
+        "2023-04-15 02:00:00 +08:00"
+    );
+    assert_eq!(
+        winter.floor_to_nearest(interval).unwrap().to_string(),
+        "2023-04-15 01:00:00 +08:00"
+    );
 
-        let utc_to_summer_transition = NaiveDate::from_ymd_opt(
-            utc.year(),
-            DstTester::TO_SUMMER_MONTH_DAY.0,
-            DstTester::TO_SUMMER_MONTH_DAY.1,
-        )
-        .unwrap()
-        .and_time(DstTester::transition_start_local())
-            - DstTester::winter_offset();
-
-        if *utc < utc_to_winter_transition || *utc >= utc_to_summer_transition {
-            DstTester::summer_offset()
-        } else if *utc >= utc_to_winter_transition && *utc < utc_to_summer_transition {
-            DstTester::winter_offset()
-        } else {
-            panic!("Unexpected utc time {}", utc)
-        }
-    }
+    // Across the gap in the transition to summer time, 02:00 to 03:00 local time doesn't exist.
+    let before = DstTester.with_ymd_and_hms(2023, 9, 15, 1, 50, 0).single().unwrap();
+    let interval = TimeDelta::try_minutes(15).unwrap();
+    assert_eq!(before.ceil_to_nearest(interval).unwrap().to_string(), "2023-09-15 03:00:00 +09:00");
 }
 
 #[test]
@@ -247,6 +171,106 @@ fn test_datetime_from_timestamp_nanos() {
     }
 }
 
+#[test]
+fn test_datetime_from_timestamp_micros_nanos_limits() {
+    // Microseconds can represent the whole range of `DateTime`, but not every `i64` is valid.
+    let max_micros = DateTime::<Utc>::MAX_UTC.timestamp_micros();
+    let min_micros = DateTime::<Utc>::MIN_UTC.timestamp_micros();
+    let max = DateTime::from_timestamp_micros(max_micros).unwrap();
+    assert_eq!(max.timestamp_micros(), max_micros);
+    assert_eq!(max, DateTime::<Utc>::MAX_UTC.trunc_subsecs(6));
+    assert_eq!(DateTime::from_timestamp_micros(max_micros + 1), None);
+    assert_eq!(DateTime::from_timestamp_micros(min_micros), Some(DateTime::<Utc>::MIN_UTC));
+    assert_eq!(DateTime::from_timestamp_micros(min_micros - 1), None);
+
+    // Every `i64` number of nanoseconds is valid, and round-trips.
+    for nanos in [i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX - 1, i64::MAX] {
+        let dt = DateTime::from_timestamp_nanos(nanos);
+        assert_eq!(dt.timestamp_nanos_opt(), Some(nanos));
+        assert_eq!(dt.timestamp_nanos_i128(), i128::from(nanos));
+        assert_eq!(
+            DateTime::from_timestamp_micros(dt.timestamp_micros()),
+            Some(dt.trunc_subsecs(6))
+        );
+    }
+}
+
+#[test]
+fn test_datetime_timestamp_nanos_i128() {
+    let ymd_hms_nano = |y, m, d, h, n, s, nano| {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_nano_opt(h, n, s, nano).unwrap()
+    };
+
+    let cases = [
+        (ymd_hms_nano(1970, 1, 1, 0, 0, 0, 0), 0),
+        (ymd_hms_nano(1969, 12, 31, 23, 59, 59, 999_999_999), -1),
+        (ymd_hms_nano(2022, 9, 11, 18, 34, 48, 123_456_789), 1_662_921_288_123_456_789),
+        (ymd_hms_nano(3000, 1, 1, 0, 0, 0, 1), 32_503_680_000_000_000_001),
+        (ymd_hms_nano(-1000, 1, 1, 0, 0, 0, 0), -93_724_128_000_000_000_000),
+        (ymd_hms_nano(-1000, 1, 1, 0, 0, 0, 999_999_999), -93_724_127_999_000_000_001),
+    ];
+    for (naive, nanos) in cases {
+        let datetime = naive.and_utc();
+        assert_eq!(datetime.timestamp_nanos_i128(), nanos);
+        assert_eq!(naive.timestamp_nanos_i128(), nanos);
+        assert_eq!(DateTime::from_timestamp_nanos_i128(nanos), Some(datetime));
+        if let Some(nanos_i64) = datetime.timestamp_nanos_opt() {
+            assert_eq!(i128::from(nanos_i64), nanos);
+        }
+    }
+
+    // the instant is independent of the offset
+    let est = FixedOffset::west_opt(5 * 60 * 60).unwrap();
+    let datetime = est.with_ymd_and_hms(3000, 1, 1, 0, 0, 0).unwrap();
+    assert_eq!(datetime.timestamp_nanos_i128(), 32_503_698_000_000_000_000);
+
+    // the full range of `DateTime` round-trips
+    for datetime in [DateTime::<Utc>::MIN_UTC, DateTime::<Utc>::MAX_UTC] {
+        let nanos = datetime.timestamp_nanos_i128();
+        assert_eq!(DateTime::from_timestamp_nanos_i128(nanos), Some(datetime));
+        assert_eq!(DateTime::from_timestamp_nanos_i128(nanos + nanos.signum()), None);
+    }
+    assert_eq!(DateTime::from_timestamp_nanos_i128(i128::MIN), None);
+    assert_eq!(DateTime::from_timestamp_nanos_i128(i128::MAX), None);
+
+    // a leap second is counted as the next second, so it doesn't round-trip
+    let leap = NaiveDate::from_ymd_opt(2016, 12, 31)
+        .unwrap()
+        .and_hms_nano_opt(23, 59, 59, 1_500_000_000)
+        .unwrap()
+        .and_utc();
+    let nanos = leap.timestamp_nanos_i128();
+    assert_eq!(nanos, 1_483_228_800_500_000_000);
+    let datetime = DateTime::from_timestamp_nanos_i128(nanos).unwrap();
+    assert_ne!(datetime, leap);
+    assert_eq!(datetime.timestamp_nanos_i128(), nanos);
+}
+
+#[test]
+fn test_datetime_timestamp_micros_i128() {
+    let ymd_hms_nano = |y, m, d, h, n, s, nano| {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_nano_opt(h, n, s, nano).unwrap().and_utc()
+    };
+
+    let datetime = ymd_hms_nano(2022, 9, 11, 18, 34, 48, 123_456_789);
+    assert_eq!(datetime.timestamp_subsec_millis(), 123);
+    assert_eq!(datetime.timestamp_subsec_micros(), 123_456);
+    assert_eq!(datetime.timestamp_subsec_nanos(), 123_456_789);
+    assert_eq!(datetime.timestamp_micros_i128(), 1_662_921_288_123_456);
+    assert_eq!(datetime.timestamp_micros_i128(), i128::from(datetime.timestamp_micros()));
+
+    let datetime = ymd_hms_nano(3000, 1, 1, 0, 0, 0, 123_456_789);
+    assert_eq!(datetime.timestamp_micros_i128(), 32_503_680_000_123_456);
+    assert_eq!(datetime.timestamp_micros_i128(), datetime.timestamp_nanos_i128() / 1_000);
+
+    let datetime = ymd_hms_nano(1969, 12, 31, 23, 59, 59, 999_999_999);
+    assert_eq!(datetime.timestamp_micros_i128(), -1);
+
+    for datetime in [DateTime::<Utc>::MIN_UTC, DateTime::<Utc>::MAX_UTC] {
+        assert_eq!(datetime.timestamp_micros_i128(), i128::from(datetime.timestamp_micros()));
+    }
+}
+
 #[test]
 fn test_datetime_from_timestamp() {
     let from_timestamp = |secs| DateTime::from_timestamp(secs, 0);
@@ -311,6 +335,522 @@ fn test_nanosecond_range() {
     assert!(beyond_max.timestamp_nanos_opt().is_none());
 }
 
+#[test]
+fn test_datetime_beginning_end_of() {
+    let dt = Utc.with_ymd_and_hms(2024, 5, 15, 12, 34, 56).unwrap();
+    let ymdhms_nano = |y, m, d, h, n, s, nano| {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_nano_opt(h, n, s, nano).unwrap().and_utc()
+    };
+    let start = |y, m, d| ymdhms_nano(y, m, d, 0, 0, 0, 0);
+    let end = |y, m, d| ymdhms_nano(y, m, d, 23, 59, 59, 999_999_999);
+
+    assert_eq!(dt.beginning_of_day(), Some(start(2024, 5, 15)));
+    assert_eq!(dt.end_of_day(), Some(end(2024, 5, 15)));
+    assert_eq!(dt.beginning_of_week(Weekday::Mon), Some(start(2024, 5, 13)));
+    assert_eq!(dt.end_of_week(Weekday::Mon), Some(end(2024, 5, 19)));
+    assert_eq!(dt.beginning_of_week(Weekday::Wed), Some(start(2024, 5, 15)));
+    assert_eq!(dt.end_of_week(Weekday::Wed), Some(end(2024, 5, 21)));
+    assert_eq!(dt.beginning_of_week(Weekday::Thu), Some(start(2024, 5, 9)));
+    assert_eq!(dt.end_of_week(Weekday::Thu), Some(end(2024, 5, 15)));
+    assert_eq!(dt.beginning_of_month(), Some(start(2024, 5, 1)));
+    assert_eq!(dt.end_of_month(), Some(end(2024, 5, 31)));
+    assert_eq!(dt.beginning_of_quarter(), Some(start(2024, 4, 1)));
+    assert_eq!(dt.end_of_quarter(), Some(end(2024, 6, 30)));
+    assert_eq!(dt.beginning_of_year(), Some(start(2024, 1, 1)));
+    assert_eq!(dt.end_of_year(), Some(end(2024, 12, 31)));
+
+    // The local date is used.
+    let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    let dt = kst.with_ymd_and_hms(2024, 7, 1, 3, 0, 0).unwrap();
+    assert_eq!(dt.beginning_of_day(), Some(kst.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap()));
+    assert_eq!(dt.beginning_of_quarter(), Some(kst.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap()));
+    assert_eq!(dt.with_timezone(&Utc).beginning_of_quarter(), Some(start(2024, 4, 1)));
+
+    // Out of range.
+    assert_eq!(DateTime::<Utc>::MAX_UTC.end_of_day(), Some(DateTime::<Utc>::MAX_UTC));
+    assert_eq!(DateTime::<Utc>::MIN_UTC.beginning_of_day(), Some(DateTime::<Utc>::MIN_UTC));
+    // The local date of `local_max` is beyond `NaiveDate::MAX`.
+    let local_max = DateTime::<Utc>::MAX_UTC.with_timezone(&kst);
+    assert_eq!(local_max.beginning_of_day(), None);
+    assert_eq!(local_max.end_of_day(), None);
+    assert_eq!(local_max.end_of_year(), None);
+    assert_eq!(DateTime::<Utc>::MIN_UTC.beginning_of_week(Weekday::Mon), None);
+}
+
+#[test]
+fn test_datetime_beginning_end_of_day_spring_forward() {
+    // Local midnight on 2024-09-15 is skipped, the day starts at 01:00.
+    let tz = TransitionTester::<0>;
+    let dt = tz.with_ymd_and_hms(2024, 9, 15, 12, 0, 0).unwrap();
+    let start = dt.beginning_of_day().unwrap();
+    assert_eq!(start.to_string(), "2024-09-15 01:00:00 +09:00");
+    assert_eq!(start.naive_utc().to_string(), "2024-09-14 16:00:00");
+    assert_eq!(dt.beginning_of_month().unwrap().to_string(), "2024-09-01 00:00:00 +08:00");
+
+    let previous = dt - TimeDelta::try_days(1).unwrap();
+    assert_eq!(previous.end_of_day().unwrap().to_string(), "2024-09-14 23:59:59.999999999 +08:00");
+    assert_eq!(previous.end_of_day().unwrap() + TimeDelta::nanoseconds(1), start);
+
+    // The gap from 23:30 to 00:30 contains midnight, the day starts at 00:30.
+    let tz = TransitionTester::<-30>;
+    let dt = tz.with_ymd_and_hms(2024, 9, 15, 12, 0, 0).unwrap();
+    let start = dt.beginning_of_day().unwrap();
+    assert_eq!(start.to_string(), "2024-09-15 00:30:00 +09:00");
+    assert_eq!(start.naive_utc().to_string(), "2024-09-14 15:30:00");
+
+    let previous = dt - TimeDelta::try_days(1).unwrap();
+    assert_eq!(previous.end_of_day().unwrap().to_string(), "2024-09-14 23:29:59.999999999 +08:00");
+    assert_eq!(previous.end_of_day().unwrap() + TimeDelta::nanoseconds(1), start);
+}
+
+#[test]
+fn test_datetime_duration_trunc_to_day_in_tz() {
+    // `DstTester` skips from 02:00 to 03:00 on September 15, so that day is 23 hours long and its
+    // midnight still exists with the winter offset.
+    let winter = DstTester::winter_offset();
+    let summer = DstTester::summer_offset();
+    let midnight = winter.with_ymd_and_hms(2013, 9, 15, 0, 0, 0).unwrap();
+    let next_midnight = summer.with_ymd_and_hms(2013, 9, 16, 0, 0, 0).unwrap();
+    assert_eq!(next_midnight - midnight, TimeDelta::hours(23));
+
+    for (h, offset) in [(0, winter), (1, winter), (3, summer), (12, summer), (23, summer)] {
+        let dt = DstTester.with_ymd_and_hms(2013, 9, 15, h, 30, 0).unwrap();
+        assert_eq!(dt.offset(), &offset);
+        let start = dt.duration_trunc_to_day_in_tz().unwrap();
+        assert_eq!(start, midnight);
+        assert_eq!(start.offset(), &winter);
+        assert_eq!(start.naive_local(), midnight.naive_local());
+    }
+    let dt = DstTester.with_ymd_and_hms(2013, 9, 16, 0, 30, 0).unwrap();
+    assert_eq!(dt.duration_trunc_to_day_in_tz().unwrap(), next_midnight);
+
+    // Truncating by a `TimeDelta` of one day keeps the summer offset and misses midnight.
+    let dt = DstTester.with_ymd_and_hms(2013, 9, 15, 12, 30, 0).unwrap();
+    assert_eq!(dt.duration_trunc_to_day_in_tz().unwrap().to_string(), "2013-09-15 00:00:00 +08:00");
+    let truncated = dt.duration_trunc(TimeDelta::days(1)).unwrap();
+    assert_eq!(truncated.to_string(), "2013-09-14 23:00:00 +08:00");
+
+    // The fall back day on April 15 is 25 hours long.
+    let midnight = summer.with_ymd_and_hms(2013, 4, 15, 0, 0, 0).unwrap();
+    let next_midnight = winter.with_ymd_and_hms(2013, 4, 16, 0, 0, 0).unwrap();
+    assert_eq!(next_midnight - midnight, TimeDelta::hours(25));
+    let dt = DstTester.with_ymd_and_hms(2013, 4, 15, 23, 30, 0).unwrap();
+    assert_eq!(dt.duration_trunc_to_day_in_tz().unwrap(), midnight);
+
+    // If local midnight is skipped the day starts at the end of the gap.
+    let dt = TransitionTester::<0>.with_ymd_and_hms(2024, 9, 15, 12, 0, 0).unwrap();
+    let start = dt.duration_trunc_to_day_in_tz().unwrap();
+    assert_eq!(start.to_string(), "2024-09-15 01:00:00 +09:00");
+
+    let west = FixedOffset::west_opt(3600).unwrap();
+    assert_eq!(
+        DateTime::<Utc>::MIN_UTC.with_timezone(&west).duration_trunc_to_day_in_tz(),
+        Err(RoundingError::TimestampExceedsLimit)
+    );
+}
+
+#[test]
+fn test_datetime_abs_diff() {
+    let a = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+    let b = Utc.with_ymd_and_hms(2024, 3, 2, 13, 30, 0).unwrap();
+    assert_eq!(a.abs_diff(b), TimeDelta::minutes(25 * 60 + 30));
+    assert_eq!(b.abs_diff(a), TimeDelta::minutes(25 * 60 + 30));
+    assert_eq!(a.abs_diff(a), TimeDelta::zero());
+    let b_fixed = b.with_timezone(&FixedOffset::west_opt(5 * 3600).unwrap());
+    assert_eq!(a.abs_diff(b_fixed), a.abs_diff(b));
+
+    // The full range of `DateTime` is much smaller than that of `TimeDelta`.
+    let (min, max) = (DateTime::<Utc>::MIN_UTC, DateTime::<Utc>::MAX_UTC);
+    let span = max.abs_diff(min);
+    assert!(span > TimeDelta::zero());
+    assert_eq!(span, max - min);
+    assert_eq!(min.abs_diff(max), span);
+    assert_eq!(min.abs_diff(max.with_timezone(&FixedOffset::east_opt(3600).unwrap())), span);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_datetime_format_compiled() {
+    use crate::format::{CompiledFormat, ParseErrorKind, StrftimeItems};
+
+    const FORMAT: &str = "%a %e %b %Y %H:%M:%S%.f %:z (%j, week %V) literal %% text";
+    let format = CompiledFormat::compile(FORMAT).unwrap();
+    assert_eq!(format.items(), StrftimeItems::new(FORMAT).parse_to_owned().unwrap());
+
+    let tz = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+    let start = tz.with_ymd_and_hms(2023, 12, 31, 23, 59, 59).unwrap();
+    for i in 0..1000 {
+        let dt = start + TimeDelta::seconds(i * 86_461) + TimeDelta::nanoseconds(i * 1_234_567);
+        assert_eq!(dt.format_compiled(&format).to_string(), dt.format(FORMAT).to_string());
+    }
+
+    // The compiled format does not borrow from the format string.
+    let format = {
+        let fmt = String::from("%Y-%m-%dT%H:%M");
+        CompiledFormat::compile(&fmt).unwrap()
+    };
+    assert_eq!(start.format_compiled(&format).to_string(), "2023-12-31T23:59");
+
+    assert_eq!(CompiledFormat::compile("%Y-%!").unwrap_err().kind(), ParseErrorKind::BadFormat);
+    assert_eq!(CompiledFormat::compile("%").unwrap_err().kind(), ParseErrorKind::BadFormat);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_datetime_try_format() {
+    let tz = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+    let dt = tz.with_ymd_and_hms(2017, 4, 2, 12, 50, 32).unwrap() + TimeDelta::milliseconds(123);
+    for fmt in ["%d/%m/%Y %H:%M", "%+", "%c %:z %.3f", "%Y%%%j", "", "no specifiers"] {
+        assert_eq!(dt.try_format(fmt), Ok(dt.format(fmt).to_string()), "{:?}", fmt);
+    }
+    let max = DateTime::<Utc>::MAX_UTC;
+    assert_eq!(max.try_format("%+"), Ok(max.format("%+").to_string()));
+
+    for fmt in ["%Q", "%Y-%Q", "%", "%Y%", "%_a", "%.7f", "%:"] {
+        let err = dt.try_format(fmt).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::BadFormat, "{:?}", fmt);
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_datetime_format_with_locale_table() {
+    use crate::format::{Item, LocaleTable, StrftimeItems};
+    use crate::Month;
+
+    struct French;
+
+    impl LocaleTable for French {
+        fn short_month_name(&self, month: Month) -> &str {
+            [
+                "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+                "nov.", "déc.",
+            ][month.number_from_month() as usize - 1]
+        }
+        fn long_month_name(&self, month: Month) -> &str {
+            [
+                "janvier",
+                "février",
+                "mars",
+                "avril",
+                "mai",
+                "juin",
+                "juillet",
+                "août",
+                "septembre",
+                "octobre",
+                "novembre",
+                "décembre",
+            ][month.number_from_month() as usize - 1]
+        }
+        fn short_weekday_name(&self, weekday: Weekday) -> &str {
+            ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."]
+                [weekday.num_days_from_monday() as usize]
+        }
+        fn long_weekday_name(&self, weekday: Weekday) -> &str {
+            ["lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche"]
+                [weekday.num_days_from_monday() as usize]
+        }
+        fn am_pm(&self, pm: bool) -> &str {
+            if pm {
+                "PM"
+            } else {
+                "AM"
+            }
+        }
+        fn decimal_point(&self) -> &str {
+            ","
+        }
+    }
+
+    let dt = FixedOffset::east_opt(3600)
+        .unwrap()
+        .with_ymd_and_hms(2024, 2, 15, 14, 5, 9)
+        .unwrap()
+        .with_nanosecond(250_000_000)
+        .unwrap();
+    let format = |fmt| dt.format_with_locale_table(StrftimeItems::new(fmt), &French);
+    assert_eq!(format("%A %e %B %Y").unwrap(), "jeudi 15 février 2024");
+    assert_eq!(format("%a %d %b").unwrap(), "jeu. 15 févr.");
+    assert_eq!(format("%H:%M:%S%.f %z").unwrap(), "14:05:09,250 +0100");
+    assert_eq!(format("%I %p %P").unwrap(), "02 PM pm");
+    assert_eq!(format("%Y-%m-%d").unwrap(), dt.format("%Y-%m-%d").to_string());
+    assert!(dt.format_with_locale_table([Item::Error].iter(), &French).is_err());
+
+    let dt = Utc.with_ymd_and_hms(2023, 12, 31, 9, 0, 0).unwrap();
+    let formatted = dt.format_with_locale_table(StrftimeItems::new("%A %e %B %p"), &French);
+    assert_eq!(formatted.unwrap(), "dimanche 31 décembre AM");
+
+    // the built-in names are available as a table as well
+    #[cfg(feature = "unstable-locales")]
+    {
+        let table: &dyn LocaleTable = &crate::Locale::fr_FR;
+        let formatted = dt.format_with_locale_table(StrftimeItems::new("%A %e %B"), table);
+        assert_eq!(
+            formatted.unwrap(),
+            dt.format_localized("%A %e %B", crate::Locale::fr_FR).to_string()
+        );
+    }
+}
+
+#[test]
+fn test_datetime_with_offset() {
+    let utc = FixedOffset::east_opt(0).unwrap();
+    let ist = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+    let dt = utc.with_ymd_and_hms(2024, 12, 31, 20, 15, 30).unwrap();
+
+    let shifted = dt.with_offset(ist);
+    assert_eq!(shifted, dt);
+    assert_eq!(shifted.timestamp(), dt.timestamp());
+    assert_eq!(shifted.naive_utc(), dt.naive_utc());
+    assert_eq!(shifted.offset(), &ist);
+    assert_eq!(shifted.naive_local(), dt.naive_local() + TimeDelta::minutes(330));
+    assert_eq!((shifted.year(), shifted.month(), shifted.day()), (2025, 1, 1));
+    assert_eq!((shifted.hour(), shifted.minute(), shifted.second()), (1, 45, 30));
+    assert_eq!((dt.year(), dt.month(), dt.day(), dt.hour(), dt.minute()), (2024, 12, 31, 20, 15));
+    assert_eq!(shifted, dt.with_timezone(&ist));
+    assert_eq!(shifted.with_offset(utc).naive_local(), dt.naive_local());
+
+    let west = FixedOffset::west_opt(23 * 3600 + 59 * 60).unwrap();
+    assert_eq!(dt.with_offset(west), dt.with_timezone(&west));
+
+    // the local time may be outside of the range of `NaiveDateTime`
+    let max = DateTime::<Utc>::MAX_UTC.fixed_offset().with_offset(ist);
+    assert_eq!(max.naive_utc(), NaiveDateTime::MAX);
+    assert_eq!(max.timestamp(), DateTime::<Utc>::MAX_UTC.timestamp());
+}
+
+#[test]
+fn test_datetime_with_time_dst() {
+    let winter = DstTester::winter_offset();
+    let summer = DstTester::summer_offset();
+    let hm = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+    // `DstTester` skips from 02:00 to 03:00 on September 15.
+    let dt = DstTester.with_ymd_and_hms(2013, 9, 15, 0, 30, 0).unwrap();
+    assert_eq!(dt.with_time(hm(2, 30)), MappedLocalTime::None);
+    assert_eq!(dt.with_time(hm(1, 30)), DstTester.with_ymd_and_hms(2013, 9, 15, 1, 30, 0));
+    let later = dt.with_time(hm(17, 0)).unwrap();
+    assert_eq!(later, summer.with_ymd_and_hms(2013, 9, 15, 17, 0, 0).unwrap());
+    assert_eq!(later.offset(), &summer);
+    assert_eq!(later.with_time(hm(0, 30)), MappedLocalTime::Single(dt));
+
+    // And repeats 01:00 to 02:00 on April 15.
+    let dt = DstTester.with_ymd_and_hms(2013, 4, 15, 12, 0, 0).unwrap();
+    match dt.with_time(hm(1, 30)) {
+        MappedLocalTime::Ambiguous(a, b) => {
+            assert_eq!(a.naive_local(), b.naive_local());
+            assert_eq!(a.time(), hm(1, 30));
+            let mut offsets = [a.offset().local_minus_utc(), b.offset().local_minus_utc()];
+            offsets.sort();
+            assert_eq!(offsets, [winter.local_minus_utc(), summer.local_minus_utc()]);
+        }
+        result => panic!("expected an ambiguous result, got {:?}", result),
+    }
+}
+
+#[test]
+fn test_datetime_with_date_fields_local_dst() {
+    let winter = DstTester::winter_offset();
+    let summer = DstTester::summer_offset();
+
+    // `DstTester` skips from 02:00 to 03:00 on September 15.
+    let dt = DstTester.with_ymd_and_hms(2013, 8, 15, 2, 30, 0).unwrap();
+    assert_eq!(dt.with_month_local(9), MappedLocalTime::None);
+    assert_eq!(dt.with_month(9), None);
+    assert_eq!(dt.with_month_local(10), DstTester.with_ymd_and_hms(2013, 10, 15, 2, 30, 0));
+    let dt = DstTester.with_ymd_and_hms(2013, 9, 14, 2, 30, 0).unwrap();
+    assert_eq!(dt.with_day_local(15), MappedLocalTime::None);
+    assert_eq!(dt.with_day_local(16), DstTester.with_ymd_and_hms(2013, 9, 16, 2, 30, 0));
+
+    // And repeats 01:00 to 02:00 on April 15.
+    let dt = DstTester.with_ymd_and_hms(2013, 3, 15, 1, 30, 0).unwrap();
+    assert_eq!(dt.with_month(4), None);
+    match dt.with_month_local(4) {
+        MappedLocalTime::Ambiguous(a, b) => {
+            assert_eq!(a.naive_local(), b.naive_local());
+            assert_eq!(a.naive_local(), dt.naive_local().with_month(4).unwrap());
+            let mut offsets = [a.offset().local_minus_utc(), b.offset().local_minus_utc()];
+            offsets.sort();
+            assert_eq!(offsets, [winter.local_minus_utc(), summer.local_minus_utc()]);
+        }
+        result => panic!("expected an ambiguous result, got {:?}", result),
+    }
+    let dt = DstTester.with_ymd_and_hms(2013, 4, 14, 1, 30, 0).unwrap();
+    assert!(matches!(dt.with_day_local(15), MappedLocalTime::Ambiguous(..)));
+
+    // Invalid dates.
+    let dt = DstTester.with_ymd_and_hms(2012, 2, 29, 12, 0, 0).unwrap();
+    assert_eq!(dt.with_year_local(2013), MappedLocalTime::None);
+    assert_eq!(dt.with_year_local(2016), DstTester.with_ymd_and_hms(2016, 2, 29, 12, 0, 0));
+    assert_eq!(dt.with_year_local(2012), MappedLocalTime::Single(dt.clone()));
+    assert_eq!(dt.with_month_local(13), MappedLocalTime::None);
+    assert_eq!(dt.with_day_local(30), MappedLocalTime::None);
+
+    // Out of range.
+    let max = Utc.from_utc_datetime(&NaiveDateTime::MAX);
+    assert_eq!(
+        max.with_year_local(2000),
+        Utc.with_ymd_and_hms(2000, 12, 31, 23, 59, 59)
+            .map(|dt| dt.with_nanosecond(999_999_999).unwrap())
+    );
+    let max = max.with_timezone(&FixedOffset::east_opt(3600).unwrap());
+    assert_eq!(max.with_day_local(31), MappedLocalTime::None);
+}
+
+#[test]
+fn test_datetime_add_sub_days_dst() {
+    let winter = DstTester::winter_offset();
+    let summer = DstTester::summer_offset();
+
+    // `DstTester` skips from 02:00 to 03:00 on September 15.
+    let dt = DstTester.with_ymd_and_hms(2013, 9, 14, 9, 0, 0).unwrap();
+    let next = dt.clone().checked_add_days(Days::new(1)).unwrap();
+    assert_eq!(next.naive_local(), dt.naive_local() + TimeDelta::days(1));
+    assert_eq!(next.offset(), &summer);
+    assert_eq!(next.naive_utc() - dt.naive_utc(), TimeDelta::hours(23));
+    assert_eq!(next.clone().checked_sub_days(Days::new(1)), Some(dt.clone()));
+    // adding 24 hours gives a different wall-clock time
+    let plus_24h = dt + TimeDelta::days(1);
+    assert_eq!(plus_24h.naive_local(), next.naive_local() + TimeDelta::hours(1));
+
+    // `DstTester` repeats the hour from 01:00 to 02:00 on April 15.
+    let dt = DstTester.with_ymd_and_hms(2013, 4, 16, 9, 0, 0).unwrap();
+    let previous = dt.clone().checked_sub_days(Days::new(1)).unwrap();
+    assert_eq!(previous.naive_local(), dt.naive_local() - TimeDelta::days(1));
+    assert_eq!(previous.offset(), &winter);
+    assert_eq!(dt.naive_utc() - previous.naive_utc(), TimeDelta::hours(24));
+    let previous = dt.clone().checked_sub_days(Days::new(2)).unwrap();
+    assert_eq!(previous.offset(), &summer);
+    assert_eq!(dt.naive_utc() - previous.naive_utc(), TimeDelta::hours(49));
+
+    // many days at once
+    let dt = DstTester.with_ymd_and_hms(2013, 1, 1, 9, 0, 0).unwrap();
+    let later = dt.clone().checked_add_days(Days::new(365)).unwrap();
+    assert_eq!(later.naive_local(), dt.naive_local() + TimeDelta::days(365));
+    assert_eq!(later.offset(), dt.offset());
+
+    // the local time does not exist or is ambiguous on the resulting day
+    let dt = DstTester.with_ymd_and_hms(2013, 9, 14, 2, 30, 0).unwrap();
+    assert_eq!(dt.checked_add_days(Days::new(1)), None);
+    let dt = DstTester.with_ymd_and_hms(2013, 4, 16, 1, 30, 0).unwrap();
+    assert_eq!(dt.checked_sub_days(Days::new(1)), None);
+}
+
+#[test]
+fn test_datetime_beginning_end_of_day_fall_back() {
+    // `DstTester` has an ambiguous hour from 01:00 to 02:00 on April 15.
+    let dt = DstTester.with_ymd_and_hms(2013, 4, 15, 12, 0, 0).unwrap();
+    let midnight = NaiveDate::from_ymd_opt(2013, 4, 15).unwrap().and_time(NaiveTime::MIN);
+    assert_eq!(dt.beginning_of_day().unwrap().naive_local(), midnight);
+    let ambiguous = DstTester.with_ymd_and_hms(2013, 4, 15, 1, 30, 0);
+    assert!(matches!(ambiguous, MappedLocalTime::Ambiguous(_, _)));
+}
+
+#[test]
+fn test_datetime_saturating_add_sub_signed() {
+    let dt = Utc.with_ymd_and_hms(2014, 5, 6, 7, 8, 9).unwrap();
+    assert_eq!(
+        dt.saturating_add_signed(TimeDelta::try_hours(1).unwrap()),
+        Utc.with_ymd_and_hms(2014, 5, 6, 8, 8, 9).unwrap()
+    );
+    assert_eq!(
+        dt.saturating_sub_signed(TimeDelta::try_hours(1).unwrap()),
+        Utc.with_ymd_and_hms(2014, 5, 6, 6, 8, 9).unwrap()
+    );
+
+    // clamp at `MAX_UTC`
+    let max = DateTime::<Utc>::MAX_UTC;
+    assert_eq!(max.saturating_add_signed(TimeDelta::zero()), max);
+    assert_eq!(max.saturating_add_signed(TimeDelta::nanoseconds(1)), max);
+    assert_eq!(
+        max.saturating_sub_signed(TimeDelta::nanoseconds(1)),
+        max.checked_sub_signed(TimeDelta::nanoseconds(1)).unwrap()
+    );
+    assert_eq!(dt.saturating_add_signed(TimeDelta::max_value()), max);
+    assert_eq!(dt.saturating_sub_signed(TimeDelta::min_value()), max);
+
+    // clamp at `MIN_UTC`
+    let min = DateTime::<Utc>::MIN_UTC;
+    assert_eq!(min.saturating_sub_signed(TimeDelta::nanoseconds(1)), min);
+    assert_eq!(min.saturating_add_signed(TimeDelta::nanoseconds(-1)), min);
+    assert_eq!(dt.saturating_add_signed(TimeDelta::min_value()), min);
+    assert_eq!(dt.saturating_sub_signed(TimeDelta::max_value()), min);
+
+    // clamps to the UTC bounds, in the original timezone
+    let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    let dt = kst.with_ymd_and_hms(2014, 5, 6, 7, 8, 9).unwrap();
+    let clamped = dt.saturating_add_signed(TimeDelta::max_value());
+    assert_eq!(clamped, max);
+    assert_eq!(clamped.offset(), &kst);
+    let clamped = dt.saturating_sub_signed(TimeDelta::max_value());
+    assert_eq!(clamped, min);
+    assert_eq!(clamped.offset(), &kst);
+}
+
+#[test]
+fn test_datetime_overflowing_add_signed() {
+    let dt = Utc.with_ymd_and_hms(2014, 5, 6, 7, 8, 9).unwrap();
+    let hour = TimeDelta::try_hours(1).unwrap();
+    assert_eq!(dt.overflowing_add_signed(hour), (dt + hour, TimeDelta::zero()));
+    assert_eq!(dt.overflowing_add_signed(-hour), (dt - hour, TimeDelta::zero()));
+
+    // the remainder is what is left after reaching `MAX_UTC`
+    let max = DateTime::<Utc>::MAX_UTC;
+    assert_eq!(max.overflowing_add_signed(TimeDelta::zero()), (max, TimeDelta::zero()));
+    assert_eq!(max.overflowing_add_signed(hour), (max, hour));
+    let (clamped, remainder) = dt.overflowing_add_signed(TimeDelta::max_value());
+    assert_eq!(clamped, max);
+    assert!(remainder > TimeDelta::zero());
+    assert_eq!(remainder, TimeDelta::max_value() - max.signed_duration_since(dt));
+
+    // and after reaching `MIN_UTC`, with a negative sign
+    let min = DateTime::<Utc>::MIN_UTC;
+    assert_eq!(min.overflowing_add_signed(-hour), (min, -hour));
+    let (clamped, remainder) = dt.overflowing_add_signed(TimeDelta::min_value());
+    assert_eq!(clamped, min);
+    assert!(remainder < TimeDelta::zero());
+    assert_eq!(remainder, TimeDelta::min_value() - min.signed_duration_since(dt));
+
+    // clamps to the UTC bounds, in the original timezone
+    let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    let dt = kst.from_utc_datetime(&max.naive_utc());
+    let (clamped, remainder) = dt.overflowing_add_signed(hour);
+    assert_eq!(clamped, max);
+    assert_eq!(clamped.offset(), &kst);
+    assert_eq!(remainder, hour);
+}
+
+#[test]
+#[cfg(feature = "leap-seconds")]
+fn test_datetime_duration_since_including_leap() {
+    let before = Utc.with_ymd_and_hms(2016, 12, 31, 23, 59, 59).unwrap();
+    let leap = before.with_nanosecond(1_500_000_000).unwrap();
+    let after = Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap();
+
+    // the leap second at the end of 2016-12-31 is counted
+    assert_eq!(after.signed_duration_since(before), TimeDelta::seconds(1));
+    assert_eq!(after.duration_since_including_leap(&before), TimeDelta::seconds(2));
+    assert_eq!(before.duration_since_including_leap(&after), TimeDelta::seconds(-2));
+
+    // an instant within the leap second
+    assert_eq!(leap.duration_since_including_leap(&before), TimeDelta::milliseconds(1_500));
+    assert_eq!(after.duration_since_including_leap(&leap), TimeDelta::milliseconds(500));
+    assert_eq!(leap.duration_since_including_leap(&after), TimeDelta::milliseconds(-500));
+
+    // no leap second in between
+    let later = Utc.with_ymd_and_hms(2017, 1, 2, 0, 0, 0).unwrap();
+    assert_eq!(later.duration_since_including_leap(&after), TimeDelta::days(1));
+
+    // all 27 leap seconds since 1972, with time zones on either side
+    let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    let start = kst.with_ymd_and_hms(1972, 1, 1, 9, 0, 0).unwrap();
+    assert_eq!(
+        later.duration_since_including_leap(&start),
+        later.signed_duration_since(start) + TimeDelta::seconds(27)
+    );
+}
+
 #[test]
 fn test_datetime_add_days() {
     let est = FixedOffset::west_opt(5 * 60 * 60).unwrap();
@@ -401,6 +941,35 @@ fn test_datetime_add_months() {
     );
 }
 
+#[test]
+fn test_datetime_checked_add_months_local() {
+    let dst = |y, m, d, h, min| DstTester.with_ymd_and_hms(y, m, d, h, min, 0).unwrap();
+
+    // keeps the local time across a DST transition, with a different offset
+    let before = dst(2024, 8, 20, 10, 0);
+    assert_eq!(before.offset(), &DstTester::winter_offset());
+    let after = before.clone().checked_add_months(Months::new(1)).unwrap();
+    assert_eq!(after, dst(2024, 9, 20, 10, 0));
+    assert_eq!(after.offset(), &DstTester::summer_offset());
+    assert_eq!(after.clone() - before.clone(), TimeDelta::days(31) - TimeDelta::hours(1));
+    assert_eq!(after.checked_sub_months(Months::new(1)), Some(before));
+
+    // the local time doesn't exist or is ambiguous in the resulting month
+    assert_eq!(dst(2024, 8, 15, 2, 30).checked_add_months(Months::new(1)), None);
+    assert_eq!(dst(2024, 3, 15, 1, 30).checked_add_months(Months::new(1)), None);
+    assert_eq!(dst(2024, 10, 15, 2, 30).checked_sub_months(Months::new(1)), None);
+
+    // clamps to the last day of the month
+    let jan31 = dst(2023, 1, 31, 12, 0);
+    assert_eq!(jan31.clone().checked_add_months(Months::new(1)), Some(dst(2023, 2, 28, 12, 0)));
+    assert_eq!(
+        dst(2024, 1, 31, 12, 0).checked_add_months(Months::new(1)),
+        Some(dst(2024, 2, 29, 12, 0))
+    );
+    assert_eq!(jan31.clone().checked_add_months(Months::new(3)), Some(dst(2023, 4, 30, 12, 0)));
+    assert_eq!(jan31.checked_add_months(Months::new(12)), Some(dst(2024, 1, 31, 12, 0)));
+}
+
 #[test]
 fn test_datetime_sub_months() {
     let est = FixedOffset::west_opt(5 * 60 * 60).unwrap();
@@ -764,6 +1333,38 @@ fn test_datetime_rfc2822() {
     assert!(DateTime::parse_from_rfc2822("Wed, 18 Feb 2015 23:16:09 +0000   ").is_err());
 }
 
+#[test]
+fn test_datetime_rfc2822_strict() {
+    let utc = FixedOffset::east_opt(0).unwrap();
+    let dt = ymdhms(&utc, 2024, 1, 1, 0, 0, 0);
+
+    // accepted by both modes
+    for s in [
+        "Mon, 1 Jan 2024 00:00:00 +0000",
+        "Mon, 1 Jan 2024 00:00:00 GMT (UTC)",
+        "Mon, 1 Jan 2024 00:00:00 -0000",
+        "Mon,  1 Jan 2024\r\n 00:00:00 +0000",
+    ] {
+        assert_eq!(DateTime::parse_from_rfc2822(s), Ok(dt));
+        assert_eq!(DateTime::parse_from_rfc2822_strict(s), Ok(dt));
+    }
+
+    // comments between the tokens are only accepted by the lenient mode
+    for s in [
+        "Mon, 1 Jan (comment) 2024 00:00:00 +0000",
+        "Mon (Monday), 1 Jan 2024 00:00:00 +0000",
+        "Mon, 1(day)Jan 2024 00:00:00 +0000",
+        "Mon, 1 Jan 2024 00 (h) :00:00 +0000",
+        "Mon, 1 Jan 2024 00:00:00 (UTC) +0000",
+    ] {
+        assert_eq!(DateTime::parse_from_rfc2822(s), Ok(dt));
+        assert!(DateTime::parse_from_rfc2822_strict(s).is_err());
+    }
+
+    assert!(DateTime::parse_from_rfc2822_strict("Mon, 1 Jan 2024 00:00:00 +0000 ").is_err());
+    assert!(DateTime::parse_from_rfc2822_strict("Mon, 1 Jan2024 00:00:00 +0000").is_err());
+}
+
 #[test]
 #[cfg(feature = "alloc")]
 fn test_datetime_rfc3339() {
@@ -848,6 +1449,45 @@ fn test_datetime_rfc3339() {
     assert!(DateTime::parse_from_rfc3339(" 2015-02-18T23:59:60.234567+05:00").is_err());
     assert!(DateTime::parse_from_rfc3339("2015- 02-18T23:59:60.234567+05:00").is_err());
     assert!(DateTime::parse_from_rfc3339("2015-02-18T23:59:60.234567A+05:00").is_err());
+
+    // a comma is accepted as the decimal separator, but a dot is formatted
+    let comma = DateTime::parse_from_rfc3339("2024-01-01T00:00:00,5Z").unwrap();
+    let dot = DateTime::parse_from_rfc3339("2024-01-01T00:00:00.5Z").unwrap();
+    assert_eq!(comma, dot);
+    assert_eq!(comma.nanosecond(), 500_000_000);
+    assert_eq!(comma.to_rfc3339(), "2024-01-01T00:00:00.500+00:00");
+    assert_eq!(comma.format("%S%.f").to_string(), "00.500");
+
+    // `%.f` only takes a dot, so a literal comma may follow it
+    assert!(
+        DateTime::parse_from_str("2024-01-01 00:00:00,5 +0000", "%Y-%m-%d %H:%M:%S%.f %z").is_err()
+    );
+    assert_eq!(
+        NaiveDateTime::parse_from_str("12:00:00, 2024-01-01", "%H:%M:%S%.f, %Y-%m-%d"),
+        Ok(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap())
+    );
+}
+
+#[test]
+fn test_datetime_rfc3339_separators() {
+    let expected = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap().fixed_offset();
+    for s in [
+        "2024-01-01T00:00:00Z",
+        "2024-01-01T00:00:00z",
+        "2024-01-01t00:00:00Z",
+        "2024-01-01t00:00:00z",
+        "2024-01-01 00:00:00Z",
+        "2024-01-01 00:00:00z",
+        "2024-01-01 00:00:00+00:00",
+        "2024-01-01t01:00:00+01:00",
+    ] {
+        assert_eq!(DateTime::parse_from_rfc3339(s), Ok(expected), "{}", s);
+    }
+    // other separators are still rejected
+    assert!(DateTime::parse_from_rfc3339("2024-01-01_00:00:00Z").is_err());
+    assert!(DateTime::parse_from_rfc3339("2024-01-0100:00:00Z").is_err());
+    assert!(DateTime::parse_from_rfc3339("2024-01-01  00:00:00Z").is_err());
+    assert!(DateTime::parse_from_rfc3339("2024-01-01\t00:00:00Z").is_err());
 }
 
 #[test]
@@ -880,6 +1520,25 @@ fn test_rfc3339_opts() {
     assert_eq!(ut.to_rfc3339_opts(AutoSi, true), "2018-01-11T02:05:13.084660Z");
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+fn test_rfc3339_opts_use_z() {
+    use crate::SecondsFormat::Secs;
+    let naive = NaiveDate::from_ymd_opt(2018, 1, 11).unwrap().and_hms_opt(10, 5, 13).unwrap();
+
+    let zero = FixedOffset::east_opt(0).unwrap().from_utc_datetime(&naive);
+    assert_eq!(zero.to_rfc3339_opts(Secs, true), "2018-01-11T10:05:13Z");
+    assert_eq!(zero.to_rfc3339_opts(Secs, false), "2018-01-11T10:05:13+00:00");
+
+    let nonzero = FixedOffset::east_opt(5 * 60 * 60).unwrap().from_utc_datetime(&naive);
+    assert_eq!(nonzero.to_rfc3339_opts(Secs, true), "2018-01-11T15:05:13+05:00");
+    assert_eq!(nonzero.to_rfc3339_opts(Secs, false), "2018-01-11T15:05:13+05:00");
+
+    let west = FixedOffset::west_opt(5 * 60 * 60).unwrap().from_utc_datetime(&naive);
+    assert_eq!(west.to_rfc3339_opts(Secs, true), "2018-01-11T05:05:13-05:00");
+    assert_eq!(west.to_rfc3339_opts(Secs, false), "2018-01-11T05:05:13-05:00");
+}
+
 #[test]
 #[should_panic]
 #[cfg(feature = "alloc")]
@@ -1146,6 +1805,100 @@ fn test_parse_from_str() {
     // no test for `DateTime<Local>`, we cannot verify that much.
 }
 
+#[test]
+fn test_datetime_parse_from_str_zulu() {
+    let expected = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap().fixed_offset();
+    for (s, fmt) in [
+        ("2024-01-01 12:00:00 +0000", "%Y-%m-%d %H:%M:%S %z"),
+        ("2024-01-01 12:00:00 +00:00", "%Y-%m-%d %H:%M:%S %:z"),
+        ("2024-01-01 12:00:00 +00:00", "%Y-%m-%d %H:%M:%S %z"),
+        ("2024-01-01 12:00:00 Z", "%Y-%m-%d %H:%M:%S %z"),
+        ("2024-01-01 12:00:00 Z", "%Y-%m-%d %H:%M:%S %:z"),
+        ("2024-01-01 12:00:00 z", "%Y-%m-%d %H:%M:%S %z"),
+        ("2024-01-01T12:00:00Z", "%Y-%m-%dT%H:%M:%S%z"),
+        ("2024-01-01T12:00:00Z", "%Y-%m-%dT%H:%M:%S%::z"),
+    ] {
+        let dt = DateTime::parse_from_str(s, fmt).unwrap();
+        assert_eq!(dt, expected, "{} with {}", s, fmt);
+        assert_eq!(dt.offset(), &FixedOffset::east_opt(0).unwrap());
+    }
+    assert!(DateTime::parse_from_str("2024-01-01T12:00:00ZZ", "%Y-%m-%dT%H:%M:%S%z").is_err());
+    assert!(DateTime::parse_from_str("2024-01-01T12:00:00Z+01", "%Y-%m-%dT%H:%M:%S%z").is_err());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_to_rfc3339_opts_ext() {
+    use crate::format::{Colons, OffsetFormat, OffsetPrecision, Pad};
+    use crate::SecondsFormat;
+
+    let format = |precision, colons| OffsetFormat {
+        precision,
+        colons,
+        allow_zulu: false,
+        padding: Pad::Zero,
+    };
+    let colon = format(OffsetPrecision::Minutes, Colons::Colon);
+    let no_colon = format(OffsetPrecision::Minutes, Colons::None);
+    let hours_only = format(OffsetPrecision::Hours, Colons::Colon);
+    let with_seconds = format(OffsetPrecision::OptionalSeconds, Colons::Colon);
+
+    let offset = FixedOffset::east_opt(5 * 3600 + 30 * 60 + 15).unwrap();
+    let dt = offset.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+    let secs = SecondsFormat::Secs;
+    assert_eq!(dt.to_rfc3339_opts_ext(secs, colon), "2024-03-01T12:00:00+05:30");
+    assert_eq!(dt.to_rfc3339_opts_ext(secs, no_colon), "2024-03-01T12:00:00+0530");
+    assert_eq!(dt.to_rfc3339_opts_ext(secs, hours_only), "2024-03-01T12:00:00+05");
+    assert_eq!(dt.to_rfc3339_opts_ext(secs, with_seconds), "2024-03-01T12:00:00+05:30:15");
+    assert_eq!(dt.to_rfc3339_opts_ext(secs, colon), dt.to_rfc3339_opts(secs, false));
+
+    // seconds are only written if they are not zero
+    let offset = FixedOffset::west_opt(5 * 3600 + 30 * 60).unwrap();
+    let dt = offset.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+    assert_eq!(dt.to_rfc3339_opts_ext(secs, with_seconds), "2024-03-01T12:00:00-05:30");
+    assert_eq!(dt.to_rfc3339_opts_ext(secs, hours_only), "2024-03-01T12:00:00-05");
+
+    // subseconds and `Z`
+    let dt = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap() + TimeDelta::milliseconds(250);
+    let zulu = OffsetFormat { allow_zulu: true, ..no_colon };
+    assert_eq!(dt.to_rfc3339_opts_ext(SecondsFormat::Millis, zulu), "2024-03-01T12:00:00.250Z");
+    assert_eq!(
+        dt.to_rfc3339_opts_ext(SecondsFormat::Millis, no_colon),
+        "2024-03-01T12:00:00.250+0000"
+    );
+}
+
+#[test]
+fn test_datetime_parse_from_str_with_timezone() {
+    let fmt = "%Y-%m-%d %H:%M";
+    let dt = DateTime::parse_from_str_with_timezone("2024-01-01 12:00", fmt, &Utc).unwrap();
+    assert_eq!(dt, Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap());
+
+    let tz = FixedOffset::west_opt(5 * 60 * 60).unwrap();
+    let dt = DateTime::parse_from_str_with_timezone("2024-01-01 12:00", fmt, &tz).unwrap();
+    assert_eq!(dt, tz.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap());
+    assert_eq!(dt.offset(), &tz);
+    assert_eq!(dt, Utc.with_ymd_and_hms(2024, 1, 1, 17, 0, 0).unwrap());
+
+    // an offset in the input must match the time zone
+    let fmt_z = "%Y-%m-%d %H:%M %z";
+    let dt = DateTime::parse_from_str_with_timezone("2024-01-01 12:00 -0500", fmt_z, &tz);
+    assert_eq!(dt, Ok(tz.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap()));
+    let dt = DateTime::parse_from_str_with_timezone("2024-01-01 12:00 +0100", fmt_z, &tz);
+    assert_eq!(dt.unwrap_err().kind(), ParseErrorKind::Impossible);
+
+    // nonexistent and ambiguous local times are errors
+    let dt = DateTime::parse_from_str_with_timezone("2024-09-15 02:30", fmt, &DstTester);
+    assert_eq!(dt.unwrap_err().kind(), ParseErrorKind::Impossible);
+    let dt = DateTime::parse_from_str_with_timezone("2024-04-15 01:30", fmt, &DstTester);
+    assert_eq!(dt.unwrap_err().kind(), ParseErrorKind::NotEnough);
+    // unless the offset resolves the ambiguity
+    let dt = DateTime::parse_from_str_with_timezone("2024-04-15 01:30 +0900", fmt_z, &DstTester);
+    assert_eq!(dt.unwrap().offset(), &DstTester::summer_offset());
+
+    assert!(DateTime::parse_from_str_with_timezone("2024-01-01", fmt, &Utc).is_err());
+}
+
 #[test]
 fn test_datetime_parse_from_str() {
     let dt = ymdhms(&FixedOffset::east_opt(-9 * 60 * 60).unwrap(), 2013, 8, 9, 23, 54, 35);
@@ -1186,8 +1939,10 @@ fn test_datetime_parse_from_str() {
     assert!(parse("Aug 09 2013 23:54:35 -09", "%b %d %Y %H:%M:%S %z").is_err());
     assert_eq!(parse("Aug 09 2013 23:54:35 -09::00", "%b %d %Y %H:%M:%S %z"), Ok(dt));
     assert_eq!(parse("Aug 09 2013 23:54:35 -0900::", "%b %d %Y %H:%M:%S %z::"), Ok(dt));
-    assert_eq!(parse("Aug 09 2013 23:54:35 -09:00:00", "%b %d %Y %H:%M:%S %z:00"), Ok(dt));
-    assert_eq!(parse("Aug 09 2013 23:54:35 -09:00:00 ", "%b %d %Y %H:%M:%S %z:00 "), Ok(dt));
+    assert_eq!(parse("Aug 09 2013 23:54:35 -0900:00", "%b %d %Y %H:%M:%S %z:00"), Ok(dt));
+    assert_eq!(parse("Aug 09 2013 23:54:35 -09:00:00", "%b %d %Y %H:%M:%S %z"), Ok(dt));
+    assert_eq!(parse("Aug 09 2013 23:54:35 -09:00:00 ", "%b %d %Y %H:%M:%S %z "), Ok(dt));
+    assert!(parse("Aug 09 2013 23:54:35 -09:00:00", "%b %d %Y %H:%M:%S %z:00").is_err());
 
     //
     // %:z
@@ -1212,8 +1967,8 @@ fn test_datetime_parse_from_str() {
     assert_eq!(parse("Aug 09 2013 23:54:35 -0900", "%b %d %Y %H:%M:%S %::z"), Ok(dt));
     assert_eq!(parse("Aug 09 2013 23:54:35 -09:00", "%b %d %Y %H:%M:%S %::z"), Ok(dt));
     assert_eq!(parse("Aug 09 2013 23:54:35 -09 : 00", "%b %d %Y %H:%M:%S %::z"), Ok(dt));
-    // mismatching colon expectations
-    assert!(parse("Aug 09 2013 23:54:35 -09:00:00", "%b %d %Y %H:%M:%S %::z").is_err());
+    // offset with seconds
+    assert_eq!(parse("Aug 09 2013 23:54:35 -09:00:00", "%b %d %Y %H:%M:%S %::z"), Ok(dt));
     assert_eq!(parse("Aug 09 2013 23:54:35 -09::00", "%b %d %Y %H:%M:%S %::z"), Ok(dt));
     assert_eq!(parse("Aug 09 2013 23:54:35 -09::00", "%b %d %Y %H:%M:%S %:z"), Ok(dt));
     // wrong timezone data
@@ -1226,14 +1981,13 @@ fn test_datetime_parse_from_str() {
     assert_eq!(parse("Aug 09 2013 23:54:35 :-0900:0", "%b %d %Y %H:%M:%S :%::z:0"), Ok(dt));
     // mismatching colons and spaces
     assert!(parse("Aug 09 2013 23:54:35 :-0900: ", "%b %d %Y %H:%M:%S :%::z::").is_err());
-    // mismatching colons expectations
-    assert!(parse("Aug 09 2013 23:54:35 -09:00:00", "%b %d %Y %H:%M:%S %::z").is_err());
     assert_eq!(parse("Aug 09 2013 -0900: 23:54:35", "%b %d %Y %::z: %H:%M:%S"), Ok(dt));
     assert_eq!(parse("Aug 09 2013 :-0900:0 23:54:35", "%b %d %Y :%::z:0 %H:%M:%S"), Ok(dt));
     // mismatching colons expectations mid-string
     assert!(parse("Aug 09 2013 :-0900: 23:54:35", "%b %d %Y :%::z  %H:%M:%S").is_err());
-    // mismatching colons expectations, before end
-    assert!(parse("Aug 09 2013 23:54:35 -09:00:00 ", "%b %d %Y %H:%M:%S %::z ").is_err());
+    assert_eq!(parse("Aug 09 2013 23:54:35 -09:00:00 ", "%b %d %Y %H:%M:%S %::z "), Ok(dt));
+    // offset seconds out of range
+    assert!(parse("Aug 09 2013 23:54:35 -09:00:60", "%b %d %Y %H:%M:%S %::z").is_err());
 
     //
     // %:::z
@@ -1316,6 +2070,45 @@ fn test_datetime_format_with_local() {
     assert_eq!(dt.format("%Y").to_string(), dt.with_timezone(&Utc).format("%Y").to_string());
 }
 
+#[test]
+fn test_datetime_cmp_across_timezones() {
+    let utc = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+    let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+    let new_york = FixedOffset::west_opt(4 * 3600).unwrap();
+
+    // the same instant in different time zones
+    let in_tokyo = tokyo.with_ymd_and_hms(2024, 6, 1, 21, 0, 0).unwrap();
+    let in_new_york = new_york.with_ymd_and_hms(2024, 6, 1, 8, 0, 0).unwrap();
+    assert!(utc == in_tokyo);
+    assert!(in_tokyo == utc);
+    assert!(in_tokyo == in_new_york);
+    assert_eq!(utc.partial_cmp(&in_tokyo), Some(Ordering::Equal));
+    assert_eq!(in_new_york.partial_cmp(&utc), Some(Ordering::Equal));
+    assert!(utc <= in_tokyo);
+    assert!(utc >= in_tokyo);
+    assert_eq!(DstTester.from_utc_datetime(&utc.naive_utc()), utc);
+
+    // different instants, even if the local time looks the other way around
+    let later_in_new_york = new_york.with_ymd_and_hms(2024, 6, 1, 9, 0, 0).unwrap();
+    assert!(utc != later_in_new_york);
+    assert!(utc < later_in_new_york);
+    assert!(later_in_new_york > in_tokyo);
+    assert!(later_in_new_york.naive_local() < in_tokyo.naive_local());
+    assert_eq!(later_in_new_york.partial_cmp(&utc), Some(Ordering::Greater));
+    assert_eq!(utc.partial_cmp(&later_in_new_york), Some(Ordering::Less));
+    let earlier_in_tokyo = tokyo.with_ymd_and_hms(2024, 6, 1, 20, 59, 59).unwrap();
+    assert!(earlier_in_tokyo < utc);
+    assert!(earlier_in_tokyo < in_new_york);
+
+    #[cfg(feature = "clock")]
+    {
+        let local = utc.with_timezone(&Local);
+        assert!(local == utc);
+        assert!(local == in_tokyo);
+        assert!(local < later_in_new_york);
+    }
+}
+
 #[test]
 fn test_datetime_is_send_and_copy() {
     fn _assert_send_copy<T: Send + Copy>() {}
@@ -1416,6 +2209,40 @@ fn test_from_system_time() {
     );
 }
 
+#[test]
+#[cfg(all(feature = "std", not(all(target_arch = "wasm32", target_os = "wasi"))))]
+fn test_to_system_time_leap_second() {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    let ymd_hmsn = |y, m, d, h, n, s, nano| {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_nano_opt(h, n, s, nano).unwrap().and_utc()
+    };
+
+    // an ordinary instant round-trips
+    let dt = ymd_hmsn(2016, 12, 31, 23, 59, 59, 123_456_789);
+    assert_eq!(DateTime::<Utc>::from(SystemTime::from(dt)), dt);
+    let before_epoch = ymd_hmsn(1969, 12, 31, 23, 59, 59, 1);
+    assert_eq!(DateTime::<Utc>::from(SystemTime::from(before_epoch)), before_epoch);
+
+    // a leap second is clamped to the last nanosecond of the preceding second
+    let last_nano = ymd_hmsn(2016, 12, 31, 23, 59, 59, 999_999_999);
+    let expected = UNIX_EPOCH + Duration::new(last_nano.timestamp() as u64, 999_999_999);
+    for nanos in [1_000_000_000, 1_500_000_000, 1_999_999_999] {
+        let leap = ymd_hmsn(2016, 12, 31, 23, 59, 59, nanos);
+        assert_eq!(SystemTime::from(leap), expected);
+        assert_eq!(DateTime::<Utc>::from(SystemTime::from(leap)), last_nano);
+        // the result is before the start of the next second
+        assert!(SystemTime::from(leap) < SystemTime::from(ymd_hmsn(2017, 1, 1, 0, 0, 0, 0)));
+    }
+
+    // also before the epoch and with other time zones
+    let leap = ymd_hmsn(1969, 12, 31, 23, 59, 59, 1_500_000_000);
+    assert_eq!(SystemTime::from(leap), UNIX_EPOCH - Duration::new(0, 1));
+    let tz = FixedOffset::east_opt(3600).unwrap();
+    let leap = ymd_hmsn(2016, 12, 31, 23, 59, 59, 1_000_000_000).with_timezone(&tz);
+    assert_eq!(SystemTime::from(leap), expected);
+}
+
 #[test]
 #[allow(deprecated)]
 fn test_datetime_from_local() {
@@ -1452,6 +2279,210 @@ fn test_datetime_before_windows_api_limits() {
     dbg!(local_dt);
 }
 
+#[test]
+fn test_datetime_naive_utc_and_offset() {
+    for offset in [-86_399, -5 * 3600, 0, 3600 + 1800, 86_399] {
+        let tz = FixedOffset::east_opt(offset).unwrap();
+        let dt = tz.with_ymd_and_hms(2024, 2, 29, 23, 59, 59).unwrap() + TimeDelta::nanoseconds(1);
+
+        let (naive_utc, fixed) = dt.as_naive_utc_and_offset();
+        assert_eq!(naive_utc, dt.naive_utc());
+        assert_eq!(fixed, dt.offset());
+
+        let (naive_utc, fixed) = dt.into_naive_utc_and_offset();
+        assert_eq!(naive_utc, dt.naive_utc());
+        assert_eq!(fixed, tz);
+        let rebuilt = DateTime::<FixedOffset>::from_naive_utc_and_offset(naive_utc, fixed);
+        assert_eq!(rebuilt, dt);
+        assert_eq!(rebuilt.offset(), dt.offset());
+    }
+
+    let (naive_utc, offset) = DateTime::<Utc>::MAX_UTC.into_naive_utc_and_offset();
+    assert_eq!(naive_utc, NaiveDateTime::MAX);
+    assert_eq!(offset, Utc);
+}
+
+#[test]
+fn test_datetime_parse_and_remainder() {
+    let tz = FixedOffset::east_opt(3600).unwrap();
+    let dt = tz.with_ymd_and_hms(2024, 1, 1, 9, 30, 0).unwrap();
+    let parse = DateTime::parse_and_remainder;
+    assert_eq!(
+        parse("2024-01-01 09:30:00 +01:00 garbage", "%Y-%m-%d %H:%M:%S %:z"),
+        Ok((dt, " garbage"))
+    );
+    assert_eq!(parse("2024-01-01T09:30:00+01:00", "%+"), Ok((dt, "")));
+    assert_eq!(parse("2024-01-01T09:30:00+01:00]", "%+"), Ok((dt, "]")));
+    // without an offset the prefix is not enough for a `DateTime`
+    assert!(parse("2024-01-01 09:30:00 garbage", "%Y-%m-%d %H:%M:%S").is_err());
+}
+
+#[test]
+fn test_datetime_parse_from_iso8601_basic() {
+    use crate::format::ParseErrorKind;
+
+    let basic = DateTime::parse_from_iso8601_basic;
+    let pairs = [
+        ("20240115T093000Z", "2024-01-15T09:30:00Z"),
+        ("20240115T093000+0000", "2024-01-15T09:30:00+00:00"),
+        ("20240115T093000+0530", "2024-01-15T09:30:00+05:30"),
+        ("20240115T093000-0800", "2024-01-15T09:30:00-08:00"),
+        ("20240115T093000.123Z", "2024-01-15T09:30:00.123Z"),
+        ("20240115T093000,5-0130", "2024-01-15T09:30:00.5-01:30"),
+        ("20161231T235960Z", "2016-12-31T23:59:60Z"),
+    ];
+    for (basic_str, extended_str) in pairs {
+        let dt = basic(basic_str).unwrap();
+        let expected = DateTime::parse_from_rfc3339(extended_str).unwrap();
+        assert_eq!(dt, expected, "{}", basic_str);
+        assert_eq!(dt.offset(), expected.offset(), "{}", basic_str);
+    }
+
+    let kind = |s| basic(s).map_err(|e| e.kind());
+    assert_eq!(kind("20240115T093000"), Err(ParseErrorKind::TooShort));
+    assert_eq!(kind("20240115T0930Z"), Err(ParseErrorKind::TooShort));
+    assert_eq!(kind("2024115T093000Z"), Err(ParseErrorKind::Invalid));
+    assert_eq!(kind("20240115 093000Z"), Err(ParseErrorKind::Invalid));
+    assert_eq!(kind("2024-01-15T09:30:00Z"), Err(ParseErrorKind::Invalid));
+    assert_eq!(kind("20240115T093000+05"), Err(ParseErrorKind::TooShort));
+    assert_eq!(kind("20240115T093000Zx"), Err(ParseErrorKind::TooLong));
+    assert_eq!(kind("20240230T093000Z"), Err(ParseErrorKind::OutOfRange));
+    assert_eq!(kind("20240115T243000Z"), Err(ParseErrorKind::OutOfRange));
+
+    // white space between the fields
+    assert_eq!(kind("20240115T 093000Z"), Err(ParseErrorKind::Invalid));
+    assert_eq!(kind("20240115T093000 Z"), Err(ParseErrorKind::Invalid));
+    assert_eq!(kind(" 20240115T093000Z"), Err(ParseErrorKind::Invalid));
+    assert_eq!(kind("20240115T09 3000Z"), Err(ParseErrorKind::Invalid));
+    // an offset in the extended format
+    assert_eq!(kind("20240115T093000+05:30"), Err(ParseErrorKind::Invalid));
+    assert_eq!(kind("20240115T093000Z:"), Err(ParseErrorKind::TooLong));
+    // signed or expanded years
+    assert_eq!(kind("+20240115T093000Z"), Err(ParseErrorKind::Invalid));
+    assert_eq!(kind("-00010115T093000Z"), Err(ParseErrorKind::Invalid));
+    assert_eq!(kind("+0020240115T093000Z"), Err(ParseErrorKind::Invalid));
+    assert_eq!(kind("120240115T093000Z"), Err(ParseErrorKind::OutOfRange));
+}
+
+#[test]
+fn test_datetime_clamp_between() {
+    let min = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let max = Utc.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap();
+    let inside = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+    assert_eq!(inside.clamp_between(&min, &max), inside);
+    assert_eq!(min.clamp_between(&min, &max), min);
+    assert_eq!(max.clamp_between(&min, &max), max);
+    assert_eq!((min - TimeDelta::nanoseconds(1)).clamp_between(&min, &max), min);
+    assert_eq!((max + TimeDelta::nanoseconds(1)).clamp_between(&min, &max), max);
+    assert_eq!(min.clamp_between(&inside, &inside), inside);
+
+    // the result keeps the time zone of `self`
+    let tz = FixedOffset::east_opt(5 * 3600).unwrap();
+    let before = tz.with_ymd_and_hms(2023, 6, 1, 0, 0, 0).unwrap();
+    let clamped = before.clamp_between(&min, &max);
+    assert_eq!(clamped, min);
+    assert_eq!(clamped.offset(), &tz);
+}
+
+#[test]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn test_datetime_clamp_between_invalid_range() {
+    let min = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let max = Utc.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap();
+    let _ = min.clamp_between(&max, &min);
+}
+
+#[test]
+fn test_datetime_is_between() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+    let inside = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+    let before = start - TimeDelta::nanoseconds(1);
+    let after = end + TimeDelta::nanoseconds(1);
+
+    // (inclusivity, start, inside, end)
+    let cases = [
+        (Inclusivity::Inclusive, true, true, true),
+        (Inclusivity::Exclusive, false, true, false),
+        (Inclusivity::StartInclusive, true, true, false),
+        (Inclusivity::EndInclusive, false, true, true),
+    ];
+    for (inclusivity, at_start, at_inside, at_end) in cases {
+        assert_eq!(start.is_between(&start, &end, inclusivity), at_start);
+        assert_eq!(inside.is_between(&start, &end, inclusivity), at_inside);
+        assert_eq!(end.is_between(&start, &end, inclusivity), at_end);
+        assert!(!before.is_between(&start, &end, inclusivity));
+        assert!(!after.is_between(&start, &end, inclusivity));
+        // an empty range
+        assert!(!inside.is_between(&end, &start, inclusivity));
+    }
+
+    // a single instant
+    assert!(start.is_between(&start, &start, Inclusivity::Inclusive));
+    assert!(!start.is_between(&start, &start, Inclusivity::StartInclusive));
+
+    // bounds in another time zone
+    let tz = FixedOffset::east_opt(-3600).unwrap();
+    let local_start = start.with_timezone(&tz);
+    assert!(start.is_between(&local_start, &end.with_timezone(&tz), Inclusivity::StartInclusive));
+    assert!(!start.is_between(&local_start, &end.with_timezone(&tz), Inclusivity::Exclusive));
+}
+
+#[test]
+fn test_datetime_with_ordinal() {
+    let tz = FixedOffset::east_opt(5 * 60 * 60).unwrap();
+    let leap = tz.with_ymd_and_hms(2024, 1, 10, 13, 14, 15).unwrap();
+    let common = tz.with_ymd_and_hms(2023, 1, 10, 13, 14, 15).unwrap();
+
+    // Day 60 is February 29 in a leap year and March 1 otherwise.
+    assert_eq!(leap.with_ordinal(60), tz.with_ymd_and_hms(2024, 2, 29, 13, 14, 15).single());
+    assert_eq!(common.with_ordinal(60), tz.with_ymd_and_hms(2023, 3, 1, 13, 14, 15).single());
+    assert_eq!(leap.with_ordinal0(59), leap.with_ordinal(60));
+    assert_eq!(common.with_ordinal0(59), common.with_ordinal(60));
+    assert_eq!(leap.with_ordinal(366).map(|dt| (dt.month(), dt.day())), Some((12, 31)));
+    assert_eq!(common.with_ordinal(366), None);
+    assert_eq!(common.with_ordinal0(365), None);
+    assert_eq!(common.with_ordinal(0), None);
+
+    // The time of day and the offset are preserved.
+    let dt = leap.with_ordinal(60).unwrap();
+    assert_eq!(dt.time(), leap.time());
+    assert_eq!(dt.offset(), leap.offset());
+
+    // The offset is re-resolved in the time zone.
+    let summer = DstTester.with_ymd_and_hms(2023, 1, 10, 1, 30, 0).unwrap();
+    assert_eq!(summer.offset(), &DstTester::summer_offset());
+    let winter = summer.with_ordinal(200).unwrap();
+    assert_eq!(winter.time(), summer.time());
+    assert_eq!(winter.offset(), &DstTester::winter_offset());
+
+    // No result if the local time is ambiguous (April 15) or doesn't exist (September 15).
+    assert_eq!(summer.with_ordinal(105), None);
+    let gap = DstTester.with_ymd_and_hms(2023, 1, 10, 2, 30, 0).unwrap();
+    assert_eq!(gap.with_ordinal(258), None);
+    assert!(gap.with_ordinal(257).is_some());
+}
+
+#[test]
+fn test_datetime_years_since() {
+    let birth = Utc.with_ymd_and_hms(2000, 2, 29, 12, 0, 0).unwrap();
+    let at = |y, m, d, h| Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap();
+    assert_eq!(at(2000, 2, 29, 11).years_since(birth), None);
+    assert_eq!(at(2000, 2, 29, 12).years_since(birth), Some(0));
+    assert_eq!(at(2001, 2, 28, 11).years_since(birth), Some(0));
+    assert_eq!(at(2001, 2, 28, 12).years_since(birth), Some(1));
+    assert_eq!(at(2004, 2, 28, 23).years_since(birth), Some(3));
+    assert_eq!(at(2004, 2, 29, 11).years_since(birth), Some(3));
+    assert_eq!(at(2004, 2, 29, 12).years_since(birth), Some(4));
+
+    // The local date is used.
+    let tz = FixedOffset::east_opt(13 * 3600).unwrap();
+    let birth = tz.with_ymd_and_hms(2000, 2, 29, 1, 0, 0).unwrap();
+    assert_eq!(tz.with_ymd_and_hms(2001, 2, 28, 1, 0, 0).unwrap().years_since(birth), Some(1));
+    assert_eq!(tz.with_ymd_and_hms(2001, 2, 28, 0, 0, 0).unwrap().years_since(birth), Some(0));
+}
+
 #[test]
 #[cfg(feature = "clock")]
 fn test_years_elapsed() {
@@ -1765,6 +2796,18 @@ fn test_datetime_fixed_offset() {
     let fixed_offset = FixedOffset::east_opt(3600).unwrap();
     let datetime_fixed = fixed_offset.from_local_datetime(&naivedatetime).unwrap();
     assert_eq!(datetime_fixed.fixed_offset(), datetime_fixed);
+
+    // the instant and the offset are preserved across a DST transition
+    for datetime in [
+        DstTester.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap(),
+        DstTester.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap(),
+    ] {
+        let fixed = datetime.fixed_offset();
+        assert_eq!(fixed, datetime);
+        assert_eq!(fixed.timestamp_nanos_opt(), datetime.timestamp_nanos_opt());
+        assert_eq!(fixed.naive_local(), datetime.naive_local());
+        assert_eq!(*fixed.offset(), datetime.offset().fix());
+    }
 }
 
 #[test]
@@ -5,26 +5,19 @@
 
 #[cfg(all(feature = "alloc", not(feature = "std"), not(test)))]
 use alloc::string::{String, ToString};
-#[cfg(feature = "alloc")]
 use core::borrow::Borrow;
-#[cfg(feature = "alloc")]
 use core::fmt::Display;
 use core::fmt::{self, Write};
 
 #[cfg(feature = "alloc")]
 use crate::offset::Offset;
-#[cfg(any(feature = "alloc", feature = "serde"))]
 use crate::{Datelike, FixedOffset, NaiveDateTime, Timelike};
-#[cfg(feature = "alloc")]
-use crate::{NaiveDate, NaiveTime, Weekday};
+use crate::{Month, NaiveDate, NaiveTime, Weekday};
 
-#[cfg(feature = "alloc")]
 use super::locales;
-#[cfg(any(feature = "alloc", feature = "serde"))]
+use super::LocaleTable;
 use super::{Colons, OffsetFormat, OffsetPrecision, Pad};
-#[cfg(feature = "alloc")]
 use super::{Fixed, InternalFixed, InternalInternal, Item, Numeric};
-#[cfg(feature = "alloc")]
 use locales::*;
 
 /// A *temporary* object which can be used as an argument to `format!` or others.
@@ -97,21 +90,135 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
         DelayedFormat { date, time, off: Some(name_and_diff), items, locale }
     }
 
-    fn format(&self, w: &mut impl Write) -> fmt::Result {
-        for item in self.items.clone() {
+    /// Writes the formatted value into `w`.
+    ///
+    /// Unlike formatting with `to_string()`, this writes directly into `w` without allocating an
+    /// intermediate `String`. The items can borrow a slice of pre-parsed [`Item`]s, so a format
+    /// string only has to be parsed once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{DelayedFormat, StrftimeItems};
+    /// use chrono::NaiveDate;
+    ///
+    /// let items = StrftimeItems::new("%Y-%m-%d").parse().unwrap();
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+    ///
+    /// let mut buf = String::with_capacity(16);
+    /// DelayedFormat::new(Some(date), None, items.iter()).write_to(&mut buf).unwrap();
+    /// assert_eq!(buf, "2024-01-15");
+    /// ```
+    pub fn write_to(&self, w: &mut impl Write) -> fmt::Result {
+        self.format(w, &self.locale)
+    }
+
+    /// Writes the formatted value into `w`, taking the names of months and weekdays, AM and PM,
+    /// and the decimal point from `table`.
+    ///
+    /// This ignores the locale of the `DelayedFormat`. See [`LocaleTable`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{DelayedFormat, LocaleTable, StrftimeItems};
+    /// use chrono::{Month, NaiveDate, Weekday};
+    ///
+    /// struct Shouting;
+    ///
+    /// impl LocaleTable for Shouting {
+    ///     fn short_month_name(&self, month: Month) -> &str {
+    ///         ["JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC"]
+    ///             [month.number_from_month() as usize - 1]
+    ///     }
+    ///     fn long_month_name(&self, month: Month) -> &str {
+    ///         [
+    ///             "JANUARY", "FEBRUARY", "MARCH", "APRIL", "MAY", "JUNE", "JULY", "AUGUST",
+    ///             "SEPTEMBER", "OCTOBER", "NOVEMBER", "DECEMBER",
+    ///         ][month.number_from_month() as usize - 1]
+    ///     }
+    ///     fn short_weekday_name(&self, weekday: Weekday) -> &str {
+    ///         ["MON", "TUE", "WED", "THU", "FRI", "SAT", "SUN"]
+    ///             [weekday.num_days_from_monday() as usize]
+    ///     }
+    ///     fn long_weekday_name(&self, weekday: Weekday) -> &str {
+    ///         ["MONDAY", "TUESDAY", "WEDNESDAY", "THURSDAY", "FRIDAY", "SATURDAY", "SUNDAY"]
+    ///             [weekday.num_days_from_monday() as usize]
+    ///     }
+    ///     fn am_pm(&self, pm: bool) -> &str {
+    ///         if pm { "PM" } else { "AM" }
+    ///     }
+    /// }
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+    /// let mut buf = String::new();
+    /// DelayedFormat::new(Some(date), None, StrftimeItems::new("%A %e %B"))
+    ///     .write_to_with_locale_table(&mut buf, &Shouting)
+    ///     .unwrap();
+    /// assert_eq!(buf, "MONDAY 15 JANUARY");
+    /// ```
+    pub fn write_to_with_locale_table(
+        &self,
+        w: &mut impl Write,
+        table: &dyn LocaleTable,
+    ) -> fmt::Result {
+        self.format(w, table)
+    }
+
+    fn format<L: LocaleTable + ?Sized>(&self, w: &mut impl Write, table: &L) -> fmt::Result {
+        let off = self.off.as_ref().map(|(name, off)| (name as &dyn Display, *off));
+        FormatValues { date: self.date, time: self.time, off }.format(w, self.items.clone(), table)
+    }
+}
+
+/// Writes `items` applied to the given date, time and offset into `w`, in the default locale.
+///
+/// The offset is given as its name and its local-to-UTC difference. Unlike [`DelayedFormat`] this
+/// never allocates, so it is available without the `alloc` feature.
+pub(crate) fn write_items<'a, B: Borrow<Item<'a>>>(
+    w: &mut impl Write,
+    date: Option<NaiveDate>,
+    time: Option<NaiveTime>,
+    off: Option<(&dyn Display, FixedOffset)>,
+    items: impl Iterator<Item = B>,
+) -> fmt::Result {
+    FormatValues { date, time, off }.format(w, items, &default_locale())
+}
+
+/// The values that formatting items are applied to.
+struct FormatValues<'o> {
+    /// The date view, if any.
+    date: Option<NaiveDate>,
+    /// The time view, if any.
+    time: Option<NaiveTime>,
+    /// The name and local-to-UTC difference for the offset (timezone), if any.
+    off: Option<(&'o dyn Display, FixedOffset)>,
+}
+
+impl FormatValues<'_> {
+    fn format<'a, B, L>(
+        &self,
+        w: &mut impl Write,
+        items: impl Iterator<Item = B>,
+        table: &L,
+    ) -> fmt::Result
+    where
+        B: Borrow<Item<'a>>,
+        L: LocaleTable + ?Sized,
+    {
+        for item in items {
             match *item.borrow() {
                 Item::Literal(s) | Item::Space(s) => w.write_str(s),
                 #[cfg(feature = "alloc")]
                 Item::OwnedLiteral(ref s) | Item::OwnedSpace(ref s) => w.write_str(s),
                 Item::Numeric(ref spec, pad) => self.format_numeric(w, spec, pad),
-                Item::Fixed(ref spec) => self.format_fixed(w, spec),
+                Item::Fixed(ref spec) => self.format_fixed(w, spec, table),
                 Item::Error => Err(fmt::Error),
             }?;
         }
         Ok(())
     }
 
-    #[cfg(feature = "alloc")]
     fn format_numeric(&self, w: &mut impl Write, spec: &Numeric, pad: Pad) -> fmt::Result {
         use self::Numeric::*;
 
@@ -200,41 +307,37 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
         }
     }
 
-    #[cfg(feature = "alloc")]
-    fn format_fixed(&self, w: &mut impl Write, spec: &Fixed) -> fmt::Result {
+    fn format_fixed<L: LocaleTable + ?Sized>(
+        &self,
+        w: &mut impl Write,
+        spec: &Fixed,
+        table: &L,
+    ) -> fmt::Result {
         use Fixed::*;
         use InternalInternal::*;
 
+        fn month(d: NaiveDate) -> Month {
+            Month::from_month0(d.month0())
+        }
+
         match (spec, self.date, self.time, self.off.as_ref()) {
-            (ShortMonthName, Some(d), _, _) => {
-                w.write_str(short_months(self.locale)[d.month0() as usize])
-            }
-            (LongMonthName, Some(d), _, _) => {
-                w.write_str(long_months(self.locale)[d.month0() as usize])
-            }
-            (ShortWeekdayName, Some(d), _, _) => w.write_str(
-                short_weekdays(self.locale)[d.weekday().num_days_from_sunday() as usize],
-            ),
-            (LongWeekdayName, Some(d), _, _) => {
-                w.write_str(long_weekdays(self.locale)[d.weekday().num_days_from_sunday() as usize])
-            }
+            (ShortMonthName, Some(d), _, _) => w.write_str(table.short_month_name(month(d))),
+            (LongMonthName, Some(d), _, _) => w.write_str(table.long_month_name(month(d))),
+            (ShortWeekdayName, Some(d), _, _) => w.write_str(table.short_weekday_name(d.weekday())),
+            (LongWeekdayName, Some(d), _, _) => w.write_str(table.long_weekday_name(d.weekday())),
             (LowerAmPm, _, Some(t), _) => {
-                let ampm = if t.hour12().0 { am_pm(self.locale)[1] } else { am_pm(self.locale)[0] };
-                for c in ampm.chars().flat_map(|c| c.to_lowercase()) {
+                for c in table.am_pm(t.hour12().0).chars().flat_map(|c| c.to_lowercase()) {
                     w.write_char(c)?
                 }
                 Ok(())
             }
-            (UpperAmPm, _, Some(t), _) => {
-                let ampm = if t.hour12().0 { am_pm(self.locale)[1] } else { am_pm(self.locale)[0] };
-                w.write_str(ampm)
-            }
+            (UpperAmPm, _, Some(t), _) => w.write_str(table.am_pm(t.hour12().0)),
             (Nanosecond, _, Some(t), _) => {
                 let nano = t.nanosecond() % 1_000_000_000;
                 if nano == 0 {
                     Ok(())
                 } else {
-                    w.write_str(decimal_point(self.locale))?;
+                    w.write_str(table.decimal_point())?;
                     if nano % 1_000_000 == 0 {
                         write!(w, "{:03}", nano / 1_000_000)
                     } else if nano % 1_000 == 0 {
@@ -245,15 +348,15 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
                 }
             }
             (Nanosecond3, _, Some(t), _) => {
-                w.write_str(decimal_point(self.locale))?;
+                w.write_str(table.decimal_point())?;
                 write!(w, "{:03}", t.nanosecond() / 1_000_000 % 1000)
             }
             (Nanosecond6, _, Some(t), _) => {
-                w.write_str(decimal_point(self.locale))?;
+                w.write_str(table.decimal_point())?;
                 write!(w, "{:06}", t.nanosecond() / 1_000 % 1_000_000)
             }
             (Nanosecond9, _, Some(t), _) => {
-                w.write_str(decimal_point(self.locale))?;
+                w.write_str(table.decimal_point())?;
                 write!(w, "{:09}", t.nanosecond() % 1_000_000_000)
             }
             (Internal(InternalFixed { val: Nanosecond3NoDot }), _, Some(t), _) => {
@@ -265,6 +368,9 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
             (Internal(InternalFixed { val: Nanosecond9NoDot }), _, Some(t), _) => {
                 write!(w, "{:09}", t.nanosecond() % 1_000_000_000)
             }
+            (Internal(InternalFixed { val: YearSigned }), Some(d), _, _) => {
+                write!(w, "{:+05}", d.year())
+            }
             (TimezoneName, _, _, Some((tz_name, _))) => write!(w, "{}", tz_name),
             (TimezoneOffset | TimezoneOffsetZ, _, _, Some((_, off))) => {
                 let offset_format = OffsetFormat {
@@ -320,8 +426,12 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
 #[cfg(feature = "alloc")]
 impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> Display for DelayedFormat<I> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.width().is_none() && f.precision().is_none() {
+            // Without padding or truncation the output can be written directly.
+            return self.format(f, &self.locale);
+        }
         let mut result = String::new();
-        self.format(&mut result)?;
+        self.format(&mut result, &self.locale)?;
         f.pad(&result)
     }
 }
@@ -371,7 +481,6 @@ pub fn format_item(
     .fmt(w)
 }
 
-#[cfg(any(feature = "alloc", feature = "serde"))]
 impl OffsetFormat {
     /// Writes an offset from UTC with the format defined by `self`.
     fn format(&self, w: &mut impl Write, off: FixedOffset) -> fmt::Result {
@@ -481,13 +590,29 @@ pub enum SecondsFormat {
 
 /// Writes the date, time and offset to the string. same as `%Y-%m-%dT%H:%M:%S%.f%:z`
 #[inline]
-#[cfg(any(feature = "alloc", feature = "serde"))]
 pub(crate) fn write_rfc3339(
     w: &mut impl Write,
     dt: NaiveDateTime,
     off: FixedOffset,
     secform: SecondsFormat,
     use_z: bool,
+) -> fmt::Result {
+    let offset_format = OffsetFormat {
+        precision: OffsetPrecision::Minutes,
+        colons: Colons::Colon,
+        allow_zulu: use_z,
+        padding: Pad::Zero,
+    };
+    write_rfc3339_with_offset_format(w, dt, off, secform, offset_format)
+}
+
+/// Writes the date and time like [`write_rfc3339`], with the offset in the given format.
+pub(crate) fn write_rfc3339_with_offset_format(
+    w: &mut impl Write,
+    dt: NaiveDateTime,
+    off: FixedOffset,
+    secform: SecondsFormat,
+    offset_format: OffsetFormat,
 ) -> fmt::Result {
     let year = dt.date().year();
     if (0..=9999).contains(&year) {
@@ -535,16 +660,9 @@ pub(crate) fn write_rfc3339(
         SecondsFormat::__NonExhaustive => unreachable!(),
     };
 
-    OffsetFormat {
-        precision: OffsetPrecision::Minutes,
-        colons: Colons::Colon,
-        allow_zulu: use_z,
-        padding: Pad::Zero,
-    }
-    .format(w, off)
+    offset_format.format(w, off)
 }
 
-#[cfg(feature = "alloc")]
 /// write datetimes like `Tue, 1 Jul 2003 10:52:37 +0200`, same as `%a, %d %b %Y %H:%M:%S %z`
 pub(crate) fn write_rfc2822(
     w: &mut impl Write,
@@ -748,6 +866,90 @@ mod tests {
         assert_eq!("12:34:56.1234", format!("{:.13}", time));
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_format_into() {
+        use crate::format::StrftimeItems;
+        use core::fmt;
+
+        /// A writer into a fixed-size buffer, which fails when it is full.
+        struct FixedBuf {
+            buf: [u8; 40],
+            len: usize,
+        }
+
+        impl fmt::Write for FixedBuf {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                let end = self.len + s.len();
+                self.buf.get_mut(self.len..end).ok_or(fmt::Error)?.copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        impl FixedBuf {
+            fn as_str(&self) -> &str {
+                core::str::from_utf8(&self.buf[..self.len]).unwrap()
+            }
+        }
+
+        let items = StrftimeItems::new("%Y-%m-%dT%H:%M:%S%.3f %:z %a").parse().unwrap();
+        let dt = FixedOffset::east_opt(-5 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2024, 1, 15, 9, 30, 15)
+            .unwrap()
+            .with_nanosecond(123_456_789)
+            .unwrap();
+        let expected = dt.format_with_items(items.iter()).to_string();
+        assert_eq!(expected, "2024-01-15T09:30:15.123 -05:00 Mon");
+
+        let mut s = String::new();
+        dt.format_into(&mut s, &items).unwrap();
+        assert_eq!(s, expected);
+        let mut buf = FixedBuf { buf: [0; 40], len: 0 };
+        dt.format_into(&mut buf, &items).unwrap();
+        assert_eq!(buf.as_str(), expected);
+
+        // the offset name is written without an intermediate `String`
+        let items = StrftimeItems::new("%H:%M %Z").parse().unwrap();
+        let mut buf = FixedBuf { buf: [0; 40], len: 0 };
+        dt.format_into(&mut buf, &items).unwrap();
+        assert_eq!(buf.as_str(), "09:30 -05:00");
+        let mut buf = FixedBuf { buf: [0; 40], len: 0 };
+        dt.with_timezone(&Utc).format_into(&mut buf, &items).unwrap();
+        assert_eq!(buf.as_str(), "14:30 UTC");
+
+        // naive types
+        let naive = dt.naive_local();
+        let items = StrftimeItems::new("%Y-%m-%d %H:%M:%S%.3f").parse().unwrap();
+        let mut buf = FixedBuf { buf: [0; 40], len: 0 };
+        naive.format_into(&mut buf, &items).unwrap();
+        assert_eq!(buf.as_str(), naive.format_with_items(items.iter()).to_string());
+
+        let items = StrftimeItems::new("%A %e %B").parse().unwrap();
+        let mut buf = FixedBuf { buf: [0; 40], len: 0 };
+        naive.date().format_into(&mut buf, &items).unwrap();
+        assert_eq!(buf.as_str(), "Monday 15 January");
+
+        let items = StrftimeItems::new("%I:%M %p").parse().unwrap();
+        let mut buf = FixedBuf { buf: [0; 40], len: 0 };
+        naive.time().format_into(&mut buf, &items).unwrap();
+        assert_eq!(buf.as_str(), "09:30 AM");
+
+        // errors of the writer and of the items are reported
+        let items = StrftimeItems::new("%Y%Y%Y%Y%Y%Y%Y%Y%Y%Y%Y").parse().unwrap();
+        let mut buf = FixedBuf { buf: [0; 40], len: 0 };
+        assert_eq!(naive.format_into(&mut buf, &items), Err(fmt::Error));
+        let items = StrftimeItems::new("%z").parse().unwrap();
+        assert_eq!(naive.format_into(&mut String::new(), &items), Err(fmt::Error));
+
+        // `Display` writes directly without padding, and pads otherwise
+        let delayed = naive.date().format("%Y");
+        assert_eq!(format!("{}", delayed), "2024");
+        assert_eq!(format!("{:>6}", delayed), "  2024");
+        assert_eq!(format!("{:.2}", delayed), "20");
+    }
+
     #[test]
     fn test_offset_formatting() {
         fn check_all(precision: OffsetPrecision, expected: [[&str; 7]; 12]) {
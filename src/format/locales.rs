@@ -1,3 +1,5 @@
+use crate::{Month, Weekday};
+
 #[cfg(feature = "unstable-locales")]
 mod localized {
     use pure_rust_locales::{locale_match, Locale};
@@ -101,3 +103,104 @@ mod unlocalized {
 
 #[cfg(not(feature = "unstable-locales"))]
 pub(crate) use unlocalized::*;
+
+/// A table of the localized names and symbols used when formatting a date and time.
+///
+/// This allows formatting with a locale that chrono does not bundle, see
+/// [`DelayedFormat::write_to_with_locale_table`](crate::format::DelayedFormat::write_to_with_locale_table)
+/// and [`DateTime::format_with_locale_table`](crate::DateTime::format_with_locale_table).
+/// With the `unstable-locales` feature [`Locale`](crate::Locale) implements this trait for the
+/// built-in locales.
+///
+/// The table only supplies the names for `%b`, `%B`, `%a`, `%A`, `%p`, `%P` and the decimal
+/// point for the fractional seconds. It does not change the formatting items, so the
+/// locale-dependent `%c`, `%x`, `%X` and `%r` keep using the format of the locale they were
+/// parsed with.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::LocaleTable;
+/// use chrono::{Month, Weekday};
+///
+/// struct Dutch;
+///
+/// impl LocaleTable for Dutch {
+///     fn short_month_name(&self, month: Month) -> &str {
+///         ["jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec"]
+///             [month.number_from_month() as usize - 1]
+///     }
+///     fn long_month_name(&self, month: Month) -> &str {
+///         [
+///             "januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus",
+///             "september", "oktober", "november", "december",
+///         ][month.number_from_month() as usize - 1]
+///     }
+///     fn short_weekday_name(&self, weekday: Weekday) -> &str {
+///         ["ma", "di", "wo", "do", "vr", "za", "zo"][weekday.num_days_from_monday() as usize]
+///     }
+///     fn long_weekday_name(&self, weekday: Weekday) -> &str {
+///         ["maandag", "dinsdag", "woensdag", "donderdag", "vrijdag", "zaterdag", "zondag"]
+///             [weekday.num_days_from_monday() as usize]
+///     }
+///     fn am_pm(&self, _pm: bool) -> &str {
+///         ""
+///     }
+///     fn decimal_point(&self) -> &str {
+///         ","
+///     }
+/// }
+///
+/// assert_eq!(Dutch.long_month_name(Month::March), "maart");
+/// assert_eq!(Dutch.decimal_point(), ",");
+/// ```
+pub trait LocaleTable {
+    /// Returns the abbreviated name of `month`, as written by `%b`.
+    fn short_month_name(&self, month: Month) -> &str;
+
+    /// Returns the full name of `month`, as written by `%B`.
+    fn long_month_name(&self, month: Month) -> &str;
+
+    /// Returns the abbreviated name of `weekday`, as written by `%a`.
+    fn short_weekday_name(&self, weekday: Weekday) -> &str;
+
+    /// Returns the full name of `weekday`, as written by `%A`.
+    fn long_weekday_name(&self, weekday: Weekday) -> &str;
+
+    /// Returns the name for the hours before noon if `pm` is `false`, or after noon if `pm` is
+    /// `true`, as written by `%p`. `%P` writes it in lowercase.
+    fn am_pm(&self, pm: bool) -> &str;
+
+    /// Returns the decimal point written before the fractional seconds by `%.f`.
+    ///
+    /// The default is `"."`.
+    fn decimal_point(&self) -> &str {
+        "."
+    }
+}
+
+impl LocaleTable for Locale {
+    fn short_month_name(&self, month: Month) -> &str {
+        short_months(*self)[month.number_from_month() as usize - 1]
+    }
+
+    fn long_month_name(&self, month: Month) -> &str {
+        long_months(*self)[month.number_from_month() as usize - 1]
+    }
+
+    fn short_weekday_name(&self, weekday: Weekday) -> &str {
+        short_weekdays(*self)[weekday.num_days_from_sunday() as usize]
+    }
+
+    fn long_weekday_name(&self, weekday: Weekday) -> &str {
+        long_weekdays(*self)[weekday.num_days_from_sunday() as usize]
+    }
+
+    fn am_pm(&self, pm: bool) -> &str {
+        am_pm(*self)[pm as usize]
+    }
+
+    fn decimal_point(&self) -> &str {
+        decimal_point(*self)
+    }
+}
@@ -55,19 +55,25 @@ pub mod strftime;
 pub(crate) mod locales;
 
 pub(crate) use formatting::write_hundreds;
+pub(crate) use formatting::write_items;
 #[cfg(feature = "alloc")]
 pub(crate) use formatting::write_rfc2822;
 #[cfg(any(feature = "alloc", feature = "serde"))]
 pub(crate) use formatting::write_rfc3339;
+#[cfg(feature = "alloc")]
+pub(crate) use formatting::write_rfc3339_with_offset_format;
 pub use formatting::SecondsFormat;
 #[cfg(feature = "alloc")]
 #[allow(deprecated)]
 pub use formatting::{format, format_item, DelayedFormat};
 #[cfg(feature = "unstable-locales")]
 pub use locales::Locale;
-pub(crate) use parse::parse_rfc3339;
+pub use locales::LocaleTable;
 pub use parse::{parse, parse_and_remainder};
-pub use parsed::Parsed;
+pub(crate) use parse::{parse_iso8601_basic, parse_rfc2822, parse_rfc3339};
+pub use parsed::{BasicTzResolver, Parsed, TzResolver};
+#[cfg(feature = "alloc")]
+pub use strftime::CompiledFormat;
 pub use strftime::StrftimeItems;
 
 /// An uninhabited type used for `InternalNumeric` and `InternalFixed` below.
@@ -98,6 +104,8 @@ pub enum Pad {
 /// It also trims the preceding whitespace if any.
 /// It cannot parse the negative number, so some date and time cannot be formatted then
 /// parsed with the same formatting items.
+/// With [`Parsed::set_fixed_width_numbers`], an item with [`Pad::Zero`] padding has to consume
+/// exactly its parsing width instead.
 #[non_exhaustive]
 #[derive(Clone, PartialEq, Eq, Debug, Hash)]
 pub enum Numeric {
@@ -145,6 +153,7 @@ pub enum Numeric {
     Nanosecond,
     /// The number of non-leap seconds since the midnight UTC on January 1, 1970 (FW=1, PW=∞).
     /// For formatting, it assumes UTC upon the absence of time zone offset.
+    /// It is negative for dates before 1970, and the sign is accepted when parsing.
     Timestamp,
 
     /// Internal uses only.
@@ -277,6 +286,9 @@ enum InternalInternal {
     Nanosecond6NoDot,
     /// Same as [`Nanosecond`](#variant.Nanosecond) but the accuracy is fixed to 9 and there is no leading dot.
     Nanosecond9NoDot,
+    /// Same as [`Numeric::Year`] but always with a sign and at least 4 digits, the ISO 8601
+    /// expanded representation. A sign is required when parsing.
+    YearSigned,
 }
 
 /// Type for specifying the format of UTC offsets.
@@ -460,10 +472,10 @@ impl Error for ParseError {
 pub(crate) const OUT_OF_RANGE: ParseError = ParseError(ParseErrorKind::OutOfRange);
 const IMPOSSIBLE: ParseError = ParseError(ParseErrorKind::Impossible);
 const NOT_ENOUGH: ParseError = ParseError(ParseErrorKind::NotEnough);
-const INVALID: ParseError = ParseError(ParseErrorKind::Invalid);
-const TOO_SHORT: ParseError = ParseError(ParseErrorKind::TooShort);
+pub(crate) const INVALID: ParseError = ParseError(ParseErrorKind::Invalid);
+pub(crate) const TOO_SHORT: ParseError = ParseError(ParseErrorKind::TooShort);
 pub(crate) const TOO_LONG: ParseError = ParseError(ParseErrorKind::TooLong);
-const BAD_FORMAT: ParseError = ParseError(ParseErrorKind::BadFormat);
+pub(crate) const BAD_FORMAT: ParseError = ParseError(ParseErrorKind::BadFormat);
 
 // this implementation is here only because we need some private code from `scan`
 
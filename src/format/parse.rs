@@ -40,7 +40,16 @@ fn set_weekday_with_number_from_monday(p: &mut Parsed, v: i64) -> ParseResult<()
     })
 }
 
-fn parse_rfc2822<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a str, ())> {
+/// Parses an RFC 2822 date-and-time.
+///
+/// If `lenient` is `true`, comments and folding white space (CFWS) are accepted between any two
+/// tokens. Otherwise only white space is accepted between the tokens, and comments only after the
+/// time zone.
+pub(crate) fn parse_rfc2822<'a>(
+    parsed: &mut Parsed,
+    mut s: &'a str,
+    lenient: bool,
+) -> ParseResult<(&'a str, ())> {
     macro_rules! try_consume {
         ($e:expr) => {{
             let (s_, v) = $e?;
@@ -53,21 +62,22 @@ fn parse_rfc2822<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
     //
     // c-char      = <any char except '(', ')' and '\\'>
     // c-escape    = "\" <any char>
-    // comment     = "(" *(comment / c-char / c-escape) ")" *S
-    // date-time   = [ day-of-week "," ] date 1*S time *S *comment
-    // day-of-week = *S day-name *S
+    // comment     = "(" *(comment / c-char / c-escape) ")"
+    // CFWS        = 1*(S / comment)
+    // date-time   = [ day-of-week "," ] date CFWS time [CFWS]
+    // day-of-week = [CFWS] day-name [CFWS]
     // day-name    = "Mon" / "Tue" / "Wed" / "Thu" / "Fri" / "Sat" / "Sun"
     // date        = day month year
-    // day         = *S 1*2DIGIT *S
-    // month       = 1*S month-name 1*S
+    // day         = [CFWS] 1*2DIGIT [CFWS]
+    // month       = CFWS month-name CFWS
     // month-name  = "Jan" / "Feb" / "Mar" / "Apr" / "May" / "Jun" /
     //               "Jul" / "Aug" / "Sep" / "Oct" / "Nov" / "Dec"
-    // year        = *S 2*DIGIT *S
-    // time        = time-of-day 1*S zone
+    // year        = [CFWS] 2*DIGIT [CFWS]
+    // time        = time-of-day CFWS zone
     // time-of-day = hour ":" minute [ ":" second ]
-    // hour        = *S 2DIGIT *S
-    // minute      = *S 2DIGIT *S
-    // second      = *S 2DIGIT *S
+    // hour        = [CFWS] 2DIGIT [CFWS]
+    // minute      = [CFWS] 2DIGIT [CFWS]
+    // second      = [CFWS] 2DIGIT [CFWS]
     // zone        = ( "+" / "-" ) 4DIGIT /
     //               "UT" / "GMT" /                  ; same as +0000
     //               "EST" / "CST" / "MST" / "PST" / ; same as -0500 to -0800
@@ -78,12 +88,14 @@ fn parse_rfc2822<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
     //
     // - quoted characters can be in any mixture of lower and upper cases.
     //
-    // - we do not recognize a folding white space (FWS) or comment (CFWS).
-    //   for our purposes, instead, we accept any sequence of Unicode
-    //   white space characters (denoted here to `S`). For comments, we accept
-    //   any text within parentheses while respecting escaped parentheses.
-    //   Any actual RFC 2822 parser is expected to parse FWS and/or CFWS themselves
-    //   and replace it with a single SP (`%x20`); this is legitimate.
+    // - we accept comments and folding white space (CFWS) wherever RFC 2822
+    //   allows them in the obsolete syntax, that is between any two tokens.
+    //   for white space we accept any sequence of Unicode white space
+    //   characters (denoted here to `S`), which includes the CRLF of a folded
+    //   line. For comments, we accept any text within parentheses while
+    //   respecting escaped and nested parentheses.
+    //   in strict mode `CFWS` is replaced by `1*S`, and comments are only
+    //   accepted after the zone.
     //
     // - two-digit year < 50 should be interpreted by adding 2000.
     //   two-digit year >= 50 or three-digit year should be interpreted
@@ -98,9 +110,15 @@ fn parse_rfc2822<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
     //   since we do not directly go to a `DateTime` so one can recover
     //   the offset information from `Parsed` anyway.
 
-    s = s.trim_start();
+    // separators between the tokens
+    let cfws = |s: &'a str| if lenient { scan::cfws_2822(s) } else { s.trim_start() };
+    let cfws_required =
+        |s: &'a str| if lenient { scan::cfws_2822_required(s) } else { scan::space(s) };
+
+    s = cfws(s);
 
     if let Ok((s_, weekday)) = scan::short_weekday(s) {
+        let s_ = cfws(s_);
         if !s_.starts_with(',') {
             return Err(INVALID);
         }
@@ -108,11 +126,11 @@ fn parse_rfc2822<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
         parsed.set_weekday(weekday)?;
     }
 
-    s = s.trim_start();
+    s = cfws(s);
     parsed.set_day(try_consume!(scan::number(s, 1, 2)))?;
-    s = scan::space(s)?; // mandatory
+    s = cfws_required(s)?; // mandatory
     parsed.set_month(1 + i64::from(try_consume!(scan::short_month0(s))))?;
-    s = scan::space(s)?; // mandatory
+    s = cfws_required(s)?; // mandatory
 
     // distinguish two- and three-digit years from four-digit years
     let prevlen = s.len();
@@ -132,16 +150,18 @@ fn parse_rfc2822<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
     }
     parsed.set_year(year)?;
 
-    s = scan::space(s)?; // mandatory
+    s = cfws_required(s)?; // mandatory
     parsed.set_hour(try_consume!(scan::number(s, 2, 2)))?;
-    s = scan::char(s.trim_start(), b':')?.trim_start(); // *S ":" *S
+    s = cfws(scan::char(cfws(s), b':')?); // [CFWS] ":" [CFWS]
     parsed.set_minute(try_consume!(scan::number(s, 2, 2)))?;
-    if let Ok(s_) = scan::char(s.trim_start(), b':') {
-        // [ ":" *S 2DIGIT ]
-        parsed.set_second(try_consume!(scan::number(s_, 2, 2)))?;
+    if let Ok(s_) = scan::char(cfws(s), b':') {
+        // [ ":" [CFWS] 2DIGIT ]
+        parsed.set_second(try_consume!(scan::number(cfws(s_), 2, 2)))?;
     }
 
-    s = scan::space(s)?; // mandatory
+    // a `-0000` offset means the time is in UTC, but the local time zone is unknown.
+    // we can't represent this distinction, so it is parsed as `+0000`.
+    s = cfws_required(s)?; // mandatory
     parsed.set_offset(i64::from(try_consume!(scan::timezone_offset_2822(s))))?;
 
     // optional comments
@@ -169,7 +189,7 @@ pub(crate) fn parse_rfc3339<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseRes
     // time-hour      = 2DIGIT ; 00-23
     // time-minute    = 2DIGIT ; 00-59
     // time-second    = 2DIGIT ; 00-58, 00-59, 00-60 based on leap second rules
-    // time-secfrac   = "." 1*DIGIT
+    // time-secfrac   = ("." / ",") 1*DIGIT
     // time-numoffset = ("+" / "-") time-hour ":" time-minute
     // time-offset    = "Z" / time-numoffset
     // partial-time   = time-hour ":" time-minute ":" time-second [time-secfrac]
@@ -189,6 +209,8 @@ pub(crate) fn parse_rfc3339<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseRes
     //   since this is not a typical Chrono behavior, we check it earlier.
     //
     // - For readability a full-date and a full-time may be separated by a space character.
+    //
+    // - ISO 8601 allows a comma as the decimal separator of the fractional seconds.
 
     parsed.set_year(try_consume!(scan::number(s, 4, 4)))?;
     s = scan::char(s, b'-')?;
@@ -207,12 +229,13 @@ pub(crate) fn parse_rfc3339<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseRes
     parsed.set_minute(try_consume!(scan::number(s, 2, 2)))?;
     s = scan::char(s, b':')?;
     parsed.set_second(try_consume!(scan::number(s, 2, 2)))?;
-    if s.starts_with('.') {
+    if s.starts_with(['.', ',']) {
         let nanosecond = try_consume!(scan::nanosecond(&s[1..]));
         parsed.set_nanosecond(nanosecond)?;
     }
 
-    let offset = try_consume!(scan::timezone_offset(s, |s| scan::char(s, b':'), true, false, true));
+    let offset =
+        try_consume!(scan::timezone_offset(s, |s| scan::char(s, b':'), true, false, false, true));
     // This range check is similar to the one in `FixedOffset::east_opt`, so it would be redundant.
     // But it is possible to read the offset directly from `Parsed`. We want to only successfully
     // populate `Parsed` if the input is fully valid RFC 3339.
@@ -226,6 +249,56 @@ pub(crate) fn parse_rfc3339<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseRes
     Ok((s, ()))
 }
 
+pub(crate) fn parse_iso8601_basic<'a>(
+    parsed: &mut Parsed,
+    mut s: &'a str,
+) -> ParseResult<(&'a str, ())> {
+    macro_rules! try_consume {
+        ($e:expr) => {{
+            let (s_, v) = $e?;
+            s = s_;
+            v
+        }};
+    }
+
+    // the ISO 8601 basic format, without separators:
+    //
+    // year      = 4DIGIT
+    // month     = 2DIGIT
+    // day       = 2DIGIT
+    // hour      = 2DIGIT
+    // minute    = 2DIGIT
+    // second    = 2DIGIT
+    // secfrac   = ("." / ",") 1*DIGIT
+    // numoffset = ("+" / "-") hour minute
+    // offset    = "Z" / numoffset
+    // date-time = year month day "T" hour minute second [secfrac] offset
+    //
+    // some notes:
+    //
+    // - unlike the generic parser, no white space is accepted between the fields.
+    //
+    // - years outside 0000 to 9999 would need a sign and an agreed number of
+    //   digits, so they are not accepted.
+
+    parsed.set_year(try_consume!(scan::number(s, 4, 4)))?;
+    parsed.set_month(try_consume!(scan::number(s, 2, 2)))?;
+    parsed.set_day(try_consume!(scan::number(s, 2, 2)))?;
+    s = scan::char(s, b'T')?;
+    parsed.set_hour(try_consume!(scan::number(s, 2, 2)))?;
+    parsed.set_minute(try_consume!(scan::number(s, 2, 2)))?;
+    parsed.set_second(try_consume!(scan::number(s, 2, 2)))?;
+    if s.starts_with(['.', ',']) {
+        let nanosecond = try_consume!(scan::nanosecond(&s[1..]));
+        parsed.set_nanosecond(nanosecond)?;
+    }
+
+    let offset = try_consume!(scan::timezone_offset(s, |s| Ok(s), true, false, false, false));
+    parsed.set_offset(i64::from(offset))?;
+
+    Ok((s, ()))
+}
+
 /// Tries to parse given string into `parsed` with given formatting items.
 /// Returns `Ok` when the entire string has been parsed (otherwise `parsed` should not be used).
 /// There should be no trailing string after parsing;
@@ -333,7 +406,7 @@ where
                 s = s.trim_start();
             }
 
-            Item::Numeric(ref spec, ref _pad) => {
+            Item::Numeric(ref spec, ref pad) => {
                 use super::Numeric::*;
                 type Setter = fn(&mut Parsed, i64) -> ParseResult<()>;
 
@@ -357,12 +430,18 @@ where
                     Minute => (2, false, Parsed::set_minute),
                     Second => (2, false, Parsed::set_second),
                     Nanosecond => (9, false, Parsed::set_nanosecond),
-                    Timestamp => (usize::MAX, false, Parsed::set_timestamp),
+                    Timestamp => (usize::MAX, true, Parsed::set_timestamp),
 
                     // for the future expansion
                     Internal(ref int) => match int._dummy {},
                 };
 
+                // in fixed-width mode a zero-padded item must consume its full width
+                let min_width = match (parsed.fixed_width_numbers(), pad) {
+                    (true, &Pad::Zero) if width != usize::MAX => width,
+                    _ => 1,
+                };
+
                 s = s.trim_start();
                 let v = if signed {
                     if s.starts_with('-') {
@@ -372,11 +451,19 @@ where
                         try_consume!(scan::number(&s[1..], 1, usize::MAX))
                     } else {
                         // if there is no explicit sign, we respect the original `width`
-                        try_consume!(scan::number(s, 1, width))
+                        try_consume!(scan::number(s, min_width, width))
                     }
                 } else {
-                    try_consume!(scan::number(s, 1, width))
+                    try_consume!(scan::number(s, min_width, width))
                 };
+                if let Nanosecond = *spec {
+                    // digits beyond nanosecond precision are skipped, truncating the fraction.
+                    let rest = s.trim_start_matches(|c: char| c.is_ascii_digit());
+                    if rest.len() < s.len() && parsed.strict_fractions() {
+                        return Err(TOO_LONG);
+                    }
+                    s = rest;
+                }
                 set(parsed, v)?;
             }
 
@@ -386,21 +473,39 @@ where
                 match spec {
                     &ShortMonthName => {
                         let month0 = try_consume!(scan::short_month0(s));
+                        if parsed.strict_names() {
+                            scan::name_end(s)?;
+                        }
                         parsed.set_month(i64::from(month0) + 1)?;
                     }
 
                     &LongMonthName => {
-                        let month0 = try_consume!(scan::short_or_long_month0(s));
+                        let month0 = if parsed.strict_names() {
+                            let month0 = try_consume!(scan::long_month0(s));
+                            scan::name_end(s)?;
+                            month0
+                        } else {
+                            try_consume!(scan::short_or_long_month0(s))
+                        };
                         parsed.set_month(i64::from(month0) + 1)?;
                     }
 
                     &ShortWeekdayName => {
                         let weekday = try_consume!(scan::short_weekday(s));
+                        if parsed.strict_names() {
+                            scan::name_end(s)?;
+                        }
                         parsed.set_weekday(weekday)?;
                     }
 
                     &LongWeekdayName => {
-                        let weekday = try_consume!(scan::short_or_long_weekday(s));
+                        let weekday = if parsed.strict_names() {
+                            let weekday = try_consume!(scan::long_weekday(s));
+                            scan::name_end(s)?;
+                            weekday
+                        } else {
+                            try_consume!(scan::short_or_long_weekday(s))
+                        };
                         parsed.set_weekday(weekday)?;
                     }
 
@@ -419,11 +524,29 @@ where
 
                     &Nanosecond | &Nanosecond3 | &Nanosecond6 | &Nanosecond9 => {
                         if s.starts_with('.') {
+                            if parsed.strict_fractions() {
+                                let digits = s[1..].bytes().take_while(u8::is_ascii_digit).count();
+                                if digits > 9 {
+                                    return Err(TOO_LONG);
+                                }
+                            }
                             let nano = try_consume!(scan::nanosecond(&s[1..]));
                             parsed.set_nanosecond(nano)?;
                         }
                     }
 
+                    &Internal(InternalFixed { val: InternalInternal::YearSigned }) => {
+                        s = s.trim_start();
+                        let negative = match s.as_bytes().first() {
+                            Some(b'+') => false,
+                            Some(b'-') => true,
+                            Some(_) => return Err(INVALID),
+                            None => return Err(TOO_SHORT),
+                        };
+                        let year = try_consume!(scan::number(&s[1..], 4, usize::MAX));
+                        parsed.set_year(if negative { -year } else { year })?;
+                    }
+
                     &Internal(InternalFixed { val: InternalInternal::Nanosecond3NoDot }) => {
                         if s.len() < 3 {
                             return Err(TOO_SHORT);
@@ -449,7 +572,12 @@ where
                     }
 
                     &TimezoneName => {
-                        try_consume!(Ok((s.trim_start_matches(|c: char| !c.is_whitespace()), ())));
+                        let rest = s.trim_start_matches(|c: char| !c.is_whitespace());
+                        let name = &s[..s.len() - rest.len()];
+                        if parsed.capture_timezone_name() && !name.is_empty() {
+                            parsed.set_timezone_name(name)?;
+                        }
+                        s = rest;
                     }
 
                     &TimezoneOffsetColon
@@ -459,8 +587,9 @@ where
                         let offset = try_consume!(scan::timezone_offset(
                             s.trim_start(),
                             scan::colon_or_space,
+                            true,
                             false,
-                            false,
+                            true,
                             true,
                         ));
                         parsed.set_offset(i64::from(offset))?;
@@ -472,6 +601,7 @@ where
                             scan::colon_or_space,
                             true,
                             false,
+                            false,
                             true,
                         ));
                         parsed.set_offset(i64::from(offset))?;
@@ -484,12 +614,13 @@ where
                             scan::colon_or_space,
                             true,
                             true,
+                            false,
                             true,
                         ));
                         parsed.set_offset(i64::from(offset))?;
                     }
 
-                    &RFC2822 => try_consume!(parse_rfc2822(parsed, s)),
+                    &RFC2822 => try_consume!(parse_rfc2822(parsed, s, true)),
                     &RFC3339 => {
                         // Used for the `%+` specifier, which has the description:
                         // "Same as `%Y-%m-%dT%H:%M:%S%.f%:z` (...)
@@ -577,7 +708,7 @@ fn parse_rfc3339_relaxed<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult
     let (s, offset) = if s.len() >= 3 && "UTC".as_bytes().eq_ignore_ascii_case(&s.as_bytes()[..3]) {
         (&s[3..], 0)
     } else {
-        scan::timezone_offset(s, scan::colon_or_space, true, false, true)?
+        scan::timezone_offset(s, scan::colon_or_space, true, false, false, true)?
     };
     parsed.set_offset(i64::from(offset))?;
     Ok((s, ()))
@@ -596,6 +727,12 @@ mod tests {
         });
     }
 
+    fn with_timezone_name(name: &str, expected: ParseResult<Parsed>) -> ParseResult<Parsed> {
+        let mut expected = expected?;
+        expected.set_timezone_name(name)?;
+        Ok(expected)
+    }
+
     #[test]
     fn test_parse_whitespace_and_literal() {
         use crate::format::Item::{Literal, Space};
@@ -832,6 +969,9 @@ mod tests {
             &[num(Hour), num(Minute), num(Second), num(Nanosecond), num(Timestamp)],
             parsed!(hour_div_12: 1, hour_mod_12: 11, minute: 45, second: 6, nanosecond: 78_901_234, timestamp: 567_890_123),
         );
+        check("-1234567890", &[num(Timestamp)], parsed!(timestamp: -1_234_567_890));
+        check("+1234567890", &[num(Timestamp)], parsed!(timestamp: 1_234_567_890));
+        check("- 1234567890", &[num(Timestamp)], Err(INVALID));
     }
 
     #[test]
@@ -899,6 +1039,28 @@ mod tests {
         check("", &[fixed(LowerAmPm)], Err(TOO_SHORT));
     }
 
+    #[test]
+    fn test_parse_year_signed() {
+        use crate::format::InternalInternal::YearSigned;
+        use crate::format::Item::Literal;
+
+        check("+2024", &[internal_fixed(YearSigned)], parsed!(year: 2024));
+        check("+12024", &[internal_fixed(YearSigned)], parsed!(year: 12024));
+        check("-0044", &[internal_fixed(YearSigned)], parsed!(year: -44));
+        check("+0000", &[internal_fixed(YearSigned)], parsed!(year: 0));
+        check("-262144", &[internal_fixed(YearSigned)], parsed!(year: -262144));
+        check(" +2024", &[internal_fixed(YearSigned)], parsed!(year: 2024));
+        check("+2024-", &[internal_fixed(YearSigned), Literal("-")], parsed!(year: 2024));
+        check("2024", &[internal_fixed(YearSigned)], Err(INVALID));
+        check("+44", &[internal_fixed(YearSigned)], Err(TOO_SHORT));
+        check("-044", &[internal_fixed(YearSigned)], Err(TOO_SHORT));
+        check("+044x", &[internal_fixed(YearSigned)], Err(INVALID));
+        check("+", &[internal_fixed(YearSigned)], Err(TOO_SHORT));
+        check("", &[internal_fixed(YearSigned)], Err(TOO_SHORT));
+        check("+ 2024", &[internal_fixed(YearSigned)], Err(INVALID));
+        check("+99999999999", &[internal_fixed(YearSigned)], Err(OUT_OF_RANGE));
+    }
+
     #[test]
     fn test_parse_fixed_nanosecond() {
         use crate::format::Fixed::Nanosecond;
@@ -931,6 +1093,9 @@ mod tests {
         check(".000000000547", &[fixed(Nanosecond)], parsed!(nanosecond: 0));
         check(".0000000009999999999999999999999999", &[fixed(Nanosecond)], parsed!(nanosecond: 0));
         check(".4🤠", &[fixed(Nanosecond), Literal("🤠")], parsed!(nanosecond: 400_000_000));
+        // a comma is not a decimal separator here, the fraction is optional
+        check(",4", &[fixed(Nanosecond)], Err(TOO_LONG));
+        check(",2024", &[fixed(Nanosecond), Literal(","), num(Numeric::Year)], parsed!(year: 2024));
         check(".4x", &[fixed(Nanosecond)], Err(TOO_LONG));
         check(".  4", &[fixed(Nanosecond)], Err(INVALID));
         check("  .4", &[fixed(Nanosecond)], Err(TOO_LONG)); // no automatic trimming
@@ -1025,7 +1190,7 @@ mod tests {
         check("−12:34", &[fixed(TimezoneOffset)], parsed!(offset: -45_240)); // MINUS SIGN (U+2212)
         check("+12:34:", &[fixed(TimezoneOffset)], Err(TOO_LONG));
         check("+12:34:5", &[fixed(TimezoneOffset)], Err(TOO_LONG));
-        check("+12:34:56", &[fixed(TimezoneOffset)], Err(TOO_LONG));
+        check("+12:34:56", &[fixed(TimezoneOffset)], parsed!(offset: 45_296));
         check("+12:34:56:", &[fixed(TimezoneOffset)], Err(TOO_LONG));
         check("+12 34", &[fixed(TimezoneOffset)], parsed!(offset: 45_240));
         check("+12  34", &[fixed(TimezoneOffset)], parsed!(offset: 45_240));
@@ -1036,7 +1201,7 @@ mod tests {
         check("+12:::34", &[fixed(TimezoneOffset)], parsed!(offset: 45_240));
         check("+12::::34", &[fixed(TimezoneOffset)], parsed!(offset: 45_240));
         check("+12::34", &[fixed(TimezoneOffset)], parsed!(offset: 45_240));
-        check("+12:34:56", &[fixed(TimezoneOffset)], Err(TOO_LONG));
+        check("+12:34:56", &[fixed(TimezoneOffset)], parsed!(offset: 45_296));
         check("+12:3456", &[fixed(TimezoneOffset)], Err(TOO_LONG));
         check("+1234:56", &[fixed(TimezoneOffset)], Err(TOO_LONG));
         check("+1234:567", &[fixed(TimezoneOffset)], Err(TOO_LONG));
@@ -1054,6 +1219,10 @@ mod tests {
         check("-99:59", &[fixed(TimezoneOffset)], parsed!(offset: -359_940));
         check("+00:60", &[fixed(TimezoneOffset)], Err(OUT_OF_RANGE));
         check("+00:99", &[fixed(TimezoneOffset)], Err(OUT_OF_RANGE));
+        check("+05:30:00", &[fixed(TimezoneOffset)], parsed!(offset: 19_800));
+        check("-00:44:30", &[fixed(TimezoneOffset)], parsed!(offset: -2_670));
+        check("+00:00:60", &[fixed(TimezoneOffset)], Err(OUT_OF_RANGE));
+        check("+12:34:56 ", &[fixed(TimezoneOffset)], Err(TOO_LONG));
         check("#12:34", &[fixed(TimezoneOffset)], Err(INVALID));
         check("+12:34 ", &[fixed(TimezoneOffset)], Err(TOO_LONG));
         check("+12 34 ", &[fixed(TimezoneOffset)], Err(TOO_LONG));
@@ -1086,9 +1255,9 @@ mod tests {
             parsed!(offset: 45_240, day: 5),
         );
         check("+12:34:", &[fixed(TimezoneOffset), Literal(":")], parsed!(offset: 45_240));
-        check("Z12:34", &[fixed(TimezoneOffset)], Err(INVALID));
+        check("Z12:34", &[fixed(TimezoneOffset)], Err(TOO_LONG));
         check("X12:34", &[fixed(TimezoneOffset)], Err(INVALID));
-        check("Z+12:34", &[fixed(TimezoneOffset)], Err(INVALID));
+        check("Z+12:34", &[fixed(TimezoneOffset)], Err(TOO_LONG));
         check("X+12:34", &[fixed(TimezoneOffset)], Err(INVALID));
         check("X−12:34", &[fixed(TimezoneOffset)], Err(INVALID)); // MINUS SIGN (U+2212)
         check("🤠+12:34", &[fixed(TimezoneOffset)], Err(INVALID));
@@ -1101,7 +1270,7 @@ mod tests {
         check("-12:34🤠", &[fixed(TimezoneOffset), Literal("🤠")], parsed!(offset: -45_240));
         check("−12:34🤠", &[fixed(TimezoneOffset), Literal("🤠")], parsed!(offset: -45_240)); // MINUS SIGN (U+2212)
         check("🤠+12:34", &[Literal("🤠"), fixed(TimezoneOffset)], parsed!(offset: 45_240));
-        check("Z", &[fixed(TimezoneOffset)], Err(INVALID));
+        check("Z", &[fixed(TimezoneOffset)], parsed!(offset: 0));
         check("A", &[fixed(TimezoneOffset)], Err(INVALID));
         check("PST", &[fixed(TimezoneOffset)], Err(INVALID));
         check("#Z", &[fixed(TimezoneOffset)], Err(INVALID));
@@ -1109,10 +1278,10 @@ mod tests {
         check("+Z", &[fixed(TimezoneOffset)], Err(TOO_SHORT));
         check("+:Z", &[fixed(TimezoneOffset)], Err(INVALID));
         check("+Z:", &[fixed(TimezoneOffset)], Err(INVALID));
-        check("z", &[fixed(TimezoneOffset)], Err(INVALID));
+        check("z", &[fixed(TimezoneOffset)], parsed!(offset: 0));
         check(" :Z", &[fixed(TimezoneOffset)], Err(INVALID));
-        check(" Z", &[fixed(TimezoneOffset)], Err(INVALID));
-        check(" z", &[fixed(TimezoneOffset)], Err(INVALID));
+        check(" Z", &[fixed(TimezoneOffset)], parsed!(offset: 0));
+        check(" z", &[fixed(TimezoneOffset)], parsed!(offset: 0));
 
         // TimezoneOffsetColon
         check("1", &[fixed(TimezoneOffsetColon)], Err(INVALID));
@@ -1148,7 +1317,7 @@ mod tests {
         check("−12:34", &[fixed(TimezoneOffsetColon)], parsed!(offset: -45_240)); // MINUS SIGN (U+2212)
         check("+12:34:", &[fixed(TimezoneOffsetColon)], Err(TOO_LONG));
         check("+12:34:5", &[fixed(TimezoneOffsetColon)], Err(TOO_LONG));
-        check("+12:34:56", &[fixed(TimezoneOffsetColon)], Err(TOO_LONG));
+        check("+12:34:56", &[fixed(TimezoneOffsetColon)], parsed!(offset: 45_296));
         check("+12:34:56:", &[fixed(TimezoneOffsetColon)], Err(TOO_LONG));
         check("+12:34:56:7", &[fixed(TimezoneOffsetColon)], Err(TOO_LONG));
         check("+12:34:56:78", &[fixed(TimezoneOffsetColon)], Err(TOO_LONG));
@@ -1193,7 +1362,7 @@ mod tests {
             parsed!(offset: 45_240, day: 5),
         );
         check("+12:34:", &[fixed(TimezoneOffsetColon), Literal(":")], parsed!(offset: 45_240));
-        check("Z", &[fixed(TimezoneOffsetColon)], Err(INVALID));
+        check("Z", &[fixed(TimezoneOffsetColon)], parsed!(offset: 0));
         check("A", &[fixed(TimezoneOffsetColon)], Err(INVALID));
         check("PST", &[fixed(TimezoneOffsetColon)], Err(INVALID));
         check("#Z", &[fixed(TimezoneOffsetColon)], Err(INVALID));
@@ -1201,10 +1370,10 @@ mod tests {
         check("+Z", &[fixed(TimezoneOffsetColon)], Err(TOO_SHORT));
         check("+:Z", &[fixed(TimezoneOffsetColon)], Err(INVALID));
         check("+Z:", &[fixed(TimezoneOffsetColon)], Err(INVALID));
-        check("z", &[fixed(TimezoneOffsetColon)], Err(INVALID));
+        check("z", &[fixed(TimezoneOffsetColon)], parsed!(offset: 0));
         check(" :Z", &[fixed(TimezoneOffsetColon)], Err(INVALID));
-        check(" Z", &[fixed(TimezoneOffsetColon)], Err(INVALID));
-        check(" z", &[fixed(TimezoneOffsetColon)], Err(INVALID));
+        check(" Z", &[fixed(TimezoneOffsetColon)], parsed!(offset: 0));
+        check(" z", &[fixed(TimezoneOffsetColon)], parsed!(offset: 0));
         // testing `TimezoneOffsetColon` also tests same path as `TimezoneOffsetDoubleColon`
         // and `TimezoneOffsetTripleColon` for function `parse_internal`.
         // No need for separate tests for `TimezoneOffsetDoubleColon` and
@@ -1426,6 +1595,92 @@ mod tests {
         check("CEST ", &[fixed(TimezoneName)], Err(TOO_LONG));
         check(" CEST", &[fixed(TimezoneName)], Err(TOO_LONG));
         check("CE ST", &[fixed(TimezoneName)], Err(TOO_LONG));
+        // names are not stored, so they don't have to agree or fit
+        check("CEST CET", &[fixed(TimezoneName), Literal(" "), fixed(TimezoneName)], parsed!());
+        check("ABCDEFGHIJKLMNOPQRSTUVWXYZ_ABCDEFG", &[fixed(TimezoneName)], parsed!());
+    }
+
+    #[test]
+    fn test_parse_timezone_name_capture() {
+        use crate::format::Fixed::TimezoneName;
+        use crate::format::Item::Literal;
+
+        let check = |s: &str, items: &[Item], expected: ParseResult<Parsed>| {
+            let mut parsed = Parsed::new();
+            parsed.set_capture_timezone_name(true);
+            let result = parse(&mut parsed, s, items.iter());
+            let expected = expected.map(|mut expected| {
+                expected.set_capture_timezone_name(true);
+                expected
+            });
+            assert_eq!(result.map(|_| parsed), expected);
+        };
+
+        check("CEST", &[fixed(TimezoneName)], with_timezone_name("CEST", parsed!()));
+        // lowercase
+        check("cest", &[fixed(TimezoneName)], with_timezone_name("cest", parsed!()));
+        // not a real timezone name
+        check("XXXXXXXX", &[fixed(TimezoneName)], with_timezone_name("XXXXXXXX", parsed!()));
+        // not a real timezone name!
+        check("!!!!", &[fixed(TimezoneName)], with_timezone_name("!!!!", parsed!()));
+        check(
+            "CEST 5",
+            &[fixed(TimezoneName), Literal(" "), num(Numeric::Day)],
+            with_timezone_name("CEST", parsed!(day: 5)),
+        );
+        check(
+            "CEST CEST",
+            &[fixed(TimezoneName), Literal(" "), fixed(TimezoneName)],
+            with_timezone_name("CEST", parsed!()),
+        );
+        check(
+            "CEST CET",
+            &[fixed(TimezoneName), Literal(" "), fixed(TimezoneName)],
+            Err(IMPOSSIBLE),
+        );
+        check("CEST ", &[fixed(TimezoneName)], Err(TOO_LONG));
+        check(" CEST", &[fixed(TimezoneName)], Err(TOO_LONG));
+        // too long to be stored
+        check(
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZ_ABCDEF",
+            &[fixed(TimezoneName)],
+            with_timezone_name("ABCDEFGHIJKLMNOPQRSTUVWXYZ_ABCDEF", parsed!()),
+        );
+        check("ABCDEFGHIJKLMNOPQRSTUVWXYZ_ABCDEFG", &[fixed(TimezoneName)], Err(OUT_OF_RANGE));
+        // IANA time zone identifiers are stored as well
+        check(
+            "America/New_York",
+            &[fixed(TimezoneName)],
+            with_timezone_name("America/New_York", parsed!()),
+        );
+        check(
+            "America/Argentina/ComodRivadavia",
+            &[fixed(TimezoneName)],
+            with_timezone_name("America/Argentina/ComodRivadavia", parsed!()),
+        );
+        check("Etc/GMT+5", &[fixed(TimezoneName)], with_timezone_name("Etc/GMT+5", parsed!()));
+    }
+
+    #[test]
+    fn test_parse_timezone_name_iana() {
+        let mut parsed = Parsed::new();
+        parsed.set_capture_timezone_name(true);
+        let s = "2024-01-01 00:00 America/New_York";
+        parse(&mut parsed, s, StrftimeItems::new("%Y-%m-%d %H:%M %Z")).unwrap();
+        assert_eq!(parsed.timezone_name(), Some("America/New_York"));
+        let expected = crate::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0);
+        assert_eq!(parsed.to_naive_datetime_with_offset(0).ok(), expected);
+        // the name is not resolved to an offset
+        assert_eq!(parsed.offset(), None);
+        assert_eq!(parsed.to_datetime(), Err(NOT_ENOUGH));
+
+        // the name can be followed by other items
+        let mut parsed = Parsed::new();
+        parsed.set_capture_timezone_name(true);
+        let s = "Europe/Paris 2024-01-01T12:00:00+01:00";
+        parse(&mut parsed, s, StrftimeItems::new("%Z %+")).unwrap();
+        assert_eq!(parsed.timezone_name(), Some("Europe/Paris"));
+        assert_eq!(parsed.offset(), Some(3600));
     }
 
     #[test]
@@ -1571,6 +1826,145 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_fixed_width_numbers() {
+        fn parse_fixed(s: &str, fmt: &str) -> ParseResult<NaiveDateTime> {
+            let mut parsed = Parsed::new();
+            parsed.set_fixed_width_numbers(true);
+            parse(&mut parsed, s, StrftimeItems::new(fmt))?;
+            parsed.to_naive_datetime_with_offset(0)
+        }
+
+        let fmt = "%Y%m%d%H%M%S";
+        let expected =
+            crate::NaiveDate::from_ymd_opt(2024, 1, 15).unwrap().and_hms_opt(9, 30, 0).unwrap();
+        assert_eq!(parse_fixed("20240115093000", fmt), Ok(expected));
+        // a missing digit shifts the following fields: this reads day 50
+        assert_eq!(parse_fixed("2024115093000", fmt), Err(OUT_OF_RANGE));
+        assert_eq!(parse_fixed("202401150930", fmt), Err(TOO_SHORT));
+        // the lenient mode would accept a single digit for the seconds here
+        assert_eq!(parse_fixed("2024011509300", fmt), Err(TOO_SHORT));
+        assert_eq!(parse_fixed("202401150930001", fmt), Err(TOO_LONG));
+
+        // separators do not relax the width of zero-padded fields
+        let fmt = "%Y-%m-%d %H:%M:%S";
+        assert_eq!(parse_fixed("2024-01-15 09:30:00", fmt), Ok(expected));
+        assert_eq!(parse_fixed("2024-1-15 09:30:00", fmt), Err(INVALID));
+        assert_eq!(parse_fixed("2024-01-15 9:30:00", fmt), Err(INVALID));
+        assert_eq!(parse_fixed("24-01-15 09:30:00", fmt), Err(INVALID));
+        // an explicit sign still allows any number of digits for the year
+        assert_eq!(parse_fixed("+2024-01-15 09:30:00", fmt), Ok(expected));
+
+        // items without zero padding keep accepting fewer digits
+        assert_eq!(parse_fixed("2024-1-15 9:30:00", "%Y-%-m-%d %-H:%M:%S"), Ok(expected));
+        assert_eq!(parse_fixed("2024-01-15  9:30:00", "%Y-%m-%d %_H:%M:%S"), Ok(expected));
+
+        // the default mode is lenient
+        let mut parsed = Parsed::new();
+        assert!(!parsed.fixed_width_numbers());
+        parse(&mut parsed, "2024-1-15 9:30:00", StrftimeItems::new(fmt)).unwrap();
+        assert_eq!(parsed.to_naive_datetime_with_offset(0), Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_strict_names() {
+        use crate::format::parse_and_remainder;
+
+        fn parse_names(s: &str, fmt: &str, strict: bool) -> ParseResult<Parsed> {
+            let mut parsed = Parsed::new();
+            parsed.set_strict_names(strict);
+            parse(&mut parsed, s, StrftimeItems::new(fmt))?;
+            Ok(parsed)
+        }
+        let month = |s, fmt, strict| parse_names(s, fmt, strict).map(|p| p.month());
+        let weekday = |s, fmt, strict| parse_names(s, fmt, strict).map(|p| p.weekday());
+
+        // the lenient mode accepts an abbreviation for a full name
+        assert_eq!(month("Jan", "%b", false), Ok(Some(1)));
+        assert_eq!(month("Jan", "%B", false), Ok(Some(1)));
+        assert_eq!(month("jAnUaRy", "%B", false), Ok(Some(1)));
+        assert_eq!(month("Janu", "%b", false), Err(TOO_LONG));
+        assert_eq!(month("Janu", "%B", false), Err(TOO_LONG));
+        assert_eq!(weekday("Mon", "%A", false), Ok(Some(Weekday::Mon)));
+        assert_eq!(weekday("Monday", "%a", false), Err(TOO_LONG));
+        let mut parsed = Parsed::new();
+        assert!(!parsed.strict_names());
+        assert_eq!(parse_and_remainder(&mut parsed, "Janu", StrftimeItems::new("%b")), Ok("u"));
+        assert_eq!(parsed.month(), Some(1));
+        let mut parsed = Parsed::new();
+        assert_eq!(parse_and_remainder(&mut parsed, "Jans 5", StrftimeItems::new("%B")), Ok("s 5"));
+
+        // the strict mode requires exactly the abbreviation or the full name
+        assert_eq!(month("Jan", "%b", true), Ok(Some(1)));
+        assert_eq!(month("JAN 5", "%b %e", true), Ok(Some(1)));
+        assert_eq!(month("Janu", "%b", true), Err(INVALID));
+        assert_eq!(month("January", "%b", true), Err(INVALID));
+        assert_eq!(month("January", "%B", true), Ok(Some(1)));
+        assert_eq!(month("january, 5", "%B, %e", true), Ok(Some(1)));
+        assert_eq!(month("May", "%B", true), Ok(Some(5)));
+        assert_eq!(month("Jan", "%B", true), Err(TOO_SHORT));
+        assert_eq!(month("Jan 5", "%B %e", true), Err(INVALID));
+        assert_eq!(month("Januaryx", "%B", true), Err(INVALID));
+        assert_eq!(weekday("Mon", "%a", true), Ok(Some(Weekday::Mon)));
+        assert_eq!(weekday("Mond", "%a", true), Err(INVALID));
+        assert_eq!(weekday("Monday", "%A", true), Ok(Some(Weekday::Mon)));
+        assert_eq!(weekday("Mon", "%A", true), Err(TOO_SHORT));
+        assert_eq!(weekday("Mondays", "%A", true), Err(INVALID));
+        let mut parsed = Parsed::new();
+        parsed.set_strict_names(true);
+        assert_eq!(
+            parse_and_remainder(&mut parsed, "Janu", StrftimeItems::new("%b")),
+            Err(INVALID)
+        );
+        assert_eq!(parse_and_remainder(&mut parsed, "Jan u", StrftimeItems::new("%b")), Ok(" u"));
+
+        // numbers and other items are not affected
+        assert_eq!(month("Jan15", "%b%d", true), Ok(Some(1)));
+        assert_eq!(month("01", "%m", true), Ok(Some(1)));
+    }
+
+    #[test]
+    fn test_parse_extra_fraction_digits() {
+        use crate::format::InternalInternal::Nanosecond9NoDot;
+        use crate::format::Item::Literal;
+        use crate::format::{Fixed, Numeric};
+
+        fn parse_fraction(s: &str, items: &[Item], strict: bool) -> ParseResult<Parsed> {
+            let mut parsed = Parsed::new();
+            parsed.set_strict_fractions(strict);
+            parse(&mut parsed, s, items.iter())?;
+            Ok(parsed)
+        }
+        let nanos = |s, items, strict| parse_fraction(s, items, strict).map(|p| p.nanosecond());
+
+        // extra digits are consumed and truncate the fraction
+        let items = [fixed(Fixed::Nanosecond)];
+        assert_eq!(nanos(".123456789123", &items, false), Ok(Some(123_456_789)));
+        assert_eq!(nanos(".999999999999", &items, false), Ok(Some(999_999_999)));
+        let items = [fixed(Fixed::Nanosecond), Literal("Z")];
+        assert_eq!(nanos(".123456789123Z", &items, false), Ok(Some(123_456_789)));
+        let items = [num(Numeric::Nanosecond)];
+        assert_eq!(nanos("123456789123", &items, false), Ok(Some(123_456_789)));
+        let items = [num(Numeric::Nanosecond), Literal("Z")];
+        assert_eq!(nanos("123456789123Z", &items, false), Ok(Some(123_456_789)));
+        assert_eq!(nanos("1234Z", &items, false), Ok(Some(1234)));
+
+        // the strict mode rejects them
+        let items = [fixed(Fixed::Nanosecond)];
+        assert_eq!(nanos(".123456789123", &items, true), Err(TOO_LONG));
+        assert_eq!(nanos(".1234567890", &items, true), Err(TOO_LONG));
+        assert_eq!(nanos(".123456789", &items, true), Ok(Some(123_456_789)));
+        assert_eq!(nanos(".1", &items, true), Ok(Some(100_000_000)));
+        let items = [num(Numeric::Nanosecond)];
+        assert_eq!(nanos("123456789123", &items, true), Err(TOO_LONG));
+        assert_eq!(nanos("123456789", &items, true), Ok(Some(123_456_789)));
+        assert!(!Parsed::new().strict_fractions());
+
+        // the fixed-precision specifiers never consume extra digits
+        let items = [internal_fixed(Nanosecond9NoDot)];
+        assert_eq!(nanos("123456789123", &items, false), Err(TOO_LONG));
+    }
+
     #[track_caller]
     fn parses(s: &str, items: &[Item]) {
         let mut parsed = Parsed::new();
@@ -1612,6 +2006,20 @@ mod tests {
                 Ok(ymd_hmsn(2015, 1, 20, 17, 35, 20, 0, -8)),
             ), // multiple comments
             ("Tue, 20 Jan 2015 17:35:20 -0800 (UTC) ", Err(TOO_LONG)), // trailing whitespace after comment
+            ("Mon, 1 Jan 2024 00:00:00 GMT (UTC)", Ok(ymd_hmsn(2024, 1, 1, 0, 0, 0, 0, 0))), // obsolete zone with comment
+            ("Mon, 1 Jan (comment) 2024 00:00:00 +0000", Ok(ymd_hmsn(2024, 1, 1, 0, 0, 0, 0, 0))), // comment in the middle
+            (
+                "Mon (Monday) , 1 (day) Jan (month) 2024 (year) 00 (h) : (m) 00 : 00 (UTC) +0000",
+                Ok(ymd_hmsn(2024, 1, 1, 0, 0, 0, 0, 0)),
+            ), // comments between every token
+            (
+                "Mon, 1(day)Jan(month)2024(year)00:00:00(UTC)+0000",
+                Ok(ymd_hmsn(2024, 1, 1, 0, 0, 0, 0, 0)),
+            ), // comments as separators
+            ("Mon, 1 Jan 2024\r\n 00:00:00 +0000", Ok(ymd_hmsn(2024, 1, 1, 0, 0, 0, 0, 0))), // folded line
+            ("Mon, 1 Jan (unterminated 2024 00:00:00 +0000", Err(INVALID)), // incorrect comment in the middle
+            ("Mon, 1 Jan2024 00:00:00 +0000", Err(INVALID)),                // missing separator
+            ("Mon, 1 Jan 2024 00:00:00 -0000", Ok(ymd_hmsn(2024, 1, 1, 0, 0, 0, 0, 0))), // UTC with unknown local zone
             ("20 Jan 2015 17:35:20 -0800", Ok(ymd_hmsn(2015, 1, 20, 17, 35, 20, 0, -8))), // no day of week
             ("20 JAN 2015 17:35:20 -0800", Ok(ymd_hmsn(2015, 1, 20, 17, 35, 20, 0, -8))), // upper case month
             ("Tue, 20 Jan 2015 17:35 -0800", Ok(ymd_hmsn(2015, 1, 20, 17, 35, 0, 0, -8))), // no second
@@ -1769,6 +2177,8 @@ mod tests {
             ("2015-01-20T17:35:20.001-08:00", Ok(ymd_hmsn(2015, 1, 20, 17, 35, 20, 1_000_000, -8))),
             ("2015-01-20T17:35:20.001−08:00", Ok(ymd_hmsn(2015, 1, 20, 17, 35, 20, 1_000_000, -8))), // with MINUS SIGN (U+2212)
             ("2015-01-20T17:35:20.000031-08:00", Ok(ymd_hmsn(2015, 1, 20, 17, 35, 20, 31_000, -8))),
+            ("2015-01-20T17:35:20,5-08:00", Ok(ymd_hmsn(2015, 1, 20, 17, 35, 20, 500_000_000, -8))), // comma separator
+            ("2015-01-20T17:35:20,-08:00", Err(INVALID)), // comma without digits
             ("2015-01-20T17:35:20.000000004-08:00", Ok(ymd_hmsn(2015, 1, 20, 17, 35, 20, 4, -8))),
             ("2015-01-20T17:35:20.000000004−08:00", Ok(ymd_hmsn(2015, 1, 20, 17, 35, 20, 4, -8))), // with MINUS SIGN (U+2212)
             (
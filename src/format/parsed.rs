@@ -4,6 +4,8 @@
 //! A collection of parsed date and time items.
 //! They can be constructed incrementally while being checked for consistency.
 
+use core::fmt;
+
 use super::{ParseResult, IMPOSSIBLE, NOT_ENOUGH, OUT_OF_RANGE};
 use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
 use crate::offset::{FixedOffset, MappedLocalTime, Offset, TimeZone};
@@ -167,10 +169,49 @@ pub struct Parsed {
     pub timestamp: Option<i64>,
     #[doc(hidden)]
     pub offset: Option<i32>,
+    timezone_name: Option<TimezoneName>,
+    two_digit_year_pivot: Option<u16>,
+    fixed_width_numbers: bool,
+    strict_fractions: bool,
+    strict_names: bool,
+    capture_timezone_name: bool,
     #[doc(hidden)]
     _dummy: (),
 }
 
+/// The maximum length in bytes of a time zone name stored in [`Parsed`].
+const MAX_TIMEZONE_NAME_LEN: usize = 32;
+
+/// A time zone name stored inline, so `Parsed` does not need to allocate.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct TimezoneName {
+    len: u8,
+    bytes: [u8; MAX_TIMEZONE_NAME_LEN],
+}
+
+impl TimezoneName {
+    fn new(name: &str) -> Option<TimezoneName> {
+        if name.len() > MAX_TIMEZONE_NAME_LEN {
+            return None;
+        }
+        let mut bytes = [0; MAX_TIMEZONE_NAME_LEN];
+        bytes[..name.len()].copy_from_slice(name.as_bytes());
+        Some(TimezoneName { len: name.len() as u8, bytes })
+    }
+
+    fn as_str(&self) -> &str {
+        // The bytes are copied from a `&str` in `new`.
+        core::str::from_utf8(&self.bytes[..usize::from(self.len)])
+            .expect("time zone name should be valid UTF-8")
+    }
+}
+
+impl fmt::Debug for TimezoneName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
 /// Checks if `old` is either empty or has the same value as `new` (i.e. "consistent"),
 /// and if it is empty, set `old` to `new` as well.
 #[inline]
@@ -231,7 +272,8 @@ impl Parsed {
     /// If this field is set while the [`year_div_100`](Parsed::year_div_100) field is missing (and
     /// the full [`year`](Parsed::year) field is also not set), it assumes a default value for the
     /// [`year_div_100`](Parsed::year_div_100) field.
-    /// The default is 19 when `year_mod_100 >= 70` and 20 otherwise.
+    /// The default is 19 when `year_mod_100 >= 70` and 20 otherwise, which can be changed with
+    /// [`set_two_digit_year_pivot()`](Parsed::set_two_digit_year_pivot).
     ///
     /// # Errors
     ///
@@ -246,6 +288,208 @@ impl Parsed {
         set_if_consistent(&mut self.year_mod_100, value as i32)
     }
 
+    /// Set the pivot year used to resolve a two-digit year to a full year.
+    ///
+    /// When the [`year_mod_100`](Parsed::year_mod_100) field is set without the
+    /// [`year_div_100`](Parsed::year_div_100) or [`year`](Parsed::year) fields, as when parsing
+    /// `%y`, the year is resolved to the only year within `pivot..pivot + 100` that has the parsed
+    /// two digits. The same applies to the ISO week date year.
+    ///
+    /// A `pivot` below 100 is shorthand for `1900 + pivot`: a two-digit year `yy` resolves to
+    /// `20yy` if `yy < pivot`, and to `19yy` if `yy >= pivot`.
+    ///
+    /// If not set the pivot is 70, so two-digit years resolve to 1970 through 2069.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{parse, Parsed, StrftimeItems};
+    /// use chrono::NaiveDate;
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parsed.set_two_digit_year_pivot(69);
+    /// parse(&mut parsed, "68-01-01", StrftimeItems::new("%y-%m-%d"))?;
+    /// assert_eq!(parsed.to_naive_date()?, NaiveDate::from_ymd_opt(2068, 1, 1).unwrap());
+    ///
+    /// // A full year selects a custom 100-year window.
+    /// let mut parsed = Parsed::new();
+    /// parsed.set_two_digit_year_pivot(1850);
+    /// parse(&mut parsed, "68-01-01", StrftimeItems::new("%y-%m-%d"))?;
+    /// assert_eq!(parsed.to_naive_date()?, NaiveDate::from_ymd_opt(1868, 1, 1).unwrap());
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    #[inline]
+    pub fn set_two_digit_year_pivot(&mut self, pivot: u16) {
+        self.two_digit_year_pivot = Some(pivot);
+    }
+
+    /// Require zero-padded numeric fields to be parsed with exactly their full width.
+    ///
+    /// By default the parser reads between one digit and the parsing width of a numeric item, so
+    /// `%m` accepts both `1` and `01`. This is ambiguous for fixed-width formats that pack fields
+    /// without separators, such as `20240115093000` for `%Y%m%d%H%M%S`.
+    ///
+    /// With this option set, numeric items with [`Pad::Zero`](crate::format::Pad::Zero) padding
+    /// must consume exactly their parsing width: four digits for `%Y` (unless it begins with an
+    /// explicit sign) and two digits for `%m`, `%d`, `%H`, `%M` and `%S`. Zero padding is the
+    /// default for these specifiers; items with [`Pad::None`](crate::format::Pad::None) or
+    /// [`Pad::Space`](crate::format::Pad::Space) padding, such as `%-m` or `%e`, still accept fewer
+    /// digits. Items without a fixed parsing width, such as `%s`, are not affected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{parse, Parsed, StrftimeItems};
+    /// use chrono::NaiveDate;
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parsed.set_fixed_width_numbers(true);
+    /// parse(&mut parsed, "20240115093000", StrftimeItems::new("%Y%m%d%H%M%S"))?;
+    /// let expected = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap().and_hms_opt(9, 30, 0).unwrap();
+    /// assert_eq!(parsed.to_naive_datetime_with_offset(0)?, expected);
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parsed.set_fixed_width_numbers(true);
+    /// assert!(parse(&mut parsed, "2024-1-15", StrftimeItems::new("%Y-%m-%d")).is_err());
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    #[inline]
+    pub fn set_fixed_width_numbers(&mut self, fixed: bool) {
+        self.fixed_width_numbers = fixed;
+    }
+
+    /// Returns `true` if zero-padded numeric fields must be parsed with exactly their full width.
+    ///
+    /// See [`set_fixed_width_numbers()`](Parsed::set_fixed_width_numbers).
+    #[inline]
+    #[must_use]
+    pub fn fixed_width_numbers(&self) -> bool {
+        self.fixed_width_numbers
+    }
+
+    /// Reject fractional seconds with more than nine digits.
+    ///
+    /// By default `%.f` (and `%.3f`, `%.6f`, `%.9f`) and `%f` accept any number of fractional
+    /// digits. Chrono stores nanoseconds, so digits beyond the ninth are consumed but ignored: the
+    /// fraction is truncated, rounding toward zero. For example `.123456789999` is parsed as
+    /// 123,456,789 nanoseconds.
+    ///
+    /// With this option set, more than nine digits are a [`TooLong`] error instead.
+    ///
+    /// [`TooLong`]: crate::format::ParseErrorKind::TooLong
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{parse, Parsed, StrftimeItems};
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parse(&mut parsed, "05.123456789123", StrftimeItems::new("%S%.f"))?;
+    /// assert_eq!(parsed.nanosecond(), Some(123_456_789));
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parsed.set_strict_fractions(true);
+    /// assert!(parse(&mut parsed, "05.123456789123", StrftimeItems::new("%S%.f")).is_err());
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    #[inline]
+    pub fn set_strict_fractions(&mut self, strict: bool) {
+        self.strict_fractions = strict;
+    }
+
+    /// Returns `true` if fractional seconds with more than nine digits are rejected.
+    ///
+    /// See [`set_strict_fractions()`](Parsed::set_strict_fractions).
+    #[inline]
+    #[must_use]
+    pub fn strict_fractions(&self) -> bool {
+        self.strict_fractions
+    }
+
+    /// Only accept exact month and weekday names.
+    ///
+    /// By default `%B` and `%A` accept either the full name or the three-letter abbreviation, and
+    /// all of `%b`, `%B`, `%a` and `%A` stop after the longest name they recognize, even if it is
+    /// followed by more letters. For example `%b` reads `Jan` from `Janu` and leaves the `u`
+    /// for the next item.
+    ///
+    /// With this option set, `%B` and `%A` only accept the full English name, `%b` and `%a` only
+    /// accept the three-letter abbreviation, and a name followed by another letter is an
+    /// [`Invalid`] error. Names are still matched in any case.
+    ///
+    /// [`Invalid`]: crate::format::ParseErrorKind::Invalid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{parse, Parsed, StrftimeItems};
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parse(&mut parsed, "Jan 5", StrftimeItems::new("%B %e"))?;
+    /// assert_eq!(parsed.month(), Some(1));
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parsed.set_strict_names(true);
+    /// assert!(parse(&mut parsed, "Jan 5", StrftimeItems::new("%B %e")).is_err());
+    /// assert!(parse(&mut parsed, "Janu 5", StrftimeItems::new("%b %e")).is_err());
+    /// parse(&mut parsed, "Jan 5", StrftimeItems::new("%b %e"))?;
+    /// assert_eq!(parsed.month(), Some(1));
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    #[inline]
+    pub fn set_strict_names(&mut self, strict: bool) {
+        self.strict_names = strict;
+    }
+
+    /// Returns `true` if month and weekday names have to match exactly.
+    ///
+    /// See [`set_strict_names()`](Parsed::set_strict_names).
+    #[inline]
+    #[must_use]
+    pub fn strict_names(&self) -> bool {
+        self.strict_names
+    }
+
+    /// Store the time zone name read by `%Z`.
+    ///
+    /// By default `%Z` skips over a time zone name without storing it. With this option set the
+    /// name is stored in the [`timezone_name`](Parsed::timezone_name) field, so it can be
+    /// resolved to an offset with [`to_datetime_with_resolver()`](Parsed::to_datetime_with_resolver).
+    /// Parsing then fails with [`OutOfRange`] if the name is longer than 32 bytes, and with
+    /// [`Impossible`] if the input contains two different names.
+    ///
+    /// [`OutOfRange`]: crate::format::ParseErrorKind::OutOfRange
+    /// [`Impossible`]: crate::format::ParseErrorKind::Impossible
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{parse, Parsed, StrftimeItems};
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parse(&mut parsed, "12:00 CET", StrftimeItems::new("%H:%M %Z"))?;
+    /// assert_eq!(parsed.timezone_name(), None);
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parsed.set_capture_timezone_name(true);
+    /// parse(&mut parsed, "12:00 CET", StrftimeItems::new("%H:%M %Z"))?;
+    /// assert_eq!(parsed.timezone_name(), Some("CET"));
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    #[inline]
+    pub fn set_capture_timezone_name(&mut self, capture: bool) {
+        self.capture_timezone_name = capture;
+    }
+
+    /// Returns `true` if `%Z` stores the time zone name it reads.
+    ///
+    /// See [`set_capture_timezone_name()`](Parsed::set_capture_timezone_name).
+    #[inline]
+    #[must_use]
+    pub fn capture_timezone_name(&self) -> bool {
+        self.capture_timezone_name
+    }
+
     /// Set the [`isoyear`](Parsed::isoyear) field, that is part of an [ISO 8601 week date], to the
     /// given value.
     ///
@@ -290,7 +534,8 @@ impl Parsed {
     /// If this field is set while the [`isoyear_div_100`](Parsed::isoyear_div_100) field is missing
     /// (and the full [`isoyear`](Parsed::isoyear) field is also not set), it assumes a default
     /// value for the [`isoyear_div_100`](Parsed::isoyear_div_100) field.
-    /// The default is 19 when `year_mod_100 >= 70` and 20 otherwise.
+    /// The default is 19 when `year_mod_100 >= 70` and 20 otherwise, which can be changed with
+    /// [`set_two_digit_year_pivot()`](Parsed::set_two_digit_year_pivot).
     ///
     /// [ISO 8601 week date]: crate::NaiveDate#week-date
     ///
@@ -544,6 +789,21 @@ impl Parsed {
         set_if_consistent(&mut self.offset, i32::try_from(value).map_err(|_| OUT_OF_RANGE)?)
     }
 
+    /// Set the [`timezone_name`](Parsed::timezone_name) field to the given value.
+    ///
+    /// The name is not interpreted by itself, but it can be resolved to an offset with
+    /// [`to_datetime_with_resolver()`](Parsed::to_datetime_with_resolver).
+    ///
+    /// # Errors
+    ///
+    /// Returns `OUT_OF_RANGE` if `name` is longer than 32 bytes.
+    ///
+    /// Returns `IMPOSSIBLE` if this field was already set to a different value.
+    #[inline]
+    pub fn set_timezone_name(&mut self, name: &str) -> ParseResult<()> {
+        set_if_consistent(&mut self.timezone_name, TimezoneName::new(name).ok_or(OUT_OF_RANGE)?)
+    }
+
     /// Returns a parsed naive date out of given fields.
     ///
     /// This method is able to determine the date from given subset of fields:
@@ -572,6 +832,7 @@ impl Parsed {
             y: Option<i32>,
             q: Option<i32>,
             r: Option<i32>,
+            pivot: u16,
         ) -> ParseResult<Option<i32>> {
             match (y, q, r) {
                 // if there is no further information, simply return the given full year.
@@ -605,9 +866,15 @@ impl Parsed {
                     Ok(Some(y.ok_or(OUT_OF_RANGE)?))
                 }
 
-                // we only have modulo. try to interpret a modulo as a conventional two-digit year.
+                // we only have modulo. interpret it as a two-digit year within the 100 years
+                // starting at the pivot year.
                 // note: we are affected by Rust issue #18060. avoid multiple range patterns.
-                (None, None, Some(r @ 0..=99)) => Ok(Some(r + if r < 70 { 2000 } else { 1900 })),
+                (None, None, Some(r @ 0..=99)) => {
+                    let start =
+                        if pivot < 100 { 1900 + i32::from(pivot) } else { i32::from(pivot) };
+                    let y = start - start % 100 + r;
+                    Ok(Some(if y < start { y + 100 } else { y }))
+                }
 
                 // otherwise it is an out-of-bound or insufficient condition.
                 (None, Some(_), None) => Err(NOT_ENOUGH),
@@ -615,8 +882,10 @@ impl Parsed {
             }
         }
 
-        let given_year = resolve_year(self.year, self.year_div_100, self.year_mod_100)?;
-        let given_isoyear = resolve_year(self.isoyear, self.isoyear_div_100, self.isoyear_mod_100)?;
+        let pivot = self.two_digit_year_pivot.unwrap_or(70);
+        let given_year = resolve_year(self.year, self.year_div_100, self.year_mod_100, pivot)?;
+        let given_isoyear =
+            resolve_year(self.isoyear, self.isoyear_div_100, self.isoyear_mod_100, pivot)?;
 
         // verify the normal year-month-day date.
         let verify_ymd = |date: NaiveDate| {
@@ -693,7 +962,7 @@ impl Parsed {
 
             (_, Some(isoyear), &Parsed { isoweek: Some(isoweek), weekday: Some(weekday), .. }) => {
                 // ISO year, week, day of the week
-                let date = NaiveDate::from_isoywd_opt(isoyear, isoweek, weekday);
+                let date = NaiveDate::from_iso_week(isoyear, isoweek, weekday);
                 let date = date.ok_or(OUT_OF_RANGE)?;
                 (verify_ymd(date) && verify_ordinal(date), date)
             }
@@ -896,6 +1165,53 @@ impl Parsed {
         }
     }
 
+    /// Returns a parsed timezone-aware date and time out of given fields, using `resolver` to
+    /// determine the offset from the [`timezone_name`](Parsed::timezone_name) field.
+    ///
+    /// This works the same as [`to_datetime()`](Parsed::to_datetime), but if the time zone name
+    /// is set and `resolver` knows it, the resolved offset is used as the offset field. If the
+    /// resolver doesn't know the name, the name is ignored.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`to_datetime()`](Parsed::to_datetime), and:
+    /// - `IMPOSSIBLE` if the offset field is set but differs from the resolved offset.
+    /// - `NOT_ENOUGH` if there is no offset field, and the time zone name is not set or
+    ///   couldn't be resolved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{parse, BasicTzResolver, Parsed, StrftimeItems};
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parsed.set_capture_timezone_name(true);
+    /// parse(&mut parsed, "2024-01-15 09:30 EST", StrftimeItems::new("%Y-%m-%d %H:%M %Z"))?;
+    /// assert_eq!(parsed.timezone_name(), Some("EST"));
+    ///
+    /// let dt = parsed.to_datetime_with_resolver(&BasicTzResolver)?;
+    /// let est = FixedOffset::west_opt(5 * 3600).unwrap();
+    /// assert_eq!(dt, est.with_ymd_and_hms(2024, 1, 15, 9, 30, 0).unwrap());
+    ///
+    /// // Without a resolver the abbreviation is ignored, so there is no offset.
+    /// assert!(parsed.to_datetime().is_err());
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    pub fn to_datetime_with_resolver<R: TzResolver + ?Sized>(
+        &self,
+        resolver: &R,
+    ) -> ParseResult<DateTime<FixedOffset>> {
+        match self.timezone_name.as_ref().and_then(|name| resolver.resolve(name.as_str())) {
+            Some(offset) => {
+                let mut parsed = self.clone();
+                parsed.set_offset(i64::from(offset.local_minus_utc()))?;
+                parsed.to_datetime()
+            }
+            None => self.to_datetime(),
+        }
+    }
+
     /// Returns a parsed timezone-aware date and time out of given fields,
     /// with an additional [`TimeZone`] used to interpret and validate the local date.
     ///
@@ -921,6 +1237,33 @@ impl Parsed {
     ///   - if the value would be outside the range of a [`NaiveDateTime`] or [`FixedOffset`].
     ///   - if any of the fields of `Parsed` are set to a value beyond their acceptable range.
     ///   - if the date does not exist.
+    ///
+    /// # Example
+    ///
+    /// The fields can be set by hand, for example when they come from a custom tokenizer or from
+    /// several sources.
+    ///
+    /// ```
+    /// use chrono::format::Parsed;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parsed.set_year(2024)?;
+    /// parsed.set_month(3)?;
+    /// parsed.set_day(9)?;
+    /// parsed.set_hour12(4)?;
+    /// parsed.set_ampm(true)?;
+    /// parsed.set_minute(30)?;
+    /// parsed.set_second(0)?;
+    ///
+    /// // A second source agreeing with the first one is fine, a conflicting value is not.
+    /// parsed.set_year(2024)?;
+    /// assert!(parsed.set_month(4).is_err());
+    ///
+    /// let dt = parsed.to_datetime_with_timezone(&Utc)?;
+    /// assert_eq!(dt, Utc.with_ymd_and_hms(2024, 3, 9, 16, 30, 0).unwrap());
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
     pub fn to_datetime_with_timezone<Tz: TimeZone>(&self, tz: &Tz) -> ParseResult<DateTime<Tz>> {
         // if we have `timestamp` specified, guess an offset from that.
         let mut guessed_offset = 0;
@@ -1134,6 +1477,122 @@ impl Parsed {
     pub fn offset(&self) -> Option<i32> {
         self.offset
     }
+
+    /// Get the `timezone_name` field if set.
+    ///
+    /// This is set by the [`%Z`](crate::format::strftime) specifier when parsing, if
+    /// [`set_capture_timezone_name()`](Parsed::set_capture_timezone_name) is enabled. The name is
+    /// stored as it appears in the input, without trying to resolve it, so it can be an
+    /// abbreviation like `EST` or an IANA time zone identifier like `America/New_York`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{parse, Parsed, StrftimeItems};
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parsed.set_capture_timezone_name(true);
+    /// let s = "2024-01-01 00:00 America/New_York";
+    /// parse(&mut parsed, s, StrftimeItems::new("%Y-%m-%d %H:%M %Z"))?;
+    /// assert_eq!(parsed.timezone_name(), Some("America/New_York"));
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    ///
+    /// See also [`set_timezone_name()`](Parsed::set_timezone_name).
+    #[inline]
+    pub fn timezone_name(&self) -> Option<&str> {
+        self.timezone_name.as_ref().map(TimezoneName::as_str)
+    }
+}
+
+/// Resolves a time zone abbreviation, such as parsed by [`%Z`](crate::format::strftime), to an
+/// offset from UTC.
+///
+/// Time zone abbreviations are ambiguous and don't describe when daylight saving time applies, so
+/// chrono doesn't resolve them by default: parsing a string with `%Z` but without an offset fails.
+/// A `TzResolver` can be passed to [`Parsed::to_datetime_with_resolver`] to resolve the
+/// abbreviations that are known to be unambiguous for an application.
+///
+/// [`BasicTzResolver`] covers UTC, the US and the military time zone abbreviations. This trait is
+/// also implemented for closures, and can be implemented by crates with time zone databases.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::{parse, Parsed, StrftimeItems, TzResolver};
+/// use chrono::{FixedOffset, TimeZone};
+///
+/// let resolver = |name: &str| match name {
+///     "CET" => FixedOffset::east_opt(3600),
+///     "CEST" => FixedOffset::east_opt(2 * 3600),
+///     _ => None,
+/// };
+/// assert_eq!(resolver.resolve("CEST"), FixedOffset::east_opt(2 * 3600));
+///
+/// let mut parsed = Parsed::new();
+/// parsed.set_capture_timezone_name(true);
+/// parse(&mut parsed, "2020-08-02 13:39:15 CEST", StrftimeItems::new("%Y-%m-%d %H:%M:%S %Z"))?;
+/// let dt = parsed.to_datetime_with_resolver(&resolver)?;
+/// let cest = FixedOffset::east_opt(2 * 3600).unwrap();
+/// assert_eq!(dt, cest.with_ymd_and_hms(2020, 8, 2, 13, 39, 15).unwrap());
+/// # Ok::<(), chrono::ParseError>(())
+/// ```
+pub trait TzResolver {
+    /// Returns the offset for the time zone `abbreviation`, or `None` if it is unknown.
+    fn resolve(&self, abbreviation: &str) -> Option<FixedOffset>;
+}
+
+impl<F: Fn(&str) -> Option<FixedOffset>> TzResolver for F {
+    fn resolve(&self, abbreviation: &str) -> Option<FixedOffset> {
+        self(abbreviation)
+    }
+}
+
+/// A [`TzResolver`] for time zone abbreviations with a fixed and commonly agreed upon offset.
+///
+/// Abbreviations are matched case-insensitively.
+///
+/// Abbreviation                  | Offset
+/// ----------------------------- | ------
+/// `UTC`, `UT`, `GMT`, `Z`       | `+00:00`
+/// `EST`, `EDT`                  | `-05:00`, `-04:00`
+/// `CST`, `CDT`                  | `-06:00`, `-05:00`
+/// `MST`, `MDT`                  | `-07:00`, `-06:00`
+/// `PST`, `PDT`                  | `-08:00`, `-07:00`
+/// `AKST`, `AKDT`                | `-09:00`, `-08:00`
+/// `HST`                         | `-10:00`
+/// `A` through `I`, `K` to `M`   | `+01:00` through `+12:00` (military time zones)
+/// `N` through `Y`               | `-01:00` through `-12:00` (military time zones)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BasicTzResolver;
+
+impl TzResolver for BasicTzResolver {
+    fn resolve(&self, abbreviation: &str) -> Option<FixedOffset> {
+        const NAMES: [(&str, i32); 15] = [
+            ("UTC", 0),
+            ("UT", 0),
+            ("GMT", 0),
+            ("Z", 0),
+            ("EST", -5),
+            ("EDT", -4),
+            ("CST", -6),
+            ("CDT", -5),
+            ("MST", -7),
+            ("MDT", -6),
+            ("PST", -8),
+            ("PDT", -7),
+            ("AKST", -9),
+            ("AKDT", -8),
+            ("HST", -10),
+        ];
+        let hours = match *abbreviation.as_bytes() {
+            [c @ (b'a'..=b'i' | b'A'..=b'I')] => i32::from((c | 0x20) - b'a') + 1,
+            [c @ (b'k'..=b'm' | b'K'..=b'M')] => i32::from((c | 0x20) - b'a'),
+            [c @ (b'n'..=b'y' | b'N'..=b'Y')] => -(i32::from((c | 0x20) - b'n') + 1),
+            _ => NAMES.iter().find(|(name, _)| name.eq_ignore_ascii_case(abbreviation))?.1,
+        };
+        FixedOffset::east_opt(hours * 3600)
+    }
 }
 
 /// Create a `NaiveDate` when given a year, week, weekday, and the definition at which day of the
@@ -1164,8 +1623,8 @@ fn resolve_week_date(
 
 #[cfg(test)]
 mod tests {
-    use super::super::{IMPOSSIBLE, NOT_ENOUGH, OUT_OF_RANGE};
-    use super::Parsed;
+    use super::super::{parse, StrftimeItems, IMPOSSIBLE, NOT_ENOUGH, OUT_OF_RANGE};
+    use super::{BasicTzResolver, Parsed, TzResolver};
     use crate::naive::{NaiveDate, NaiveTime};
     use crate::offset::{FixedOffset, TimeZone, Utc};
     use crate::Datelike;
@@ -1499,6 +1958,10 @@ mod tests {
         assert_eq!(parse!(year: 2100, ordinal: 365), ymd(2100, 12, 31));
         assert_eq!(parse!(year: 2100, ordinal: 366), Err(OUT_OF_RANGE));
         assert_eq!(parse!(year: 2100, ordinal: 0xffffffff), Err(OUT_OF_RANGE));
+        assert_eq!(parse!(year: 2024, month: 2, day: 14, ordinal: 45), ymd(2024, 2, 14));
+        assert_eq!(parse!(year: 2024, month: 2, day: 15, ordinal: 45), Err(IMPOSSIBLE));
+        assert_eq!(parse!(year: 2024, month: 3, ordinal: 45), Err(IMPOSSIBLE));
+        assert_eq!(parse!(year: 2024, day: 15, ordinal: 45), Err(IMPOSSIBLE));
 
         // more complex cases
         assert_eq!(
@@ -1865,4 +2328,185 @@ mod tests {
         parsed.year = Some(2001);
         assert_eq!(NaiveDate::from_ymd_opt(2001, 5, 28).unwrap(), parsed.to_naive_date().unwrap());
     }
+
+    #[test]
+    fn test_parsed_built_by_hand() {
+        let mut parsed = Parsed::new();
+        assert_eq!(parsed.set_year(2024), Ok(()));
+        assert_eq!(parsed.set_month(11), Ok(()));
+        assert_eq!(parsed.set_day(28), Ok(()));
+        assert_eq!(parsed.set_weekday(Thu), Ok(()));
+        assert_eq!(parsed.set_hour12(12), Ok(()));
+        assert_eq!(parsed.set_ampm(false), Ok(()));
+        assert_eq!(parsed.set_minute(5), Ok(()));
+        assert_eq!(parsed.set_second(6), Ok(()));
+        assert_eq!(parsed.set_nanosecond(7), Ok(()));
+        assert_eq!(parsed.set_offset(0), Ok(()));
+
+        // setting a field again is only allowed with the same value
+        assert_eq!(parsed.set_year(2024), Ok(()));
+        assert_eq!(parsed.set_year(2025), Err(IMPOSSIBLE));
+        assert_eq!(parsed.set_hour(0), Ok(()));
+        assert_eq!(parsed.set_hour(12), Err(IMPOSSIBLE));
+        assert_eq!(parsed.set_ampm(true), Err(IMPOSSIBLE));
+        assert_eq!(parsed.year(), Some(2024));
+        assert_eq!(parsed.hour_div_12(), Some(0));
+
+        let expected =
+            Utc.with_ymd_and_hms(2024, 11, 28, 0, 5, 6).unwrap() + crate::TimeDelta::nanoseconds(7);
+        assert_eq!(parsed.to_datetime_with_timezone(&Utc), Ok(expected));
+        assert_eq!(parsed.to_datetime(), Ok(expected.fixed_offset()));
+
+        // conflicts between fields are detected when resolving
+        let mut wrong_weekday = parsed.clone();
+        wrong_weekday.weekday = None;
+        assert_eq!(wrong_weekday.set_weekday(Fri), Ok(()));
+        assert_eq!(wrong_weekday.to_datetime_with_timezone(&Utc), Err(IMPOSSIBLE));
+
+        // as are conflicts with the time zone
+        let mut wrong_offset = parsed.clone();
+        wrong_offset.offset = None;
+        assert_eq!(wrong_offset.set_offset(3600), Ok(()));
+        assert_eq!(wrong_offset.to_datetime_with_timezone(&Utc), Err(IMPOSSIBLE));
+        let tz = FixedOffset::east_opt(3600).unwrap();
+        assert_eq!(
+            wrong_offset.to_datetime_with_timezone(&tz),
+            Ok((expected - crate::TimeDelta::hours(1)).with_timezone(&tz))
+        );
+
+        // an incomplete `Parsed` can't be resolved
+        let mut incomplete = Parsed::new();
+        assert_eq!(incomplete.set_year(2024), Ok(()));
+        assert_eq!(incomplete.set_month(11), Ok(()));
+        assert_eq!(incomplete.to_datetime_with_timezone(&Utc), Err(NOT_ENOUGH));
+    }
+
+    #[test]
+    fn test_parsed_to_datetime_with_resolver() {
+        let parse = |name: &str, offset: Option<i32>| {
+            let mut parsed = Parsed::new();
+            parsed.set_year(2024).unwrap();
+            parsed.set_month(3).unwrap();
+            parsed.set_day(10).unwrap();
+            parsed.set_hour(12).unwrap();
+            parsed.set_minute(30).unwrap();
+            parsed.set_timezone_name(name).unwrap();
+            parsed.offset = offset;
+            parsed
+        };
+        let ymdhm = |offset: i32| {
+            FixedOffset::east_opt(offset).unwrap().with_ymd_and_hms(2024, 3, 10, 12, 30, 0).unwrap()
+        };
+
+        assert_eq!(parse("UTC", None).to_datetime_with_resolver(&BasicTzResolver), Ok(ymdhm(0)));
+        assert_eq!(parse("gmt", None).to_datetime_with_resolver(&BasicTzResolver), Ok(ymdhm(0)));
+        assert_eq!(
+            parse("PDT", None).to_datetime_with_resolver(&BasicTzResolver),
+            Ok(ymdhm(-7 * 3600))
+        );
+        assert_eq!(
+            parse("EST", Some(-5 * 3600)).to_datetime_with_resolver(&BasicTzResolver),
+            Ok(ymdhm(-5 * 3600))
+        );
+        assert_eq!(
+            parse("EST", Some(0)).to_datetime_with_resolver(&BasicTzResolver),
+            Err(IMPOSSIBLE)
+        );
+
+        // Unknown names are an error without an offset, and are ignored with one.
+        assert_eq!(
+            parse("CEST", None).to_datetime_with_resolver(&BasicTzResolver),
+            Err(NOT_ENOUGH)
+        );
+        assert_eq!(parse("UTC", None).to_datetime(), Err(NOT_ENOUGH));
+        assert_eq!(
+            parse("CEST", Some(7200)).to_datetime_with_resolver(&BasicTzResolver),
+            Ok(ymdhm(7200))
+        );
+
+        let resolver = |name: &str| if name == "CEST" { FixedOffset::east_opt(7200) } else { None };
+        assert_eq!(parse("CEST", None).to_datetime_with_resolver(&resolver), Ok(ymdhm(7200)));
+    }
+
+    #[test]
+    fn test_basic_tz_resolver() {
+        let hours = |h: i32| FixedOffset::east_opt(h * 3600);
+        for (name, expected) in [
+            ("UTC", hours(0)),
+            ("ut", hours(0)),
+            ("Z", hours(0)),
+            ("z", hours(0)),
+            ("EDT", hours(-4)),
+            ("cst", hours(-6)),
+            ("AKST", hours(-9)),
+            ("HST", hours(-10)),
+            ("A", hours(1)),
+            ("I", hours(9)),
+            ("K", hours(10)),
+            ("m", hours(12)),
+            ("N", hours(-1)),
+            ("Y", hours(-12)),
+            ("J", None),
+            ("CEST", None),
+            ("", None),
+            ("UTC+1", None),
+        ] {
+            assert_eq!(BasicTzResolver.resolve(name), expected, "{:?}", name);
+        }
+    }
+
+    #[test]
+    fn test_parsed_set_timezone_name() {
+        let mut parsed = Parsed::new();
+        assert_eq!(parsed.timezone_name(), None);
+        assert_eq!(parsed.set_timezone_name("EST"), Ok(()));
+        assert_eq!(parsed.set_timezone_name("EST"), Ok(()));
+        assert_eq!(parsed.set_timezone_name("EDT"), Err(IMPOSSIBLE));
+        assert_eq!(parsed.timezone_name(), Some("EST"));
+        assert_eq!(Parsed::new().set_timezone_name(&"X".repeat(32)), Ok(()));
+        assert_eq!(Parsed::new().set_timezone_name(&"X".repeat(33)), Err(OUT_OF_RANGE));
+    }
+
+    #[test]
+    fn test_parsed_two_digit_year_pivot() {
+        let year = |s: &str, pivot: Option<u16>| {
+            let mut parsed = Parsed::new();
+            if let Some(pivot) = pivot {
+                parsed.set_two_digit_year_pivot(pivot);
+            }
+            parse(&mut parsed, s, StrftimeItems::new("%y-%m-%d"))?;
+            parsed.to_naive_date().map(|d| d.year())
+        };
+
+        // default
+        assert_eq!(year("69-01-01", None), Ok(2069));
+        assert_eq!(year("70-01-01", None), Ok(1970));
+        assert_eq!(year("70-01-01", Some(70)), Ok(1970));
+
+        assert_eq!(year("68-01-01", Some(69)), Ok(2068));
+        assert_eq!(year("69-01-01", Some(69)), Ok(1969));
+        assert_eq!(year("00-01-01", Some(0)), Ok(1900));
+        assert_eq!(year("99-01-01", Some(0)), Ok(1999));
+        assert_eq!(year("99-01-01", Some(99)), Ok(1999));
+        assert_eq!(year("98-01-01", Some(99)), Ok(2098));
+
+        // full years
+        assert_eq!(year("49-01-01", Some(1850)), Ok(1949));
+        assert_eq!(year("50-01-01", Some(1850)), Ok(1850));
+        assert_eq!(year("00-01-01", Some(2000)), Ok(2000));
+        assert_eq!(year("99-01-01", Some(2000)), Ok(2099));
+        assert_eq!(year("11-01-01", Some(u16::MAX)), Ok(65611));
+
+        // the century is still respected if given
+        let mut parsed = Parsed::new();
+        parsed.set_two_digit_year_pivot(69);
+        parse(&mut parsed, "2068-01-01", StrftimeItems::new("%C%y-%m-%d")).unwrap();
+        assert_eq!(parsed.to_naive_date().map(|d| d.year()), Ok(2068));
+
+        // ISO week dates use the same pivot
+        let mut parsed = Parsed::new();
+        parsed.set_two_digit_year_pivot(69);
+        parse(&mut parsed, "69-W01-1", StrftimeItems::new("%g-W%V-%u")).unwrap();
+        assert_eq!(parsed.to_naive_date().map(|d| d.iso_week().year()), Ok(1969));
+    }
 }
@@ -47,6 +47,8 @@ pub(super) fn number(s: &str, min: usize, max: usize) -> ParseResult<(&str, i64)
 
 /// Tries to consume at least one digits as a fractional second.
 /// Returns the number of whole nanoseconds (0--999,999,999).
+///
+/// Digits beyond the ninth are consumed, and truncate the fraction.
 pub(super) fn nanosecond(s: &str) -> ParseResult<(&str, i64)> {
     // record the number of digits consumed for later scaling.
     let origlen = s.len();
@@ -121,15 +123,19 @@ pub(super) fn short_weekday(s: &str) -> ParseResult<(&str, Weekday)> {
     Ok((&s[3..], weekday))
 }
 
+// lowercased month names, minus first three chars
+static LONG_MONTH_SUFFIXES: [&[u8]; 12] = [
+    b"uary", b"ruary", b"ch", b"il", b"", b"e", b"y", b"ust", b"tember", b"ober", b"ember",
+    b"ember",
+];
+
+// lowercased weekday names, minus first three chars
+static LONG_WEEKDAY_SUFFIXES: [&[u8]; 7] =
+    [b"day", b"sday", b"nesday", b"rsday", b"day", b"urday", b"day"];
+
 /// Tries to parse the month index (0 through 11) with short or long month names.
 /// It prefers long month names to short month names when both are possible.
 pub(super) fn short_or_long_month0(s: &str) -> ParseResult<(&str, u8)> {
-    // lowercased month names, minus first three chars
-    static LONG_MONTH_SUFFIXES: [&[u8]; 12] = [
-        b"uary", b"ruary", b"ch", b"il", b"", b"e", b"y", b"ust", b"tember", b"ober", b"ember",
-        b"ember",
-    ];
-
     let (mut s, month0) = short_month0(s)?;
 
     // tries to consume the suffix if possible
@@ -144,10 +150,6 @@ pub(super) fn short_or_long_month0(s: &str) -> ParseResult<(&str, u8)> {
 /// Tries to parse the weekday with short or long weekday names.
 /// It prefers long weekday names to short weekday names when both are possible.
 pub(super) fn short_or_long_weekday(s: &str) -> ParseResult<(&str, Weekday)> {
-    // lowercased weekday names, minus first three chars
-    static LONG_WEEKDAY_SUFFIXES: [&[u8]; 7] =
-        [b"day", b"sday", b"nesday", b"rsday", b"day", b"urday", b"day"];
-
     let (mut s, weekday) = short_weekday(s)?;
 
     // tries to consume the suffix if possible
@@ -159,6 +161,41 @@ pub(super) fn short_or_long_weekday(s: &str) -> ParseResult<(&str, Weekday)> {
     Ok((s, weekday))
 }
 
+/// Tries to parse the month index (0 through 11) with the full month name only.
+pub(super) fn long_month0(s: &str) -> ParseResult<(&str, u8)> {
+    let (s, month0) = short_month0(s)?;
+    let s = long_name_suffix(s, LONG_MONTH_SUFFIXES[month0 as usize])?;
+    Ok((s, month0))
+}
+
+/// Tries to parse the weekday with the full weekday name only.
+pub(super) fn long_weekday(s: &str) -> ParseResult<(&str, Weekday)> {
+    let (s, weekday) = short_weekday(s)?;
+    let s = long_name_suffix(s, LONG_WEEKDAY_SUFFIXES[weekday.num_days_from_monday() as usize])?;
+    Ok((s, weekday))
+}
+
+/// Tries to consume the given lowercased suffix of a long name, in any case.
+fn long_name_suffix<'a>(s: &'a str, suffix: &[u8]) -> ParseResult<&'a str> {
+    let len = core::cmp::min(s.len(), suffix.len());
+    if !s.as_bytes()[..len].eq_ignore_ascii_case(&suffix[..len]) {
+        return Err(INVALID);
+    }
+    if len < suffix.len() {
+        return Err(TOO_SHORT);
+    }
+    Ok(&s[len..])
+}
+
+/// Checks that a month or weekday name is not followed by another letter, so that it is not
+/// just a prefix of a longer word.
+pub(super) fn name_end(s: &str) -> ParseResult<()> {
+    match s.chars().next() {
+        Some(c) if c.is_alphabetic() => Err(INVALID),
+        _ => Ok(()),
+    }
+}
+
 /// Tries to consume exactly one given character.
 pub(super) fn char(s: &str, c1: u8) -> ParseResult<&str> {
     match s.as_bytes().first() {
@@ -193,6 +230,10 @@ pub(crate) fn colon_or_space(s: &str) -> ParseResult<&str> {
 /// The `allow_missing_minutes` flag allows the timezone minutes offset to be
 /// missing from `s`.
 ///
+/// The `allow_seconds` flag allows an optional seconds offset to follow the
+/// minutes offset, separated by a `:`. This is only accepted if the hours and
+/// minutes offsets are separated as well.
+///
 /// The `allow_tz_minus_sign` flag allows the timezone offset negative character
 /// to also be `−` MINUS SIGN (U+2212) in addition to the typical
 /// ASCII-compatible `-` HYPHEN-MINUS (U+2D).
@@ -204,6 +245,7 @@ pub(crate) fn timezone_offset<F>(
     mut consume_colon: F,
     allow_zulu: bool,
     allow_missing_minutes: bool,
+    allow_seconds: bool,
     allow_tz_minus_sign: bool,
 ) -> ParseResult<(&str, i32)>
 where
@@ -257,7 +299,9 @@ where
     s = &s[2..];
 
     // colons (and possibly other separators)
+    let len_before_separator = s.len();
     s = consume_colon(s)?;
+    let has_separator = s.len() < len_before_separator;
 
     // minutes (00--59)
     // if the next two items are digits then we have to add minutes
@@ -278,7 +322,21 @@ where
         _ => return Err(TOO_SHORT),
     };
 
-    let seconds = hours * 3600 + minutes * 60;
+    // seconds (00--59)
+    // only consumed if hours and minutes were separated, and a colon and two digits follow
+    let mut seconds = 0;
+    if allow_seconds && has_separator && s.starts_with(':') {
+        match digits(&s[1..]) {
+            Ok((s1 @ b'0'..=b'5', s2 @ b'0'..=b'9')) => {
+                seconds = i32::from((s1 - b'0') * 10 + (s2 - b'0'));
+                s = &s[3..];
+            }
+            Ok((b'6'..=b'9', b'0'..=b'9')) => return Err(OUT_OF_RANGE),
+            _ => {}
+        }
+    }
+
+    let seconds = hours * 3600 + minutes * 60 + seconds;
     Ok((s, if negative { -seconds } else { seconds }))
 }
 
@@ -319,7 +377,7 @@ pub(super) fn timezone_offset_2822(s: &str) -> ParseResult<(&str, i32)> {
         }
         Err(INVALID)
     } else {
-        timezone_offset(s, |s| Ok(s), false, false, false)
+        timezone_offset(s, |s| Ok(s), false, false, false, false)
     }
 }
 
@@ -347,6 +405,29 @@ pub(super) fn comment_2822(s: &str) -> ParseResult<(&str, ())> {
     Err(TOO_SHORT)
 }
 
+/// Consumes any sequence of white space and RFC2822 comments (`CFWS`).
+///
+/// An unterminated comment is left in place.
+pub(super) fn cfws_2822(s: &str) -> &str {
+    let mut s = s.trim_start();
+    while let Ok((s_, ())) = comment_2822(s) {
+        s = s_.trim_start();
+    }
+    s
+}
+
+/// Consumes a mandatory sequence of white space and RFC2822 comments (`CFWS`).
+pub(super) fn cfws_2822_required(s: &str) -> ParseResult<&str> {
+    let s_ = cfws_2822(s);
+    if s_.len() < s.len() {
+        Ok(s_)
+    } else if s.is_empty() {
+        Err(TOO_SHORT)
+    } else {
+        Err(INVALID)
+    }
+}
+
 enum CommentState {
     Start,
     Next(usize),
@@ -356,8 +437,8 @@ enum CommentState {
 #[cfg(test)]
 mod tests {
     use super::{
-        comment_2822, nanosecond, nanosecond_fixed, short_or_long_month0, short_or_long_weekday,
-        timezone_offset_2822,
+        comment_2822, long_month0, long_weekday, name_end, nanosecond, nanosecond_fixed,
+        short_or_long_month0, short_or_long_weekday, timezone_offset_2822,
     };
     use crate::format::{INVALID, TOO_SHORT};
     use crate::Weekday;
@@ -422,6 +503,36 @@ mod tests {
         assert_eq!(short_or_long_weekday("thu").unwrap(), ("", Weekday::Thu));
     }
 
+    #[test]
+    fn test_long_month0() {
+        assert_eq!(long_month0("January").unwrap(), ("", 0));
+        assert_eq!(long_month0("mAY").unwrap(), ("", 4));
+        assert_eq!(long_month0("sEpTeMbEr 5").unwrap(), (" 5", 8));
+        assert_eq!(long_month0("Decemberx").unwrap(), ("x", 11));
+        assert_eq!(long_month0("Jan"), Err(TOO_SHORT));
+        assert_eq!(long_month0("Janu"), Err(TOO_SHORT));
+        assert_eq!(long_month0("Jan 5"), Err(INVALID));
+        assert_eq!(long_month0("Juny"), Err(INVALID));
+    }
+
+    #[test]
+    fn test_long_weekday() {
+        assert_eq!(long_weekday("Wednesday").unwrap(), ("", Weekday::Wed));
+        assert_eq!(long_weekday("fRIDAY,").unwrap(), (",", Weekday::Fri));
+        assert_eq!(long_weekday("Sat"), Err(TOO_SHORT));
+        assert_eq!(long_weekday("Saturnday"), Err(INVALID));
+    }
+
+    #[test]
+    fn test_name_end() {
+        assert_eq!(name_end(""), Ok(()));
+        assert_eq!(name_end(" 5"), Ok(()));
+        assert_eq!(name_end("5"), Ok(()));
+        assert_eq!(name_end(","), Ok(()));
+        assert_eq!(name_end("u"), Err(INVALID));
+        assert_eq!(name_end("â"), Err(INVALID));
+    }
+
     #[test]
     fn test_nanosecond_fixed() {
         assert_eq!(nanosecond_fixed("", 0usize).unwrap(), ("", 0));
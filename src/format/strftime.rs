@@ -12,19 +12,20 @@ The following specifiers are available both to formatting and parsing.
 |-------|----------|----------------------------------------------------------------------------|
 |       |          | **DATE SPECIFIERS:**                                                       |
 | `%Y`  | `2001`   | The full proleptic Gregorian year, zero-padded to 4 digits. chrono supports years from -262144 to 262143. Note: years before 1 BCE or after 9999 CE, require an initial sign (+/-).|
+| `%+Y` | `+2001`  | The year in the ISO 8601 expanded representation, always signed and zero-padded to 4 digits. [^11] |
 | `%C`  | `20`     | The proleptic Gregorian year divided by 100, zero-padded to 2 digits. [^1] |
 | `%y`  | `01`     | The proleptic Gregorian year modulo 100, zero-padded to 2 digits. [^1]     |
 |       |          |                                                                            |
 | `%m`  | `07`     | Month number (01--12), zero-padded to 2 digits.                            |
-| `%b`  | `Jul`    | Abbreviated month name. Always 3 letters.                                  |
-| `%B`  | `July`   | Full month name. Also accepts corresponding abbreviation in parsing.       |
+| `%b`  | `Jul`    | Abbreviated month name. Always 3 letters. [^12]                            |
+| `%B`  | `July`   | Full month name. Also accepts the abbreviation in parsing. [^12]           |
 | `%h`  | `Jul`    | Same as `%b`.                                                              |
 |       |          |                                                                            |
 | `%d`  | `08`     | Day number (01--31), zero-padded to 2 digits.                              |
 | `%e`  | ` 8`     | Same as `%d` but space-padded. Same as `%_d`.                              |
 |       |          |                                                                            |
-| `%a`  | `Sun`    | Abbreviated weekday name. Always 3 letters.                                |
-| `%A`  | `Sunday` | Full weekday name. Also accepts corresponding abbreviation in parsing.     |
+| `%a`  | `Sun`    | Abbreviated weekday name. Always 3 letters. [^12]                          |
+| `%A`  | `Sunday` | Full weekday name. Also accepts the abbreviation in parsing. [^12]         |
 | `%w`  | `0`      | Sunday = 0, Monday = 1, ..., Saturday = 6.                                 |
 | `%u`  | `7`      | Monday = 1, Tuesday = 2, ..., Sunday = 7. (ISO 8601)                       |
 |       |          |                                                                            |
@@ -55,12 +56,12 @@ The following specifiers are available both to formatting and parsing.
 | `%S`  | `60`     | Second number (00--60), zero-padded to 2 digits. [^4]                      |
 | `%f`  | `26490000`    | Number of nanoseconds since last whole second. [^7]                   |
 | `%.f` | `.026490`| Decimal fraction of a second. Consumes the leading dot. [^7]               |
-| `%.3f`| `.026`        | Decimal fraction of a second with a fixed length of 3.                |
-| `%.6f`| `.026490`     | Decimal fraction of a second with a fixed length of 6.                |
-| `%.9f`| `.026490000`  | Decimal fraction of a second with a fixed length of 9.                |
-| `%3f` | `026`         | Decimal fraction of a second like `%.3f` but without the leading dot. |
-| `%6f` | `026490`      | Decimal fraction of a second like `%.6f` but without the leading dot. |
-| `%9f` | `026490000`   | Decimal fraction of a second like `%.9f` but without the leading dot. |
+| `%.3f`| `.026`        | Decimal fraction of a second with a fixed length of 3. [^10]          |
+| `%.6f`| `.026490`     | Decimal fraction of a second with a fixed length of 6. [^10]          |
+| `%.9f`| `.026490000`  | Decimal fraction of a second with a fixed length of 9. [^10]          |
+| `%3f` | `026`         | Like `%.3f` but without the leading dot. [^10]                        |
+| `%6f` | `026490`      | Like `%.6f` but without the leading dot. [^10]                        |
+| `%9f` | `026490000`   | Like `%.9f` but without the leading dot. [^10]                        |
 |       |               |                                                                       |
 | `%R`  | `00:34`       | Hour-minute format. Same as `%H:%M`.                                  |
 | `%T`  | `00:34:60`    | Hour-minute-second format. Same as `%H:%M:%S`.                        |
@@ -69,7 +70,7 @@ The following specifiers are available both to formatting and parsing.
 |       |          |                                                                            |
 |       |          | **TIME ZONE SPECIFIERS:**                                                  |
 | `%Z`  | `ACST`   | Local time zone name. Skips all non-whitespace characters during parsing. Identical to `%:z` when formatting. [^8] |
-| `%z`  | `+0930`  | Offset from the local time to UTC (with UTC being `+0000`).                |
+| `%z`  | `+0930`  | Offset from the local time to UTC (with UTC being `+0000`). [^9]           |
 | `%:z` | `+09:30` | Same as `%z` but with a colon.                                             |
 |`%::z`|`+09:30:00`| Offset from the local time to UTC with seconds.                            |
 |`%:::z`| `+09`    | Offset from the local time to UTC without minutes.                         |
@@ -86,6 +87,10 @@ The following specifiers are available both to formatting and parsing.
 | `%n`  |          | Literal newline (`\n`).                                                    |
 | `%%`  |          | Literal percent sign.                                                      |
 
+When parsing, whitespace in the format string, including `%t` and `%n`, matches any run of
+whitespace in the input, including none at all. For example, `%Y-%m-%d %H:%M` accepts
+`2024-01-01 12:00`, `2024-01-01   12:00` and `2024-01-01\t12:00` alike.
+
 It is possible to override the default padding behavior of numeric specifiers `%?`.
 This is not allowed for other specifiers and will result in the `BAD_FORMAT` error.
 
@@ -99,10 +104,15 @@ Notes:
 
 [^1]: `%C`, `%y`:
    This is floor division, so 100 BCE (year number -99) will print `-1` and `99` respectively.
+   When parsing `%y` without `%C`, years 70 through 99 resolve to the 1900s and 00 through 69
+   to the 2000s. The pivot can be changed with
+   [`Parsed::set_two_digit_year_pivot`](crate::format::Parsed::set_two_digit_year_pivot).
 
 [^2]: `%U`:
    Week 1 starts with the first Sunday in that year.
    It is possible to have week 0 for days before the first Sunday.
+   When parsing, `%U` or `%W` resolve to a date together with the year and the day of the week,
+   such as `%Y %W %a`. Week 0 is accepted as long as the resulting date is within that year.
 
 [^3]: `%G`, `%g`, `%V`:
    Week 1 is the first week with at least 4 days in that year.
@@ -138,6 +148,12 @@ Notes:
    `%f` counts the number of nanoseconds since the last whole second, while `%.f` is a fraction of a
    second.<br>
    Example: 7μs is formatted as `7000` with `%f`, and formatted as `.000007` with `%.f`.
+   <br>
+   <br>
+   When parsing, digits beyond nanosecond precision are consumed and ignored, so the fraction is
+   truncated (rounded toward zero). For example `.123456789999` is parsed as 123,456,789
+   nanoseconds. Use [`Parsed::set_strict_fractions`](crate::format::Parsed::set_strict_fractions)
+   to reject them instead.
 
 [^8]: `%Z`:
    Since `chrono` is not aware of timezones beyond their offsets, this specifier
@@ -151,9 +167,50 @@ Notes:
    this format code.
    <br>
    <br>
+   With [`Parsed::set_capture_timezone_name`](crate::format::Parsed::set_capture_timezone_name)
+   the token up to the next whitespace is stored in
+   [`Parsed::timezone_name`](crate::format::Parsed::timezone_name) instead. This also captures
+   an IANA time zone identifier like `America/New_York`, for example to look it up with a time
+   zone database. Names longer than 32 bytes are then an error.
+   <br>
+   <br>
    It is not possible to reliably convert from an abbreviation to an offset,
    for example CDT can mean either Central Daylight Time (North America) or
-   China Daylight Time.
+   China Daylight Time. Parsing a string without an offset therefore fails,
+   unless the name is resolved with a [`TzResolver`](crate::format::TzResolver) using
+   [`Parsed::to_datetime_with_resolver`](crate::format::Parsed::to_datetime_with_resolver).
+
+[^9]: `%z`, `%:z`, `%::z`, `%:::z`:
+   When parsing, an offset in seconds may follow the minutes after a colon, such as
+   `+09:30:15`. This allows parsing the output of `%::z`.
+   <br>
+   <br>
+   A `Z` or `z` is also accepted as a zero offset when parsing, as in ISO 8601 timestamps like
+   `2024-01-01T00:00:00Z`. Formatting always writes a numeric offset.
+
+[^10]: `%.3f`, `%.6f`, `%.9f`, `%3f`, `%6f`, `%9f`:
+   When parsing, `%3f`, `%6f` and `%9f` consume exactly 3, 6 or 9 digits, so they can be used
+   to reject malformed fixed-precision timestamps. Fewer digits, including digits followed by
+   a non-digit character such as `12345Z` for `%6f`, are an error. A digit after the expected
+   number of digits is not consumed, but left for the next item like any other character. At
+   the end of the input it is a `TOO_LONG` error.
+   <br>
+   <br>
+   `%.3f`, `%.6f` and `%.9f` parse like `%.f` for compatibility: the fraction with its leading
+   dot is optional, and may have any number of digits. Use `.%6f` to require a dot and exactly
+   6 digits.
+
+[^11]: `%+Y`:
+   Years from 0 to 9999 are also written with a sign, so year 2001 is `+2001`, year -44 is
+   `-0044` and year 12024 is `+12024`. When parsing, the sign and at least 4 digits are
+   required. `%Y` also accepts this form, but only writes a sign outside of 0 to 9999.
+
+[^12]: `%b`, `%B`, `%a`, `%A`:
+   Names are parsed in any case, and parsing stops after the recognized name, so `%b` reads
+   `Jan` from `January` and leaves `uary` for the next item. Use
+   [`Parsed::set_strict_names`](crate::format::Parsed::set_strict_names) to only accept the full
+   name for `%B` and `%A`, only the abbreviation for `%b` and `%a`, and to reject a name that is
+   followed by another letter.
 */
 
 #[cfg(feature = "alloc")]
@@ -383,6 +440,62 @@ impl<'a> StrftimeItems<'a> {
     }
 }
 
+/// A `strftime`-like format string that has been parsed once into formatting items.
+///
+/// [`StrftimeItems`] parses the format string again every time a value is formatted. A
+/// `CompiledFormat` owns its items, so it can be created once, stored, and reused to format many
+/// values without parsing the format string again.
+///
+/// See the [`format::strftime` module](crate::format::strftime) for supported formatting
+/// specifiers.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::CompiledFormat;
+/// use chrono::{TimeZone, Utc};
+///
+/// let format = CompiledFormat::compile("%Y-%m-%d %H:%M:%S")?;
+/// let dt = Utc.with_ymd_and_hms(2024, 1, 15, 9, 30, 0).unwrap();
+/// assert_eq!(dt.format_compiled(&format).to_string(), "2024-01-15 09:30:00");
+/// assert_eq!(dt.format_compiled(&format).to_string(), dt.format("%Y-%m-%d %H:%M:%S").to_string());
+/// # Ok::<(), chrono::ParseError>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CompiledFormat {
+    items: Vec<Item<'static>>,
+}
+
+#[cfg(feature = "alloc")]
+impl CompiledFormat {
+    /// Parses the format string `fmt` into a `CompiledFormat`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the format string contains an invalid or unrecognized formatting
+    /// specifier.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::CompiledFormat;
+    ///
+    /// assert!(CompiledFormat::compile("%Y-%m-%d").is_ok());
+    /// assert!(CompiledFormat::compile("%Y-%m-%!").is_err());
+    /// ```
+    pub fn compile(fmt: &str) -> Result<CompiledFormat, ParseError> {
+        Ok(CompiledFormat { items: StrftimeItems::new(fmt).parse_to_owned()? })
+    }
+
+    /// Returns the formatting items of this format.
+    #[inline]
+    #[must_use]
+    pub fn items(&self) -> &[Item<'static>] {
+        &self.items
+    }
+}
+
 const HAVE_ALTERNATES: &str = "z";
 
 impl<'a> Iterator for StrftimeItems<'a> {
@@ -574,7 +687,14 @@ impl<'a> StrftimeItems<'a> {
                             fixed(Fixed::TimezoneOffset)
                         }
                     }
-                    '+' => fixed(Fixed::RFC3339),
+                    '+' => {
+                        if remainder.starts_with('Y') {
+                            remainder = &remainder[1..];
+                            internal_fixed(YearSigned)
+                        } else {
+                            fixed(Fixed::RFC3339)
+                        }
+                    }
                     ':' => {
                         if remainder.starts_with("::z") {
                             remainder = &remainder[3..];
@@ -845,6 +965,13 @@ mod tests {
             [internal_fixed(InternalInternal::TimezoneOffsetPermissive)]
         );
         assert_eq!(parse_and_collect("%#m"), [Item::Error]);
+        assert_eq!(parse_and_collect("%+Y"), [internal_fixed(InternalInternal::YearSigned)]);
+        assert_eq!(
+            parse_and_collect("%+Y-%m"),
+            [internal_fixed(InternalInternal::YearSigned), Literal("-"), num0(Month)]
+        );
+        assert_eq!(parse_and_collect("%+"), [fixed(Fixed::RFC3339)]);
+        assert_eq!(parse_and_collect("%+y"), [fixed(Fixed::RFC3339), Literal("y")]);
     }
 
     #[test]
@@ -1124,6 +1251,31 @@ mod tests {
         assert_eq!(size_of::<Locale>(), 2);
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_strftime_year_signed() {
+        let ymd = |y| NaiveDate::from_ymd_opt(y, 1, 15).unwrap();
+        for (year, expected) in [
+            (2024, "+2024-01-15"),
+            (12024, "+12024-01-15"),
+            (-44, "-0044-01-15"),
+            (0, "+0000-01-15"),
+            (5, "+0005-01-15"),
+            (-262143, "-262143-01-15"),
+            (262142, "+262142-01-15"),
+        ] {
+            let formatted = ymd(year).format("%+Y-%m-%d").to_string();
+            assert_eq!(formatted, expected);
+            assert_eq!(NaiveDate::parse_from_str(&formatted, "%+Y-%m-%d"), Ok(ymd(year)));
+            assert_eq!(NaiveDate::parse_from_str(&formatted, "%Y-%m-%d"), Ok(ymd(year)));
+        }
+        // `%Y` only writes a sign outside of 0 to 9999
+        assert_eq!(ymd(12024).format("%Y").to_string(), "+12024");
+        assert_eq!(ymd(-44).format("%Y").to_string(), "-0044");
+        assert_eq!(ymd(2024).format("%Y").to_string(), "2024");
+        assert!(NaiveDate::parse_from_str("2024-01-15", "%+Y-%m-%d").is_err());
+    }
+
     #[test]
     #[cfg(any(feature = "alloc", feature = "std"))]
     fn test_strftime_parse() {
@@ -0,0 +1,192 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Humanized descriptions of relative time, such as "3 hours ago".
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::{format, string::String};
+use core::fmt;
+
+use crate::TimeDelta;
+
+/// A coarse unit of time used to describe a [`TimeDelta`] in human terms.
+///
+/// [`TimeDelta::humanize`] uses this to produce English phrases. Crates that want to produce
+/// phrases in other languages can use [`RelativeTimeUnit::from_time_delta`] to get the same
+/// bucketing.
+///
+/// Months are counted as 30 days, and years as 365 days.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash, PartialOrd, Ord)]
+pub enum RelativeTimeUnit {
+    /// A second.
+    Second,
+    /// A minute, 60 seconds.
+    Minute,
+    /// An hour, 60 minutes.
+    Hour,
+    /// A day, 24 hours.
+    Day,
+    /// A week, 7 days.
+    Week,
+    /// A month, approximated as 30 days.
+    Month,
+    /// A year, approximated as 365 days.
+    Year,
+}
+
+impl RelativeTimeUnit {
+    /// Returns the length of the unit in seconds.
+    #[inline]
+    #[must_use]
+    pub const fn num_seconds(&self) -> i64 {
+        match *self {
+            RelativeTimeUnit::Second => 1,
+            RelativeTimeUnit::Minute => 60,
+            RelativeTimeUnit::Hour => 60 * 60,
+            RelativeTimeUnit::Day => 24 * 60 * 60,
+            RelativeTimeUnit::Week => 7 * 24 * 60 * 60,
+            RelativeTimeUnit::Month => 30 * 24 * 60 * 60,
+            RelativeTimeUnit::Year => 365 * 24 * 60 * 60,
+        }
+    }
+
+    /// Returns the English name of the unit, such as `"hour"`.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match *self {
+            RelativeTimeUnit::Second => "second",
+            RelativeTimeUnit::Minute => "minute",
+            RelativeTimeUnit::Hour => "hour",
+            RelativeTimeUnit::Day => "day",
+            RelativeTimeUnit::Week => "week",
+            RelativeTimeUnit::Month => "month",
+            RelativeTimeUnit::Year => "year",
+        }
+    }
+
+    /// Returns the English plural name of the unit, such as `"hours"`.
+    #[must_use]
+    pub const fn plural_name(&self) -> &'static str {
+        match *self {
+            RelativeTimeUnit::Second => "seconds",
+            RelativeTimeUnit::Minute => "minutes",
+            RelativeTimeUnit::Hour => "hours",
+            RelativeTimeUnit::Day => "days",
+            RelativeTimeUnit::Week => "weeks",
+            RelativeTimeUnit::Month => "months",
+            RelativeTimeUnit::Year => "years",
+        }
+    }
+
+    /// Splits `delta` into a count of the largest unit that fits at least once.
+    ///
+    /// The count is truncated towards zero and has the same sign as `delta`. A `delta` of less
+    /// than one second returns a count of zero seconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{RelativeTimeUnit, TimeDelta};
+    ///
+    /// let delta = TimeDelta::try_hours(-50).unwrap();
+    /// assert_eq!(RelativeTimeUnit::from_time_delta(delta), (-2, RelativeTimeUnit::Day));
+    /// let delta = TimeDelta::milliseconds(999);
+    /// assert_eq!(RelativeTimeUnit::from_time_delta(delta), (0, RelativeTimeUnit::Second));
+    /// ```
+    #[must_use]
+    pub const fn from_time_delta(delta: TimeDelta) -> (i64, RelativeTimeUnit) {
+        const UNITS: [RelativeTimeUnit; 6] = [
+            RelativeTimeUnit::Year,
+            RelativeTimeUnit::Month,
+            RelativeTimeUnit::Week,
+            RelativeTimeUnit::Day,
+            RelativeTimeUnit::Hour,
+            RelativeTimeUnit::Minute,
+        ];
+        let secs = delta.num_seconds();
+        let mut i = 0;
+        while i < UNITS.len() {
+            let count = secs / UNITS[i].num_seconds();
+            if count != 0 {
+                return (count, UNITS[i]);
+            }
+            i += 1;
+        }
+        (secs, RelativeTimeUnit::Second)
+    }
+}
+
+impl fmt::Display for RelativeTimeUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Formats `delta` as an English phrase relative to now.
+pub(crate) fn humanize(delta: TimeDelta) -> String {
+    let (count, unit) = RelativeTimeUnit::from_time_delta(delta);
+    let abs = count.unsigned_abs();
+    let name = if abs == 1 { unit.name() } else { unit.plural_name() };
+    if count == 0 {
+        String::from("just now")
+    } else if count > 0 {
+        format!("in {} {}", abs, name)
+    } else {
+        format!("{} {} ago", abs, name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RelativeTimeUnit;
+    use crate::{NaiveDate, TimeDelta};
+
+    #[test]
+    fn test_from_time_delta() {
+        let cases = [
+            (TimeDelta::zero(), (0, RelativeTimeUnit::Second)),
+            (TimeDelta::seconds(59), (59, RelativeTimeUnit::Second)),
+            (TimeDelta::seconds(60), (1, RelativeTimeUnit::Minute)),
+            (TimeDelta::minutes(119), (1, RelativeTimeUnit::Hour)),
+            (TimeDelta::days(6), (6, RelativeTimeUnit::Day)),
+            (TimeDelta::days(29), (4, RelativeTimeUnit::Week)),
+            (TimeDelta::days(30), (1, RelativeTimeUnit::Month)),
+            (TimeDelta::days(364), (12, RelativeTimeUnit::Month)),
+            (TimeDelta::days(365), (1, RelativeTimeUnit::Year)),
+            (TimeDelta::days(-800), (-2, RelativeTimeUnit::Year)),
+            (TimeDelta::milliseconds(-1_500), (-1, RelativeTimeUnit::Second)),
+        ];
+        for (delta, expected) in cases {
+            assert_eq!(RelativeTimeUnit::from_time_delta(delta), expected, "{:?}", delta);
+        }
+    }
+
+    #[test]
+    fn test_humanize() {
+        assert_eq!(TimeDelta::zero().humanize(), "just now");
+        assert_eq!(TimeDelta::milliseconds(999).humanize(), "just now");
+        assert_eq!(TimeDelta::milliseconds(-999).humanize(), "just now");
+        assert_eq!(TimeDelta::seconds(1).humanize(), "in 1 second");
+        assert_eq!(TimeDelta::minutes(1).humanize(), "in 1 minute");
+        assert_eq!(TimeDelta::hours(1).humanize(), "in 1 hour");
+        assert_eq!(TimeDelta::days(1).humanize(), "in 1 day");
+        assert_eq!(TimeDelta::weeks(1).humanize(), "in 1 week");
+        assert_eq!(TimeDelta::days(30).humanize(), "in 1 month");
+        assert_eq!(TimeDelta::days(365).humanize(), "in 1 year");
+        assert_eq!(TimeDelta::seconds(-1).humanize(), "1 second ago");
+        assert_eq!(TimeDelta::hours(-3).humanize(), "3 hours ago");
+        assert_eq!(TimeDelta::minutes(-150).humanize(), "2 hours ago");
+        assert_eq!(TimeDelta::days(-400).humanize(), "1 year ago");
+        assert_eq!(TimeDelta::min_value().humanize(), "292471208 years ago");
+        assert_eq!(TimeDelta::max_value().humanize(), "in 292471208 years");
+    }
+
+    #[test]
+    fn test_humanize_since() {
+        let now = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+        let now = now.and_utc();
+        assert_eq!(now.humanize_since(now), "just now");
+        assert_eq!((now - TimeDelta::minutes(5)).humanize_since(now), "5 minutes ago");
+        assert_eq!((now + TimeDelta::days(2)).humanize_since(now), "in 2 days");
+    }
+}
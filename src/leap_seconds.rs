@@ -0,0 +1,79 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A table of historical leap seconds.
+//!
+//! This table has to be updated by hand whenever the IERS announces a new leap second in its
+//! Bulletin C. The most recent leap second in the table is the one at the end of 2016-12-31.
+
+/// UNIX timestamps of the first second after each positive leap second.
+///
+/// Each leap second was inserted as `23:59:60` UTC at the end of the date in the comment, so the
+/// timestamp is midnight of the following day. There have been no negative leap seconds.
+const LEAP_SECONDS: [i64; 27] = [
+    78796800,   // 1972-06-30
+    94694400,   // 1972-12-31
+    126230400,  // 1973-12-31
+    157766400,  // 1974-12-31
+    189302400,  // 1975-12-31
+    220924800,  // 1976-12-31
+    252460800,  // 1977-12-31
+    283996800,  // 1978-12-31
+    315532800,  // 1979-12-31
+    362793600,  // 1981-06-30
+    394329600,  // 1982-06-30
+    425865600,  // 1983-06-30
+    489024000,  // 1985-06-30
+    567993600,  // 1987-12-31
+    631152000,  // 1989-12-31
+    662688000,  // 1990-12-31
+    709948800,  // 1992-06-30
+    741484800,  // 1993-06-30
+    773020800,  // 1994-06-30
+    820454400,  // 1995-12-31
+    867715200,  // 1997-06-30
+    915148800,  // 1998-12-31
+    1136073600, // 2005-12-31
+    1230768000, // 2008-12-31
+    1341100800, // 2012-06-30
+    1435708800, // 2015-06-30
+    1483228800, // 2016-12-31
+];
+
+/// Returns the number of leap seconds inserted before the second starting at `timestamp`.
+///
+/// A time within a leap second is represented with the timestamp of the preceding `23:59:59`,
+/// so the leap second itself is not counted for it.
+pub(crate) fn leap_seconds_before(timestamp: i64) -> i64 {
+    LEAP_SECONDS.iter().take_while(|&&leap| leap <= timestamp).count() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{leap_seconds_before, LEAP_SECONDS};
+    use crate::{DateTime, Datelike, NaiveTime};
+
+    #[test]
+    fn test_leap_seconds_table() {
+        for window in LEAP_SECONDS.windows(2) {
+            assert!(window[0] < window[1]);
+        }
+        for &leap in LEAP_SECONDS.iter() {
+            // every leap second was inserted at the end of June or December
+            let date = DateTime::from_timestamp(leap, 0).unwrap();
+            assert_eq!(date.time(), NaiveTime::MIN);
+            assert!(date.month() == 1 || date.month() == 7);
+            assert_eq!(date.day(), 1);
+        }
+    }
+
+    #[test]
+    fn test_leap_seconds_before() {
+        assert_eq!(leap_seconds_before(i64::MIN), 0);
+        assert_eq!(leap_seconds_before(78_796_799), 0);
+        assert_eq!(leap_seconds_before(78_796_800), 1);
+        assert_eq!(leap_seconds_before(1_483_228_799), 26);
+        assert_eq!(leap_seconds_before(1_483_228_800), 27);
+        assert_eq!(leap_seconds_before(i64::MAX), 27);
+    }
+}
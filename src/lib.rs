@@ -44,8 +44,13 @@
 //!    using 64-bit integers for integral `*size` types.
 //! - `rkyv-validation`: Enable rkyv validation support using `bytecheck`.
 //! - `arbitrary`: Construct arbitrary instances of a type with the Arbitrary crate.
+//! - `humanize`: Describe durations as English phrases relative to now, such as "3 hours ago".
+//! - `leap-seconds`: Include a table of historical leap seconds, used by
+//!   `DateTime::duration_since_including_leap`.
 //! - `unstable-locales`: Enable localization. This adds various methods with a `_localized` suffix.
 //!   The implementation and API may change or even be removed in a patch release. Feedback welcome.
+//! - `unstable-step`: Implement the unstable `core::iter::Step` trait for `NaiveDate`, so ranges
+//!   of dates such as `start..end` can be iterated. This requires a nightly compiler.
 //! - `oldtime`: This feature no longer has any effect; it used to offer compatibility with the
 //!   `time` 0.1 crate.
 //!
@@ -142,7 +147,7 @@
 //! // July 8 is Tuesday in ISO week 28 of the year 2014.
 //! assert_eq!(
 //!     dt,
-//!     NaiveDate::from_isoywd_opt(2014, 28, Weekday::Tue)?.and_hms_opt(9, 10, 11)?.and_utc()
+//!     NaiveDate::from_iso_week(2014, 28, Weekday::Tue)?.and_hms_opt(9, 10, 11)?.and_utc()
 //! );
 //!
 //! let dt = NaiveDate::from_ymd_opt(2014, 7, 8)?
@@ -502,6 +507,7 @@
 
 #![doc(html_root_url = "https://docs.rs/chrono/latest/", test(attr(deny(warnings))))]
 #![cfg_attr(feature = "bench", feature(test))] // lib stability features as per RFC #507
+#![cfg_attr(feature = "unstable-step", feature(step_trait))]
 #![deny(missing_docs)]
 #![deny(missing_debug_implementations)]
 #![warn(unreachable_pub)]
@@ -517,6 +523,7 @@ mod time_delta;
 #[doc(no_inline)]
 pub use time_delta::OutOfRangeError;
 pub use time_delta::TimeDelta;
+pub use time_delta::{TimeDeltaDisplay, TimeDeltaStyle};
 
 /// Alias of [`TimeDelta`].
 pub type Duration = TimeDelta;
@@ -547,7 +554,7 @@ pub use date::Date;
 pub use date::{MAX_DATE, MIN_DATE};
 
 mod datetime;
-pub use datetime::DateTime;
+pub use datetime::{DateTime, Inclusivity};
 #[allow(deprecated)]
 #[doc(no_inline)]
 pub use datetime::{MAX_DATETIME, MIN_DATETIME};
@@ -560,7 +567,7 @@ pub use format::{ParseError, ParseResult, SecondsFormat};
 
 pub mod naive;
 #[doc(inline)]
-pub use naive::{Days, NaiveDate, NaiveDateTime, NaiveTime};
+pub use naive::{CalendarDuration, Days, NaiveDate, NaiveDateTime, NaiveTime};
 pub use naive::{IsoWeek, NaiveWeek};
 
 pub mod offset;
@@ -576,19 +583,34 @@ pub use offset::{FixedOffset, Offset, TimeZone, Utc};
 pub mod round;
 pub use round::{DurationRound, RoundingError, SubsecRound};
 
+pub mod calendar;
+
 mod weekday;
 #[doc(no_inline)]
 pub use weekday::ParseWeekdayError;
-pub use weekday::Weekday;
+pub use weekday::{Weekday, WeekdayIterator};
 
 mod month;
 #[doc(no_inline)]
 pub use month::ParseMonthError;
 pub use month::{Month, Months};
 
+mod quarter;
+#[doc(no_inline)]
+pub use quarter::ParseQuarterError;
+pub use quarter::Quarter;
+
 mod traits;
 pub use traits::{Datelike, Timelike};
 
+#[cfg(feature = "humanize")]
+mod humanize;
+#[cfg(feature = "humanize")]
+pub use humanize::RelativeTimeUnit;
+
+#[cfg(feature = "leap-seconds")]
+mod leap_seconds;
+
 #[cfg(feature = "__internal_bench")]
 #[doc(hidden)]
 pub use naive::__BenchYearFlags;
@@ -598,6 +620,15 @@ pub use naive::__BenchYearFlags;
 /// The [`DateTime`] type has default implementations for (de)serializing to/from the [RFC 3339]
 /// format. This module provides alternatives for serializing to timestamps.
 ///
+/// [`TimeDelta`] is (de)serialized as a struct with the fields `secs` and `nanos` by default. The
+/// `duration_*` modules provide alternatives for serializing it to a number of seconds or
+/// milliseconds, or to an ISO 8601 duration string.
+///
+/// [`NaiveDate`] and [`NaiveTime`] are (de)serialized as strings by default. For compact binary
+/// formats [`naive_date_as_days`](serde::naive_date_as_days) stores a number of days since
+/// 1970-01-01, and [`naive_time_as_nanos`](serde::naive_time_as_nanos) a number of nanoseconds
+/// since midnight.
+///
 /// The alternatives are for use with serde's [`with` annotation] combined with the module name.
 /// Alternatively the individual `serialize` and `deserialize` functions in each module can be used
 /// with serde's [`serialize_with`] and [`deserialize_with`] annotations.
@@ -614,6 +645,9 @@ pub mod serde {
     use serde::de;
 
     pub use super::datetime::serde::*;
+    pub use super::naive::date::serde::*;
+    pub use super::naive::time::serde::*;
+    pub use super::time_delta::serde::*;
 
     /// Create a custom `de::Error` with `SerdeError::InvalidTimestamp`.
     pub(crate) fn invalid_ts<E, T>(value: T) -> E
@@ -654,6 +688,7 @@ pub mod rkyv {
     #[cfg(feature = "clock")]
     pub use crate::offset::local::ArchivedLocal;
     pub use crate::offset::utc::ArchivedUtc;
+    pub use crate::quarter::ArchivedQuarter;
     pub use crate::time_delta::ArchivedTimeDelta;
     pub use crate::weekday::ArchivedWeekday;
 
@@ -161,6 +161,68 @@ impl Month {
             Month::December => "December",
         }
     }
+
+    /// Get the number of days in the month in the given year.
+    ///
+    /// February has 29 days in leap years of the proleptic Gregorian calendar, and 28 otherwise.
+    ///
+    /// ```
+    /// use chrono::Month;
+    ///
+    /// assert_eq!(Month::February.num_days(2000), 29);
+    /// assert_eq!(Month::February.num_days(2100), 28);
+    /// assert_eq!(Month::April.num_days(2100), 30);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn num_days(&self, year: i32) -> u8 {
+        match *self {
+            Month::January => 31,
+            Month::February if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+            Month::February => 28,
+            Month::March => 31,
+            Month::April => 30,
+            Month::May => 31,
+            Month::June => 30,
+            Month::July => 31,
+            Month::August => 31,
+            Month::September => 30,
+            Month::October => 31,
+            Month::November => 30,
+            Month::December => 31,
+        }
+    }
+
+    /// Returns the month for the zero-based month number `month0`, as returned by
+    /// [`Datelike::month0`](crate::Datelike::month0).
+    ///
+    /// Panics if `month0` is not in the range `0..12`.
+    #[inline]
+    pub(crate) const fn from_month0(month0: u32) -> Month {
+        const MONTHS: [Month; 12] = [
+            Month::January,
+            Month::February,
+            Month::March,
+            Month::April,
+            Month::May,
+            Month::June,
+            Month::July,
+            Month::August,
+            Month::September,
+            Month::October,
+            Month::November,
+            Month::December,
+        ];
+        MONTHS[month0 as usize]
+    }
+
+    /// Returns `true` if the number of days in the month depends on whether the year is a leap
+    /// year, which is only the case for February.
+    #[inline]
+    #[must_use]
+    pub const fn is_leap_relevant(&self) -> bool {
+        matches!(*self, Month::February)
+    }
 }
 
 impl TryFrom<u8> for Month {
@@ -229,6 +291,12 @@ pub struct Months(pub(crate) u32);
 
 impl Months {
     /// Construct a new `Months` from a number of months
+    ///
+    /// Every `u32` is a valid number of months, so this never fails. Applying a large `Months` to
+    /// a date can go out of range, which methods like [`NaiveDate::checked_add_months`] report by
+    /// returning `None`.
+    ///
+    /// [`NaiveDate::checked_add_months`]: crate::NaiveDate::checked_add_months
     pub const fn new(num: u32) -> Self {
         Self(num)
     }
@@ -238,6 +306,36 @@ impl Months {
     pub const fn as_u32(&self) -> u32 {
         self.0
     }
+
+    /// Returns the total number of months in the `Months` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::Months;
+    ///
+    /// assert_eq!(Months::new(14).num_months(), 14);
+    /// ```
+    #[inline]
+    pub const fn num_months(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns the number of whole years in the `Months` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::Months;
+    ///
+    /// assert_eq!(Months::new(11).num_years(), 0);
+    /// assert_eq!(Months::new(14).num_years(), 1);
+    /// assert_eq!(Months::new(24).num_years(), 2);
+    /// ```
+    #[inline]
+    pub const fn num_years(&self) -> u32 {
+        self.0 / 12
+    }
 }
 
 /// An error resulting from reading `<Month>` value with `FromStr`.
@@ -307,7 +405,7 @@ mod month_serde {
 #[cfg(test)]
 mod tests {
     use super::Month;
-    use crate::{Datelike, Months, OutOfRange, TimeZone, Utc};
+    use crate::{Datelike, Months, NaiveDate, OutOfRange, TimeZone, Utc};
 
     #[test]
     fn test_month_enum_try_from() {
@@ -324,6 +422,30 @@ mod tests {
         assert_eq!((dt.year(), dt.month(), dt.day()), (2019, 1, 28));
     }
 
+    #[test]
+    fn test_month_num_days() {
+        assert_eq!(Month::February.num_days(2000), 29);
+        assert_eq!(Month::February.num_days(1900), 28);
+        assert_eq!(Month::February.num_days(2001), 28);
+        assert_eq!(Month::February.num_days(2024), 29);
+        assert_eq!(Month::February.num_days(0), 29);
+        assert_eq!(Month::February.num_days(-4), 29);
+        assert_eq!(Month::February.num_days(-100), 28);
+        assert_eq!(Month::April.num_days(2000), 30);
+
+        for year in [1900, 2000, 2001, 2024, -400, -1] {
+            let mut month = Month::January;
+            for _ in 0..12 {
+                let date = NaiveDate::from_ymd_opt(year, month.number_from_month(), 1).unwrap();
+                let next = date.checked_add_months(Months::new(1)).unwrap();
+                let days = next.signed_duration_since(date).num_days();
+                assert_eq!(i64::from(month.num_days(year)), days, "{:?} {}", month, year);
+                assert_eq!(month.is_leap_relevant(), month == Month::February);
+                month = month.succ();
+            }
+        }
+    }
+
     #[test]
     fn test_month_enum_primitive_parse() {
         use num_traits::FromPrimitive;
@@ -362,6 +484,27 @@ mod tests {
         assert!(Month::September > Month::March);
     }
 
+    #[test]
+    fn test_month_from_str() {
+        for (i, month) in (1..=12).map(|n| Month::try_from(n as u8).unwrap()).enumerate() {
+            let name = month.name();
+            assert_eq!(name.parse::<Month>(), Ok(month), "{}", name);
+            assert_eq!(name.to_uppercase().parse::<Month>(), Ok(month));
+            assert_eq!(name.to_lowercase().parse::<Month>(), Ok(month));
+            assert_eq!(name[..3].parse::<Month>(), Ok(month));
+            assert_eq!(name[..3].to_uppercase().parse::<Month>(), Ok(month));
+            assert_eq!(month.number_from_month() as usize, i + 1);
+        }
+        assert_eq!("SEPTEMBER".parse::<Month>(), Ok(Month::September));
+        assert_eq!("Sep".parse::<Month>(), Ok(Month::September));
+        assert_eq!("sep".parse::<Month>(), Ok(Month::September));
+
+        for s in ["Octobr", "Sept", "Se", "", " Sep", "Sep ", "Septembers", "1"] {
+            assert!(s.parse::<Month>().is_err(), "{:?}", s);
+        }
+        assert_eq!("Octobr".parse::<Month>().unwrap_err().to_string(), "ParseMonthError { .. }");
+    }
+
     #[test]
     fn test_months_as_u32() {
         assert_eq!(Months::new(0).as_u32(), 0);
@@ -369,6 +512,18 @@ mod tests {
         assert_eq!(Months::new(u32::MAX).as_u32(), u32::MAX);
     }
 
+    #[test]
+    fn test_months_num_months_years() {
+        for n in [0, 1, 11, 12, 13, 14, 24, 1200, u32::MAX] {
+            let months = Months::new(n);
+            assert_eq!(months.num_months(), n);
+            assert_eq!(months.num_months(), months.as_u32());
+            assert_eq!(months.num_years(), n / 12);
+        }
+        assert_eq!(Months::new(14).num_years(), 1);
+        assert_eq!(Months::new(u32::MAX).num_years(), 357_913_941);
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn test_serde_serialize() {
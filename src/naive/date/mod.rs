@@ -16,6 +16,8 @@
 #[cfg(feature = "alloc")]
 use core::borrow::Borrow;
 use core::iter::FusedIterator;
+#[cfg(feature = "unstable-step")]
+use core::iter::Step;
 use core::num::NonZeroI32;
 use core::ops::{Add, AddAssign, Sub, SubAssign};
 use core::{fmt, str};
@@ -27,14 +29,15 @@ use rkyv::{Archive, Deserialize, Serialize};
 #[cfg(all(feature = "unstable-locales", feature = "alloc"))]
 use pure_rust_locales::Locale;
 
+use crate::calendar::Weekend;
 #[cfg(feature = "alloc")]
 use crate::format::DelayedFormat;
 use crate::format::{
-    parse, parse_and_remainder, write_hundreds, Item, Numeric, Pad, ParseError, ParseResult,
-    Parsed, StrftimeItems,
+    parse, parse_and_remainder, write_hundreds, write_items, Item, Numeric, Pad, ParseError,
+    ParseResult, Parsed, StrftimeItems,
 };
 use crate::month::Months;
-use crate::naive::{Days, IsoWeek, NaiveDateTime, NaiveTime, NaiveWeek};
+use crate::naive::{CalendarDuration, Days, IsoWeek, NaiveDateTime, NaiveTime, NaiveWeek};
 use crate::{expect, try_opt};
 use crate::{Datelike, TimeDelta, Weekday};
 
@@ -192,6 +195,18 @@ impl NaiveDate {
     /// assert!(from_ymd_opt(400000, 1, 1).is_none());
     /// assert!(from_ymd_opt(-400000, 1, 1).is_none());
     /// ```
+    ///
+    /// This is a `const fn`, so it can be used to define constants:
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// const EPOCH: NaiveDate = match NaiveDate::from_ymd_opt(1970, 1, 1) {
+    ///     Some(date) => date,
+    ///     None => panic!("invalid date"),
+    /// };
+    /// assert_eq!(EPOCH, NaiveDate::from_ymd_opt(1970, 1, 1).unwrap());
+    /// ```
     #[must_use]
     pub const fn from_ymd_opt(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
         let flags = YearFlags::from_year(year);
@@ -255,22 +270,25 @@ impl NaiveDate {
     ///
     /// Panics if the specified week does not exist in that year, on invalid values for `week`, or
     /// if the resulting date is out of range for `NaiveDate`.
-    #[deprecated(since = "0.4.23", note = "use `from_isoywd_opt()` instead")]
+    #[deprecated(since = "0.4.23", note = "use `from_iso_week()` instead")]
     #[must_use]
     pub const fn from_isoywd(year: i32, week: u32, weekday: Weekday) -> NaiveDate {
-        expect(NaiveDate::from_isoywd_opt(year, week, weekday), "invalid or out-of-range date")
+        expect(NaiveDate::from_iso_week(year, week, weekday), "invalid or out-of-range date")
     }
 
-    /// Makes a new `NaiveDate` from the [ISO week date](#week-date)
-    /// (year, week number and day of the week).
-    /// The resulting `NaiveDate` may have a different year from the input year.
+    /// Makes a new `NaiveDate` from the ISO 8601 week-numbering `year`, `week` and `weekday`.
+    ///
+    /// An ISO week-numbering year has 52 or 53 weeks. It has 53 weeks if it starts on a Thursday,
+    /// or if it is a leap year that starts on a Wednesday; 2015 and 2020 are examples. Week 1 is
+    /// the week with the year's first Thursday, so the resulting date may be in the previous or
+    /// the next calendar year.
     ///
     /// # Errors
     ///
     /// Returns `None` if:
-    /// - The specified week does not exist in that year (for example 2023 week 53).
-    /// - The value for `week` is invalid (for example: `0`, `60`).
-    /// - If the resulting date is out of range for `NaiveDate`.
+    /// - `week` is 0.
+    /// - `week` is 53 and the year only has 52 weeks, or `week` is greater than 53.
+    /// - The resulting date is out of range for `NaiveDate`.
     ///
     /// # Example
     ///
@@ -278,15 +296,11 @@ impl NaiveDate {
     /// use chrono::{NaiveDate, Weekday};
     ///
     /// let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
-    /// let from_isoywd_opt = NaiveDate::from_isoywd_opt;
     ///
-    /// assert_eq!(from_isoywd_opt(2015, 0, Weekday::Sun), None);
-    /// assert_eq!(from_isoywd_opt(2015, 10, Weekday::Sun), Some(from_ymd(2015, 3, 8)));
-    /// assert_eq!(from_isoywd_opt(2015, 30, Weekday::Mon), Some(from_ymd(2015, 7, 20)));
-    /// assert_eq!(from_isoywd_opt(2015, 60, Weekday::Mon), None);
-    ///
-    /// assert_eq!(from_isoywd_opt(400000, 10, Weekday::Fri), None);
-    /// assert_eq!(from_isoywd_opt(-400000, 10, Weekday::Sat), None);
+    /// assert_eq!(NaiveDate::from_iso_week(2024, 1, Weekday::Mon), Some(from_ymd(2024, 1, 1)));
+    /// assert_eq!(NaiveDate::from_iso_week(2020, 53, Weekday::Fri), Some(from_ymd(2021, 1, 1)));
+    /// assert_eq!(NaiveDate::from_iso_week(2021, 53, Weekday::Mon), None);
+    /// assert_eq!(NaiveDate::from_iso_week(2021, 0, Weekday::Mon), None);
     /// ```
     ///
     /// The year number of ISO week date may differ from that of the calendar date.
@@ -294,24 +308,23 @@ impl NaiveDate {
     /// ```
     /// # use chrono::{NaiveDate, Weekday};
     /// # let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
-    /// # let from_isoywd_opt = NaiveDate::from_isoywd_opt;
     /// //           Mo Tu We Th Fr Sa Su
     /// // 2014-W52  22 23 24 25 26 27 28    has 4+ days of new year,
     /// // 2015-W01  29 30 31  1  2  3  4 <- so this is the first week
-    /// assert_eq!(from_isoywd_opt(2014, 52, Weekday::Sun), Some(from_ymd(2014, 12, 28)));
-    /// assert_eq!(from_isoywd_opt(2014, 53, Weekday::Mon), None);
-    /// assert_eq!(from_isoywd_opt(2015, 1, Weekday::Mon), Some(from_ymd(2014, 12, 29)));
+    /// assert_eq!(NaiveDate::from_iso_week(2014, 52, Weekday::Sun), Some(from_ymd(2014, 12, 28)));
+    /// assert_eq!(NaiveDate::from_iso_week(2014, 53, Weekday::Mon), None);
+    /// assert_eq!(NaiveDate::from_iso_week(2015, 1, Weekday::Mon), Some(from_ymd(2014, 12, 29)));
     ///
     /// // 2015-W52  21 22 23 24 25 26 27    has 4+ days of old year,
     /// // 2015-W53  28 29 30 31  1  2  3 <- so this is the last week
     /// // 2016-W01   4  5  6  7  8  9 10
-    /// assert_eq!(from_isoywd_opt(2015, 52, Weekday::Sun), Some(from_ymd(2015, 12, 27)));
-    /// assert_eq!(from_isoywd_opt(2015, 53, Weekday::Sun), Some(from_ymd(2016, 1, 3)));
-    /// assert_eq!(from_isoywd_opt(2015, 54, Weekday::Mon), None);
-    /// assert_eq!(from_isoywd_opt(2016, 1, Weekday::Mon), Some(from_ymd(2016, 1, 4)));
+    /// assert_eq!(NaiveDate::from_iso_week(2015, 52, Weekday::Sun), Some(from_ymd(2015, 12, 27)));
+    /// assert_eq!(NaiveDate::from_iso_week(2015, 53, Weekday::Sun), Some(from_ymd(2016, 1, 3)));
+    /// assert_eq!(NaiveDate::from_iso_week(2015, 54, Weekday::Mon), None);
+    /// assert_eq!(NaiveDate::from_iso_week(2016, 1, Weekday::Mon), Some(from_ymd(2016, 1, 4)));
     /// ```
     #[must_use]
-    pub const fn from_isoywd_opt(year: i32, week: u32, weekday: Weekday) -> Option<NaiveDate> {
+    pub const fn from_iso_week(year: i32, week: u32, weekday: Weekday) -> Option<NaiveDate> {
         let flags = YearFlags::from_year(year);
         let nweeks = flags.nisoweeks();
         if week == 0 || week > nweeks {
@@ -339,6 +352,21 @@ impl NaiveDate {
         NaiveDate::from_ordinal_and_flags(year, ordinal, flags)
     }
 
+    /// Makes a new `NaiveDate` from the [ISO week date](#week-date)
+    /// (year, week number and day of the week).
+    /// The resulting `NaiveDate` may have a different year from the input year.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the specified week does not exist in that year, on invalid values for
+    /// `week`, or if the resulting date is out of range for `NaiveDate`.
+    #[deprecated(since = "0.4.38", note = "use `from_iso_week()` instead")]
+    #[inline]
+    #[must_use]
+    pub const fn from_isoywd_opt(year: i32, week: u32, weekday: Weekday) -> Option<NaiveDate> {
+        NaiveDate::from_iso_week(year, week, weekday)
+    }
+
     /// Makes a new `NaiveDate` from a day's number in the proleptic Gregorian calendar, with
     /// January 1, 1 being day 1.
     ///
@@ -384,6 +412,66 @@ impl NaiveDate {
         NaiveDate::from_ordinal_and_flags(year_div_400 * 400 + year_mod_400 as i32, ordinal, flags)
     }
 
+    /// Makes a new `NaiveDate` from a day's number in the proleptic Gregorian calendar, with
+    /// January 1, 1 being day 1.
+    ///
+    /// This is the same as [`NaiveDate::from_num_days_from_ce_opt`], but takes an `i64` like the
+    /// result of [`Datelike::num_days_from_ce_i64`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the date is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(-200_000, 6, 15).unwrap();
+    /// assert_eq!(NaiveDate::from_num_days_from_ce_i64(date.num_days_from_ce_i64()), Some(date));
+    /// assert_eq!(NaiveDate::from_num_days_from_ce_i64(i64::MAX), None);
+    /// ```
+    #[must_use]
+    pub const fn from_num_days_from_ce_i64(days: i64) -> Option<NaiveDate> {
+        if days < i32::MIN as i64 || days > i32::MAX as i64 {
+            return None;
+        }
+        NaiveDate::from_num_days_from_ce_opt(days as i32)
+    }
+
+    /// Makes a new `NaiveDate` from a Julian Day Number.
+    ///
+    /// The Julian Day Number counts days continuously since noon of January 1, 4713 BCE in the
+    /// proleptic Julian calendar, which is November 24, -4713 in the proleptic Gregorian calendar
+    /// used by chrono. It is commonly used in astronomy. The day starts at noon, so the JDN of a
+    /// `NaiveDate` is that of the period from its noon to the next day's noon.
+    ///
+    /// See also [`to_julian_day_number`](NaiveDate::to_julian_day_number).
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the date is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// assert_eq!(NaiveDate::from_julian_day_number(2_451_545), Some(from_ymd(2000, 1, 1)));
+    /// assert_eq!(NaiveDate::from_julian_day_number(0), Some(from_ymd(-4713, 11, 24)));
+    /// assert_eq!(NaiveDate::from_julian_day_number(i64::MAX), None);
+    /// ```
+    #[must_use]
+    pub const fn from_julian_day_number(jdn: i64) -> Option<NaiveDate> {
+        let days = try_opt!(jdn.checked_sub(JDN_OF_DAY_0_CE));
+        if days < i32::MIN as i64 || days > i32::MAX as i64 {
+            return None;
+        }
+        NaiveDate::from_num_days_from_ce_opt(days as i32)
+    }
+
     /// Makes a new `NaiveDate` by counting the number of occurrences of a particular day-of-week
     /// since the beginning of the given month. For instance, if you want the 2nd Friday of March
     /// 2017, you would use `NaiveDate::from_weekday_of_month(2017, 3, Weekday::Fri, 2)`.
@@ -394,7 +482,7 @@ impl NaiveDate {
     ///
     /// Panics if the specified day does not exist in that month, on invalid values for `month` or
     /// `n`, or if `year` is out of range for `NaiveDate`.
-    #[deprecated(since = "0.4.23", note = "use `from_weekday_of_month_opt()` instead")]
+    #[deprecated(since = "0.4.23", note = "use `nth_weekday_of_month()` instead")]
     #[must_use]
     pub const fn from_weekday_of_month(
         year: i32,
@@ -402,19 +490,19 @@ impl NaiveDate {
         weekday: Weekday,
         n: u8,
     ) -> NaiveDate {
-        expect(NaiveDate::from_weekday_of_month_opt(year, month, weekday, n), "out-of-range date")
+        expect(NaiveDate::nth_weekday_of_month(year, month, weekday, n), "out-of-range date")
     }
 
-    /// Makes a new `NaiveDate` by counting the number of occurrences of a particular day-of-week
-    /// since the beginning of the given month. For instance, if you want the 2nd Friday of March
-    /// 2017, you would use `NaiveDate::from_weekday_of_month(2017, 3, Weekday::Fri, 2)`.
+    /// Makes a new `NaiveDate` for the `n`th occurrence of `weekday` in the given month, such as
+    /// the third Thursday of November.
     ///
     /// `n` is 1-indexed.
     ///
     /// # Errors
     ///
     /// Returns `None` if:
-    /// - The specified day does not exist in that month (for example the 5th Monday of Apr. 2023).
+    /// - The month has less than `n` occurrences of `weekday` (for example the 5th Monday of Apr.
+    ///   2023).
     /// - The value for `month` or `n` is invalid.
     /// - `year` is out of range for `NaiveDate`.
     ///
@@ -422,13 +510,13 @@ impl NaiveDate {
     ///
     /// ```
     /// use chrono::{NaiveDate, Weekday};
-    /// assert_eq!(
-    ///     NaiveDate::from_weekday_of_month_opt(2017, 3, Weekday::Fri, 2),
-    ///     NaiveDate::from_ymd_opt(2017, 3, 10)
-    /// )
+    ///
+    /// let thanksgiving = NaiveDate::nth_weekday_of_month(2024, 11, Weekday::Thu, 4);
+    /// assert_eq!(thanksgiving, NaiveDate::from_ymd_opt(2024, 11, 28));
+    /// assert_eq!(NaiveDate::nth_weekday_of_month(2023, 4, Weekday::Mon, 5), None);
     /// ```
     #[must_use]
-    pub const fn from_weekday_of_month_opt(
+    pub const fn nth_weekday_of_month(
         year: i32,
         month: u32,
         weekday: Weekday,
@@ -443,6 +531,54 @@ impl NaiveDate {
         NaiveDate::from_ymd_opt(year, month, day)
     }
 
+    /// Makes a new `NaiveDate` by counting the number of occurrences of a particular day-of-week
+    /// since the beginning of the given month.
+    ///
+    /// `n` is 1-indexed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the specified day does not exist in that month, on invalid values for
+    /// `month` or `n`, or if `year` is out of range for `NaiveDate`.
+    #[deprecated(since = "0.4.38", note = "use `nth_weekday_of_month()` instead")]
+    #[inline]
+    #[must_use]
+    pub const fn from_weekday_of_month_opt(
+        year: i32,
+        month: u32,
+        weekday: Weekday,
+        n: u8,
+    ) -> Option<NaiveDate> {
+        NaiveDate::nth_weekday_of_month(year, month, weekday, n)
+    }
+
+    /// Makes a new `NaiveDate` for the last occurrence of `weekday` in the given month, such as
+    /// the last Monday of May.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the value for `month` is invalid, or if `year` is out of range for
+    /// `NaiveDate`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// let memorial_day = NaiveDate::last_weekday_of_month(2024, 5, Weekday::Mon);
+    /// assert_eq!(memorial_day, NaiveDate::from_ymd_opt(2024, 5, 27));
+    /// ```
+    #[must_use]
+    pub const fn last_weekday_of_month(
+        year: i32,
+        month: u32,
+        weekday: Weekday,
+    ) -> Option<NaiveDate> {
+        let last = try_opt!(NaiveDate::from_ymd_opt(year, month, 1)).last_day_of_month();
+        let day = last.day() - last.weekday().days_since(weekday);
+        NaiveDate::from_ymd_opt(year, month, day)
+    }
+
     /// Parses a string with the specified format string and returns a new `NaiveDate`.
     /// See the [`format::strftime` module](crate::format::strftime)
     /// on the supported escape sequences.
@@ -588,6 +724,46 @@ impl NaiveDate {
         }
     }
 
+    /// Add a duration in [`Months`] to the date, saturating at [`NaiveDate::MAX`].
+    ///
+    /// Uses the last day of the month if the day does not exist in the resulting month.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{NaiveDate, Months};
+    /// let date = NaiveDate::from_ymd_opt(2022, 7, 31).unwrap();
+    /// assert_eq!(date.saturating_add_months(Months::new(2)), NaiveDate::from_ymd_opt(2022, 9, 30).unwrap());
+    /// assert_eq!(date.saturating_add_months(Months::new(u32::MAX)), NaiveDate::MAX);
+    /// ```
+    #[must_use]
+    pub const fn saturating_add_months(self, months: Months) -> Self {
+        match self.checked_add_months(months) {
+            Some(date) => date,
+            None => NaiveDate::MAX,
+        }
+    }
+
+    /// Subtract a duration in [`Months`] from the date, saturating at [`NaiveDate::MIN`].
+    ///
+    /// Uses the last day of the month if the day does not exist in the resulting month.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{NaiveDate, Months};
+    /// let date = NaiveDate::from_ymd_opt(2022, 3, 31).unwrap();
+    /// assert_eq!(date.saturating_sub_months(Months::new(1)), NaiveDate::from_ymd_opt(2022, 2, 28).unwrap());
+    /// assert_eq!(date.saturating_sub_months(Months::new(u32::MAX)), NaiveDate::MIN);
+    /// ```
+    #[must_use]
+    pub const fn saturating_sub_months(self, months: Months) -> Self {
+        match self.checked_sub_months(months) {
+            Some(date) => date,
+            None => NaiveDate::MIN,
+        }
+    }
+
     const fn diff_months(self, months: i32) -> Option<Self> {
         let months = try_opt!((self.year() * 12 + self.month() as i32 - 1).checked_add(months));
         let year = months.div_euclid(12);
@@ -664,6 +840,69 @@ impl NaiveDate {
         }
     }
 
+    /// Add a duration in [`Days`] to the date, saturating at [`NaiveDate::MAX`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{NaiveDate, Days};
+    /// let date = NaiveDate::from_ymd_opt(2022, 2, 20).unwrap();
+    /// assert_eq!(date.saturating_add_days(Days::new(9)), NaiveDate::from_ymd_opt(2022, 3, 1).unwrap());
+    /// assert_eq!(date.saturating_add_days(Days::new(1000000000000)), NaiveDate::MAX);
+    /// ```
+    #[must_use]
+    pub const fn saturating_add_days(self, days: Days) -> Self {
+        match self.checked_add_days(days) {
+            Some(date) => date,
+            None => NaiveDate::MAX,
+        }
+    }
+
+    /// Subtract a duration in [`Days`] from the date, saturating at [`NaiveDate::MIN`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{NaiveDate, Days};
+    /// let date = NaiveDate::from_ymd_opt(2022, 2, 20).unwrap();
+    /// assert_eq!(date.saturating_sub_days(Days::new(6)), NaiveDate::from_ymd_opt(2022, 2, 14).unwrap());
+    /// assert_eq!(date.saturating_sub_days(Days::new(1000000000000)), NaiveDate::MIN);
+    /// ```
+    #[must_use]
+    pub const fn saturating_sub_days(self, days: Days) -> Self {
+        match self.checked_sub_days(days) {
+            Some(date) => date,
+            None => NaiveDate::MIN,
+        }
+    }
+
+    /// Add a signed number of days to the date.
+    ///
+    /// This is the same as [`checked_add_days`](NaiveDate::checked_add_days) for a positive `days`
+    /// and [`checked_sub_days`](NaiveDate::checked_sub_days) for a negative `days`, which is
+    /// convenient if the number of days is computed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// let date = NaiveDate::from_ymd_opt(2022, 2, 20).unwrap();
+    /// assert_eq!(date.add_days_signed(9), NaiveDate::from_ymd_opt(2022, 3, 1));
+    /// assert_eq!(date.add_days_signed(-6), NaiveDate::from_ymd_opt(2022, 2, 14));
+    /// assert_eq!(date.add_days_signed(i64::MAX), None);
+    /// ```
+    #[must_use]
+    pub const fn add_days_signed(self, days: i64) -> Option<Self> {
+        if days < i32::MIN as i64 || days > i32::MAX as i64 {
+            return None;
+        }
+        self.add_days(days as i32)
+    }
+
     /// Add a duration of `i32` days to the date.
     pub(crate) const fn add_days(self, days: i32) -> Option<Self> {
         // Fast path if the result is within the same year.
@@ -1134,15 +1373,32 @@ impl NaiveDate {
 
     /// Returns the number of whole years from the given `base` until `self`.
     ///
+    /// This is the age on `self` of someone born on `base`. If `base` is February 29, the
+    /// anniversary in a common year is taken to be February 28.
+    ///
     /// # Errors
     ///
-    /// Returns `None` if `base < self`.
+    /// Returns `None` if `base > self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// let birth = from_ymd(2000, 2, 29);
+    /// assert_eq!(from_ymd(2001, 2, 27).years_since(birth), Some(0));
+    /// assert_eq!(from_ymd(2001, 2, 28).years_since(birth), Some(1));
+    /// assert_eq!(from_ymd(2004, 2, 28).years_since(birth), Some(3));
+    /// assert_eq!(from_ymd(2004, 2, 29).years_since(birth), Some(4));
+    /// assert_eq!(from_ymd(1999, 2, 28).years_since(birth), None);
+    /// ```
     #[must_use]
     pub const fn years_since(&self, base: Self) -> Option<u32> {
         let mut years = self.year() - base.year();
         // Comparing tuples is not (yet) possible in const context. Instead we combine month and
         // day into one `u32` for easy comparison.
-        if (self.month() << 5 | self.day()) < (base.month() << 5 | base.day()) {
+        if (self.month() << 5 | self.day()) < base.anniversary_in(self.leap_year()) {
             years -= 1;
         }
 
@@ -1152,6 +1408,60 @@ impl NaiveDate {
         }
     }
 
+    /// Returns the difference between `self` and `other` in years, months and days.
+    ///
+    /// This is the age on `self` of something that started on `other`, and works like the `age()`
+    /// function of PostgreSQL. Years and months are counted first, from the calendar fields.
+    /// If the day of month of the later date is smaller than that of the earlier date, one month
+    /// is borrowed and converted to days using the length of the month of the earlier date.
+    ///
+    /// If `self` is before `other` the same difference is computed with the dates swapped, and
+    /// all fields of the result are negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// // January has 31 days, so 1 month is borrowed as 31 days: 1 - 31 + 31 = 1.
+    /// let diff = from_ymd(2020, 3, 1).calendar_difference(from_ymd(2020, 1, 31));
+    /// assert_eq!((diff.years(), diff.months(), diff.days()), (0, 1, 1));
+    ///
+    /// let diff = from_ymd(2020, 1, 31).calendar_difference(from_ymd(2020, 3, 1));
+    /// assert_eq!((diff.years(), diff.months(), diff.days()), (0, -1, -1));
+    /// ```
+    #[must_use]
+    pub const fn calendar_difference(&self, other: Self) -> CalendarDuration {
+        if self.yof() < other.yof() {
+            return other.calendar_difference(*self).neg();
+        }
+        let mut years = self.year() - other.year();
+        let mut months = self.month() as i32 - other.month() as i32;
+        let mut days = self.day() as i32 - other.day() as i32;
+        if days < 0 {
+            // Borrow a month, using the length of the month of the earlier date.
+            days += other.last_day_of_month().day() as i32;
+            months -= 1;
+        }
+        if months < 0 {
+            months += 12;
+            years -= 1;
+        }
+        CalendarDuration { years, months, days }
+    }
+
+    /// Returns the month and day of the anniversary of this date in a year that is a leap year
+    /// or not, combined as `month << 5 | day` for comparisons.
+    ///
+    /// February 29 falls on February 28 in common years.
+    pub(crate) const fn anniversary_in(&self, leap_year: bool) -> u32 {
+        match (self.month(), self.day()) {
+            (2, 29) if !leap_year => 2 << 5 | 28,
+            (month, day) => month << 5 | day,
+        }
+    }
+
     /// Formats the date with the specified formatting items.
     /// Otherwise it is the same as the ordinary `format` method.
     ///
@@ -1190,6 +1500,31 @@ impl NaiveDate {
         DelayedFormat::new(Some(*self), None, items)
     }
 
+    /// Writes the date formatted with the specified formatting items into `w`.
+    ///
+    /// This writes directly into `w` without allocating an intermediate `String`, which is useful
+    /// to reuse a buffer. The items can be parsed once with [`StrftimeItems::parse`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use chrono::format::StrftimeItems;
+    /// use chrono::NaiveDate;
+    ///
+    /// let items = StrftimeItems::new("%Y-%m-%d").parse().unwrap();
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+    ///
+    /// let mut buf = String::with_capacity(16);
+    /// date.format_into(&mut buf, &items).unwrap();
+    /// assert_eq!(buf, "2024-01-15");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn format_into(&self, w: &mut impl fmt::Write, items: &[Item<'_>]) -> fmt::Result {
+        write_items(w, Some(*self), None, None, items.iter())
+    }
+
     /// Formats the date with the specified format string.
     /// See the [`format::strftime` module](crate::format::strftime)
     /// on the supported escape sequences.
@@ -1322,6 +1657,155 @@ impl NaiveDate {
         NaiveDateWeeksIterator { value: *self }
     }
 
+    /// Returns an iterator over the business days starting at `self`, skipping the days of
+    /// `weekend`.
+    ///
+    /// The iterator includes `self` if it is a business day, and steps across all representable
+    /// dates like [`iter_days`](NaiveDate::iter_days). Iterating in reverse walks back in time
+    /// from `self`. Holidays are not taken into account; see the [`calendar`](crate::calendar)
+    /// module for business-day arithmetic with holidays.
+    ///
+    /// The iterator yields nothing if every day of the week is part of `weekend`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::calendar::Weekend;
+    /// use chrono::NaiveDate;
+    ///
+    /// let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    /// // 2024-01-05 is a Friday.
+    /// let mut days = ymd(2024, 1, 5).iter_business_days(Weekend::SATURDAY_SUNDAY);
+    /// assert_eq!(days.next(), Some(ymd(2024, 1, 5)));
+    /// assert_eq!(days.next(), Some(ymd(2024, 1, 8)));
+    ///
+    /// let tenth = ymd(2024, 1, 5).iter_business_days(Weekend::SATURDAY_SUNDAY).nth(9);
+    /// assert_eq!(tenth, Some(ymd(2024, 1, 18)));
+    ///
+    /// let mut days = ymd(2024, 1, 8).iter_business_days(Weekend::SATURDAY_SUNDAY).rev();
+    /// assert_eq!(days.next(), Some(ymd(2024, 1, 8)));
+    /// assert_eq!(days.next(), Some(ymd(2024, 1, 5)));
+    /// ```
+    #[inline]
+    pub const fn iter_business_days(self, weekend: Weekend) -> NaiveDateBusinessDaysIterator {
+        NaiveDateBusinessDaysIterator { days: self.iter_days(), weekend }
+    }
+
+    /// Returns an iterator over the days from `self` until `end`.
+    ///
+    /// The range is half-open: it includes `self` but not `end`. The iterator yields nothing if
+    /// `self >= end`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// let days: Vec<_> = from_ymd(2016, 2, 27).iter_days_until(from_ymd(2016, 3, 2)).collect();
+    /// assert_eq!(
+    ///     days,
+    ///     [from_ymd(2016, 2, 27), from_ymd(2016, 2, 28), from_ymd(2016, 2, 29), from_ymd(2016, 3, 1)]
+    /// );
+    ///
+    /// let mut iter = from_ymd(2016, 2, 27).iter_days_until(from_ymd(2016, 3, 2)).rev();
+    /// assert_eq!(iter.len(), 4);
+    /// assert_eq!(iter.next(), Some(from_ymd(2016, 3, 1)));
+    /// ```
+    #[inline]
+    pub const fn iter_days_until(self, end: NaiveDate) -> NaiveDateStepIterator {
+        self.iter_step(end, Days::new(1))
+    }
+
+    /// Returns an iterator over the dates from `self` until `end`, with a step size of `step`.
+    ///
+    /// The range is half-open: it includes `self` but not `end`. The iterator yields nothing if
+    /// `self >= end`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero days, even if the range is empty. A zero step would never advance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{Days, NaiveDate};
+    /// let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// let dates: Vec<_> =
+    ///     from_ymd(2016, 2, 20).iter_step(from_ymd(2016, 3, 8), Days::new(7)).collect();
+    /// assert_eq!(dates, [from_ymd(2016, 2, 20), from_ymd(2016, 2, 27), from_ymd(2016, 3, 5)]);
+    /// ```
+    pub const fn iter_step(self, end: NaiveDate, step: Days) -> NaiveDateStepIterator {
+        if step.0 == 0 {
+            panic!("step of `NaiveDate::iter_step` must be nonzero");
+        }
+        let days = end.signed_duration_since(self).num_days();
+        let (step, len) = match days > 0 {
+            // If the step is larger than the range only `self` is included, and the step is never
+            // applied.
+            true if step.0 >= days as u64 => (1, 1),
+            true => (step.0 as i32, ((days as u64 + step.0 - 1) / step.0) as u32),
+            false => (1, 0),
+        };
+        NaiveDateStepIterator { start: self, step, front: 0, back: len }
+    }
+
+    /// Returns an iterator that steps by months across all representable dates.
+    ///
+    /// The `n`th date is `self + Months::new(n)`, counted from `self` every time. If the day of
+    /// the month doesn't exist in a month it is clamped to the last day of that month, but the
+    /// next months continue with the original day: starting from January 31 yields February 28
+    /// (or 29 in a leap year), then March 31.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// let dates: Vec<_> = from_ymd(2024, 1, 31).iter_months().take(4).collect();
+    /// assert_eq!(
+    ///     dates,
+    ///     [from_ymd(2024, 1, 31), from_ymd(2024, 2, 29), from_ymd(2024, 3, 31), from_ymd(2024, 4, 30)]
+    /// );
+    /// ```
+    #[inline]
+    pub const fn iter_months(self) -> NaiveDateMonthsIterator {
+        // Every month until the last one of `NaiveDate::MAX` can be reached.
+        let months = (NaiveDate::MAX.year() - self.year()) as u32 * 12 + 12 - self.month();
+        NaiveDateMonthsIterator { start: self, front: 0, back: months + 1 }
+    }
+
+    /// Returns an iterator over the dates from `self` until `end`, stepping by months.
+    ///
+    /// The dates are the same as those of [`NaiveDate::iter_months`], including the clamping to
+    /// the end of the month. The range is half-open: it includes `self` but not `end`. The
+    /// iterator yields nothing if `self >= end`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// let dates: Vec<_> =
+    ///     from_ymd(2023, 11, 30).iter_months_until(from_ymd(2024, 2, 29)).collect();
+    /// assert_eq!(dates, [from_ymd(2023, 11, 30), from_ymd(2023, 12, 30), from_ymd(2024, 1, 30)]);
+    /// ```
+    pub const fn iter_months_until(self, end: NaiveDate) -> NaiveDateMonthsIterator {
+        let months = (end.year() - self.year()) * 12 + end.month() as i32 - self.month() as i32;
+        let len = match months < 0 {
+            true => 0,
+            false => {
+                // All but the last month are before `end`, the last one is in the same month.
+                let last = expect(self.checked_add_months(Months::new(months as u32)), "in range");
+                months as u32 + (last.day() < end.day()) as u32
+            }
+        };
+        NaiveDateMonthsIterator { start: self, front: 0, back: len }
+    }
+
     /// Returns the [`NaiveWeek`] that the date belongs to, starting with the [`Weekday`]
     /// specified.
     #[inline]
@@ -1329,6 +1813,90 @@ impl NaiveDate {
         NaiveWeek::new(*self, start)
     }
 
+    /// Returns the first day of the quarter that the date belongs to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap();
+    /// assert_eq!(date.first_day_of_quarter(), NaiveDate::from_ymd_opt(2024, 4, 1).unwrap());
+    /// ```
+    #[must_use]
+    pub const fn first_day_of_quarter(&self) -> NaiveDate {
+        let month = (self.month() - 1) / 3 * 3 + 1;
+        expect(NaiveDate::from_ymd_opt(self.year(), month, 1), "always in range")
+    }
+
+    /// Returns the last day of the quarter that the date belongs to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap();
+    /// assert_eq!(date.last_day_of_quarter(), NaiveDate::from_ymd_opt(2024, 6, 30).unwrap());
+    /// ```
+    #[must_use]
+    pub const fn last_day_of_quarter(&self) -> NaiveDate {
+        // The last months of the quarters all have a fixed length: March 31, June 30,
+        // September 30 and December 31.
+        let month = (self.month() - 1) / 3 * 3 + 3;
+        let day = match month {
+            6 | 9 => 30,
+            _ => 31,
+        };
+        expect(NaiveDate::from_ymd_opt(self.year(), month, day), "always in range")
+    }
+
+    /// Returns the first day of the month that the date belongs to.
+    pub(crate) const fn first_day_of_month(&self) -> NaiveDate {
+        expect(NaiveDate::from_ymd_opt(self.year(), self.month(), 1), "always in range")
+    }
+
+    /// Returns the last day of the month that the date belongs to.
+    pub(crate) const fn last_day_of_month(&self) -> NaiveDate {
+        let day = match self.month() {
+            2 if self.leap_year() => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        };
+        expect(NaiveDate::from_ymd_opt(self.year(), self.month(), day), "always in range")
+    }
+
+    /// Returns the first day of the year that the date belongs to.
+    pub(crate) const fn first_day_of_year(&self) -> NaiveDate {
+        expect(NaiveDate::from_ymd_opt(self.year(), 1, 1), "always in range")
+    }
+
+    /// Returns the last day of the year that the date belongs to.
+    pub(crate) const fn last_day_of_year(&self) -> NaiveDate {
+        expect(NaiveDate::from_ymd_opt(self.year(), 12, 31), "always in range")
+    }
+
+    /// Returns the Julian Day Number of this date.
+    ///
+    /// This is the number of days since November 24, -4713 in the proleptic Gregorian calendar
+    /// (January 1, 4713 BCE in the proleptic Julian calendar). See
+    /// [`from_julian_day_number`](NaiveDate::from_julian_day_number).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+    /// assert_eq!(date.to_julian_day_number(), 2_451_545);
+    /// assert_eq!(NaiveDate::from_ymd_opt(-4713, 11, 24).unwrap().to_julian_day_number(), 0);
+    /// ```
+    #[must_use]
+    pub const fn to_julian_day_number(&self) -> i64 {
+        self.num_days_from_ce() as i64 + JDN_OF_DAY_0_CE
+    }
+
     /// Returns `true` if this is a leap year.
     ///
     /// ```
@@ -2124,6 +2692,41 @@ impl From<NaiveDateTime> for NaiveDate {
     }
 }
 
+/// Steps through `NaiveDate`s one day at a time, so a range like `start..end` is iterable.
+///
+/// Stepping beyond [`NaiveDate::MIN`] or [`NaiveDate::MAX`] returns `None`.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// let start = NaiveDate::from_ymd_opt(2016, 2, 27).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2016, 3, 2).unwrap();
+/// assert_eq!((start..end).count(), 4);
+/// assert_eq!((start..end).last(), NaiveDate::from_ymd_opt(2016, 3, 1));
+/// ```
+#[cfg(feature = "unstable-step")]
+impl Step for NaiveDate {
+    fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+        let days = i64::from(end.num_days_from_ce()) - i64::from(start.num_days_from_ce());
+        match usize::try_from(days) {
+            Ok(days) => (days, Some(days)),
+            Err(_) => (0, None),
+        }
+    }
+
+    fn forward_checked(start: Self, count: usize) -> Option<Self> {
+        let days = i32::try_from(count).ok()?;
+        NaiveDate::from_num_days_from_ce_opt(start.num_days_from_ce().checked_add(days)?)
+    }
+
+    fn backward_checked(start: Self, count: usize) -> Option<Self> {
+        let days = i32::try_from(count).ok()?;
+        NaiveDate::from_num_days_from_ce_opt(start.num_days_from_ce().checked_sub(days)?)
+    }
+}
+
 /// Iterator over `NaiveDate` with a step size of one day.
 #[derive(Debug, Copy, Clone, Hash, PartialEq, PartialOrd, Eq, Ord)]
 pub struct NaiveDateDaysIterator {
@@ -2193,6 +2796,155 @@ impl DoubleEndedIterator for NaiveDateWeeksIterator {
 
 impl FusedIterator for NaiveDateWeeksIterator {}
 
+/// Iterator over a half-open range of `NaiveDate`s with a step size of a number of days.
+///
+/// Created by [`NaiveDate::iter_days_until`], [`NaiveDate::iter_step`] and
+/// [`NaiveWeek::iter_days`].
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct NaiveDateStepIterator {
+    start: NaiveDate,
+    step: i32,
+    // Index of the next date to return from the front.
+    front: u32,
+    // One past the index of the next date to return from the back.
+    back: u32,
+}
+
+impl NaiveDateStepIterator {
+    /// Makes an iterator over `len` consecutive days starting at `start`, which must all be in
+    /// range.
+    pub(crate) const fn days(start: NaiveDate, len: u32) -> Self {
+        NaiveDateStepIterator { start, step: 1, front: 0, back: len }
+    }
+
+    fn nth_date(&self, index: u32) -> Option<NaiveDate> {
+        // All dates in the range are before `end`, so this can't overflow or go out of range.
+        self.start.add_days(index as i32 * self.step)
+    }
+}
+
+impl Iterator for NaiveDateStepIterator {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let current = self.nth_date(self.front)?;
+        self.front += 1;
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let exact_size = (self.back - self.front) as usize;
+        (exact_size, Some(exact_size))
+    }
+}
+
+impl ExactSizeIterator for NaiveDateStepIterator {}
+
+impl DoubleEndedIterator for NaiveDateStepIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let current = self.nth_date(self.back - 1)?;
+        self.back -= 1;
+        Some(current)
+    }
+}
+
+impl FusedIterator for NaiveDateStepIterator {}
+
+/// Iterator over `NaiveDate`s with a step size of one month.
+///
+/// Created by [`NaiveDate::iter_months`] and [`NaiveDate::iter_months_until`].
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct NaiveDateMonthsIterator {
+    start: NaiveDate,
+    // Index of the next date to return from the front.
+    front: u32,
+    // One past the index of the next date to return from the back.
+    back: u32,
+}
+
+impl NaiveDateMonthsIterator {
+    fn nth_date(&self, index: u32) -> Option<NaiveDate> {
+        self.start.checked_add_months(Months::new(index))
+    }
+}
+
+impl Iterator for NaiveDateMonthsIterator {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let current = self.nth_date(self.front)?;
+        self.front += 1;
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let exact_size = (self.back - self.front) as usize;
+        (exact_size, Some(exact_size))
+    }
+}
+
+impl ExactSizeIterator for NaiveDateMonthsIterator {}
+
+impl DoubleEndedIterator for NaiveDateMonthsIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let current = self.nth_date(self.back - 1)?;
+        self.back -= 1;
+        Some(current)
+    }
+}
+
+impl FusedIterator for NaiveDateMonthsIterator {}
+
+/// Iterator over the business days of `NaiveDate`, skipping the days of a [`Weekend`].
+///
+/// Created by [`NaiveDate::iter_business_days`].
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct NaiveDateBusinessDaysIterator {
+    days: NaiveDateDaysIterator,
+    weekend: Weekend,
+}
+
+impl Iterator for NaiveDateBusinessDaysIterator {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Without any business days we would walk to the end of the representable range.
+        if self.weekend.len() == 7 {
+            return None;
+        }
+        let weekend = self.weekend;
+        self.days.find(|d| !weekend.contains(d.weekday()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.days.size_hint().1)
+    }
+}
+
+impl DoubleEndedIterator for NaiveDateBusinessDaysIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.weekend.len() == 7 {
+            return None;
+        }
+        let weekend = self.weekend;
+        self.days.rfind(|d| !weekend.contains(d.weekday()))
+    }
+}
+
+impl FusedIterator for NaiveDateBusinessDaysIterator {}
+
 /// The `Debug` output of the naive date `d` is the same as
 /// [`d.format("%Y-%m-%d")`](crate::format::strftime).
 ///
@@ -2317,6 +3069,9 @@ impl Default for NaiveDate {
     }
 }
 
+/// The Julian Day Number of December 31, 1 BCE, which is day 0 of `num_days_from_ce`.
+const JDN_OF_DAY_0_CE: i64 = 1_721_425;
+
 const fn cycle_to_yo(cycle: u32) -> (u32, u32) {
     let mut year_mod_400 = cycle / 365;
     let mut ordinal0 = cycle % 365;
@@ -2388,7 +3143,7 @@ const YEAR_DELTAS: &[u8; 401] = &[
 ];
 
 #[cfg(feature = "serde")]
-mod serde {
+pub(crate) mod serde {
     use super::NaiveDate;
     use core::fmt;
     use serde::{de, ser};
@@ -2440,6 +3195,93 @@ mod serde {
         }
     }
 
+    /// Ser/de a `NaiveDate` to/from an integer number of days since the Unix epoch.
+    ///
+    /// The epoch is 1970-01-01, so that date is serialized as `0` and dates before it are
+    /// negative. This is the same day count as a `DATE` in Apache Arrow or Parquet. Every
+    /// `NaiveDate` fits in an `i32`, which is what binary formats will store.
+    ///
+    /// Intended for use with `serde`'s `with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::NaiveDate;
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::naive_date_as_days;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "naive_date_as_days")]
+    ///     date: NaiveDate,
+    /// }
+    ///
+    /// let my_s = S { date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap() };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"date":19723}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.date, NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub mod naive_date_as_days {
+        use core::fmt;
+        use serde::{de, ser};
+
+        use crate::NaiveDate;
+
+        /// The number of days from 0001-01-01 to 1970-01-01.
+        const UNIX_EPOCH_DAY: i64 = 719_163;
+
+        /// Serialize a `NaiveDate` as an integer number of days since 1970-01-01.
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        pub fn serialize<S>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_i32((date.num_days_from_ce() as i64 - UNIX_EPOCH_DAY) as i32)
+        }
+
+        /// Deserialize a `NaiveDate` from an integer number of days since 1970-01-01.
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D>(d: D) -> Result<NaiveDate, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_i32(DaysVisitor)
+        }
+
+        struct DaysVisitor;
+
+        impl<'de> de::Visitor<'de> for DaysVisitor {
+            type Value = NaiveDate;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a number of days since 1970-01-01")
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<NaiveDate, E>
+            where
+                E: de::Error,
+            {
+                value
+                    .checked_add(UNIX_EPOCH_DAY)
+                    .and_then(|days| i32::try_from(days).ok())
+                    .and_then(NaiveDate::from_num_days_from_ce_opt)
+                    .ok_or_else(|| E::custom("NaiveDate out of range"))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<NaiveDate, E>
+            where
+                E: de::Error,
+            {
+                i64::try_from(value)
+                    .map_err(|_| E::custom("NaiveDate out of range"))
+                    .and_then(|value| self.visit_i64(value))
+            }
+        }
+    }
+
     #[cfg(test)]
     mod tests {
         use crate::NaiveDate;
@@ -2528,5 +3370,42 @@ mod serde {
             let decoded: NaiveDate = deserialize(&encoded).unwrap();
             assert_eq!(d, decoded);
         }
+
+        #[test]
+        fn test_serde_naive_date_as_days() {
+            use serde_derive::{Deserialize, Serialize};
+
+            #[derive(Debug, PartialEq, Deserialize, Serialize)]
+            struct S {
+                #[serde(with = "crate::serde::naive_date_as_days")]
+                date: NaiveDate,
+            }
+
+            let cases = [
+                (NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(), 0),
+                (NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(), 19_723),
+                (NaiveDate::from_ymd_opt(1969, 12, 31).unwrap(), -1),
+                (NaiveDate::from_ymd_opt(1, 1, 1).unwrap(), -719_162),
+                (NaiveDate::MIN, -96_465_292),
+                (NaiveDate::MAX, 95_026_236),
+            ];
+            for (date, days) in cases {
+                let s = S { date };
+                let json = serde_json::to_string(&s).unwrap();
+                assert_eq!(json, format!(r#"{{"date":{}}}"#, days));
+                assert_eq!(serde_json::from_str::<S>(&json).unwrap(), s);
+
+                // Bincode is not self-describing and stores an `i32`.
+                let encoded = bincode::serialize(&s).unwrap();
+                assert_eq!(encoded, i32::to_le_bytes(days));
+                assert_eq!(bincode::deserialize::<S>(&encoded).unwrap(), s);
+            }
+
+            assert!(serde_json::from_str::<S>(r#"{"date":95026237}"#).is_err());
+            assert!(serde_json::from_str::<S>(r#"{"date":-96465293}"#).is_err());
+            assert!(serde_json::from_str::<S>(r#"{"date":9223372036854775807}"#).is_err());
+            assert!(serde_json::from_str::<S>(r#"{"date":18446744073709551615}"#).is_err());
+            assert!(serde_json::from_str::<S>(r#"{"date":"2024-01-01"}"#).is_err());
+        }
     }
 }
@@ -1,4 +1,6 @@
 use super::{Days, Months, NaiveDate, MAX_YEAR, MIN_YEAR};
+use crate::calendar::Weekend;
+use crate::format::ParseErrorKind;
 use crate::naive::internals::{YearFlags, A, AG, B, BA, C, CB, D, DC, E, ED, F, FE, G, GF};
 use crate::{Datelike, TimeDelta, Weekday};
 
@@ -149,6 +151,30 @@ fn test_readme_doomsday() {
     }
 }
 
+#[test]
+fn test_date_const_construction() {
+    const EPOCH: Option<NaiveDate> = NaiveDate::from_ymd_opt(1970, 1, 1);
+    const LEAP_DAY: Option<NaiveDate> = NaiveDate::from_ymd_opt(-4, 2, 29);
+    const ORDINAL: Option<NaiveDate> = NaiveDate::from_yo_opt(2024, 366);
+    const ISO_WEEK: Option<NaiveDate> = NaiveDate::from_iso_week(2015, 53, Weekday::Sun);
+    const INVALID: Option<NaiveDate> = NaiveDate::from_ymd_opt(2023, 2, 29);
+
+    // Go through function pointers so the values are computed at runtime.
+    let from_ymd = NaiveDate::from_ymd_opt;
+    let from_yo = NaiveDate::from_yo_opt;
+    let from_isoywd = NaiveDate::from_iso_week;
+    for (constant, runtime) in [
+        (EPOCH, from_ymd(1970, 1, 1)),
+        (LEAP_DAY, from_ymd(-4, 2, 29)),
+        (ORDINAL, from_yo(2024, 366)),
+        (ISO_WEEK, from_isoywd(2015, 53, Weekday::Sun)),
+        (INVALID, from_ymd(2023, 2, 29)),
+    ] {
+        assert_eq!(constant, runtime);
+        assert_eq!(constant.map(|d| d.yof), runtime.map(|d| d.yof));
+    }
+}
+
 #[test]
 fn test_date_from_ymd() {
     let from_ymd = NaiveDate::from_ymd_opt;
@@ -198,7 +224,7 @@ fn test_date_from_yo() {
 
 #[test]
 fn test_date_from_isoywd() {
-    let from_isoywd = NaiveDate::from_isoywd_opt;
+    let from_isoywd = NaiveDate::from_iso_week;
     let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
 
     assert_eq!(from_isoywd(2004, 0, Weekday::Sun), None);
@@ -225,6 +251,37 @@ fn test_date_from_isoywd() {
     assert_eq!(from_isoywd(2018, 53, Weekday::Mon), None);
 }
 
+#[test]
+fn test_date_from_iso_week() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    assert_eq!(NaiveDate::from_iso_week(2024, 1, Weekday::Mon), Some(ymd(2024, 1, 1)));
+    assert_eq!(NaiveDate::from_iso_week(2020, 53, Weekday::Mon), Some(ymd(2020, 12, 28)));
+    assert_eq!(NaiveDate::from_iso_week(2020, 53, Weekday::Sun), Some(ymd(2021, 1, 3)));
+    assert_eq!(NaiveDate::from_iso_week(2020, 0, Weekday::Mon), None);
+    assert_eq!(NaiveDate::from_iso_week(2020, 54, Weekday::Mon), None);
+    assert_eq!(NaiveDate::from_iso_week(2020, u32::MAX, Weekday::Mon), None);
+    assert_eq!(NaiveDate::from_iso_week(MAX_YEAR + 1, 2, Weekday::Mon), None);
+
+    // week 53 only exists in years with 53 ISO weeks
+    for year in 2000..2401 {
+        let last = NaiveDate::from_ymd_opt(year, 12, 28).unwrap().iso_week().week();
+        assert!(last == 52 || last == 53);
+        assert!(NaiveDate::from_iso_week(year, last, Weekday::Sun).is_some());
+        assert_eq!(NaiveDate::from_iso_week(year, 53, Weekday::Mon).is_some(), last == 53);
+    }
+    assert_eq!(NaiveDate::from_iso_week(2021, 53, Weekday::Mon), None);
+
+    #[allow(deprecated)]
+    let from_isoywd_opt = NaiveDate::from_isoywd_opt;
+    for week in 0..55 {
+        assert_eq!(
+            from_isoywd_opt(2020, week, Weekday::Thu),
+            NaiveDate::from_iso_week(2020, week, Weekday::Thu)
+        );
+    }
+}
+
 #[test]
 fn test_date_from_isoywd_and_iso_week() {
     for year in 2000..2401 {
@@ -240,7 +297,7 @@ fn test_date_from_isoywd_and_iso_week() {
             ]
             .iter()
             {
-                let d = NaiveDate::from_isoywd_opt(year, week, weekday);
+                let d = NaiveDate::from_iso_week(year, week, weekday);
                 if let Some(d) = d {
                     assert_eq!(d.weekday(), weekday);
                     let w = d.iso_week();
@@ -257,7 +314,7 @@ fn test_date_from_isoywd_and_iso_week() {
                 let d = NaiveDate::from_ymd_opt(year, month, day);
                 if let Some(d) = d {
                     let w = d.iso_week();
-                    let d_ = NaiveDate::from_isoywd_opt(w.year(), w.week(), d.weekday());
+                    let d_ = NaiveDate::from_iso_week(w.year(), w.week(), d.weekday());
                     assert_eq!(d, d_.unwrap());
                 }
             }
@@ -304,7 +361,7 @@ fn test_date_from_num_days_from_ce() {
 
 #[test]
 fn test_date_from_weekday_of_month_opt() {
-    let ymwd = NaiveDate::from_weekday_of_month_opt;
+    let ymwd = NaiveDate::nth_weekday_of_month;
     assert_eq!(ymwd(2018, 8, Weekday::Tue, 0), None);
     assert_eq!(ymwd(2018, 8, Weekday::Wed, 1), Some(NaiveDate::from_ymd_opt(2018, 8, 1).unwrap()));
     assert_eq!(ymwd(2018, 8, Weekday::Thu, 1), Some(NaiveDate::from_ymd_opt(2018, 8, 2).unwrap()));
@@ -320,6 +377,54 @@ fn test_date_from_weekday_of_month_opt() {
     assert_eq!(ymwd(2018, 8, Weekday::Sat, 5), None);
 }
 
+#[test]
+fn test_date_nth_weekday_of_month() {
+    let nth = NaiveDate::nth_weekday_of_month;
+    assert_eq!(nth(2024, 11, Weekday::Thu, 3), NaiveDate::from_ymd_opt(2024, 11, 21));
+    assert_eq!(nth(2024, 11, Weekday::Thu, 4), NaiveDate::from_ymd_opt(2024, 11, 28));
+    assert_eq!(nth(2024, 11, Weekday::Thu, 5), None);
+    assert_eq!(nth(2024, 11, Weekday::Fri, 1), NaiveDate::from_ymd_opt(2024, 11, 1));
+    assert_eq!(nth(2024, 11, Weekday::Fri, 5), NaiveDate::from_ymd_opt(2024, 11, 29));
+    assert_eq!(nth(2024, 2, Weekday::Thu, 5), NaiveDate::from_ymd_opt(2024, 2, 29));
+    assert_eq!(nth(2023, 2, Weekday::Wed, 5), None);
+    assert_eq!(nth(2024, 11, Weekday::Thu, 0), None);
+    assert_eq!(nth(2024, 13, Weekday::Thu, 1), None);
+    assert_eq!(nth(MAX_YEAR + 1, 1, Weekday::Thu, 1), None);
+
+    #[allow(deprecated)]
+    let from_weekday_of_month_opt = NaiveDate::from_weekday_of_month_opt;
+    for n in 0..6 {
+        assert_eq!(
+            from_weekday_of_month_opt(2024, 11, Weekday::Thu, n),
+            nth(2024, 11, Weekday::Thu, n)
+        );
+    }
+}
+
+#[test]
+fn test_date_last_weekday_of_month() {
+    let last = NaiveDate::last_weekday_of_month;
+    assert_eq!(last(2024, 11, Weekday::Thu), NaiveDate::from_ymd_opt(2024, 11, 28));
+    assert_eq!(last(2024, 11, Weekday::Sat), NaiveDate::from_ymd_opt(2024, 11, 30));
+    assert_eq!(last(2024, 11, Weekday::Sun), NaiveDate::from_ymd_opt(2024, 11, 24));
+    assert_eq!(last(2024, 5, Weekday::Mon), NaiveDate::from_ymd_opt(2024, 5, 27));
+    assert_eq!(last(2024, 2, Weekday::Thu), NaiveDate::from_ymd_opt(2024, 2, 29));
+    assert_eq!(last(2023, 2, Weekday::Thu), NaiveDate::from_ymd_opt(2023, 2, 23));
+    assert_eq!(last(2024, 0, Weekday::Thu), None);
+    assert_eq!(last(2024, 13, Weekday::Thu), None);
+    assert_eq!(last(MAX_YEAR, 12, Weekday::Fri).map(|d| d.month()), Some(12));
+    assert_eq!(last(MAX_YEAR + 1, 1, Weekday::Fri), None);
+
+    for month in 1..=12 {
+        for weekday in Weekday::Mon.iter_from() {
+            let date = last(2024, month, weekday).unwrap();
+            assert_eq!(date.weekday(), weekday);
+            assert_eq!(date.month(), month);
+            assert_ne!((date + Days::new(7)).month(), month);
+        }
+    }
+}
+
 #[test]
 fn test_date_fields() {
     fn check(year: i32, month: u32, day: u32, ordinal: u32) {
@@ -412,6 +517,43 @@ fn test_date_with_ordinal() {
     assert_eq!(d.with_ordinal(u32::MAX), None);
 }
 
+#[test]
+fn test_date_julian_day_number() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    for (date, jdn) in [
+        (ymd(2000, 1, 1), 2_451_545),
+        (ymd(1970, 1, 1), 2_440_588),
+        (ymd(1858, 11, 17), 2_400_001), // the epoch of the Modified Julian Date, plus one
+        (ymd(1582, 10, 15), 2_299_161), // the first day of the Gregorian calendar
+        (ymd(1, 1, 1), 1_721_426),
+        (ymd(0, 12, 31), 1_721_425),
+        (ymd(-4713, 11, 24), 0),
+        (ymd(-4713, 11, 23), -1),
+    ] {
+        assert_eq!(date.to_julian_day_number(), jdn, "{}", date);
+        assert_eq!(NaiveDate::from_julian_day_number(jdn), Some(date));
+    }
+
+    // round trip over a range of dates, across leap years and the epoch
+    let mut date = ymd(-4800, 1, 1);
+    let mut jdn = date.to_julian_day_number();
+    while date < ymd(2400, 1, 1) {
+        assert_eq!(date.to_julian_day_number(), jdn);
+        assert_eq!(NaiveDate::from_julian_day_number(jdn), Some(date));
+        date = date.succ_opt().unwrap();
+        jdn += 1;
+    }
+
+    let min = NaiveDate::MIN.to_julian_day_number();
+    let max = NaiveDate::MAX.to_julian_day_number();
+    assert_eq!(NaiveDate::from_julian_day_number(min), Some(NaiveDate::MIN));
+    assert_eq!(NaiveDate::from_julian_day_number(max), Some(NaiveDate::MAX));
+    assert_eq!(NaiveDate::from_julian_day_number(min - 1), None);
+    assert_eq!(NaiveDate::from_julian_day_number(max + 1), None);
+    assert_eq!(NaiveDate::from_julian_day_number(i64::MIN), None);
+    assert_eq!(NaiveDate::from_julian_day_number(i64::MAX), None);
+}
+
 #[test]
 fn test_date_num_days_from_ce() {
     assert_eq!(NaiveDate::from_ymd_opt(1, 1, 1).unwrap().num_days_from_ce(), 1);
@@ -551,6 +693,75 @@ fn test_date_sub_days() {
     );
 }
 
+#[test]
+fn test_date_add_days_signed() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    assert_eq!(ymd(2014, 1, 1).add_days_signed(0), Some(ymd(2014, 1, 1)));
+    assert_eq!(ymd(2014, 1, 1).add_days_signed(400), Some(ymd(2015, 2, 5)));
+    assert_eq!(ymd(2014, 1, 1).add_days_signed(-400), Some(ymd(2012, 11, 27)));
+    assert_eq!(ymd(2024, 2, 29).add_days_signed(400), Some(ymd(2025, 4, 4)));
+    assert_eq!(ymd(2024, 2, 29).add_days_signed(-400), Some(ymd(2023, 1, 25)));
+    assert_eq!(ymd(1, 1, 1).add_days_signed(-1), Some(ymd(0, 12, 31)));
+
+    for days in [-400, -365, -1, 0, 1, 59, 365, 400, 146_097] {
+        let date = ymd(2000, 6, 15);
+        let expected = if days < 0 {
+            date.checked_sub_days(Days::new(-days as u64))
+        } else {
+            date.checked_add_days(Days::new(days as u64))
+        };
+        assert_eq!(date.add_days_signed(days), expected);
+        assert_eq!(date.add_days_signed(days).unwrap().add_days_signed(-days), Some(date));
+    }
+
+    // out of range at both bounds
+    assert_eq!(NaiveDate::MAX.add_days_signed(0), Some(NaiveDate::MAX));
+    assert_eq!(NaiveDate::MAX.add_days_signed(1), None);
+    assert_eq!(NaiveDate::MAX.add_days_signed(-1), Some(ymd(MAX_YEAR, 12, 30)));
+    assert_eq!(NaiveDate::MIN.add_days_signed(0), Some(NaiveDate::MIN));
+    assert_eq!(NaiveDate::MIN.add_days_signed(-1), None);
+    assert_eq!(NaiveDate::MIN.add_days_signed(1), Some(ymd(MIN_YEAR, 1, 2)));
+    let span = NaiveDate::MAX.signed_duration_since(NaiveDate::MIN).num_days();
+    assert_eq!(NaiveDate::MIN.add_days_signed(span), Some(NaiveDate::MAX));
+    assert_eq!(NaiveDate::MAX.add_days_signed(-span), Some(NaiveDate::MIN));
+    assert_eq!(NaiveDate::MIN.add_days_signed(span + 1), None);
+    assert_eq!(NaiveDate::MAX.add_days_signed(-span - 1), None);
+    for days in [i32::MIN as i64, i32::MAX as i64, i32::MIN as i64 - 1, i32::MAX as i64 + 1] {
+        assert_eq!(ymd(2014, 1, 1).add_days_signed(days), None);
+    }
+    assert_eq!(ymd(2014, 1, 1).add_days_signed(i64::MIN), None);
+    assert_eq!(ymd(2014, 1, 1).add_days_signed(i64::MAX), None);
+}
+
+#[test]
+fn test_date_saturating_add_sub() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    assert_eq!(ymd(2014, 1, 1).saturating_add_days(Days::new(31)), ymd(2014, 2, 1));
+    assert_eq!(ymd(2014, 1, 1).saturating_sub_days(Days::new(1)), ymd(2013, 12, 31));
+    assert_eq!(ymd(2014, 1, 31).saturating_add_months(Months::new(1)), ymd(2014, 2, 28));
+    assert_eq!(ymd(2014, 3, 31).saturating_sub_months(Months::new(1)), ymd(2014, 2, 28));
+
+    // clamp at `MAX`
+    assert_eq!(NaiveDate::MAX.saturating_add_days(Days::new(0)), NaiveDate::MAX);
+    assert_eq!(NaiveDate::MAX.saturating_add_days(Days::new(1)), NaiveDate::MAX);
+    assert_eq!(ymd(MAX_YEAR, 12, 30).saturating_add_days(Days::new(1)), NaiveDate::MAX);
+    assert_eq!(ymd(MAX_YEAR, 12, 30).saturating_add_days(Days::new(2)), NaiveDate::MAX);
+    assert_eq!(ymd(0, 1, 1).saturating_add_days(Days::new(u64::MAX)), NaiveDate::MAX);
+    assert_eq!(ymd(MAX_YEAR, 11, 30).saturating_add_months(Months::new(1)), ymd(MAX_YEAR, 12, 30));
+    assert_eq!(ymd(MAX_YEAR, 12, 1).saturating_add_months(Months::new(1)), NaiveDate::MAX);
+    assert_eq!(NaiveDate::MIN.saturating_add_months(Months::new(u32::MAX)), NaiveDate::MAX);
+    assert_eq!(NaiveDate::MIN.saturating_sub_days(Days::new(u64::MAX)), NaiveDate::MIN);
+
+    // clamp at `MIN`
+    assert_eq!(NaiveDate::MIN.saturating_sub_days(Days::new(1)), NaiveDate::MIN);
+    assert_eq!(ymd(MIN_YEAR, 1, 2).saturating_sub_days(Days::new(1)), NaiveDate::MIN);
+    assert_eq!(ymd(MIN_YEAR, 1, 2).saturating_sub_days(Days::new(2)), NaiveDate::MIN);
+    assert_eq!(ymd(MIN_YEAR, 1, 31).saturating_sub_months(Months::new(1)), NaiveDate::MIN);
+    assert_eq!(NaiveDate::MAX.saturating_sub_months(Months::new(u32::MAX)), NaiveDate::MIN);
+}
+
 #[test]
 fn test_date_addassignment() {
     let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
@@ -685,6 +896,106 @@ fn test_date_parse_from_str() {
     );
 }
 
+#[test]
+fn test_date_parse_from_str_ordinal() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    assert_eq!(NaiveDate::parse_from_str("2024-045", "%Y-%j"), Ok(ymd(2024, 2, 14)));
+    assert_eq!(NaiveDate::parse_from_str("2024-060", "%Y-%j"), Ok(ymd(2024, 2, 29)));
+    assert_eq!(NaiveDate::parse_from_str("2023-060", "%Y-%j"), Ok(ymd(2023, 3, 1)));
+    assert_eq!(NaiveDate::parse_from_str("2024-366", "%Y-%j"), Ok(ymd(2024, 12, 31)));
+    assert_eq!(
+        NaiveDate::parse_from_str("2023-366", "%Y-%j").map_err(|e| e.kind()),
+        Err(ParseErrorKind::OutOfRange)
+    );
+    // an explicit month and day must agree with the ordinal
+    assert_eq!(NaiveDate::parse_from_str("2024-045 02-14", "%Y-%j %m-%d"), Ok(ymd(2024, 2, 14)));
+    assert_eq!(
+        NaiveDate::parse_from_str("2024-045 02-15", "%Y-%j %m-%d").map_err(|e| e.kind()),
+        Err(ParseErrorKind::Impossible)
+    );
+    assert_eq!(
+        NaiveDate::parse_from_str("2024-045 03", "%Y-%j %m").map_err(|e| e.kind()),
+        Err(ParseErrorKind::Impossible)
+    );
+}
+
+#[test]
+fn test_date_parse_from_str_iso_week() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let parse = |s| NaiveDate::parse_from_str(s, "%G-W%V-%u").map_err(|e| e.kind());
+    assert_eq!(parse("2024-W01-1"), Ok(ymd(2024, 1, 1)));
+    assert_eq!(parse("2024-W03-1"), Ok(ymd(2024, 1, 15)));
+    // weeks that start in the previous Gregorian year or end in the next one
+    assert_eq!(parse("2015-W01-1"), Ok(ymd(2014, 12, 29)));
+    assert_eq!(parse("2020-W53-5"), Ok(ymd(2021, 1, 1)));
+    assert_eq!(parse("2020-W53-7"), Ok(ymd(2021, 1, 3)));
+    assert_eq!(parse("2021-W53-1"), Err(ParseErrorKind::OutOfRange));
+    assert_eq!(parse("2024-W00-1"), Err(ParseErrorKind::OutOfRange));
+
+    // an explicit Gregorian date must agree with the ISO week date
+    let parse = |s| NaiveDate::parse_from_str(s, "%G-W%V-%u %Y-%m-%d").map_err(|e| e.kind());
+    assert_eq!(parse("2020-W53-5 2021-01-01"), Ok(ymd(2021, 1, 1)));
+    assert_eq!(parse("2020-W53-5 2020-01-01"), Err(ParseErrorKind::Impossible));
+    assert_eq!(parse("2020-W53-5 2021-01-02"), Err(ParseErrorKind::Impossible));
+}
+
+#[test]
+fn test_date_parse_from_str_week_of_year() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let parse = |s, fmt| NaiveDate::parse_from_str(s, fmt).map_err(|e| e.kind());
+    assert_eq!(parse("2024 05 Mon", "%Y %W %a"), Ok(ymd(2024, 1, 29)));
+    assert_eq!(parse("2024 05 Mon", "%Y %U %a"), Ok(ymd(2024, 2, 5)));
+
+    // week 0 contains the days before the first Monday (`%W`) or Sunday (`%U`)
+    assert_eq!(parse("2023-00-7", "%Y-%W-%u"), Ok(ymd(2023, 1, 1)));
+    assert_eq!(parse("2023-00-6", "%Y-%W-%u"), Err(ParseErrorKind::Impossible));
+    assert_eq!(parse("2023-01-1", "%Y-%W-%u"), Ok(ymd(2023, 1, 2)));
+    assert_eq!(parse("2023-00-7", "%Y-%U-%u"), Err(ParseErrorKind::Impossible));
+    assert_eq!(parse("2023-01-7", "%Y-%U-%u"), Ok(ymd(2023, 1, 1)));
+    assert_eq!(parse("2022-00-6", "%Y-%U-%u"), Ok(ymd(2022, 1, 1)));
+    // the last week can run into the next year
+    assert_eq!(parse("2024-53-2", "%Y-%W-%u"), Ok(ymd(2024, 12, 31)));
+    assert_eq!(parse("2024-53-3", "%Y-%W-%u"), Err(ParseErrorKind::Impossible));
+    assert_eq!(parse("2024-54-1", "%Y-%W-%u"), Err(ParseErrorKind::OutOfRange));
+    // not enough fields without a weekday
+    assert_eq!(parse("2024-05", "%Y-%W"), Err(ParseErrorKind::NotEnough));
+
+    #[cfg(feature = "alloc")]
+    for fmt in ["%Y-%W-%u", "%Y-%U-%u", "%Y %W %a", "%Y %U %A"] {
+        for year in [2000, 2021, 2022, 2023, 2024] {
+            let mut date = ymd(year, 1, 1);
+            while date.year() == year {
+                let s = date.format(fmt).to_string();
+                assert_eq!(NaiveDate::parse_from_str(&s, fmt), Ok(date), "{} with {}", s, fmt);
+                date = date.succ_opt().unwrap();
+            }
+        }
+    }
+}
+
+#[test]
+fn test_date_parse_and_remainder() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let parse = NaiveDate::parse_and_remainder;
+    assert_eq!(parse("2024-01-01 garbage", "%Y-%m-%d"), Ok((ymd(2024, 1, 1), " garbage")));
+    assert_eq!(parse("2024-01-01", "%Y-%m-%d"), Ok((ymd(2024, 1, 1), "")));
+    assert_eq!(parse("2024-01-012", "%Y-%m-%d"), Ok((ymd(2024, 1, 1), "2")));
+    assert_eq!(parse("2024-01-01T12:00", "%Y-%m-%d"), Ok((ymd(2024, 1, 1), "T12:00")));
+    assert_eq!(
+        NaiveDate::parse_from_str("2024-01-01 garbage", "%Y-%m-%d").map_err(|e| e.kind()),
+        Err(ParseErrorKind::TooLong)
+    );
+    // the prefix must still be a complete date
+    assert_eq!(
+        parse("2024-01 garbage", "%Y-%m-%d").map_err(|e| e.kind()),
+        Err(ParseErrorKind::Invalid)
+    );
+    assert_eq!(
+        parse("2024-01 garbage", "%Y-%m").map_err(|e| e.kind()),
+        Err(ParseErrorKind::NotEnough)
+    );
+}
+
 #[test]
 fn test_day_iterator_limit() {
     assert_eq!(NaiveDate::from_ymd_opt(MAX_YEAR, 12, 29).unwrap().iter_days().take(4).count(), 2);
@@ -703,6 +1014,342 @@ fn test_week_iterator_limit() {
     );
 }
 
+#[test]
+fn test_date_calendar_difference() {
+    let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let diff = |a, b| {
+        let d = NaiveDate::calendar_difference(&a, b);
+        (d.years(), d.months(), d.days())
+    };
+
+    // borrowing from January (31 days)
+    assert_eq!(diff(from_ymd(2020, 3, 1), from_ymd(2020, 1, 31)), (0, 1, 1));
+    assert_eq!(diff(from_ymd(2020, 1, 31), from_ymd(2020, 3, 1)), (0, -1, -1));
+    assert_eq!(diff(from_ymd(2020, 2, 1), from_ymd(2020, 1, 31)), (0, 0, 1));
+    // borrowing from February, in a leap year and a common year
+    assert_eq!(diff(from_ymd(2020, 3, 1), from_ymd(2020, 2, 29)), (0, 0, 1));
+    assert_eq!(diff(from_ymd(2020, 3, 27), from_ymd(2020, 2, 28)), (0, 0, 28));
+    assert_eq!(diff(from_ymd(2021, 3, 27), from_ymd(2021, 2, 28)), (0, 0, 27));
+    // without borrowing
+    assert_eq!(diff(from_ymd(2020, 3, 31), from_ymd(2020, 1, 31)), (0, 2, 0));
+    assert_eq!(diff(from_ymd(2024, 2, 15), from_ymd(2021, 11, 10)), (2, 3, 5));
+    assert_eq!(diff(from_ymd(2024, 1, 1), from_ymd(2023, 12, 31)), (0, 0, 1));
+    assert_eq!(diff(from_ymd(2024, 6, 15), from_ymd(2000, 6, 15)), (24, 0, 0));
+    assert_eq!(diff(from_ymd(2024, 6, 14), from_ymd(2000, 6, 15)), (23, 11, 29));
+    assert_eq!(diff(from_ymd(2000, 6, 15), from_ymd(2024, 6, 14)), (-23, -11, -29));
+
+    let zero = from_ymd(2024, 5, 5).calendar_difference(from_ymd(2024, 5, 5));
+    assert_eq!(zero, Default::default());
+    assert!(!zero.is_negative());
+    assert!(from_ymd(2024, 5, 4).calendar_difference(from_ymd(2024, 5, 5)).is_negative());
+
+    // unlike `years_since`, February 28 is not the anniversary of February 29
+    let leap_day = from_ymd(2000, 2, 29);
+    assert_eq!(diff(from_ymd(2001, 2, 28), leap_day), (0, 11, 28));
+    assert_eq!(diff(from_ymd(2001, 3, 1), leap_day), (1, 0, 1));
+    assert_eq!(diff(from_ymd(2004, 2, 29), leap_day), (4, 0, 0));
+
+    assert_eq!(diff(NaiveDate::MAX, NaiveDate::MIN), (MAX_YEAR - MIN_YEAR, 11, 30));
+    assert_eq!(diff(NaiveDate::MIN, NaiveDate::MAX), (MIN_YEAR - MAX_YEAR, -11, -30));
+}
+
+#[test]
+fn test_date_years_since() {
+    let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    let base = from_ymd(2000, 6, 15);
+    assert_eq!(base.years_since(base), Some(0));
+    assert_eq!(from_ymd(2001, 6, 14).years_since(base), Some(0));
+    assert_eq!(from_ymd(2001, 6, 15).years_since(base), Some(1));
+    assert_eq!(from_ymd(2024, 1, 1).years_since(base), Some(23));
+    assert_eq!(from_ymd(2000, 6, 14).years_since(base), None);
+    assert_eq!(from_ymd(1999, 12, 31).years_since(base), None);
+
+    // Born on February 29: the anniversary is February 28 in common years.
+    let leap_day = from_ymd(2000, 2, 29);
+    assert_eq!(from_ymd(2000, 2, 28).years_since(leap_day), None);
+    assert_eq!(from_ymd(2000, 3, 1).years_since(leap_day), Some(0));
+    assert_eq!(from_ymd(2001, 2, 27).years_since(leap_day), Some(0));
+    assert_eq!(from_ymd(2001, 2, 28).years_since(leap_day), Some(1));
+    assert_eq!(from_ymd(2001, 3, 1).years_since(leap_day), Some(1));
+    assert_eq!(from_ymd(2003, 2, 28).years_since(leap_day), Some(3));
+    assert_eq!(from_ymd(2004, 2, 28).years_since(leap_day), Some(3));
+    assert_eq!(from_ymd(2004, 2, 29).years_since(leap_day), Some(4));
+    assert_eq!(from_ymd(2100, 2, 28).years_since(leap_day), Some(100));
+
+    // Born on February 28 is not affected.
+    let day_before = from_ymd(2000, 2, 28);
+    assert_eq!(from_ymd(2004, 2, 27).years_since(day_before), Some(3));
+    assert_eq!(from_ymd(2004, 2, 28).years_since(day_before), Some(4));
+
+    assert_eq!(NaiveDate::MAX.years_since(NaiveDate::MIN), Some((MAX_YEAR - MIN_YEAR) as u32));
+    assert_eq!(NaiveDate::MIN.years_since(NaiveDate::MAX), None);
+}
+
+#[test]
+fn test_iter_months() {
+    let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    // The day is clamped to the end of shorter months, without affecting later months.
+    let dates: Vec<_> = from_ymd(2023, 12, 31).iter_months().take(6).collect();
+    assert_eq!(
+        dates,
+        [
+            from_ymd(2023, 12, 31),
+            from_ymd(2024, 1, 31),
+            from_ymd(2024, 2, 29),
+            from_ymd(2024, 3, 31),
+            from_ymd(2024, 4, 30),
+            from_ymd(2024, 5, 31),
+        ]
+    );
+    let dates: Vec<_> = from_ymd(2023, 1, 31).iter_months().take(3).collect();
+    assert_eq!(dates, [from_ymd(2023, 1, 31), from_ymd(2023, 2, 28), from_ymd(2023, 3, 31)]);
+
+    // The first of each month of a year.
+    let firsts: Vec<_> = from_ymd(2024, 1, 1).iter_months().take(12).collect();
+    assert!(firsts.iter().enumerate().all(|(i, d)| d.month0() == i as u32 && d.day() == 1));
+
+    // Limits
+    let mut iter = from_ymd(MAX_YEAR, 10, 31).iter_months();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back(), Some(from_ymd(MAX_YEAR, 12, 31)));
+    assert_eq!(iter.next(), Some(from_ymd(MAX_YEAR, 10, 31)));
+    assert_eq!(iter.next(), Some(from_ymd(MAX_YEAR, 11, 30)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(NaiveDate::MAX.iter_months().count(), 1);
+    assert_eq!(NaiveDate::MIN.iter_months().len(), (MAX_YEAR - MIN_YEAR + 1) as usize * 12);
+}
+
+#[test]
+fn test_iter_months_until() {
+    let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    let start = from_ymd(2024, 1, 31);
+    let dates: Vec<_> = start.iter_months_until(from_ymd(2024, 5, 1)).collect();
+    assert_eq!(
+        dates,
+        [
+            from_ymd(2024, 1, 31),
+            from_ymd(2024, 2, 29),
+            from_ymd(2024, 3, 31),
+            from_ymd(2024, 4, 30)
+        ]
+    );
+    let dates: Vec<_> = start.iter_months_until(from_ymd(2024, 4, 30)).rev().collect();
+    assert_eq!(dates, [from_ymd(2024, 3, 31), from_ymd(2024, 2, 29), from_ymd(2024, 1, 31)]);
+
+    // `end` is excluded, also when it is reached by clamping.
+    assert_eq!(start.iter_months_until(from_ymd(2024, 2, 29)).len(), 1);
+    assert_eq!(start.iter_months_until(from_ymd(2024, 3, 1)).len(), 2);
+    assert_eq!(start.iter_months_until(from_ymd(2025, 1, 31)).len(), 12);
+    assert_eq!(start.iter_months_until(from_ymd(2025, 2, 1)).len(), 13);
+
+    // Empty ranges
+    assert_eq!(start.iter_months_until(start).count(), 0);
+    assert_eq!(start.iter_months_until(from_ymd(2024, 1, 1)).count(), 0);
+    assert_eq!(start.iter_months_until(from_ymd(2023, 12, 31)).count(), 0);
+    assert_eq!(start.iter_months_until(from_ymd(2024, 1, 31).succ_opt().unwrap()).count(), 1);
+
+    // Iterating from both ends meets in the middle.
+    let mut iter = from_ymd(2024, 1, 15).iter_months_until(from_ymd(2024, 4, 15));
+    assert_eq!(iter.next(), Some(from_ymd(2024, 1, 15)));
+    assert_eq!(iter.next_back(), Some(from_ymd(2024, 3, 15)));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next_back(), Some(from_ymd(2024, 2, 15)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    let full = NaiveDate::MIN.iter_months_until(NaiveDate::MAX);
+    assert_eq!(full.len(), (MAX_YEAR - MIN_YEAR + 1) as usize * 12);
+}
+
+#[test]
+fn test_iter_business_days() {
+    let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    // 2024-01-03 is a Wednesday.
+    let wednesday = from_ymd(2024, 1, 3);
+    let days: Vec<_> = wednesday.iter_business_days(Weekend::SATURDAY_SUNDAY).take(5).collect();
+    assert_eq!(
+        days,
+        [
+            from_ymd(2024, 1, 3),
+            from_ymd(2024, 1, 4),
+            from_ymd(2024, 1, 5),
+            from_ymd(2024, 1, 8),
+            from_ymd(2024, 1, 9),
+        ]
+    );
+    assert_eq!(days.last().unwrap().signed_duration_since(days[0]), TimeDelta::days(6));
+    assert!(days.iter().all(|d| !d.is_weekend()));
+
+    // Reverse iteration walks back in time.
+    let days: Vec<_> =
+        from_ymd(2024, 1, 10).iter_business_days(Weekend::SATURDAY_SUNDAY).rev().take(5).collect();
+    assert_eq!(
+        days,
+        [
+            from_ymd(2024, 1, 10),
+            from_ymd(2024, 1, 9),
+            from_ymd(2024, 1, 8),
+            from_ymd(2024, 1, 5),
+            from_ymd(2024, 1, 4),
+        ]
+    );
+
+    // A weekend start is skipped in both directions.
+    let saturday = from_ymd(2024, 1, 6);
+    let mut iter = saturday.iter_business_days(Weekend::SATURDAY_SUNDAY);
+    assert_eq!(iter.next(), Some(from_ymd(2024, 1, 8)));
+    let mut iter = saturday.iter_business_days(Weekend::SATURDAY_SUNDAY);
+    assert_eq!(iter.next_back(), Some(from_ymd(2024, 1, 5)));
+
+    // Other weekends.
+    let mut iter = from_ymd(2024, 1, 4).iter_business_days(Weekend::FRIDAY_SATURDAY);
+    assert_eq!(iter.next(), Some(from_ymd(2024, 1, 4)));
+    assert_eq!(iter.next(), Some(from_ymd(2024, 1, 7)));
+    let all_days = wednesday.iter_days().take(10).collect::<Vec<_>>();
+    assert_eq!(wednesday.iter_business_days(Weekend::NONE).take(10).collect::<Vec<_>>(), all_days);
+    let everything = Weekend::from_weekdays(&[
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ]);
+    assert_eq!(wednesday.iter_business_days(everything).next(), None);
+    assert_eq!(wednesday.iter_business_days(everything).next_back(), None);
+
+    // The iterator ends at the limits of the representable range.
+    let near_max = NaiveDate::MAX - Days::new(10);
+    assert!(near_max.iter_business_days(Weekend::SATURDAY_SUNDAY).count() <= 10);
+    let near_min = NaiveDate::MIN + Days::new(10);
+    assert!(near_min.iter_business_days(Weekend::SATURDAY_SUNDAY).rev().count() <= 11);
+}
+
+#[test]
+fn test_iter_days_until() {
+    let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    let start = from_ymd(2023, 12, 30);
+    let end = from_ymd(2024, 1, 2);
+    assert_eq!(
+        start.iter_days_until(end).collect::<Vec<_>>(),
+        [from_ymd(2023, 12, 30), from_ymd(2023, 12, 31), from_ymd(2024, 1, 1)]
+    );
+    assert_eq!(
+        start.iter_days_until(end).rev().collect::<Vec<_>>(),
+        [from_ymd(2024, 1, 1), from_ymd(2023, 12, 31), from_ymd(2023, 12, 30)]
+    );
+    assert_eq!(start.iter_days_until(end).len(), 3);
+
+    // Iterating from both ends meets in the middle.
+    let mut iter = start.iter_days_until(end);
+    assert_eq!(iter.next(), Some(from_ymd(2023, 12, 30)));
+    assert_eq!(iter.next_back(), Some(from_ymd(2024, 1, 1)));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next_back(), Some(from_ymd(2023, 12, 31)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    assert_eq!(start.iter_days_until(start).count(), 0);
+    assert_eq!(end.iter_days_until(start).count(), 0);
+    assert_eq!(end.iter_days_until(start).len(), 0);
+}
+
+#[test]
+#[cfg(feature = "unstable-step")]
+fn test_step() {
+    use core::iter::Step;
+
+    let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    let start = from_ymd(2023, 12, 30);
+    let end = from_ymd(2024, 3, 2);
+    assert_eq!((start..end).collect::<Vec<_>>(), start.iter_days_until(end).collect::<Vec<_>>());
+    assert_eq!(
+        (start..end).rev().collect::<Vec<_>>(),
+        start.iter_days_until(end).rev().collect::<Vec<_>>()
+    );
+    assert_eq!((start..=end).last(), Some(end));
+    assert_eq!((end..start).count(), 0);
+
+    assert_eq!(Step::steps_between(&start, &end), (63, Some(63)));
+    assert_eq!(Step::steps_between(&end, &start), (0, None));
+    assert_eq!(Step::forward_checked(start, 63), Some(end));
+    assert_eq!(Step::backward_checked(end, 63), Some(start));
+
+    // stepping beyond the boundaries returns `None`
+    assert_eq!(Step::forward_checked(NaiveDate::MAX, 1), None);
+    assert_eq!(Step::backward_checked(NaiveDate::MIN, 1), None);
+    assert_eq!(Step::forward_checked(NaiveDate::MIN, usize::MAX), None);
+    assert_eq!(Step::backward_checked(NaiveDate::MAX, usize::MAX), None);
+    assert_eq!((NaiveDate::MAX..=NaiveDate::MAX).count(), 1);
+    let days = Step::steps_between(&NaiveDate::MIN, &NaiveDate::MAX).0;
+    assert_eq!(Step::forward_checked(NaiveDate::MIN, days), Some(NaiveDate::MAX));
+}
+
+#[test]
+fn test_iter_step() {
+    let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    let start = from_ymd(2024, 1, 1);
+    let iter = start.iter_step(from_ymd(2024, 1, 11), Days::new(3));
+    assert_eq!(iter.len(), 4);
+    assert_eq!(
+        iter.collect::<Vec<_>>(),
+        [from_ymd(2024, 1, 1), from_ymd(2024, 1, 4), from_ymd(2024, 1, 7), from_ymd(2024, 1, 10)]
+    );
+    assert_eq!(
+        iter.rev().collect::<Vec<_>>(),
+        [from_ymd(2024, 1, 10), from_ymd(2024, 1, 7), from_ymd(2024, 1, 4), from_ymd(2024, 1, 1)]
+    );
+
+    // `end` is excluded when it falls on a step.
+    assert_eq!(start.iter_step(from_ymd(2024, 1, 10), Days::new(3)).len(), 3);
+
+    // A step larger than the range only yields the start.
+    assert_eq!(
+        start.iter_step(from_ymd(2024, 1, 3), Days::new(u64::MAX)).collect::<Vec<_>>(),
+        [start]
+    );
+    assert_eq!(from_ymd(2024, 1, 3).iter_step(start, Days::new(2)).count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "step of `NaiveDate::iter_step` must be nonzero")]
+fn test_iter_step_zero() {
+    let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    let _ = date.iter_step(date + Days::new(3), Days::new(0));
+}
+
+#[test]
+#[should_panic(expected = "step of `NaiveDate::iter_step` must be nonzero")]
+fn test_iter_step_zero_empty_range() {
+    let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    let _ = date.iter_step(date, Days::new(0));
+}
+
+#[test]
+fn test_iter_step_limit() {
+    let near_max = NaiveDate::MAX.checked_sub_days(Days::new(3)).unwrap();
+    assert_eq!(near_max.iter_days_until(NaiveDate::MAX).count(), 3);
+    assert_eq!(near_max.iter_days_until(NaiveDate::MAX).rev().count(), 3);
+    assert_eq!(near_max.iter_step(NaiveDate::MAX, Days::new(2)).count(), 2);
+    assert_eq!(near_max.iter_step(NaiveDate::MAX, Days::new(i32::MAX as u64 + 1)).count(), 1);
+
+    let mut iter = NaiveDate::MIN.iter_step(NaiveDate::MAX, Days::new(1000));
+    let days = NaiveDate::MAX.signed_duration_since(NaiveDate::MIN).num_days();
+    assert_eq!(iter.len() as i64, (days + 999) / 1000);
+    assert_eq!(iter.count(), iter.len());
+    let last = iter.fold(None, |_, date| Some(date)).unwrap();
+    assert_eq!(iter.next_back(), Some(last));
+    assert!(NaiveDate::MAX.signed_duration_since(last).num_days() <= 1000);
+}
+
 #[test]
 fn test_weeks_from() {
     // tests per: https://github.com/chronotope/chrono/issues/961
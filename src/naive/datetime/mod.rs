@@ -16,8 +16,10 @@ use rkyv::{Archive, Deserialize, Serialize};
 #[cfg(feature = "alloc")]
 use crate::format::DelayedFormat;
 use crate::format::{parse, parse_and_remainder, ParseError, ParseResult, Parsed, StrftimeItems};
-use crate::format::{Fixed, Item, Numeric, Pad};
+use crate::format::{write_items, Fixed, Item, Numeric, Pad};
 use crate::naive::{Days, IsoWeek, NaiveDate, NaiveTime};
+#[cfg(feature = "clock")]
+use crate::offset::Local;
 use crate::offset::Utc;
 use crate::time_delta::NANOS_PER_SEC;
 use crate::{
@@ -418,11 +420,34 @@ impl NaiveDateTime {
         self.and_utc().timestamp_nanos_opt()
     }
 
+    /// Returns the number of non-leap *nanoseconds* since midnight on January 1, 1970, as an
+    /// `i128`.
+    ///
+    /// Note that this does *not* account for the timezone!
+    /// The true "UNIX timestamp" would count seconds since the midnight *UTC* on the epoch.
+    ///
+    /// Unlike `timestamp_nanos_opt` this can represent the full range of `NaiveDateTime`, so it
+    /// never overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(-1000, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// assert_eq!(dt.timestamp_nanos_i128(), -93_724_128_000_000_000_000);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn timestamp_nanos_i128(&self) -> i128 {
+        self.and_utc().timestamp_nanos_i128()
+    }
+
     /// Returns the number of milliseconds since the last whole non-leap second.
     ///
     /// The return value ranges from 0 to 999,
     /// or for [leap seconds](./struct.NaiveTime.html#leap-second-handling), to 1,999.
-    #[deprecated(since = "0.4.35", note = "use `.and_utc().timestamp_subsec_millis()` instead")]
+    #[deprecated(since = "0.4.35", note = "use `.subsec_millis()` instead")]
     #[inline]
     #[must_use]
     pub const fn timestamp_subsec_millis(&self) -> u32 {
@@ -433,7 +458,7 @@ impl NaiveDateTime {
     ///
     /// The return value ranges from 0 to 999,999,
     /// or for [leap seconds](./struct.NaiveTime.html#leap-second-handling), to 1,999,999.
-    #[deprecated(since = "0.4.35", note = "use `.and_utc().timestamp_subsec_micros()` instead")]
+    #[deprecated(since = "0.4.35", note = "use `.subsec_micros()` instead")]
     #[inline]
     #[must_use]
     pub const fn timestamp_subsec_micros(&self) -> u32 {
@@ -444,11 +469,104 @@ impl NaiveDateTime {
     ///
     /// The return value ranges from 0 to 999,999,999,
     /// or for [leap seconds](./struct.NaiveTime.html#leap-second-handling), to 1,999,999,999.
-    #[deprecated(since = "0.4.36", note = "use `.and_utc().timestamp_subsec_nanos()` instead")]
+    #[deprecated(since = "0.4.36", note = "use `.subsec_nanos()` instead")]
     pub const fn timestamp_subsec_nanos(&self) -> u32 {
         self.and_utc().timestamp_subsec_nanos()
     }
 
+    /// Returns the number of whole milliseconds since the last whole non-leap second.
+    ///
+    /// The return value ranges from 0 to 999,
+    /// or for [leap seconds](./struct.NaiveTime.html#leap-second-handling), to 1,999.
+    ///
+    /// Unlike the timestamp methods this does not depend on a time zone, because every offset is a
+    /// whole number of seconds. It always equals `self.and_utc().timestamp_subsec_millis()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2016, 7, 8).unwrap().and_hms_nano_opt(9, 10, 11, 12_345_678).unwrap();
+    /// assert_eq!(dt.subsec_millis(), 12);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn subsec_millis(&self) -> u32 {
+        self.time.subsec_millis()
+    }
+
+    /// Returns the number of whole microseconds since the last whole non-leap second.
+    ///
+    /// The return value ranges from 0 to 999,999,
+    /// or for [leap seconds](./struct.NaiveTime.html#leap-second-handling), to 1,999,999.
+    ///
+    /// Unlike the timestamp methods this does not depend on a time zone, because every offset is a
+    /// whole number of seconds. It always equals `self.and_utc().timestamp_subsec_micros()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2016, 7, 8).unwrap().and_hms_nano_opt(9, 10, 11, 12_345_678).unwrap();
+    /// assert_eq!(dt.subsec_micros(), 12_345);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn subsec_micros(&self) -> u32 {
+        self.time.subsec_micros()
+    }
+
+    /// Returns the number of nanoseconds since the last whole non-leap second.
+    ///
+    /// The return value ranges from 0 to 999,999,999,
+    /// or for [leap seconds](./struct.NaiveTime.html#leap-second-handling), to 1,999,999,999.
+    ///
+    /// Unlike the timestamp methods this does not depend on a time zone, because every offset is a
+    /// whole number of seconds. It always equals `self.and_utc().timestamp_subsec_nanos()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2016, 7, 8).unwrap().and_hms_nano_opt(9, 10, 11, 12_345_678).unwrap();
+    /// assert_eq!(dt.subsec_nanos(), 12_345_678);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn subsec_nanos(&self) -> u32 {
+        self.time.subsec_nanos()
+    }
+
+    /// Returns the Julian Date, the Julian Day Number with the time as a fraction of the day.
+    ///
+    /// A Julian day starts at noon, so midnight is at a fraction of `.5` of the previous Julian
+    /// Day Number. The `NaiveDateTime` is assumed to be in UT, so use
+    /// [`DateTime::naive_utc`](crate::DateTime::naive_utc) for a `DateTime`. A leap second counts
+    /// as a second past the end of the day.
+    ///
+    /// The value is an `f64`, which only has a precision of around 40 microseconds for current
+    /// dates. See [`NaiveDate::to_julian_day_number`] for the integer day number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+    /// assert_eq!(date.and_hms_opt(12, 0, 0).unwrap().to_julian_date(), 2_451_545.0);
+    /// assert_eq!(date.and_hms_opt(0, 0, 0).unwrap().to_julian_date(), 2_451_544.5);
+    /// assert_eq!(date.and_hms_opt(18, 0, 0).unwrap().to_julian_date(), 2_451_545.25);
+    /// ```
+    #[must_use]
+    pub fn to_julian_date(&self) -> f64 {
+        let secs = self.time.num_seconds_from_midnight() as f64 - 43_200.0;
+        let nanos = self.time.nanosecond() as f64;
+        self.date.to_julian_day_number() as f64 + (secs + nanos / 1e9) / 86_400.0
+    }
+
     /// Adds given `TimeDelta` to the current date and time.
     ///
     /// As a part of Chrono's [leap second handling](./struct.NaiveTime.html#leap-second-handling),
@@ -570,10 +688,29 @@ impl NaiveDateTime {
     }
 
     /// Adds given `FixedOffset` to the current datetime.
+    ///
+    /// This converts a datetime in UTC to the local wall clock time at the given offset. It is
+    /// similar to adding `TimeDelta::seconds(offset.local_minus_utc())` with
+    /// [`checked_add_signed`](#method.checked_add_signed), but preserves leap seconds and never
+    /// panics.
+    ///
+    /// # Errors
+    ///
     /// Returns `None` if the result would be outside the valid range for [`NaiveDateTime`].
     ///
-    /// This method is similar to [`checked_add_signed`](#method.checked_add_offset), but preserves
-    /// leap seconds.
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, NaiveDate, NaiveDateTime};
+    ///
+    /// let offset = FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+    /// let dt = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap().and_hms_opt(22, 0, 0).unwrap();
+    /// assert_eq!(
+    ///     dt.checked_add_offset(offset),
+    ///     NaiveDate::from_ymd_opt(2024, 3, 2).unwrap().and_hms_opt(3, 30, 0)
+    /// );
+    /// assert_eq!(NaiveDateTime::MAX.checked_add_offset(offset), None);
+    /// ```
     #[must_use]
     pub const fn checked_add_offset(self, rhs: FixedOffset) -> Option<NaiveDateTime> {
         let (time, days) = self.time.overflowing_add_offset(rhs);
@@ -586,10 +723,30 @@ impl NaiveDateTime {
     }
 
     /// Subtracts given `FixedOffset` from the current datetime.
+    ///
+    /// This converts a local wall clock time at the given offset to UTC. It is similar to
+    /// subtracting `TimeDelta::seconds(offset.local_minus_utc())` with
+    /// [`checked_sub_signed`](#method.checked_sub_signed), but preserves leap seconds and never
+    /// panics.
+    ///
+    /// # Errors
+    ///
     /// Returns `None` if the result would be outside the valid range for [`NaiveDateTime`].
     ///
-    /// This method is similar to [`checked_sub_signed`](#method.checked_sub_signed), but preserves
-    /// leap seconds.
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, NaiveDate, NaiveDateTime};
+    ///
+    /// let offset = FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+    /// let dt = NaiveDate::from_ymd_opt(2024, 3, 2).unwrap().and_hms_opt(3, 30, 0).unwrap();
+    /// assert_eq!(
+    ///     dt.checked_sub_offset(offset),
+    ///     NaiveDate::from_ymd_opt(2024, 3, 1).unwrap().and_hms_opt(22, 0, 0)
+    /// );
+    /// assert_eq!(NaiveDateTime::MIN.checked_sub_offset(offset), None);
+    /// ```
+    #[must_use]
     pub const fn checked_sub_offset(self, rhs: FixedOffset) -> Option<NaiveDateTime> {
         let (time, days) = self.time.overflowing_sub_offset(rhs);
         let date = match days {
@@ -862,6 +1019,31 @@ impl NaiveDateTime {
         DelayedFormat::new(Some(self.date), Some(self.time), items)
     }
 
+    /// Writes the combined date and time formatted with the specified formatting items into `w`.
+    ///
+    /// This writes directly into `w` without allocating an intermediate `String`, which is useful
+    /// to reuse a buffer. The items can be parsed once with [`StrftimeItems::parse`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use chrono::format::StrftimeItems;
+    /// use chrono::NaiveDate;
+    ///
+    /// let items = StrftimeItems::new("%Y-%m-%d %H:%M:%S").parse().unwrap();
+    /// let dt = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap().and_hms_opt(9, 30, 0).unwrap();
+    ///
+    /// let mut buf = String::with_capacity(32);
+    /// dt.format_into(&mut buf, &items).unwrap();
+    /// assert_eq!(buf, "2024-01-15 09:30:00");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn format_into(&self, w: &mut impl fmt::Write, items: &[Item<'_>]) -> fmt::Result {
+        write_items(w, Some(self.date), Some(self.time), None, items.iter())
+    }
+
     /// Formats the combined date and time with the specified format string.
     /// See the [`format::strftime` module](crate::format::strftime)
     /// on the supported escape sequences.
@@ -923,6 +1105,46 @@ impl NaiveDateTime {
         tz.from_local_datetime(self)
     }
 
+    /// Converts the `NaiveDateTime` into a timezone-aware `DateTime<Tz>` with the provided
+    /// time zone, picking the earliest result if the local time is ambiguous.
+    ///
+    /// This is a shortcut for `self.and_local_timezone(tz).earliest()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the local time falls in a _gap_ in the local time of `tz`, or if there
+    /// was an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, NaiveDate};
+    /// let tz = FixedOffset::east_opt(5 * 3600).unwrap();
+    /// let naive = NaiveDate::from_ymd_opt(2015, 9, 5).unwrap().and_hms_opt(23, 56, 4).unwrap();
+    /// let dt = naive.and_local_timezone_earliest(tz).unwrap();
+    /// assert_eq!(dt.naive_local(), naive);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn and_local_timezone_earliest<Tz: TimeZone>(&self, tz: Tz) -> Option<DateTime<Tz>> {
+        tz.from_local_datetime(self).earliest()
+    }
+
+    /// Converts the `NaiveDateTime` into a timezone-aware `DateTime<Tz>` with the provided
+    /// time zone, picking the latest result if the local time is ambiguous.
+    ///
+    /// This is a shortcut for `self.and_local_timezone(tz).latest()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the local time falls in a _gap_ in the local time of `tz`, or if there
+    /// was an error.
+    #[inline]
+    #[must_use]
+    pub fn and_local_timezone_latest<Tz: TimeZone>(&self, tz: Tz) -> Option<DateTime<Tz>> {
+        tz.from_local_datetime(self).latest()
+    }
+
     /// Converts the `NaiveDateTime` into the timezone-aware `DateTime<Utc>`.
     ///
     /// # Example
@@ -938,6 +1160,164 @@ impl NaiveDateTime {
         DateTime::from_naive_utc_and_offset(*self, Utc)
     }
 
+    /// Converts the `NaiveDateTime` into a `DateTime<Local>` in the system time zone.
+    ///
+    /// This is a shortcut for `self.and_local_timezone(Local)`. The result is a
+    /// [`MappedLocalTime`], so a local time that is ambiguous or does not exist, for example
+    /// during a daylight saving time transition, is reported instead of causing a panic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Local, NaiveDate};
+    ///
+    /// let naive = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap().and_hms_opt(9, 0, 0).unwrap();
+    /// let mapped = naive.and_local();
+    /// assert_eq!(mapped, naive.and_local_timezone(Local));
+    /// if let Some(dt) = mapped.earliest() {
+    ///     assert_eq!(dt.naive_local(), naive);
+    /// }
+    /// ```
+    #[cfg(feature = "clock")]
+    #[must_use]
+    pub fn and_local(&self) -> MappedLocalTime<DateTime<Local>> {
+        Local.from_local_datetime(self)
+    }
+
+    /// Returns the first moment of the day, at midnight.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap().and_hms_opt(12, 34, 56).unwrap();
+    /// assert_eq!(dt.beginning_of_day().to_string(), "2024-05-15 00:00:00");
+    /// assert_eq!(dt.end_of_day().to_string(), "2024-05-15 23:59:59.999999999");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn beginning_of_day(&self) -> NaiveDateTime {
+        self.date.and_time(NaiveTime::MIN)
+    }
+
+    /// Returns the last moment of the day, one nanosecond before midnight.
+    ///
+    /// This is never a [leap second](NaiveTime#leap-second-handling).
+    #[inline]
+    #[must_use]
+    pub const fn end_of_day(&self) -> NaiveDateTime {
+        self.date.and_time(NaiveTime::MAX)
+    }
+
+    /// Returns the first moment of the week that starts on the [`Weekday`] `start`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the first day of the week falls out of range of `NaiveDate`, see
+    /// [`NaiveWeek::first_day`](crate::NaiveWeek::first_day).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// // 2024-05-15 is a Wednesday.
+    /// let dt = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap().and_hms_opt(12, 34, 56).unwrap();
+    /// assert_eq!(dt.beginning_of_week(Weekday::Mon).to_string(), "2024-05-13 00:00:00");
+    /// assert_eq!(dt.end_of_week(Weekday::Mon).to_string(), "2024-05-19 23:59:59.999999999");
+    /// assert_eq!(dt.beginning_of_week(Weekday::Sun).to_string(), "2024-05-12 00:00:00");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn beginning_of_week(&self, start: Weekday) -> NaiveDateTime {
+        self.date.week(start).first_day().and_time(NaiveTime::MIN)
+    }
+
+    /// Returns the last moment of the week that starts on the [`Weekday`] `start`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the last day of the week falls out of range of `NaiveDate`, see
+    /// [`NaiveWeek::last_day`](crate::NaiveWeek::last_day).
+    #[inline]
+    #[must_use]
+    pub const fn end_of_week(&self, start: Weekday) -> NaiveDateTime {
+        self.date.week(start).last_day().and_time(NaiveTime::MAX)
+    }
+
+    /// Returns the first moment of the month.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2024, 2, 15).unwrap().and_hms_opt(12, 34, 56).unwrap();
+    /// assert_eq!(dt.beginning_of_month().to_string(), "2024-02-01 00:00:00");
+    /// assert_eq!(dt.end_of_month().to_string(), "2024-02-29 23:59:59.999999999");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn beginning_of_month(&self) -> NaiveDateTime {
+        self.date.first_day_of_month().and_time(NaiveTime::MIN)
+    }
+
+    /// Returns the last moment of the month.
+    #[inline]
+    #[must_use]
+    pub const fn end_of_month(&self) -> NaiveDateTime {
+        self.date.last_day_of_month().and_time(NaiveTime::MAX)
+    }
+
+    /// Returns the first moment of the quarter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap().and_hms_opt(12, 34, 56).unwrap();
+    /// assert_eq!(dt.beginning_of_quarter().to_string(), "2024-04-01 00:00:00");
+    /// assert_eq!(dt.end_of_quarter().to_string(), "2024-06-30 23:59:59.999999999");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn beginning_of_quarter(&self) -> NaiveDateTime {
+        self.date.first_day_of_quarter().and_time(NaiveTime::MIN)
+    }
+
+    /// Returns the last moment of the quarter.
+    #[inline]
+    #[must_use]
+    pub const fn end_of_quarter(&self) -> NaiveDateTime {
+        self.date.last_day_of_quarter().and_time(NaiveTime::MAX)
+    }
+
+    /// Returns the first moment of the year.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap().and_hms_opt(12, 34, 56).unwrap();
+    /// assert_eq!(dt.beginning_of_year().to_string(), "2024-01-01 00:00:00");
+    /// assert_eq!(dt.end_of_year().to_string(), "2024-12-31 23:59:59.999999999");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn beginning_of_year(&self) -> NaiveDateTime {
+        self.date.first_day_of_year().and_time(NaiveTime::MIN)
+    }
+
+    /// Returns the last moment of the year.
+    #[inline]
+    #[must_use]
+    pub const fn end_of_year(&self) -> NaiveDateTime {
+        self.date.last_day_of_year().and_time(NaiveTime::MAX)
+    }
+
     /// The minimum possible `NaiveDateTime`.
     pub const MIN: Self = Self { date: NaiveDate::MIN, time: NaiveTime::MIN };
 
@@ -1,5 +1,8 @@
 use super::NaiveDateTime;
-use crate::{Datelike, FixedOffset, MappedLocalTime, NaiveDate, TimeDelta, Utc};
+use crate::format::ParseErrorKind;
+use crate::{
+    DateTime, Datelike, FixedOffset, MappedLocalTime, NaiveDate, TimeDelta, Timelike, Utc, Weekday,
+};
 
 #[test]
 fn test_datetime_add() {
@@ -228,6 +231,31 @@ fn test_datetime_parse_from_str() {
     );
 }
 
+#[test]
+fn test_datetime_parse_from_str_timestamp() {
+    let ymdhms =
+        |y, m, d, h, n, s| NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, n, s).unwrap();
+    let parse = NaiveDateTime::parse_from_str;
+    assert_eq!(parse("1609459200", "%s"), Ok(ymdhms(2021, 1, 1, 0, 0, 0)));
+    assert_eq!(parse("-1", "%s"), Ok(ymdhms(1969, 12, 31, 23, 59, 59)));
+    assert_eq!(parse("-86400", "%s"), Ok(ymdhms(1969, 12, 31, 0, 0, 0)));
+    assert_eq!(
+        DateTime::parse_from_str("1609459200", "%s").map(|dt| dt.to_utc()),
+        Ok(ymdhms(2021, 1, 1, 0, 0, 0).and_utc())
+    );
+
+    // Other fields must agree with the timestamp.
+    assert_eq!(parse("1609459200 2021-01-01", "%s %Y-%m-%d"), Ok(ymdhms(2021, 1, 1, 0, 0, 0)));
+    assert_eq!(
+        parse("1609459200 2020-12-31", "%s %Y-%m-%d").unwrap_err().kind(),
+        ParseErrorKind::Impossible
+    );
+    assert_eq!(
+        parse("1609459200 01:00", "%s %H:%M").unwrap_err().kind(),
+        ParseErrorKind::Impossible
+    );
+}
+
 #[test]
 fn test_datetime_parse_from_str_with_spaces() {
     let parse_from_str = NaiveDateTime::parse_from_str;
@@ -249,6 +277,14 @@ fn test_datetime_parse_from_str_with_spaces() {
     assert_eq!(parse_from_str("Aug 09 2013 23:54:35", "%b %d %Y %H:%M:%S "), Ok(dt));
     assert_eq!(parse_from_str("Aug 09 2013 23:54:35", " %b %d %Y %H:%M:%S"), Ok(dt));
     assert_eq!(parse_from_str("Aug 09 2013 23:54:35", "%b %d %Y %H:%M:%S\n"), Ok(dt));
+    // a single space in the format matches any run of whitespace
+    let noon = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    assert_eq!(parse_from_str("2024-01-01 12:00", "%Y-%m-%d %H:%M"), Ok(noon));
+    assert_eq!(parse_from_str("2024-01-01   12:00", "%Y-%m-%d %H:%M"), Ok(noon));
+    assert_eq!(parse_from_str("2024-01-01\t12:00", "%Y-%m-%d %H:%M"), Ok(noon));
+    assert_eq!(parse_from_str("2024-01-01 \t \n12:00", "%Y-%m-%d %H:%M"), Ok(noon));
+    assert_eq!(parse_from_str("2024-01-01   12:00", "%Y-%m-%d%t%H:%M"), Ok(noon));
+    assert_eq!(parse_from_str("2024-01-01   12:00", "%Y-%m-%d%n%H:%M"), Ok(noon));
     // with varying spaces - should fail
     // leading space in data
     assert!(parse_from_str(" Aug 09 2013 23:54:35", "%b %d %Y %H:%M:%S").is_err());
@@ -262,6 +298,19 @@ fn test_datetime_parse_from_str_with_spaces() {
     assert!(parse_from_str("Aug 09 2013 23:54:35 !!!", "%b %d %Y %H:%M:%S ").is_err());
 }
 
+#[test]
+fn test_datetime_parse_and_remainder() {
+    let dt = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(9, 30, 0).unwrap();
+    let parse = NaiveDateTime::parse_and_remainder;
+    assert_eq!(parse("2024-01-01 09:30:00 garbage", "%Y-%m-%d %H:%M:%S"), Ok((dt, " garbage")));
+    assert_eq!(parse("2024-01-01 09:30 garbage", "%Y-%m-%d %H:%M"), Ok((dt, " garbage")));
+    assert_eq!(parse("2024-01-01 09:30:00", "%Y-%m-%d %H:%M:%S"), Ok((dt, "")));
+    assert_eq!(
+        parse("2024-01-01 garbage", "%Y-%m-%d").map_err(|e| e.kind()),
+        Err(ParseErrorKind::NotEnough)
+    );
+}
+
 #[test]
 fn test_datetime_add_sub_invariant() {
     // issue #37
@@ -284,12 +333,132 @@ fn test_and_local_timezone() {
     assert_eq!(dt_offset.timezone(), offset_tz);
 }
 
+#[test]
+fn test_datetime_to_julian_date() {
+    let date = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+    let hms = |h, m, s| date.and_hms_opt(h, m, s).unwrap().to_julian_date();
+    assert_eq!(hms(12, 0, 0), 2_451_545.0);
+    assert_eq!(hms(0, 0, 0), 2_451_544.5);
+    assert_eq!(hms(6, 0, 0), 2_451_544.75);
+    assert_eq!(hms(18, 0, 0), 2_451_545.25);
+    assert!((hms(23, 59, 59) - 2_451_545.499_988_4).abs() < 1e-7);
+
+    let noon = date.and_hms_milli_opt(12, 0, 0, 500).unwrap().to_julian_date();
+    assert!((noon - (2_451_545.0 + 0.5 / 86_400.0)).abs() < 1e-7);
+    let leap = date.and_hms_milli_opt(23, 59, 59, 1_500).unwrap().to_julian_date();
+    assert!((leap - (2_451_545.5 + 0.5 / 86_400.0)).abs() < 1e-7);
+
+    let epoch = NaiveDate::from_ymd_opt(-4713, 11, 24).unwrap();
+    assert_eq!(epoch.and_hms_opt(12, 0, 0).unwrap().to_julian_date(), 0.0);
+    assert_eq!(epoch.and_hms_opt(0, 0, 0).unwrap().to_julian_date(), -0.5);
+}
+
 #[test]
 fn test_and_utc() {
     let ndt = NaiveDate::from_ymd_opt(2023, 1, 30).unwrap().and_hms_opt(19, 32, 33).unwrap();
     let dt_utc = ndt.and_utc();
     assert_eq!(dt_utc.naive_local(), ndt);
     assert_eq!(dt_utc.timezone(), Utc);
+
+    let ndt =
+        NaiveDate::from_ymd_opt(-44, 3, 15).unwrap().and_hms_nano_opt(23, 59, 59, 1_500).unwrap();
+    let dt_utc = ndt.and_utc();
+    assert_eq!((dt_utc.year(), dt_utc.month(), dt_utc.day()), (ndt.year(), ndt.month(), ndt.day()));
+    assert_eq!(
+        (dt_utc.hour(), dt_utc.minute(), dt_utc.second(), dt_utc.nanosecond()),
+        (23, 59, 59, 1_500)
+    );
+    assert_eq!(NaiveDateTime::MIN.and_utc(), DateTime::<Utc>::MIN_UTC);
+    assert_eq!(NaiveDateTime::MAX.and_utc(), DateTime::<Utc>::MAX_UTC);
+}
+
+#[test]
+fn test_and_local_timezone_dst() {
+    use crate::offset::dst_tester::DstTester;
+
+    let ymdhms =
+        |y, m, d, h, n, s| NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, n, s).unwrap();
+    let offsets = |ndt: NaiveDateTime| {
+        ndt.and_local_timezone(DstTester).map(|dt| {
+            assert_eq!(dt.naive_local(), ndt);
+            *dt.offset()
+        })
+    };
+    let winter = DstTester::winter_offset();
+    let summer = DstTester::summer_offset();
+
+    assert_eq!(offsets(ymdhms(2024, 1, 1, 12, 0, 0)), MappedLocalTime::Single(summer));
+    assert_eq!(offsets(ymdhms(2024, 7, 1, 12, 0, 0)), MappedLocalTime::Single(winter));
+
+    // Apr 15 01:00 to 02:00 happens twice
+    assert_eq!(offsets(ymdhms(2024, 4, 15, 0, 59, 59)), MappedLocalTime::Single(summer));
+    assert_eq!(offsets(ymdhms(2024, 4, 15, 1, 0, 0)), MappedLocalTime::Ambiguous(summer, winter));
+    assert_eq!(offsets(ymdhms(2024, 4, 15, 1, 59, 59)), MappedLocalTime::Ambiguous(summer, winter));
+    assert_eq!(offsets(ymdhms(2024, 4, 15, 2, 0, 0)), MappedLocalTime::Single(winter));
+
+    // Sep 15 02:00 to 03:00 is skipped
+    assert_eq!(offsets(ymdhms(2024, 9, 15, 1, 59, 59)), MappedLocalTime::Single(winter));
+    assert_eq!(offsets(ymdhms(2024, 9, 15, 2, 0, 0)), MappedLocalTime::None);
+    assert_eq!(offsets(ymdhms(2024, 9, 15, 2, 59, 59)), MappedLocalTime::None);
+    assert_eq!(offsets(ymdhms(2024, 9, 15, 3, 0, 0)), MappedLocalTime::Single(summer));
+}
+
+#[test]
+#[cfg(feature = "clock")]
+fn test_and_local() {
+    use crate::{Local, TimeZone};
+
+    let ymdhms =
+        |y, m, d, h, n, s| NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, n, s).unwrap();
+    for ndt in [
+        ymdhms(2024, 1, 1, 12, 0, 0),
+        ymdhms(2024, 7, 1, 12, 0, 0),
+        ymdhms(2024, 3, 31, 2, 30, 0),
+        ymdhms(2024, 10, 27, 2, 30, 0),
+        NaiveDateTime::UNIX_EPOCH,
+        NaiveDateTime::MIN,
+        NaiveDateTime::MAX,
+    ] {
+        let mapped = ndt.and_local();
+        assert_eq!(mapped, Local.from_local_datetime(&ndt));
+        match mapped {
+            MappedLocalTime::Single(dt) => assert_eq!(dt.naive_local(), ndt),
+            MappedLocalTime::Ambiguous(earliest, latest) => {
+                assert!(earliest < latest);
+                assert_eq!(earliest.naive_local(), ndt);
+                assert_eq!(latest.naive_local(), ndt);
+            }
+            MappedLocalTime::None => {}
+        }
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_subsec_matches_and_utc() {
+    let date = NaiveDate::from_ymd_opt(2016, 12, 31).unwrap();
+    for ndt in [
+        date.and_hms_nano_opt(12, 0, 0, 0).unwrap(),
+        date.and_hms_nano_opt(12, 0, 0, 123_456_789).unwrap(),
+        date.and_hms_nano_opt(23, 59, 59, 999_999_999).unwrap(),
+        date.and_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap(), // leap second
+    ] {
+        let dt = ndt.and_utc();
+        assert_eq!(ndt.subsec_millis(), dt.timestamp_subsec_millis());
+        assert_eq!(ndt.subsec_micros(), dt.timestamp_subsec_micros());
+        assert_eq!(ndt.subsec_nanos(), dt.timestamp_subsec_nanos());
+        assert_eq!(ndt.timestamp_subsec_millis(), dt.timestamp_subsec_millis());
+        assert_eq!(ndt.timestamp_subsec_micros(), dt.timestamp_subsec_micros());
+        assert_eq!(ndt.timestamp_subsec_nanos(), dt.timestamp_subsec_nanos());
+
+        // the offset does not change the fraction of the second
+        let dt = ndt.and_local_timezone(FixedOffset::west_opt(5 * 3600 + 1800).unwrap()).unwrap();
+        assert_eq!(ndt.subsec_nanos(), dt.timestamp_subsec_nanos());
+    }
+
+    let leap = date.and_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap();
+    assert_eq!((leap.subsec_millis(), leap.subsec_micros()), (1_500, 1_500_000));
+    assert_eq!(leap.and_utc().timestamp_subsec_nanos(), 1_500_000_000);
 }
 
 #[test]
@@ -349,6 +518,45 @@ fn test_checked_sub_offset() {
     assert_eq!(dt.checked_sub_offset(positive_offset), Some(dt - positive_offset));
 }
 
+#[test]
+fn test_checked_add_sub_offset_near_bounds() {
+    let max_east = FixedOffset::east_opt(86_399).unwrap();
+    let max_west = FixedOffset::west_opt(86_399).unwrap();
+    let max_date = NaiveDateTime::MAX.date();
+    let min_date = NaiveDateTime::MIN.date();
+
+    // a large positive offset near `NaiveDateTime::MAX`
+    let dt = max_date.and_hms_opt(0, 0, 0).unwrap();
+    assert_eq!(dt.checked_add_offset(max_east), max_date.and_hms_opt(23, 59, 59));
+    let dt = max_date.and_hms_opt(0, 0, 1).unwrap();
+    assert_eq!(dt.checked_add_offset(max_east), None);
+    assert_eq!(dt.checked_sub_offset(max_west), None);
+    let dt = max_date.pred_opt().unwrap().and_hms_opt(12, 0, 0).unwrap();
+    assert_eq!(dt.checked_add_offset(max_east), max_date.and_hms_opt(11, 59, 59));
+    assert_eq!(NaiveDateTime::MAX.checked_add_offset(max_east), None);
+    assert_eq!(
+        NaiveDateTime::MAX.checked_sub_offset(max_east),
+        Some(NaiveDateTime::MAX - max_east)
+    );
+
+    // and a large negative offset near `NaiveDateTime::MIN`
+    let dt = min_date.and_hms_opt(23, 59, 59).unwrap();
+    assert_eq!(dt.checked_add_offset(max_west), min_date.and_hms_opt(0, 0, 0));
+    let dt = min_date.and_hms_opt(23, 59, 58).unwrap();
+    assert_eq!(dt.checked_add_offset(max_west), None);
+    assert_eq!(dt.checked_sub_offset(max_east), None);
+    assert_eq!(NaiveDateTime::MIN.checked_add_offset(max_west), None);
+    assert_eq!(
+        NaiveDateTime::MIN.checked_add_offset(max_east),
+        Some(NaiveDateTime::MIN + max_east)
+    );
+
+    // a zero offset is always fine
+    let utc = FixedOffset::east_opt(0).unwrap();
+    assert_eq!(NaiveDateTime::MAX.checked_add_offset(utc), Some(NaiveDateTime::MAX));
+    assert_eq!(NaiveDateTime::MIN.checked_sub_offset(utc), Some(NaiveDateTime::MIN));
+}
+
 #[test]
 fn test_overflowing_add_offset() {
     let ymdhmsm = |y, m, d, h, mn, s, mi| {
@@ -396,6 +604,76 @@ fn test_and_timezone_min_max_dates() {
     }
 }
 
+#[test]
+fn test_beginning_end_of() {
+    let dt =
+        NaiveDate::from_ymd_opt(2023, 2, 15).unwrap().and_hms_milli_opt(12, 34, 56, 789).unwrap();
+    let start = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    let end = |y, m, d| {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_nano_opt(23, 59, 59, 999_999_999).unwrap()
+    };
+
+    assert_eq!(dt.beginning_of_day(), start(2023, 2, 15));
+    assert_eq!(dt.end_of_day(), end(2023, 2, 15));
+    assert_eq!(dt.beginning_of_week(Weekday::Mon), start(2023, 2, 13));
+    assert_eq!(dt.end_of_week(Weekday::Mon), end(2023, 2, 19));
+    assert_eq!(dt.beginning_of_week(Weekday::Sun), start(2023, 2, 12));
+    assert_eq!(dt.end_of_week(Weekday::Sun), end(2023, 2, 18));
+    assert_eq!(dt.beginning_of_month(), start(2023, 2, 1));
+    assert_eq!(dt.end_of_month(), end(2023, 2, 28));
+    assert_eq!(dt.beginning_of_quarter(), start(2023, 1, 1));
+    assert_eq!(dt.end_of_quarter(), end(2023, 3, 31));
+    assert_eq!(dt.beginning_of_year(), start(2023, 1, 1));
+    assert_eq!(dt.end_of_year(), end(2023, 12, 31));
+
+    for (y, m, last) in [(2024, 2, 29), (2100, 2, 28), (2000, 2, 29), (2024, 4, 30), (2024, 12, 31)]
+    {
+        assert_eq!(start(y, m, 10).end_of_month(), end(y, m, last));
+    }
+    assert_eq!(NaiveDateTime::MAX.end_of_day(), NaiveDateTime::MAX);
+    assert_eq!(NaiveDateTime::MAX.end_of_year(), NaiveDateTime::MAX);
+    assert_eq!(NaiveDateTime::MIN.beginning_of_year(), NaiveDateTime::MIN);
+}
+
+#[test]
+fn test_and_local_timezone_earliest_latest() {
+    use crate::offset::dst_tester::DstTester;
+
+    let ymdhm =
+        |m, d, h, n| NaiveDate::from_ymd_opt(2024, m, d).unwrap().and_hms_opt(h, n, 0).unwrap();
+
+    // ambiguous: Apr 15 01:00 to 02:00 happens first in summer time, then in winter time
+    let local = ymdhm(4, 15, 1, 30);
+    let earliest = local.and_local_timezone_earliest(DstTester).unwrap();
+    let latest = local.and_local_timezone_latest(DstTester).unwrap();
+    assert!(earliest < latest);
+    assert_eq!(earliest.naive_utc(), ymdhm(4, 14, 16, 30));
+    assert_eq!(latest.naive_utc(), ymdhm(4, 14, 17, 30));
+    assert_eq!(earliest.naive_local(), local);
+    assert_eq!(latest.naive_local(), local);
+    assert_eq!(local.and_local_timezone(DstTester).earliest(), Some(earliest.clone()));
+    assert_eq!(local.and_local_timezone(DstTester).latest(), Some(latest));
+    assert_eq!(local.and_local_timezone(DstTester).single(), None);
+
+    // single
+    for local in [ymdhm(4, 15, 0, 59), ymdhm(4, 15, 2, 0), ymdhm(4, 16, 1, 30)] {
+        let single = local.and_local_timezone(DstTester).single();
+        assert!(single.is_some());
+        assert_eq!(local.and_local_timezone_earliest(DstTester), single);
+        assert_eq!(local.and_local_timezone_latest(DstTester), single);
+    }
+
+    // gap: Sep 15 02:00 to 03:00 is skipped
+    let local = ymdhm(9, 15, 2, 30);
+    assert_eq!(local.and_local_timezone_earliest(DstTester), None);
+    assert_eq!(local.and_local_timezone_latest(DstTester), None);
+
+    // out of range
+    let offset = FixedOffset::west_opt(3600).unwrap();
+    assert_eq!(NaiveDateTime::MAX.and_local_timezone_earliest(offset), None);
+    assert_eq!(NaiveDateTime::MAX.and_local_timezone_latest(offset), None);
+}
+
 #[test]
 #[cfg(feature = "rkyv-validation")]
 fn test_rkyv_validation() {
@@ -64,7 +64,7 @@ impl IsoWeek {
     /// ```
     /// use chrono::{Datelike, NaiveDate, Weekday};
     ///
-    /// let d = NaiveDate::from_isoywd_opt(2015, 1, Weekday::Mon).unwrap();
+    /// let d = NaiveDate::from_iso_week(2015, 1, Weekday::Mon).unwrap();
     /// assert_eq!(d.iso_week().year(), 2015);
     /// ```
     ///
@@ -73,7 +73,7 @@ impl IsoWeek {
     ///
     /// ```
     /// # use chrono::{NaiveDate, Datelike, Weekday};
-    /// # let d = NaiveDate::from_isoywd_opt(2015, 1, Weekday::Mon).unwrap();
+    /// # let d = NaiveDate::from_iso_week(2015, 1, Weekday::Mon).unwrap();
     /// assert_eq!(d.year(), 2014);
     /// assert_eq!(d, NaiveDate::from_ymd_opt(2014, 12, 29).unwrap());
     /// ```
@@ -91,7 +91,7 @@ impl IsoWeek {
     /// ```
     /// use chrono::{Datelike, NaiveDate, Weekday};
     ///
-    /// let d = NaiveDate::from_isoywd_opt(2015, 15, Weekday::Mon).unwrap();
+    /// let d = NaiveDate::from_iso_week(2015, 15, Weekday::Mon).unwrap();
     /// assert_eq!(d.iso_week().week(), 15);
     /// ```
     #[inline]
@@ -108,7 +108,7 @@ impl IsoWeek {
     /// ```
     /// use chrono::{Datelike, NaiveDate, Weekday};
     ///
-    /// let d = NaiveDate::from_isoywd_opt(2015, 15, Weekday::Mon).unwrap();
+    /// let d = NaiveDate::from_iso_week(2015, 15, Weekday::Mon).unwrap();
     /// assert_eq!(d.iso_week().week0(), 14);
     /// ```
     #[inline]
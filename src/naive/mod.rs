@@ -6,8 +6,8 @@
 
 use core::ops::RangeInclusive;
 
-use crate::expect;
 use crate::Weekday;
+use crate::{expect, try_opt};
 
 pub(crate) mod date;
 pub(crate) mod datetime;
@@ -15,7 +15,10 @@ mod internals;
 pub(crate) mod isoweek;
 pub(crate) mod time;
 
-pub use self::date::{NaiveDate, NaiveDateDaysIterator, NaiveDateWeeksIterator};
+pub use self::date::{
+    NaiveDate, NaiveDateBusinessDaysIterator, NaiveDateDaysIterator, NaiveDateMonthsIterator,
+    NaiveDateStepIterator, NaiveDateWeeksIterator,
+};
 #[allow(deprecated)]
 pub use self::date::{MAX_DATE, MIN_DATE};
 #[allow(deprecated)]
@@ -29,7 +32,7 @@ pub use self::internals::YearFlags as __BenchYearFlags;
 
 /// A week represented by a [`NaiveDate`] and a [`Weekday`] which is the first
 /// day of the week.
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 pub struct NaiveWeek {
     date: NaiveDate,
     start: Weekday,
@@ -60,13 +63,18 @@ impl NaiveWeek {
     #[inline]
     #[must_use]
     pub const fn first_day(&self) -> NaiveDate {
+        expect(self.checked_first_day(), "first weekday out of range for `NaiveDate`")
+    }
+
+    /// Returns the first day of the week, or `None` if it is out of range of `NaiveDate`.
+    const fn checked_first_day(&self) -> Option<NaiveDate> {
         let start = self.start.num_days_from_monday() as i32;
         let ref_day = self.date.weekday().num_days_from_monday() as i32;
         // Calculate the number of days to subtract from `self.date`.
         // Do not construct an intermediate date beyond `self.date`, because that may be out of
         // range if `date` is close to `NaiveDate::MAX`.
         let days = start - ref_day - if start > ref_day { 7 } else { 0 };
-        expect(self.date.add_days(days), "first weekday out of range for `NaiveDate`")
+        self.date.add_days(days)
     }
 
     /// Returns a date representing the last day of the week.
@@ -88,13 +96,18 @@ impl NaiveWeek {
     #[inline]
     #[must_use]
     pub const fn last_day(&self) -> NaiveDate {
+        expect(self.checked_last_day(), "last weekday out of range for `NaiveDate`")
+    }
+
+    /// Returns the last day of the week, or `None` if it is out of range of `NaiveDate`.
+    const fn checked_last_day(&self) -> Option<NaiveDate> {
         let end = self.start.pred().num_days_from_monday() as i32;
         let ref_day = self.date.weekday().num_days_from_monday() as i32;
         // Calculate the number of days to add to `self.date`.
         // Do not construct an intermediate date before `self.date` (like with `first_day()`),
         // because that may be out of range if `date` is close to `NaiveDate::MIN`.
         let days = end - ref_day + if end < ref_day { 7 } else { 0 };
-        expect(self.date.add_days(days), "last weekday out of range for `NaiveDate`")
+        self.date.add_days(days)
     }
 
     /// Returns a [`RangeInclusive<T>`] representing the whole week bounded by
@@ -120,6 +133,101 @@ impl NaiveWeek {
     pub const fn days(&self) -> RangeInclusive<NaiveDate> {
         self.first_day()..=self.last_day()
     }
+
+    /// Returns an iterator over the seven days of the week, starting with
+    /// [first_day](NaiveWeek::first_day).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the first day of the week happens to fall just out of range of `NaiveDate`
+    /// (more than ca. 262,000 years away from common era). If only the last day of the week is out
+    /// of range the iterator stops at `NaiveDate::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate, Weekday};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2022, 4, 18).unwrap();
+    /// let mut days = date.week(Weekday::Sun).iter_days();
+    /// assert_eq!(days.next(), NaiveDate::from_ymd_opt(2022, 4, 17));
+    /// assert_eq!(days.last().map(|d| d.weekday()), Some(Weekday::Sat));
+    /// ```
+    #[inline]
+    pub const fn iter_days(&self) -> NaiveDateStepIterator {
+        let first = self.first_day();
+        let len = match self.checked_last_day() {
+            Some(_) => 7,
+            None => NaiveDate::MAX.signed_duration_since(first).num_days() as u32 + 1,
+        };
+        NaiveDateStepIterator::days(first, len)
+    }
+
+    /// Returns `true` if `date` falls within this week.
+    ///
+    /// This never panics, even if the week is partially out of range of `NaiveDate`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// let week = NaiveDate::from_ymd_opt(2022, 4, 18).unwrap().week(Weekday::Mon);
+    /// assert!(week.contains(NaiveDate::from_ymd_opt(2022, 4, 24).unwrap()));
+    /// assert!(!week.contains(NaiveDate::from_ymd_opt(2022, 4, 25).unwrap()));
+    /// ```
+    #[must_use]
+    pub const fn contains(&self, date: NaiveDate) -> bool {
+        let offset = self.date.weekday().days_since(self.start) as i64;
+        let days = date.signed_duration_since(self.date).num_days() + offset;
+        days >= 0 && days < 7
+    }
+
+    /// Returns the next week, with the same first day of the week.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if any day of the next week is out of range of `NaiveDate`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// let week = NaiveDate::from_ymd_opt(2022, 4, 18).unwrap().week(Weekday::Mon);
+    /// let next = week.succ().unwrap();
+    /// assert_eq!(next.first_day(), NaiveDate::from_ymd_opt(2022, 4, 25).unwrap());
+    /// assert!(NaiveDate::MAX.week(Weekday::Mon).succ().is_none());
+    /// ```
+    #[must_use]
+    pub const fn succ(&self) -> Option<NaiveWeek> {
+        let first = try_opt!(try_opt!(self.checked_last_day()).add_days(1));
+        try_opt!(first.add_days(6));
+        Some(NaiveWeek::new(first, self.start))
+    }
+
+    /// Returns the previous week, with the same first day of the week.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if any day of the previous week is out of range of `NaiveDate`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// let week = NaiveDate::from_ymd_opt(2022, 4, 18).unwrap().week(Weekday::Mon);
+    /// let prev = week.pred().unwrap();
+    /// assert_eq!(prev.last_day(), NaiveDate::from_ymd_opt(2022, 4, 17).unwrap());
+    /// assert!(NaiveDate::MIN.week(Weekday::Mon).pred().is_none());
+    /// ```
+    #[must_use]
+    pub const fn pred(&self) -> Option<NaiveWeek> {
+        let last = try_opt!(try_opt!(self.checked_first_day()).add_days(-1));
+        try_opt!(last.add_days(-6));
+        Some(NaiveWeek::new(last, self.start))
+    }
 }
 
 /// A duration in calendar days.
@@ -136,6 +244,75 @@ impl Days {
     pub const fn new(num: u64) -> Self {
         Self(num)
     }
+
+    /// Returns the total number of days in the `Days` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::Days;
+    ///
+    /// assert_eq!(Days::new(45).num_days(), 45);
+    /// ```
+    #[inline]
+    pub const fn num_days(&self) -> u64 {
+        self.0
+    }
+}
+
+/// A difference between two dates in calendar terms: years, months and days.
+///
+/// This is the result of [`NaiveDate::calendar_difference`]. All fields have the same sign: they
+/// are all zero or positive if the first date is later, and zero or negative otherwise.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// let birth = NaiveDate::from_ymd_opt(2021, 11, 10).unwrap();
+/// let today = NaiveDate::from_ymd_opt(2024, 2, 15).unwrap();
+/// let age = today.calendar_difference(birth);
+/// assert_eq!((age.years(), age.months(), age.days()), (2, 3, 5));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct CalendarDuration {
+    years: i32,
+    months: i32,
+    days: i32,
+}
+
+impl CalendarDuration {
+    /// Returns the number of whole years.
+    #[inline]
+    pub const fn years(&self) -> i32 {
+        self.years
+    }
+
+    /// Returns the number of whole months after the years, which is less than 12 in magnitude.
+    #[inline]
+    pub const fn months(&self) -> i32 {
+        self.months
+    }
+
+    /// Returns the number of days after the years and months, which is less than 31 in
+    /// magnitude.
+    #[inline]
+    pub const fn days(&self) -> i32 {
+        self.days
+    }
+
+    /// Returns `true` if the duration is negative.
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.years < 0 || self.months < 0 || self.days < 0
+    }
+
+    /// Returns the duration with the signs of all fields flipped.
+    #[inline]
+    pub(crate) const fn neg(self) -> CalendarDuration {
+        CalendarDuration { years: -self.years, months: -self.months, days: -self.days }
+    }
 }
 
 /// Serialization/Deserialization of `NaiveDateTime` in alternate formats
@@ -151,7 +328,7 @@ pub mod serde {
 
 #[cfg(test)]
 mod test {
-    use crate::{NaiveDate, Weekday};
+    use crate::{Days, NaiveDate, Weekday};
     #[test]
     fn test_naiveweek() {
         let date = NaiveDate::from_ymd_opt(2022, 5, 18).unwrap();
@@ -173,6 +350,60 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_days_num_days() {
+        for n in [0, 1, 45, 365, u64::MAX] {
+            assert_eq!(Days::new(n).num_days(), n);
+        }
+    }
+
+    #[test]
+    fn test_naiveweek_succ_pred() {
+        let date = NaiveDate::from_ymd_opt(2022, 5, 18).unwrap();
+        for start in Weekday::Mon.iter_from().take(7) {
+            let week = date.week(start);
+            let next = week.succ().unwrap();
+            let prev = week.pred().unwrap();
+            assert_eq!(next.first_day(), week.first_day() + Days::new(7));
+            assert_eq!(next.last_day(), week.last_day() + Days::new(7));
+            assert_eq!(prev.first_day(), week.first_day() - Days::new(7));
+            assert_eq!(next.pred().unwrap().first_day(), week.first_day());
+            assert_eq!(prev.succ().unwrap().last_day(), week.last_day());
+            assert_eq!(next.first_day().weekday(), start);
+        }
+
+        // the week containing `MAX` has no successor, and the week before it may be partial
+        let last_week = NaiveDate::MAX.week(Weekday::Mon);
+        assert!(last_week.succ().is_none());
+        let week = (NaiveDate::MAX - Days::new(7)).week(Weekday::Mon);
+        assert!(week.succ().is_none());
+        let week = (NaiveDate::MAX - Days::new(14)).week(Weekday::Mon);
+        assert_eq!(week.succ().unwrap().last_day(), week.last_day() + Days::new(7));
+        assert!(NaiveDate::MIN.week(Weekday::Sun).pred().is_none());
+        assert!((NaiveDate::MIN + Days::new(7)).week(Weekday::Sun).pred().is_none());
+    }
+
+    #[test]
+    fn test_naiveweek_contains_iter_days() {
+        let date = NaiveDate::from_ymd_opt(2022, 5, 18).unwrap();
+        for start in Weekday::Mon.iter_from().take(7) {
+            let week = date.week(start);
+            let days: Vec<_> = week.iter_days().collect();
+            assert_eq!(days.len(), 7);
+            assert_eq!(days[0], week.first_day());
+            assert_eq!(days[6], week.last_day());
+            assert!(days.iter().all(|&d| week.contains(d)));
+            assert!(!week.contains(week.first_day().pred_opt().unwrap()));
+            assert!(!week.contains(week.last_day().succ_opt().unwrap()));
+        }
+
+        let week = NaiveDate::MAX.week(Weekday::Mon);
+        assert!(week.contains(NaiveDate::MAX));
+        assert!(!week.contains(NaiveDate::MIN));
+        assert_eq!(week.iter_days().next_back(), Some(NaiveDate::MAX));
+        assert!(NaiveDate::MIN.week(Weekday::Mon).contains(NaiveDate::MIN));
+    }
+
     #[test]
     fn test_naiveweek_min_max() {
         let date_max = NaiveDate::MAX;
@@ -15,14 +15,15 @@ use rkyv::{Archive, Deserialize, Serialize};
 #[cfg(feature = "alloc")]
 use crate::format::DelayedFormat;
 use crate::format::{
-    parse, parse_and_remainder, write_hundreds, Fixed, Item, Numeric, Pad, ParseError, ParseResult,
-    Parsed, StrftimeItems,
+    parse, parse_and_remainder, write_hundreds, write_items, Fixed, Item, Numeric, Pad, ParseError,
+    ParseResult, Parsed, StrftimeItems,
 };
+use crate::round::{RoundDirection, RoundingError};
 use crate::{expect, try_opt};
 use crate::{FixedOffset, TimeDelta, Timelike};
 
 #[cfg(feature = "serde")]
-mod serde;
+pub(crate) mod serde;
 
 #[cfg(test)]
 mod tests;
@@ -482,6 +483,45 @@ impl NaiveTime {
         Some(NaiveTime { secs, frac: nano })
     }
 
+    /// Makes a new `NaiveTime` from the time elapsed since midnight.
+    ///
+    /// This is the reverse of [`NaiveTime::duration_since_midnight`]. A duration from 86,400 up to
+    /// 86,401 seconds (exclusive) gives a [leap second](#leap-second-handling) after 23:59:59.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `duration` is negative or not less than 86,401 seconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveTime, TimeDelta};
+    ///
+    /// let from_duration = NaiveTime::from_duration_since_midnight;
+    ///
+    /// assert_eq!(from_duration(TimeDelta::zero()), Some(NaiveTime::MIN));
+    /// assert_eq!(from_duration(TimeDelta::minutes(90)), NaiveTime::from_hms_opt(1, 30, 0));
+    /// assert_eq!(
+    ///     from_duration(TimeDelta::milliseconds(86_400_500)),
+    ///     NaiveTime::from_hms_milli_opt(23, 59, 59, 1_500)
+    /// );
+    /// assert_eq!(from_duration(TimeDelta::seconds(86_401)), None);
+    /// assert_eq!(from_duration(TimeDelta::nanoseconds(-1)), None);
+    /// ```
+    #[must_use]
+    pub const fn from_duration_since_midnight(duration: TimeDelta) -> Option<NaiveTime> {
+        let secs = duration.num_seconds();
+        let nanos = duration.subsec_nanos();
+        if secs < 0 || nanos < 0 {
+            return None;
+        }
+        match secs {
+            0..=86_399 => Some(NaiveTime { secs: secs as u32, frac: nanos as u32 }),
+            86_400 => Some(NaiveTime { secs: 86_399, frac: nanos as u32 + 1_000_000_000 }),
+            _ => None,
+        }
+    }
+
     /// Parses a string with the specified format string and returns a new `NaiveTime`.
     /// See the [`format::strftime` module](crate::format::strftime)
     /// on the supported escape sequences.
@@ -578,6 +618,51 @@ impl NaiveTime {
         parsed.to_naive_time().map(|t| (t, remainder))
     }
 
+    /// Parses a time on the 12-hour clock followed by `AM` or `PM`, such as `1:30 PM` or
+    /// `11:59:59.5 pm`.
+    ///
+    /// The hour is between 1 and 12, and may omit its leading zero. Seconds and a fraction of a
+    /// second are optional. The meridiem is case-insensitive, and may be preceded by whitespace.
+    /// `12 AM` is midnight and `12 PM` is noon.
+    ///
+    /// This is a shorthand for [`parse_from_str`](#method.parse_from_str) with the formats
+    /// `%I:%M %p` and `%I:%M:%S%.f %p`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveTime;
+    /// let from_hms = |h, m, s| NaiveTime::from_hms_opt(h, m, s).unwrap();
+    ///
+    /// assert_eq!(NaiveTime::parse_12h("1:30 PM"), Ok(from_hms(13, 30, 0)));
+    /// assert_eq!(NaiveTime::parse_12h("12:00 AM"), Ok(from_hms(0, 0, 0)));
+    /// assert_eq!(NaiveTime::parse_12h("12:00 PM"), Ok(from_hms(12, 0, 0)));
+    /// assert_eq!(NaiveTime::parse_12h("11:59:59 pm"), Ok(from_hms(23, 59, 59)));
+    /// assert!(NaiveTime::parse_12h("13:00 PM").is_err());
+    /// ```
+    pub fn parse_12h(s: &str) -> ParseResult<NaiveTime> {
+        const HOUR_AND_MINUTE: &[Item<'static>] = &[
+            Item::Space(""),
+            Item::Numeric(Numeric::Hour12, Pad::Zero),
+            Item::Literal(":"),
+            Item::Numeric(Numeric::Minute, Pad::Zero),
+        ];
+        const SECOND_AND_NANOS: &[Item<'static>] = &[
+            Item::Literal(":"),
+            Item::Numeric(Numeric::Second, Pad::Zero),
+            Item::Fixed(Fixed::Nanosecond),
+        ];
+        const MERIDIEM: &[Item<'static>] =
+            &[Item::Space(""), Item::Fixed(Fixed::UpperAmPm), Item::Space("")];
+
+        let mut parsed = Parsed::new();
+        let s = parse_and_remainder(&mut parsed, s, HOUR_AND_MINUTE.iter())?;
+        // Seconds are optional, don't fail if parsing them doesn't succeed.
+        let s = parse_and_remainder(&mut parsed, s, SECOND_AND_NANOS.iter()).unwrap_or(s);
+        parse(&mut parsed, s, MERIDIEM.iter())?;
+        parsed.to_naive_time()
+    }
+
     /// Adds given `TimeDelta` to the current time, and also returns the number of *seconds*
     /// in the integral number of days ignored from the addition.
     ///
@@ -765,6 +850,152 @@ impl NaiveTime {
         expect(TimeDelta::new(secs + secs_from_frac, frac), "must be in range")
     }
 
+    /// Returns the time elapsed since midnight.
+    ///
+    /// A [leap second](#leap-second-handling) counts as an extra second, so 23:59:60 gives
+    /// 86,400 seconds. A leap second at the end of another minute gives the same duration as the
+    /// start of the following minute.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveTime, TimeDelta};
+    ///
+    /// let time = NaiveTime::from_hms_milli_opt(1, 30, 15, 250).unwrap();
+    /// assert_eq!(time.duration_since_midnight(), TimeDelta::milliseconds(5_415_250));
+    /// assert_eq!(NaiveTime::MIN.duration_since_midnight(), TimeDelta::zero());
+    ///
+    /// let leap = NaiveTime::from_hms_milli_opt(23, 59, 59, 1_500).unwrap();
+    /// assert_eq!(leap.duration_since_midnight(), TimeDelta::milliseconds(86_400_500));
+    /// ```
+    #[must_use]
+    pub const fn duration_since_midnight(&self) -> TimeDelta {
+        let secs = self.secs as i64 + (self.frac / 1_000_000_000) as i64;
+        expect(TimeDelta::new(secs, self.frac % 1_000_000_000), "always in range")
+    }
+
+    /// Rounds the time of day to the nearest multiple of `interval`, counted from midnight.
+    ///
+    /// Halfway values are rounded up. The date is not taken into account, so rounding up past the
+    /// end of the day wraps around to midnight: 23:58 rounded to the nearest hour is 00:00. This
+    /// also happens if the next multiple of `interval` would lie beyond midnight, for example when
+    /// `interval` doesn't evenly divide a day.
+    ///
+    /// A [leap second](#leap-second-handling) counts as an extra second at the end of the minute,
+    /// as in [`duration_since_midnight`](NaiveTime::duration_since_midnight). So a leap second at
+    /// the end of the day lies past midnight, and rounding it to the nearest multiple or up wraps
+    /// around to 00:00. [`NaiveTime::floor_to`] never moves a time forward, it rounds a leap second
+    /// down as if it were the last nanosecond of the second before.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RoundingError::DurationExceedsLimit`] if `interval` is zero or negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveTime, TimeDelta};
+    ///
+    /// let hm = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+    /// let quarter = TimeDelta::minutes(15);
+    /// assert_eq!(hm(14, 37).round_to(quarter), Ok(hm(14, 30)));
+    /// assert_eq!(hm(14, 38).round_to(quarter), Ok(hm(14, 45)));
+    /// assert_eq!(hm(23, 58).round_to(TimeDelta::hours(1)), Ok(hm(0, 0)));
+    /// ```
+    pub fn round_to(&self, interval: TimeDelta) -> Result<NaiveTime, RoundingError> {
+        self.round_within_day(interval, RoundDirection::Nearest)
+    }
+
+    /// Rounds the time of day down to the preceding multiple of `interval`, counted from midnight.
+    ///
+    /// Times that are already a multiple of `interval` are returned unchanged.
+    /// See [`NaiveTime::round_to`] for details.
+    ///
+    /// The result is never later than `self`. A [leap second](#leap-second-handling) is rounded
+    /// down within the second it extends, so `23:59:60.5` floored to whole seconds is `23:59:59`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RoundingError::DurationExceedsLimit`] if `interval` is zero or negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveTime, TimeDelta};
+    ///
+    /// let hm = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+    /// assert_eq!(hm(14, 44).floor_to(TimeDelta::minutes(15)), Ok(hm(14, 30)));
+    ///
+    /// let leap = NaiveTime::from_hms_milli_opt(23, 59, 59, 1_500).unwrap();
+    /// let second = NaiveTime::from_hms_opt(23, 59, 59).unwrap();
+    /// assert_eq!(leap.floor_to(TimeDelta::seconds(1)), Ok(second));
+    /// ```
+    pub fn floor_to(&self, interval: TimeDelta) -> Result<NaiveTime, RoundingError> {
+        self.round_within_day(interval, RoundDirection::Floor)
+    }
+
+    /// Rounds the time of day up to the following multiple of `interval`, counted from midnight.
+    ///
+    /// Times that are already a multiple of `interval` are returned unchanged. Rounding up past
+    /// the end of the day wraps around to midnight. See [`NaiveTime::round_to`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RoundingError::DurationExceedsLimit`] if `interval` is zero or negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveTime, TimeDelta};
+    ///
+    /// let hm = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+    /// assert_eq!(hm(14, 31).ceil_to(TimeDelta::minutes(15)), Ok(hm(14, 45)));
+    /// assert_eq!(hm(23, 50).ceil_to(TimeDelta::minutes(15)), Ok(hm(0, 0)));
+    /// ```
+    pub fn ceil_to(&self, interval: TimeDelta) -> Result<NaiveTime, RoundingError> {
+        self.round_within_day(interval, RoundDirection::Ceil)
+    }
+
+    fn round_within_day(
+        &self,
+        interval: TimeDelta,
+        direction: RoundDirection,
+    ) -> Result<NaiveTime, RoundingError> {
+        const NANOS_PER_SEC: i128 = 1_000_000_000;
+        const NANOS_PER_DAY: i128 = 86_400 * NANOS_PER_SEC;
+
+        if interval <= TimeDelta::zero() {
+            return Err(RoundingError::DurationExceedsLimit);
+        }
+        let span = interval.num_seconds() as i128 * NANOS_PER_SEC + interval.subsec_nanos() as i128;
+        let stamp = match direction {
+            // Keep a leap second within the second it extends, so flooring never moves forward.
+            RoundDirection::Floor => {
+                self.secs as i128 * NANOS_PER_SEC + self.frac.min(999_999_999) as i128
+            }
+            _ => {
+                let since_midnight = self.duration_since_midnight();
+                since_midnight.num_seconds() as i128 * NANOS_PER_SEC
+                    + since_midnight.subsec_nanos() as i128
+            }
+        };
+
+        let delta_down = stamp % span;
+        let rounded = match direction {
+            _ if delta_down == 0 => stamp,
+            RoundDirection::Floor => stamp - delta_down,
+            RoundDirection::Ceil => stamp - delta_down + span,
+            RoundDirection::Nearest if delta_down >= span - delta_down => stamp - delta_down + span,
+            RoundDirection::Nearest => stamp - delta_down,
+        };
+        // Anything at or beyond the next midnight wraps around to midnight.
+        let rounded = if rounded >= NANOS_PER_DAY { 0 } else { rounded };
+        Ok(NaiveTime {
+            secs: (rounded / NANOS_PER_SEC) as u32,
+            frac: (rounded % NANOS_PER_SEC) as u32,
+        })
+    }
+
     /// Adds given `FixedOffset` to the current time, and returns the number of days that should be
     /// added to a date as a result of the offset (either `-1`, `0`, or `1` because the offset is
     /// always less than 24h).
@@ -791,6 +1022,43 @@ impl NaiveTime {
         (NaiveTime { secs: secs as u32, frac: self.frac }, days)
     }
 
+    /// Makes a new `NaiveTime` with the nanoseconds changed, only allowing a leap second at the
+    /// end of the day.
+    ///
+    /// [`Timelike::with_nanosecond`] accepts values of 1,000,000,000 or more to represent a
+    /// [leap second](#leap-second-handling) after *any* whole second, because a time in a time
+    /// zone with an unusual offset can have a leap second at that time. Leap seconds are only
+    /// inserted at the end of a UTC day however, so for a `NaiveTime` that is known to be in UTC
+    /// a leap second anywhere else is most likely an error in the input.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `nano >= 2,000,000,000`, or if `nano >= 1,000,000,000` and the time is
+    /// not 23:59:59.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveTime, Timelike};
+    ///
+    /// let end_of_day = NaiveTime::from_hms_opt(23, 59, 59).unwrap();
+    /// let leap = end_of_day.with_nanosecond_strict(1_500_000_000).unwrap();
+    /// assert_eq!(leap.nanosecond(), 1_500_000_000);
+    ///
+    /// let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+    /// assert_eq!(noon.with_nanosecond_strict(1_500_000_000), None);
+    /// assert!(noon.with_nanosecond(1_500_000_000).is_some());
+    /// assert_eq!(noon.with_nanosecond_strict(500_000_000), noon.with_nanosecond(500_000_000));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_nanosecond_strict(&self, nano: u32) -> Option<NaiveTime> {
+        if nano >= 2_000_000_000 || (nano >= 1_000_000_000 && self.secs != NaiveTime::MAX.secs) {
+            return None;
+        }
+        Some(NaiveTime { frac: nano, ..*self })
+    }
+
     /// Formats the time with the specified formatting items.
     /// Otherwise it is the same as the ordinary [`format`](#method.format) method.
     ///
@@ -829,6 +1097,31 @@ impl NaiveTime {
         DelayedFormat::new(None, Some(*self), items)
     }
 
+    /// Writes the time formatted with the specified formatting items into `w`.
+    ///
+    /// This writes directly into `w` without allocating an intermediate `String`, which is useful
+    /// to reuse a buffer. The items can be parsed once with [`StrftimeItems::parse`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use chrono::format::StrftimeItems;
+    /// use chrono::NaiveTime;
+    ///
+    /// let items = StrftimeItems::new("%H:%M:%S").parse().unwrap();
+    /// let time = NaiveTime::from_hms_opt(9, 30, 0).unwrap();
+    ///
+    /// let mut buf = String::with_capacity(16);
+    /// time.format_into(&mut buf, &items).unwrap();
+    /// assert_eq!(buf, "09:30:00");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn format_into(&self, w: &mut impl fmt::Write, items: &[Item<'_>]) -> fmt::Result {
+        write_items(w, None, Some(*self), None, items.iter())
+    }
+
     /// Formats the time with the specified format string.
     /// See the [`format::strftime` module](crate::format::strftime)
     /// on the supported escape sequences.
@@ -870,6 +1163,64 @@ impl NaiveTime {
         self.format_with_items(StrftimeItems::new(fmt))
     }
 
+    /// Returns the number of whole milliseconds since the last whole non-leap second.
+    ///
+    /// The return value ranges from 0 to 999, or for [leap seconds](#leap-second-handling), to
+    /// 1,999.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveTime;
+    ///
+    /// let t = NaiveTime::from_hms_nano_opt(23, 56, 4, 12_345_678).unwrap();
+    /// assert_eq!(t.subsec_millis(), 12);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn subsec_millis(&self) -> u32 {
+        self.frac / 1_000_000
+    }
+
+    /// Returns the number of whole microseconds since the last whole non-leap second.
+    ///
+    /// The return value ranges from 0 to 999,999, or for [leap seconds](#leap-second-handling),
+    /// to 1,999,999.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveTime;
+    ///
+    /// let t = NaiveTime::from_hms_nano_opt(23, 56, 4, 12_345_678).unwrap();
+    /// assert_eq!(t.subsec_micros(), 12_345);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn subsec_micros(&self) -> u32 {
+        self.frac / 1_000
+    }
+
+    /// Returns the number of nanoseconds since the last whole non-leap second.
+    ///
+    /// This is the same as [`Timelike::nanosecond`], but usable in `const` contexts. The return
+    /// value ranges from 0 to 999,999,999, or for [leap seconds](#leap-second-handling), to
+    /// 1,999,999,999.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveTime;
+    ///
+    /// let t = NaiveTime::from_hms_nano_opt(23, 56, 4, 12_345_678).unwrap();
+    /// assert_eq!(t.subsec_nanos(), 12_345_678);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn subsec_nanos(&self) -> u32 {
+        self.frac
+    }
+
     /// Returns a triple of the hour, minute and second numbers.
     pub(crate) fn hms(&self) -> (u32, u32, u32) {
         let sec = self.secs % 60;
@@ -896,7 +1247,7 @@ impl NaiveTime {
 
     /// The earliest possible `NaiveTime`
     pub const MIN: Self = Self { secs: 0, frac: 0 };
-    pub(super) const MAX: Self = Self { secs: 23 * 3600 + 59 * 60 + 59, frac: 999_999_999 };
+    pub(crate) const MAX: Self = Self { secs: 23 * 3600 + 59 * 60 + 59, frac: 999_999_999 };
 }
 
 impl Timelike for NaiveTime {
@@ -40,6 +40,102 @@ impl<'de> de::Deserialize<'de> for NaiveTime {
     }
 }
 
+/// Ser/de a `NaiveTime` to/from an integer number of nanoseconds since midnight.
+///
+/// Intended for use with `serde`'s `with` attribute.
+///
+/// A leap second counts as the start of the next second, as in
+/// [`NaiveTime::duration_since_midnight`](crate::NaiveTime::duration_since_midnight). A leap
+/// second at the end of the day (`23:59:60`) is serialized as a value of 86,400 seconds or more,
+/// and deserializes as a leap second again. A leap second at any other time can't be told apart
+/// from the start of the next second, and deserializes as that.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::NaiveTime;
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::naive_time_as_nanos;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "naive_time_as_nanos")]
+///     time: NaiveTime,
+/// }
+///
+/// let my_s = S { time: NaiveTime::from_hms_milli_opt(0, 0, 1, 500).unwrap() };
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":1500000000}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, NaiveTime::from_hms_milli_opt(0, 0, 1, 500).unwrap());
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod naive_time_as_nanos {
+    use core::fmt;
+    use serde::{de, ser};
+
+    use crate::NaiveTime;
+
+    const NANOS_PER_SEC: u64 = 1_000_000_000;
+
+    /// Serialize a `NaiveTime` as an integer number of nanoseconds since midnight.
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    pub fn serialize<S>(time: &NaiveTime, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let since_midnight = time.duration_since_midnight();
+        serializer.serialize_u64(
+            since_midnight.num_seconds() as u64 * NANOS_PER_SEC
+                + since_midnight.subsec_nanos() as u64,
+        )
+    }
+
+    /// Deserialize a `NaiveTime` from an integer number of nanoseconds since midnight.
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    pub fn deserialize<'de, D>(d: D) -> Result<NaiveTime, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_u64(NanosVisitor)
+    }
+
+    struct NanosVisitor;
+
+    impl<'de> de::Visitor<'de> for NanosVisitor {
+        type Value = NaiveTime;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a number of nanoseconds since midnight")
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<NaiveTime, E>
+        where
+            E: de::Error,
+        {
+            let (secs, nanos) = match value / NANOS_PER_SEC {
+                // A leap second at the end of the day.
+                86_400 => (86_399, value % NANOS_PER_SEC + NANOS_PER_SEC),
+                secs => (secs, value % NANOS_PER_SEC),
+            };
+            u32::try_from(secs)
+                .ok()
+                .and_then(|secs| NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos as u32))
+                .ok_or_else(|| E::custom("NaiveTime out of range"))
+        }
+
+        fn visit_i64<E>(self, value: i64) -> Result<NaiveTime, E>
+        where
+            E: de::Error,
+        {
+            u64::try_from(value)
+                .map_err(|_| E::custom("NaiveTime out of range"))
+                .and_then(|value| self.visit_u64(value))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::NaiveTime;
@@ -140,4 +236,48 @@ mod tests {
         let decoded: NaiveTime = deserialize(&encoded).unwrap();
         assert_eq!(t, decoded);
     }
+
+    #[test]
+    fn test_serde_naive_time_as_nanos() {
+        use serde_derive::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct S {
+            #[serde(with = "crate::serde::naive_time_as_nanos")]
+            time: NaiveTime,
+        }
+
+        let cases = [
+            (NaiveTime::MIN, 0),
+            (NaiveTime::from_hms_nano_opt(0, 0, 0, 1).unwrap(), 1),
+            (NaiveTime::from_hms_milli_opt(0, 0, 1, 500).unwrap(), 1_500_000_000),
+            (NaiveTime::from_hms_nano_opt(3, 5, 7, 98765432).unwrap(), 11_107_098_765_432),
+            (NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999).unwrap(), 86_399_999_999_999),
+            (NaiveTime::from_hms_nano_opt(23, 59, 59, 1_000_000_000).unwrap(), 86_400_000_000_000),
+            (NaiveTime::from_hms_nano_opt(23, 59, 59, 1_999_999_999).unwrap(), 86_400_999_999_999),
+        ];
+        for (time, nanos) in cases {
+            let s = S { time };
+            let json = serde_json::to_string(&s).unwrap();
+            assert_eq!(json, format!(r#"{{"time":{}}}"#, nanos));
+            assert_eq!(serde_json::from_str::<S>(&json).unwrap(), s);
+
+            // Bincode is not self-describing and stores a `u64`.
+            let encoded = bincode::serialize(&s).unwrap();
+            assert_eq!(encoded, u64::to_le_bytes(nanos));
+            assert_eq!(bincode::deserialize::<S>(&encoded).unwrap(), s);
+        }
+
+        // other leap seconds are serialized as the next second
+        let leap = NaiveTime::from_hms_nano_opt(11, 59, 59, 1_500_000_000).unwrap();
+        let json = serde_json::to_string(&S { time: leap }).unwrap();
+        assert_eq!(json, r#"{"time":43200500000000}"#);
+        let next_second = NaiveTime::from_hms_milli_opt(12, 0, 0, 500).unwrap();
+        assert_eq!(serde_json::from_str::<S>(&json).unwrap(), S { time: next_second });
+
+        assert!(serde_json::from_str::<S>(r#"{"time":86401000000000}"#).is_err());
+        assert!(serde_json::from_str::<S>(r#"{"time":-1}"#).is_err());
+        assert!(serde_json::from_str::<S>(r#"{"time":18446744073709551615}"#).is_err());
+        assert!(serde_json::from_str::<S>(r#"{"time":"00:00:00"}"#).is_err());
+    }
 }
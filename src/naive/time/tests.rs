@@ -1,5 +1,25 @@
 use super::NaiveTime;
-use crate::{FixedOffset, TimeDelta, Timelike};
+use crate::format::ParseErrorKind;
+use crate::{FixedOffset, RoundingError, TimeDelta, Timelike};
+
+#[test]
+fn test_time_const_construction() {
+    const NOON: Option<NaiveTime> = NaiveTime::from_hms_opt(12, 0, 0);
+    const LEAP: Option<NaiveTime> = NaiveTime::from_hms_milli_opt(23, 59, 59, 1_500);
+    const INVALID: Option<NaiveTime> = NaiveTime::from_hms_opt(24, 0, 0);
+
+    // Go through function pointers so the values are computed at runtime.
+    let from_hms = NaiveTime::from_hms_opt;
+    let from_hms_milli = NaiveTime::from_hms_milli_opt;
+    for (constant, runtime) in [
+        (NOON, from_hms(12, 0, 0)),
+        (LEAP, from_hms_milli(23, 59, 59, 1_500)),
+        (INVALID, from_hms(24, 0, 0)),
+    ] {
+        assert_eq!(constant, runtime);
+        assert_eq!(constant.map(|t| (t.secs, t.frac)), runtime.map(|t| (t.secs, t.frac)));
+    }
+}
 
 #[test]
 fn test_time_from_hms_milli() {
@@ -82,6 +102,116 @@ fn test_time_hms() {
     assert_eq!(NaiveTime::from_hms_opt(3, 5, 7).unwrap().with_second(u32::MAX), None);
 }
 
+#[test]
+fn test_time_with_nanosecond_strict() {
+    let hms = |h, m, s| NaiveTime::from_hms_opt(h, m, s).unwrap();
+
+    let leap = hms(23, 59, 59).with_nanosecond_strict(1_000_000_000).unwrap();
+    assert_eq!(leap, NaiveTime::from_hms_nano_opt(23, 59, 59, 1_000_000_000).unwrap());
+    assert_eq!(leap.nanosecond(), 1_000_000_000);
+    let leap = hms(23, 59, 59).with_nanosecond_strict(1_999_999_999).unwrap();
+    assert_eq!(leap.nanosecond(), 1_999_999_999);
+    assert_eq!(hms(23, 59, 59).with_nanosecond_strict(2_000_000_000), None);
+    assert_eq!(hms(23, 59, 59).with_nanosecond_strict(u32::MAX), None);
+
+    // `with_nanosecond` allows these, but there is no leap second at that time in UTC
+    for (h, m, s) in [(12, 0, 0), (23, 59, 58), (23, 58, 59), (22, 59, 59), (0, 0, 0)] {
+        let time = hms(h, m, s);
+        assert!(time.with_nanosecond(1_500_000_000).is_some());
+        assert_eq!(time.with_nanosecond_strict(1_000_000_000), None);
+        assert_eq!(time.with_nanosecond_strict(1_500_000_000), None);
+        assert_eq!(time.with_nanosecond_strict(999_999_999), time.with_nanosecond(999_999_999));
+        assert_eq!(time.with_nanosecond_strict(0), Some(time));
+    }
+
+    // a leap second can be changed back to a normal second
+    assert_eq!(
+        leap.with_nanosecond_strict(5),
+        Some(NaiveTime::from_hms_nano_opt(23, 59, 59, 5).unwrap())
+    );
+}
+
+#[test]
+fn test_time_subsec() {
+    let time = NaiveTime::from_hms_nano_opt(3, 5, 7, 123_456_789).unwrap();
+    assert_eq!(time.subsec_millis(), 123);
+    assert_eq!(time.subsec_micros(), 123_456);
+    assert_eq!(time.subsec_nanos(), 123_456_789);
+    assert_eq!(time.subsec_nanos(), time.nanosecond());
+
+    let time = NaiveTime::from_hms_opt(3, 5, 7).unwrap();
+    assert_eq!((time.subsec_millis(), time.subsec_micros(), time.subsec_nanos()), (0, 0, 0));
+
+    let leap = NaiveTime::from_hms_nano_opt(23, 59, 59, 1_999_999_999).unwrap();
+    assert_eq!(leap.subsec_millis(), 1_999);
+    assert_eq!(leap.subsec_micros(), 1_999_999);
+    assert_eq!(leap.subsec_nanos(), 1_999_999_999);
+}
+
+#[test]
+fn test_time_round_to() {
+    let hms = |h, m, s| NaiveTime::from_hms_opt(h, m, s).unwrap();
+    let hmsm = |h, m, s, ms| NaiveTime::from_hms_milli_opt(h, m, s, ms).unwrap();
+    let quarter = TimeDelta::minutes(15);
+    let hour = TimeDelta::hours(1);
+
+    assert_eq!(hms(14, 37, 0).round_to(quarter), Ok(hms(14, 30, 0)));
+    assert_eq!(hms(14, 37, 30).round_to(quarter), Ok(hms(14, 45, 0)));
+    assert_eq!(hms(14, 45, 0).round_to(quarter), Ok(hms(14, 45, 0)));
+    assert_eq!(hms(14, 37, 0).floor_to(quarter), Ok(hms(14, 30, 0)));
+    assert_eq!(hms(14, 37, 0).ceil_to(quarter), Ok(hms(14, 45, 0)));
+    assert_eq!(hms(14, 30, 0).floor_to(quarter), Ok(hms(14, 30, 0)));
+    assert_eq!(hms(14, 30, 0).ceil_to(quarter), Ok(hms(14, 30, 0)));
+
+    // wrapping at midnight
+    assert_eq!(hms(23, 58, 0).round_to(hour), Ok(hms(0, 0, 0)));
+    assert_eq!(hms(23, 29, 59).round_to(hour), Ok(hms(23, 0, 0)));
+    assert_eq!(hms(23, 30, 0).round_to(hour), Ok(hms(0, 0, 0)));
+    assert_eq!(hms(23, 0, 1).ceil_to(hour), Ok(hms(0, 0, 0)));
+    assert_eq!(hms(23, 59, 59).floor_to(hour), Ok(hms(23, 0, 0)));
+    assert_eq!(hms(0, 0, 0).round_to(hour), Ok(hms(0, 0, 0)));
+    assert_eq!(hms(0, 0, 0).ceil_to(hour), Ok(hms(0, 0, 0)));
+    // 23:55 is a multiple of 7 minutes, the next one would lie past midnight
+    assert_eq!(hms(23, 59, 0).floor_to(TimeDelta::minutes(7)), Ok(hms(23, 55, 0)));
+    assert_eq!(hms(23, 59, 0).ceil_to(TimeDelta::minutes(7)), Ok(hms(0, 0, 0)));
+    assert_eq!(hms(12, 0, 0).round_to(TimeDelta::days(1)), Ok(hms(0, 0, 0)));
+    assert_eq!(hms(11, 59, 59).floor_to(TimeDelta::days(2)), Ok(hms(0, 0, 0)));
+
+    // sub-second intervals
+    let tenth = TimeDelta::milliseconds(100);
+    assert_eq!(hmsm(10, 20, 30, 149).round_to(tenth), Ok(hmsm(10, 20, 30, 100)));
+    assert_eq!(hmsm(10, 20, 30, 150).round_to(tenth), Ok(hmsm(10, 20, 30, 200)));
+    assert_eq!(hmsm(10, 20, 30, 101).floor_to(tenth), Ok(hmsm(10, 20, 30, 100)));
+    assert_eq!(hmsm(10, 20, 30, 101).ceil_to(tenth), Ok(hmsm(10, 20, 30, 200)));
+    assert_eq!(hmsm(10, 20, 30, 950).round_to(tenth), Ok(hms(10, 20, 31)));
+    assert_eq!(hmsm(23, 59, 59, 950).round_to(tenth), Ok(hms(0, 0, 0)));
+    let time = NaiveTime::from_hms_nano_opt(1, 2, 3, 123_456_789).unwrap();
+    assert_eq!(
+        time.round_to(TimeDelta::microseconds(1)),
+        Ok(NaiveTime::from_hms_nano_opt(1, 2, 3, 123_457_000).unwrap())
+    );
+    assert_eq!(time.round_to(TimeDelta::nanoseconds(1)), Ok(time));
+
+    // leap seconds count as an extra second
+    assert_eq!(hmsm(3, 59, 59, 1_500).ceil_to(TimeDelta::seconds(1)), Ok(hms(4, 0, 1)));
+    assert_eq!(hmsm(3, 59, 59, 1_500).round_to(TimeDelta::minutes(1)), Ok(hms(4, 0, 0)));
+    assert_eq!(hmsm(3, 59, 59, 1_200).round_to(hour), Ok(hms(4, 0, 0)));
+    assert_eq!(hmsm(23, 59, 59, 1_500).ceil_to(TimeDelta::seconds(1)), Ok(hms(0, 0, 0)));
+    // but flooring stays within the second that the leap second extends
+    assert_eq!(hmsm(3, 59, 59, 1_500).floor_to(TimeDelta::seconds(1)), Ok(hms(3, 59, 59)));
+    assert_eq!(hmsm(3, 59, 59, 1_500).floor_to(TimeDelta::minutes(1)), Ok(hms(3, 59, 0)));
+    assert_eq!(hmsm(23, 59, 59, 1_500).floor_to(TimeDelta::seconds(1)), Ok(hms(23, 59, 59)));
+    assert_eq!(hmsm(23, 59, 59, 1_500).floor_to(tenth), Ok(hmsm(23, 59, 59, 900)));
+    let leap = NaiveTime::from_hms_nano_opt(23, 59, 59, 1_999_999_999).unwrap();
+    let last_nano = NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999).unwrap();
+    assert_eq!(leap.floor_to(TimeDelta::nanoseconds(1)), Ok(last_nano));
+
+    // invalid intervals
+    assert_eq!(hms(12, 0, 0).round_to(TimeDelta::zero()), Err(RoundingError::DurationExceedsLimit));
+    assert_eq!(hms(12, 0, 0).floor_to(-quarter), Err(RoundingError::DurationExceedsLimit));
+    assert_eq!(hms(12, 0, 0).ceil_to(TimeDelta::zero()), Err(RoundingError::DurationExceedsLimit));
+}
+
 #[test]
 fn test_time_add() {
     macro_rules! check {
@@ -197,6 +327,41 @@ fn test_time_sub() {
     //assert_eq!(hmsm(3, 5, 6, 1_800) + TimeDelta::try_milliseconds(400).unwrap(), hmsm(3, 5, 7, 200));
 }
 
+#[test]
+fn test_time_duration_since_midnight() {
+    let hmsn = |h, m, s, n| NaiveTime::from_hms_nano_opt(h, m, s, n).unwrap();
+    let cases = [
+        (hmsn(0, 0, 0, 0), TimeDelta::zero()),
+        (hmsn(0, 0, 0, 1), TimeDelta::nanoseconds(1)),
+        (hmsn(1, 2, 3, 456_000_000), TimeDelta::milliseconds(3_723_456)),
+        (hmsn(12, 0, 0, 0), TimeDelta::hours(12)),
+        (hmsn(23, 59, 59, 999_999_999), TimeDelta::days(1) - TimeDelta::nanoseconds(1)),
+        // leap seconds
+        (hmsn(23, 59, 59, 1_000_000_000), TimeDelta::days(1)),
+        (hmsn(23, 59, 59, 1_999_999_999), TimeDelta::seconds(86_401) - TimeDelta::nanoseconds(1)),
+    ];
+    for (time, duration) in cases {
+        assert_eq!(time.duration_since_midnight(), duration, "{}", time);
+        assert_eq!(NaiveTime::from_duration_since_midnight(duration), Some(time), "{}", time);
+        assert_eq!(time.duration_since_midnight(), time.signed_duration_since(NaiveTime::MIN));
+    }
+
+    // a leap second that is not at midnight overlaps the following second
+    let leap = hmsn(12, 59, 59, 1_500_000_000);
+    assert_eq!(leap.duration_since_midnight(), TimeDelta::milliseconds(46_800_500));
+    assert_eq!(
+        NaiveTime::from_duration_since_midnight(leap.duration_since_midnight()),
+        Some(hmsn(13, 0, 0, 500_000_000))
+    );
+
+    let from_duration = NaiveTime::from_duration_since_midnight;
+    assert_eq!(from_duration(TimeDelta::nanoseconds(-1)), None);
+    assert_eq!(from_duration(TimeDelta::seconds(-1)), None);
+    assert_eq!(from_duration(TimeDelta::seconds(86_401)), None);
+    assert_eq!(from_duration(TimeDelta::max_value()), None);
+    assert_eq!(from_duration(TimeDelta::min_value()), None);
+}
+
 #[test]
 fn test_core_duration_ops() {
     use core::time::Duration;
@@ -354,6 +519,51 @@ fn test_time_parse_from_str() {
     assert!(NaiveTime::parse_from_str("12:3456", "%H:%M:%S").is_err());
 }
 
+#[test]
+fn test_time_parse_from_str_fixed_fraction() {
+    let hmsn = |h, m, s, n| NaiveTime::from_hms_nano_opt(h, m, s, n).unwrap();
+    let parse = |s, fmt| NaiveTime::parse_from_str(s, fmt).map_err(|e| e.kind());
+
+    assert_eq!(parse("12:34:56.123456", "%H:%M:%S.%6f"), Ok(hmsn(12, 34, 56, 123_456_000)));
+    assert_eq!(parse("12:34:56.12345", "%H:%M:%S.%6f"), Err(ParseErrorKind::TooShort));
+    assert_eq!(parse("12:34:56.1234567", "%H:%M:%S.%6f"), Err(ParseErrorKind::TooLong));
+    assert_eq!(parse("12:34:56.12345Z", "%H:%M:%S.%6fZ"), Err(ParseErrorKind::Invalid));
+    assert_eq!(parse("12:34:56.123456Z", "%H:%M:%S.%6fZ"), Ok(hmsn(12, 34, 56, 123_456_000)));
+    assert_eq!(parse("12:34:56.123", "%H:%M:%S.%3f"), Ok(hmsn(12, 34, 56, 123_000_000)));
+    assert_eq!(parse("12:34:56.1234", "%H:%M:%S.%3f"), Err(ParseErrorKind::TooLong));
+    assert_eq!(parse("12:34:56.000000001", "%H:%M:%S.%9f"), Ok(hmsn(12, 34, 56, 1)));
+    assert_eq!(parse("12:34:56.00000001", "%H:%M:%S.%9f"), Err(ParseErrorKind::TooShort));
+
+    // `%.6f` accepts any number of digits, or no fraction at all
+    assert_eq!(parse("12:34:56.12345", "%H:%M:%S%.6f"), Ok(hmsn(12, 34, 56, 123_450_000)));
+    assert_eq!(parse("12:34:56", "%H:%M:%S%.6f"), Ok(hmsn(12, 34, 56, 0)));
+}
+
+#[test]
+fn test_time_parse_12h() {
+    let hms = |h, m, s| NaiveTime::from_hms_opt(h, m, s).unwrap();
+    let hmsm = |h, m, s, ms| NaiveTime::from_hms_milli_opt(h, m, s, ms).unwrap();
+
+    assert_eq!(NaiveTime::parse_12h("1:30 PM"), Ok(hms(13, 30, 0)));
+    assert_eq!(NaiveTime::parse_12h("01:30 AM"), Ok(hms(1, 30, 0)));
+    assert_eq!(NaiveTime::parse_12h("11:59:59 pm"), Ok(hms(23, 59, 59)));
+    assert_eq!(NaiveTime::parse_12h("11:59:59.25 Pm"), Ok(hmsm(23, 59, 59, 250)));
+    assert_eq!(NaiveTime::parse_12h("  7:05am  "), Ok(hms(7, 5, 0)));
+
+    // midnight and noon
+    assert_eq!(NaiveTime::parse_12h("12:00 AM"), Ok(hms(0, 0, 0)));
+    assert_eq!(NaiveTime::parse_12h("12:59 am"), Ok(hms(0, 59, 0)));
+    assert_eq!(NaiveTime::parse_12h("12:00 PM"), Ok(hms(12, 0, 0)));
+    assert_eq!(NaiveTime::parse_12h("12:30 pm"), Ok(hms(12, 30, 0)));
+
+    assert!(NaiveTime::parse_12h("13:00 PM").is_err());
+    assert!(NaiveTime::parse_12h("0:00 AM").is_err());
+    assert!(NaiveTime::parse_12h("1:60 AM").is_err());
+    assert!(NaiveTime::parse_12h("1:30").is_err());
+    assert!(NaiveTime::parse_12h("1:30 XM").is_err());
+    assert!(NaiveTime::parse_12h("1:30 PM extra").is_err());
+}
+
 #[test]
 fn test_overflowing_offset() {
     let hmsm = |h, m, s, n| NaiveTime::from_hms_milli_opt(h, m, s, n).unwrap();
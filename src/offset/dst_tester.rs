@@ -0,0 +1,87 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A mock time zone with daylight saving time transitions, shared by the tests.
+
+use crate::{Datelike, FixedOffset, MappedLocalTime, NaiveDate, NaiveDateTime, NaiveTime};
+use crate::{TimeDelta, TimeZone};
+
+/// A time zone with a summer offset of `+09:00` and a winter offset of `+08:00`.
+///
+/// The clock goes back one hour at `TRANSITION_MINUTES` after local midnight on April 15, and
+/// forward one hour at the same time on September 15. [`DstTester`] makes these transitions at
+/// 02:00, so 01:00 to 02:00 is ambiguous on April 15 and 02:00 to 03:00 is skipped on
+/// September 15.
+#[derive(Clone)]
+pub(crate) struct TransitionTester<const TRANSITION_MINUTES: i64>;
+
+pub(crate) type DstTester = TransitionTester<120>;
+
+#[allow(non_upper_case_globals)]
+pub(crate) const DstTester: DstTester = TransitionTester;
+
+impl<const TRANSITION_MINUTES: i64> TransitionTester<TRANSITION_MINUTES> {
+    pub(crate) fn winter_offset() -> FixedOffset {
+        FixedOffset::east_opt(8 * 60 * 60).unwrap()
+    }
+    pub(crate) fn summer_offset() -> FixedOffset {
+        FixedOffset::east_opt(9 * 60 * 60).unwrap()
+    }
+
+    const TO_WINTER_MONTH_DAY: (u32, u32) = (4, 15);
+    const TO_SUMMER_MONTH_DAY: (u32, u32) = (9, 15);
+
+    /// The local time of the transition on `month_day` in `year`, in the offset before it.
+    fn transition_local(year: i32, month_day: (u32, u32)) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month_day.0, month_day.1).unwrap().and_time(NaiveTime::MIN)
+            + TimeDelta::try_minutes(TRANSITION_MINUTES).unwrap()
+    }
+}
+
+impl<const TRANSITION_MINUTES: i64> TimeZone for TransitionTester<TRANSITION_MINUTES> {
+    type Offset = FixedOffset;
+
+    fn from_offset(_: &Self::Offset) -> Self {
+        TransitionTester
+    }
+
+    fn offset_from_local_date(&self, _: &NaiveDate) -> crate::MappedLocalTime<Self::Offset> {
+        unimplemented!()
+    }
+
+    fn offset_from_local_datetime(
+        &self,
+        local: &NaiveDateTime,
+    ) -> crate::MappedLocalTime<Self::Offset> {
+        let hour = TimeDelta::try_hours(1).unwrap();
+        let to_winter = Self::transition_local(local.year(), Self::TO_WINTER_MONTH_DAY);
+        let to_summer = Self::transition_local(local.year(), Self::TO_SUMMER_MONTH_DAY);
+
+        if *local < to_winter - hour || *local >= to_summer + hour {
+            MappedLocalTime::Single(Self::summer_offset())
+        } else if *local >= to_winter && *local < to_summer {
+            MappedLocalTime::Single(Self::winter_offset())
+        } else if *local < to_winter {
+            MappedLocalTime::Ambiguous(Self::summer_offset(), Self::winter_offset())
+        } else {
+            MappedLocalTime::None
+        }
+    }
+
+    fn offset_from_utc_date(&self, _: &NaiveDate) -> Self::Offset {
+        unimplemented!()
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> Self::Offset {
+        let to_winter =
+            Self::transition_local(utc.year(), Self::TO_WINTER_MONTH_DAY) - Self::summer_offset();
+        let to_summer =
+            Self::transition_local(utc.year(), Self::TO_SUMMER_MONTH_DAY) - Self::winter_offset();
+
+        if *utc >= to_winter && *utc < to_summer {
+            Self::winter_offset()
+        } else {
+            Self::summer_offset()
+        }
+    }
+}
@@ -32,6 +32,17 @@ pub struct FixedOffset {
 }
 
 impl FixedOffset {
+    /// The zero offset, which is the offset of UTC.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, Offset, Utc};
+    /// assert_eq!(FixedOffset::UTC, FixedOffset::east_opt(0).unwrap());
+    /// assert_eq!(FixedOffset::UTC, Utc.fix());
+    /// ```
+    pub const UTC: FixedOffset = FixedOffset { local_minus_utc: 0 };
+
     /// Makes a new `FixedOffset` for the Eastern Hemisphere with given timezone difference.
     /// The negative `secs` means the Western Hemisphere.
     ///
@@ -102,6 +113,82 @@ impl FixedOffset {
         }
     }
 
+    /// Makes a new `FixedOffset` from the hours, minutes and seconds east of UTC.
+    ///
+    /// All components must have the same sign, so `from_hms(-5, -30, 0)` is the offset
+    /// `-05:30`. Minutes and seconds must be within `-59..=59`, and hours within `-23..=23`.
+    ///
+    /// Returns `None` on out-of-range components, or if the components have inconsistent signs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    /// let offset = FixedOffset::from_hms(5, 30, 0).unwrap();
+    /// assert_eq!(offset, FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap());
+    /// assert_eq!(FixedOffset::from_hms(-5, 30, 0), None);
+    /// ```
+    #[must_use]
+    pub const fn from_hms(hours: i32, minutes: i32, seconds: i32) -> Option<FixedOffset> {
+        if hours >= 0 && minutes >= 0 && seconds >= 0 {
+            FixedOffset::from_hms_signed(true, hours, minutes, seconds)
+        } else if hours <= 0 && minutes <= 0 && seconds <= 0 {
+            // Negating `i32::MIN` would overflow, but it is out of range anyway.
+            if hours == i32::MIN || minutes == i32::MIN || seconds == i32::MIN {
+                return None;
+            }
+            FixedOffset::from_hms_signed(false, -hours, -minutes, -seconds)
+        } else {
+            None
+        }
+    }
+
+    /// Makes a new `FixedOffset` from the hours, minutes and seconds of the difference to UTC,
+    /// in the Eastern Hemisphere if `east` is `true` and in the Western Hemisphere otherwise.
+    ///
+    /// Returns `None` if any component is negative, if `hours` is greater than 23, or if
+    /// `minutes` or `seconds` is greater than 59.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    /// let offset = FixedOffset::from_hms_signed(false, 3, 30, 0).unwrap();
+    /// assert_eq!(offset, FixedOffset::west_opt(3 * 3600 + 30 * 60).unwrap());
+    /// ```
+    #[must_use]
+    pub const fn from_hms_signed(
+        east: bool,
+        hours: i32,
+        minutes: i32,
+        seconds: i32,
+    ) -> Option<FixedOffset> {
+        if hours < 0 || hours > 23 || minutes < 0 || minutes > 59 || seconds < 0 || seconds > 59 {
+            return None;
+        }
+        let secs = hours * 3600 + minutes * 60 + seconds;
+        Some(FixedOffset { local_minus_utc: if east { secs } else { -secs } })
+    }
+
+    /// Returns the hours, minutes and seconds of this offset east of UTC.
+    ///
+    /// All components have the same sign as the offset, so this is the inverse of
+    /// [`from_hms`](FixedOffset::from_hms).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    /// let offset = FixedOffset::west_opt(3 * 3600 + 30 * 60 + 15).unwrap();
+    /// assert_eq!(offset.hours_minutes_seconds(), (-3, -30, -15));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn hours_minutes_seconds(&self) -> (i32, i32, i32) {
+        let secs = self.local_minus_utc;
+        (secs / 3600, secs / 60 % 60, secs % 60)
+    }
+
     /// Returns the number of seconds to add to convert from UTC to the local time.
     #[inline]
     pub const fn local_minus_utc(&self) -> i32 {
@@ -113,13 +200,31 @@ impl FixedOffset {
     pub const fn utc_minus_local(&self) -> i32 {
         -self.local_minus_utc
     }
+
+    /// Returns `true` if this is the zero offset of UTC.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    /// assert!(FixedOffset::east_opt(0).unwrap().is_utc());
+    /// assert!(!FixedOffset::west_opt(5 * 3600).unwrap().is_utc());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_utc(&self) -> bool {
+        self.local_minus_utc == 0
+    }
 }
 
 /// Parsing a `str` into a `FixedOffset` uses the format [`%z`](crate::format::strftime).
+///
+/// An offset in seconds may follow the minutes, such as `-00:44:30`. Offsets of 24 hours or
+/// more are rejected with an out-of-range error.
 impl FromStr for FixedOffset {
     type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (_, offset) = scan::timezone_offset(s, scan::colon_or_space, false, false, true)?;
+        let (_, offset) = scan::timezone_offset(s, scan::colon_or_space, false, false, true, true)?;
         Self::east_opt(offset).ok_or(OUT_OF_RANGE)
     }
 }
@@ -187,6 +292,7 @@ impl arbitrary::Arbitrary<'_> for FixedOffset {
 #[cfg(test)]
 mod tests {
     use super::FixedOffset;
+    use crate::format::OUT_OF_RANGE;
     use crate::offset::TimeZone;
     use std::str::FromStr;
 
@@ -224,6 +330,64 @@ mod tests {
         assert_eq!(offset.local_minus_utc, -8 * 3600);
         let offset = FixedOffset::from_str("+06:30").unwrap();
         assert_eq!(offset.local_minus_utc, (6 * 3600) + 1800);
+        let offset = FixedOffset::from_str("+05:30:00").unwrap();
+        assert_eq!(offset.local_minus_utc, (5 * 3600) + 1800);
+        let offset = FixedOffset::from_str("-00:44:30").unwrap();
+        assert_eq!(offset.local_minus_utc, -((44 * 60) + 30));
+        assert_eq!(offset.to_string(), "-00:44:30");
+        assert_eq!(FixedOffset::from_str("+99:00"), Err(OUT_OF_RANGE));
+        assert_eq!(FixedOffset::from_str("+24:00"), Err(OUT_OF_RANGE));
+        assert_eq!(FixedOffset::from_str("+00:00:60"), Err(OUT_OF_RANGE));
+    }
+
+    #[test]
+    fn test_from_hms() {
+        let offset = FixedOffset::from_hms(5, 30, 0).unwrap();
+        assert_eq!(offset, FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap());
+        assert_eq!(offset.to_string(), "+05:30");
+        assert_eq!(offset.hours_minutes_seconds(), (5, 30, 0));
+
+        let offset = FixedOffset::from_hms(-3, -30, -15).unwrap();
+        assert_eq!(offset.local_minus_utc(), -(3 * 3600 + 30 * 60 + 15));
+        assert_eq!(offset.hours_minutes_seconds(), (-3, -30, -15));
+        assert_eq!(FixedOffset::from_hms(0, -44, -30).unwrap().to_string(), "-00:44:30");
+        assert_eq!(FixedOffset::from_hms(0, 0, 0).unwrap().hours_minutes_seconds(), (0, 0, 0));
+
+        assert_eq!(FixedOffset::from_hms(23, 59, 59), FixedOffset::east_opt(86_399));
+        assert_eq!(FixedOffset::from_hms(-23, -59, -59), FixedOffset::east_opt(-86_399));
+        assert_eq!(FixedOffset::from_hms(24, 0, 0), None);
+        assert_eq!(FixedOffset::from_hms(0, 60, 0), None);
+        assert_eq!(FixedOffset::from_hms(0, 0, -60), None);
+        assert_eq!(FixedOffset::from_hms(i32::MIN, 0, 0), None);
+        // inconsistent signs
+        assert_eq!(FixedOffset::from_hms(-5, 30, 0), None);
+        assert_eq!(FixedOffset::from_hms(5, 0, -1), None);
+    }
+
+    #[test]
+    fn test_from_hms_signed() {
+        let east = FixedOffset::from_hms_signed(true, 5, 30, 0).unwrap();
+        assert_eq!(east, FixedOffset::from_hms(5, 30, 0).unwrap());
+        let west = FixedOffset::from_hms_signed(false, 5, 30, 0).unwrap();
+        assert_eq!(west, FixedOffset::from_hms(-5, -30, 0).unwrap());
+        assert_eq!(west.hours_minutes_seconds(), (-5, -30, 0));
+        assert_eq!(FixedOffset::from_hms_signed(false, 0, 0, 0), FixedOffset::east_opt(0));
+        assert_eq!(FixedOffset::from_hms_signed(true, -5, 0, 0), None);
+        assert_eq!(FixedOffset::from_hms_signed(false, 24, 0, 0), None);
+        assert_eq!(FixedOffset::from_hms_signed(true, 0, 0, 60), None);
+    }
+
+    #[test]
+    fn test_utc() {
+        assert_eq!(FixedOffset::UTC, FixedOffset::east_opt(0).unwrap());
+        assert_eq!(FixedOffset::UTC.local_minus_utc(), 0);
+        assert_eq!(FixedOffset::UTC.to_string(), "+00:00");
+        assert!(FixedOffset::UTC.is_utc());
+        assert!(FixedOffset::east_opt(0).unwrap().is_utc());
+        assert!(FixedOffset::west_opt(0).unwrap().is_utc());
+        assert!(!FixedOffset::east_opt(1).unwrap().is_utc());
+        assert!(!FixedOffset::west_opt(5 * 3600).unwrap().is_utc());
+        assert!(!FixedOffset::from_hms(0, -44, -30).unwrap().is_utc());
     }
 
     #[test]
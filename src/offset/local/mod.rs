@@ -160,6 +160,39 @@ impl Local {
     pub fn now() -> DateTime<Local> {
         Utc::now().with_timezone(&Local)
     }
+
+    /// Returns a `DateTime<Local>` which corresponds to the current date, time and offset from
+    /// UTC, or `None` if the offset of the local time zone can't be determined.
+    ///
+    /// [`Local::now()`] panics in that case. The offset can't be determined if:
+    /// - On Unix, the offset in the time zone data is 24 hours or more, or the time zone data has
+    ///   no local time type for the current time.
+    /// - On Windows, the system calls to get the time zone information fail.
+    ///
+    /// A time zone that can't be found or read at all is not an error: chrono falls back to UTC.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::Local;
+    ///
+    /// let now = Local::now_opt().expect("no valid local time zone");
+    /// assert_eq!(now.offset(), Local::now().offset());
+    /// ```
+    pub fn now_opt() -> Option<DateTime<Local>> {
+        let utc = Utc::now().naive_utc();
+        local_from_utc(utc, inner::offset_from_utc_datetime(&utc))
+    }
+}
+
+/// Combines a UTC datetime with the offset of the local time zone that was looked up for it.
+///
+/// Returns `None` if the lookup didn't give a single offset.
+fn local_from_utc(
+    utc: NaiveDateTime,
+    offset: MappedLocalTime<FixedOffset>,
+) -> Option<DateTime<Local>> {
+    Some(DateTime::from_naive_utc_and_offset(utc, offset.single()?))
 }
 
 impl TimeZone for Local {
@@ -273,13 +306,13 @@ fn lookup_with_dst_transitions(
 
 #[cfg(test)]
 mod tests {
-    use super::Local;
+    use super::{local_from_utc, Local};
     #[cfg(windows)]
     use crate::offset::local::{lookup_with_dst_transitions, Transition};
     use crate::offset::TimeZone;
-    use crate::{Datelike, Days, Utc};
     #[cfg(windows)]
-    use crate::{FixedOffset, MappedLocalTime, NaiveDate, NaiveDateTime};
+    use crate::NaiveDateTime;
+    use crate::{Datelike, Days, FixedOffset, MappedLocalTime, NaiveDate, Utc};
 
     #[test]
     fn verify_correct_offsets() {
@@ -294,6 +327,24 @@ mod tests {
         assert_eq!(now, from_utc);
     }
 
+    #[test]
+    fn test_now_opt() {
+        let now = Local::now_opt().unwrap();
+        assert_eq!(now.offset(), Local::now().offset());
+    }
+
+    #[test]
+    fn test_local_from_utc() {
+        let utc = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap().and_hms_opt(9, 30, 0).unwrap();
+        let offset = FixedOffset::east_opt(3600).unwrap();
+
+        let local = local_from_utc(utc, MappedLocalTime::Single(offset)).unwrap();
+        assert_eq!(local.naive_utc(), utc);
+        assert_eq!(*local.offset(), offset);
+        assert_eq!(local_from_utc(utc, MappedLocalTime::None), None);
+        assert_eq!(local_from_utc(utc, MappedLocalTime::Ambiguous(offset, offset)), None);
+    }
+
     #[test]
     fn verify_correct_offsets_distant_past() {
         let distant_past = Local::now() - Days::new(365 * 500);
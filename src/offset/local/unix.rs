@@ -149,11 +149,10 @@ impl Cache {
         }
 
         if !local {
-            let offset = self
-                .zone
-                .find_local_time_type(d.and_utc().timestamp())
-                .expect("unable to select local time type")
-                .offset();
+            let offset = match self.zone.find_local_time_type(d.and_utc().timestamp()) {
+                Ok(local_time_type) => local_time_type.offset(),
+                Err(_) => return MappedLocalTime::None,
+            };
 
             return match FixedOffset::east_opt(offset) {
                 Some(offset) => MappedLocalTime::Single(offset),
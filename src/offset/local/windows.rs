@@ -204,8 +204,8 @@ fn naive_date_time_from_system_time(
         1..=5 => st.wDay as u8,
         _ => return Err(()),
     };
-    let date = NaiveDate::from_weekday_of_month_opt(year, st.wMonth as u32, weekday, nth_day)
-        .or_else(|| NaiveDate::from_weekday_of_month_opt(year, st.wMonth as u32, weekday, 4))
+    let date = NaiveDate::nth_weekday_of_month(year, st.wMonth as u32, weekday, nth_day)
+        .or_else(|| NaiveDate::nth_weekday_of_month(year, st.wMonth as u32, weekday, 4))
         .ok_or(())?; // `st.wMonth` must be invalid
     Ok(Some(date.and_time(time)))
 }
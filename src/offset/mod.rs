@@ -37,6 +37,9 @@ pub use self::local::Local;
 pub(crate) mod utc;
 pub use self::utc::Utc;
 
+#[cfg(test)]
+pub(crate) mod dst_tester;
+
 /// The result of mapping a local time to a concrete instant in a given time zone.
 ///
 /// The calculation to go from a local time (wall clock time) to an instant in UTC can end up in
@@ -91,6 +94,8 @@ impl<T> MappedLocalTime<T> {
 
     /// Returns the earliest possible result of a the time zone mapping.
     ///
+    /// A single result is returned as is, so this also serves as "single or earliest".
+    ///
     /// # Errors
     ///
     /// Returns `None` if local time falls in a _gap_ in the local time, or if there was an error.
@@ -104,6 +109,8 @@ impl<T> MappedLocalTime<T> {
 
     /// Returns the latest possible result of a the time zone mapping.
     ///
+    /// A single result is returned as is, so this also serves as "single or latest".
+    ///
     /// # Errors
     ///
     /// Returns `None` if local time falls in a _gap_ in the local time, or if there was an error.
@@ -116,6 +123,27 @@ impl<T> MappedLocalTime<T> {
     }
 
     /// Maps a `MappedLocalTime<T>` into `MappedLocalTime<U>` with given function.
+    ///
+    /// The variant is preserved. For `Ambiguous` the function is applied to both results, first
+    /// to the earliest and then to the latest.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{MappedLocalTime, NaiveDate, NaiveTime};
+    ///
+    /// let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+    /// let date = |d| NaiveDate::from_ymd_opt(2024, 1, d).unwrap();
+    ///
+    /// let single = MappedLocalTime::Single(date(1)).map(|d| d.and_time(noon));
+    /// assert_eq!(single, MappedLocalTime::Single(date(1).and_time(noon)));
+    ///
+    /// let ambiguous = MappedLocalTime::Ambiguous(date(1), date(2)).map(|d| d.and_time(noon));
+    /// assert_eq!(ambiguous, MappedLocalTime::Ambiguous(date(1).and_time(noon), date(2).and_time(noon)));
+    ///
+    /// let none = MappedLocalTime::<NaiveDate>::None.map(|d| d.and_time(noon));
+    /// assert_eq!(none, MappedLocalTime::None);
+    /// ```
     #[must_use]
     pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> MappedLocalTime<U> {
         match self {
@@ -125,11 +153,28 @@ impl<T> MappedLocalTime<T> {
         }
     }
 
-    /// Maps a `MappedLocalTime<T>` into `MappedLocalTime<U>` with given function.
+    /// Maps a `MappedLocalTime<T>` into `MappedLocalTime<U>` with given fallible function.
+    ///
+    /// This works like [`map`](MappedLocalTime::map), but the function returns an `Option`. If it
+    /// returns `None` for any result, including one of the two results of `Ambiguous`, the whole
+    /// mapping becomes `MappedLocalTime::None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{MappedLocalTime, NaiveDate};
+    ///
+    /// let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+    /// let next_month = |d: NaiveDate| d.checked_add_months(chrono::Months::new(1));
     ///
-    /// Returns `MappedLocalTime::None` if the function returns `None`.
+    /// let ambiguous = MappedLocalTime::Ambiguous(date(1, 1), date(1, 2)).and_then(next_month);
+    /// assert_eq!(ambiguous, MappedLocalTime::Ambiguous(date(2, 1), date(2, 2)));
+    ///
+    /// let overflow = MappedLocalTime::Ambiguous(date(1, 1), NaiveDate::MAX).and_then(next_month);
+    /// assert_eq!(overflow, MappedLocalTime::None);
+    /// ```
     #[must_use]
-    pub(crate) fn and_then<U, F: FnMut(T) -> Option<U>>(self, mut f: F) -> MappedLocalTime<U> {
+    pub fn and_then<U, F: FnMut(T) -> Option<U>>(self, mut f: F) -> MappedLocalTime<U> {
         match self {
             MappedLocalTime::None => MappedLocalTime::None,
             MappedLocalTime::Single(v) => match f(v) {
@@ -265,6 +310,35 @@ impl<T: fmt::Debug> MappedLocalTime<T> {
             }
         }
     }
+
+    /// Returns a single unique conversion result or panics with the given message.
+    ///
+    /// See [`unwrap`](MappedLocalTime::unwrap).
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the local time falls within a _fold_ or a _gap_ in the local time, and
+    /// on any error that may have been returned by the type implementing [`TimeZone`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).expect("valid date and time");
+    /// assert_eq!(dt.timestamp(), 1_704_110_400);
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn expect(self, msg: &str) -> T {
+        match self {
+            MappedLocalTime::None => panic!("{}: no such local time", msg),
+            MappedLocalTime::Single(t) => t,
+            MappedLocalTime::Ambiguous(t1, t2) => {
+                panic!("{}: ambiguous local time, ranging from {:?} to {:?}", msg, t1, t2)
+            }
+        }
+    }
 }
 
 /// The offset from the local time to UTC.
@@ -400,7 +474,7 @@ pub trait TimeZone: Sized + Clone {
     )]
     #[allow(deprecated)]
     fn isoywd_opt(&self, year: i32, week: u32, weekday: Weekday) -> MappedLocalTime<Date<Self>> {
-        match NaiveDate::from_isoywd_opt(year, week, weekday) {
+        match NaiveDate::from_iso_week(year, week, weekday) {
             Some(d) => self.from_local_date(&d),
             None => MappedLocalTime::None,
         }
@@ -692,4 +766,59 @@ mod tests {
         let dt = Utc.timestamp_micros(-3_600_000_000).unwrap();
         assert_eq!(dt.to_string(), "1969-12-31 23:00:00 UTC");
     }
+
+    #[test]
+    fn test_mapped_local_time_map_and_then() {
+        let single = MappedLocalTime::Single(1);
+        let ambiguous = MappedLocalTime::Ambiguous(1, 2);
+        let none = MappedLocalTime::<i32>::None;
+
+        assert_eq!(single.map(|v| v * 10), MappedLocalTime::Single(10));
+        assert_eq!(ambiguous.map(|v| v * 10), MappedLocalTime::Ambiguous(10, 20));
+        assert_eq!(none.map(|v| v * 10), MappedLocalTime::None);
+
+        // the function is applied to the earliest result first
+        let mut calls = Vec::new();
+        let mapped = ambiguous.map(|v| {
+            calls.push(v);
+            v.to_string()
+        });
+        assert_eq!(mapped, MappedLocalTime::Ambiguous("1".to_string(), "2".to_string()));
+        assert_eq!(calls, [1, 2]);
+
+        let half = |v: i32| if v % 2 == 0 { Some(v / 2) } else { None };
+        assert_eq!(MappedLocalTime::Single(4).and_then(half), MappedLocalTime::Single(2));
+        assert_eq!(MappedLocalTime::Single(3).and_then(half), MappedLocalTime::None);
+        assert_eq!(
+            MappedLocalTime::Ambiguous(4, 8).and_then(half),
+            MappedLocalTime::Ambiguous(2, 4)
+        );
+        assert_eq!(MappedLocalTime::Ambiguous(4, 7).and_then(half), MappedLocalTime::None);
+        assert_eq!(MappedLocalTime::Ambiguous(3, 8).and_then(half), MappedLocalTime::None);
+        assert_eq!(none.and_then(half), MappedLocalTime::None);
+
+        // mapping the local results of a time zone
+        let offset = FixedOffset::east_opt(3600).unwrap();
+        let local = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+        let dt =
+            MappedLocalTime::Single(local).and_then(|l| offset.from_local_datetime(&l).single());
+        assert_eq!(dt.map(|dt| dt.timestamp()), MappedLocalTime::Single(1_704_106_800));
+    }
+
+    #[test]
+    fn test_mapped_local_time_expect() {
+        assert_eq!(MappedLocalTime::Single(1).expect("single"), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "the local time: no such local time")]
+    fn test_mapped_local_time_expect_none() {
+        let _ = MappedLocalTime::<i32>::None.expect("the local time");
+    }
+
+    #[test]
+    #[should_panic(expected = "the local time: ambiguous local time, ranging from 1 to 2")]
+    fn test_mapped_local_time_expect_ambiguous() {
+        let _ = MappedLocalTime::Ambiguous(1, 2).expect("the local time");
+    }
 }
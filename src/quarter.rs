@@ -0,0 +1,306 @@
+use core::{fmt, str};
+
+#[cfg(any(feature = "rkyv", feature = "rkyv-16", feature = "rkyv-32", feature = "rkyv-64"))]
+use rkyv::{Archive, Deserialize, Serialize};
+
+use crate::{Month, OutOfRange};
+
+/// The quarter of the year.
+///
+/// Each quarter spans three calendar months, starting with `Q1` from January through March.
+///
+/// The quarter of a date can be retrieved with [`Datelike::quarter`](crate::Datelike::quarter),
+/// which returns a number that can be converted to this enum.
+/// ```
+/// use chrono::prelude::*;
+/// use chrono::Quarter;
+///
+/// let date = Utc.with_ymd_and_hms(2019, 10, 28, 9, 10, 11).unwrap();
+/// let quarter = Quarter::try_from(u8::try_from(date.quarter()).unwrap()).ok();
+/// assert_eq!(quarter, Some(Quarter::Q4));
+/// assert_eq!(quarter.unwrap().first_month(), Month::October);
+/// ```
+// Actual implementation is zero-indexed, API intended as 1-indexed for more intuitive behavior.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    any(feature = "rkyv", feature = "rkyv-16", feature = "rkyv-32", feature = "rkyv-64"),
+    derive(Archive, Deserialize, Serialize),
+    archive(compare(PartialEq, PartialOrd)),
+    archive_attr(derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash))
+)]
+#[cfg_attr(feature = "rkyv-validation", archive(check_bytes))]
+#[cfg_attr(all(feature = "arbitrary", feature = "std"), derive(arbitrary::Arbitrary))]
+pub enum Quarter {
+    /// January through March.
+    Q1 = 0,
+    /// April through June.
+    Q2 = 1,
+    /// July through September.
+    Q3 = 2,
+    /// October through December.
+    Q4 = 3,
+}
+
+impl Quarter {
+    /// The next quarter.
+    ///
+    /// `q`:        | `Q1` | `Q2` | `Q3` | `Q4`
+    /// ----------- | ---- | ---- | ---- | ----
+    /// `q.succ()`: | `Q2` | `Q3` | `Q4` | `Q1`
+    #[inline]
+    #[must_use]
+    pub const fn succ(&self) -> Quarter {
+        match *self {
+            Quarter::Q1 => Quarter::Q2,
+            Quarter::Q2 => Quarter::Q3,
+            Quarter::Q3 => Quarter::Q4,
+            Quarter::Q4 => Quarter::Q1,
+        }
+    }
+
+    /// The previous quarter.
+    ///
+    /// `q`:        | `Q1` | `Q2` | `Q3` | `Q4`
+    /// ----------- | ---- | ---- | ---- | ----
+    /// `q.pred()`: | `Q4` | `Q1` | `Q2` | `Q3`
+    #[inline]
+    #[must_use]
+    pub const fn pred(&self) -> Quarter {
+        match *self {
+            Quarter::Q1 => Quarter::Q4,
+            Quarter::Q2 => Quarter::Q1,
+            Quarter::Q3 => Quarter::Q2,
+            Quarter::Q4 => Quarter::Q3,
+        }
+    }
+
+    /// Returns a quarter-of-year number starting from `Q1` = 1.
+    ///
+    /// `q`:                       | `Q1` | `Q2` | `Q3` | `Q4`
+    /// -------------------------- | ---- | ---- | ---- | ----
+    /// `q.number_from_quarter()`: | 1    | 2    | 3    | 4
+    #[inline]
+    #[must_use]
+    pub const fn number_from_quarter(&self) -> u32 {
+        *self as u32 + 1
+    }
+
+    /// The first month of the quarter.
+    ///
+    /// `q`:               | `Q1`      | `Q2`    | `Q3`   | `Q4`
+    /// ------------------ | --------- | ------- | ------ | ---------
+    /// `q.first_month()`: | `January` | `April` | `July` | `October`
+    #[inline]
+    #[must_use]
+    pub const fn first_month(&self) -> Month {
+        match *self {
+            Quarter::Q1 => Month::January,
+            Quarter::Q2 => Month::April,
+            Quarter::Q3 => Month::July,
+            Quarter::Q4 => Month::October,
+        }
+    }
+
+    /// The last month of the quarter.
+    ///
+    /// `q`:              | `Q1`    | `Q2`   | `Q3`        | `Q4`
+    /// ----------------- | ------- | ------ | ----------- | ----------
+    /// `q.last_month()`: | `March` | `June` | `September` | `December`
+    #[inline]
+    #[must_use]
+    pub const fn last_month(&self) -> Month {
+        match *self {
+            Quarter::Q1 => Month::March,
+            Quarter::Q2 => Month::June,
+            Quarter::Q3 => Month::September,
+            Quarter::Q4 => Month::December,
+        }
+    }
+
+    /// Get the name of the quarter, such as `"Q1"`.
+    ///
+    /// ```
+    /// use chrono::Quarter;
+    ///
+    /// assert_eq!(Quarter::Q1.name(), "Q1")
+    /// ```
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match *self {
+            Quarter::Q1 => "Q1",
+            Quarter::Q2 => "Q2",
+            Quarter::Q3 => "Q3",
+            Quarter::Q4 => "Q4",
+        }
+    }
+}
+
+impl TryFrom<u8> for Quarter {
+    type Error = OutOfRange;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Quarter::Q1),
+            2 => Ok(Quarter::Q2),
+            3 => Ok(Quarter::Q3),
+            4 => Ok(Quarter::Q4),
+            _ => Err(OutOfRange::new()),
+        }
+    }
+}
+
+impl fmt::Display for Quarter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parsing a `str` into a `Quarter` accepts `Q1` through `Q4`.
+///
+/// # Example
+///
+/// ```
+/// use chrono::Quarter;
+///
+/// assert_eq!("Q3".parse::<Quarter>(), Ok(Quarter::Q3));
+/// assert!("Q5".parse::<Quarter>().is_err());
+/// ```
+///
+/// The parsing is case-insensitive.
+///
+/// ```
+/// # use chrono::Quarter;
+/// assert_eq!("q2".parse::<Quarter>(), Ok(Quarter::Q2));
+/// ```
+impl str::FromStr for Quarter {
+    type Err = ParseQuarterError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.as_bytes() {
+            [b'Q' | b'q', b'1'] => Ok(Quarter::Q1),
+            [b'Q' | b'q', b'2'] => Ok(Quarter::Q2),
+            [b'Q' | b'q', b'3'] => Ok(Quarter::Q3),
+            [b'Q' | b'q', b'4'] => Ok(Quarter::Q4),
+            _ => Err(ParseQuarterError { _dummy: () }),
+        }
+    }
+}
+
+/// An error resulting from reading `<Quarter>` value with `FromStr`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParseQuarterError {
+    pub(crate) _dummy: (),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseQuarterError {}
+
+impl fmt::Display for ParseQuarterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ParseQuarterError {{ .. }}")
+    }
+}
+
+impl fmt::Debug for ParseQuarterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ParseQuarterError {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Quarter;
+    use crate::{Datelike, FixedOffset, Month, NaiveDate, OutOfRange, TimeZone};
+
+    #[test]
+    fn test_quarter_enum_try_from() {
+        assert_eq!(Quarter::try_from(1), Ok(Quarter::Q1));
+        assert_eq!(Quarter::try_from(4), Ok(Quarter::Q4));
+        assert_eq!(Quarter::try_from(0), Err(OutOfRange::new()));
+        assert_eq!(Quarter::try_from(5), Err(OutOfRange::new()));
+
+        for quarter in [Quarter::Q1, Quarter::Q2, Quarter::Q3, Quarter::Q4] {
+            assert_eq!(Quarter::try_from(quarter.number_from_quarter() as u8), Ok(quarter));
+        }
+    }
+
+    #[test]
+    fn test_quarter_enum_succ_pred() {
+        assert_eq!(Quarter::Q1.succ(), Quarter::Q2);
+        assert_eq!(Quarter::Q4.succ(), Quarter::Q1);
+        assert_eq!(Quarter::Q1.pred(), Quarter::Q4);
+        assert_eq!(Quarter::Q3.pred(), Quarter::Q2);
+    }
+
+    #[test]
+    fn test_quarter_months() {
+        let quarters = [Quarter::Q1, Quarter::Q2, Quarter::Q3, Quarter::Q4];
+        for quarter in quarters {
+            let first = quarter.first_month().number_from_month();
+            let last = quarter.last_month().number_from_month();
+            assert_eq!(last - first, 2);
+            assert_eq!((first - 1) / 3 + 1, quarter.number_from_quarter());
+        }
+        assert_eq!(Quarter::Q1.first_month(), Month::January);
+        assert_eq!(Quarter::Q4.last_month(), Month::December);
+    }
+
+    #[test]
+    fn test_quarter_from_str() {
+        assert_eq!("Q1".parse::<Quarter>(), Ok(Quarter::Q1));
+        assert_eq!("Q2".parse::<Quarter>(), Ok(Quarter::Q2));
+        assert_eq!("Q3".parse::<Quarter>(), Ok(Quarter::Q3));
+        assert_eq!("q4".parse::<Quarter>(), Ok(Quarter::Q4));
+
+        for s in ["", "Q", "Q0", "Q5", "1", "Q1 ", " Q1", "Q12", "quarter 1"] {
+            assert!(s.parse::<Quarter>().is_err(), "{:?}", s);
+        }
+
+        for quarter in [Quarter::Q1, Quarter::Q2, Quarter::Q3, Quarter::Q4] {
+            assert_eq!(quarter.to_string().parse::<Quarter>(), Ok(quarter));
+        }
+    }
+
+    #[test]
+    fn test_datelike_quarter() {
+        for month in 1..=12 {
+            let date = NaiveDate::from_ymd_opt(2024, month, 1).unwrap();
+            assert_eq!(date.quarter(), (month - 1) / 3 + 1);
+            assert_eq!(date.quarter0(), (month - 1) / 3);
+        }
+
+        let datetime = NaiveDate::from_ymd_opt(2024, 6, 30).unwrap().and_hms_opt(23, 0, 0).unwrap();
+        assert_eq!(datetime.quarter(), 2);
+        assert_eq!(datetime.and_utc().quarter(), 2);
+        // The quarter is determined by the local date.
+        let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+        assert_eq!(offset.from_utc_datetime(&datetime).quarter(), 3);
+    }
+
+    #[test]
+    fn test_first_last_day_of_quarter() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let cases = [
+            (ymd(2024, 1, 1), ymd(2024, 1, 1), ymd(2024, 3, 31)),
+            (ymd(2024, 2, 29), ymd(2024, 1, 1), ymd(2024, 3, 31)),
+            (ymd(2024, 5, 15), ymd(2024, 4, 1), ymd(2024, 6, 30)),
+            (ymd(2023, 9, 30), ymd(2023, 7, 1), ymd(2023, 9, 30)),
+            (ymd(2023, 12, 31), ymd(2023, 10, 1), ymd(2023, 12, 31)),
+        ];
+        for (date, first, last) in cases {
+            assert_eq!(date.first_day_of_quarter(), first);
+            assert_eq!(date.last_day_of_quarter(), last);
+        }
+
+        assert_eq!(NaiveDate::MIN.first_day_of_quarter(), NaiveDate::MIN);
+        assert_eq!(NaiveDate::MAX.last_day_of_quarter(), NaiveDate::MAX);
+    }
+
+    #[test]
+    #[cfg(feature = "rkyv-validation")]
+    fn test_rkyv_validation() {
+        let quarter = Quarter::Q3;
+        let bytes = rkyv::to_bytes::<_, 1>(&quarter).unwrap();
+        assert_eq!(rkyv::from_bytes::<Quarter>(&bytes).unwrap(), quarter);
+    }
+}
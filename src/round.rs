@@ -98,6 +98,17 @@ const fn span_for_digits(digits: u16) -> u32 {
 
 /// Extension trait for rounding or truncating a DateTime by a TimeDelta.
 ///
+/// Multiples of the `TimeDelta` are counted from the Unix epoch, 1970-01-01 00:00:00, in local
+/// time. For days this gives local midnight, but for weeks it gives a Thursday because that is
+/// the weekday of the epoch. Use [`DurationRound::duration_round_anchored`] and
+/// [`DurationRound::duration_trunc_anchored`] to count from a different reference, such as a
+/// Monday.
+///
+/// The rounding is done by adding or subtracting a `TimeDelta`, so on a day with a time zone
+/// transition, such as the start of daylight saving time, truncating a `DateTime` to a day may
+/// not give local midnight. Use [`DateTime::duration_trunc_to_day_in_tz`] to get the start of the
+/// local day.
+///
 /// # Limitations
 /// Both rounding and truncating are done via [`TimeDelta::num_nanoseconds`] and
 /// [`DateTime::timestamp_nanos_opt`]. This means that they will fail if either the
@@ -153,17 +164,83 @@ pub trait DurationRound: Sized {
     /// );
     /// ```
     fn duration_trunc(self, duration: TimeDelta) -> Result<Self, Self::Err>;
+
+    /// Return a copy rounded by TimeDelta, counting multiples of the `TimeDelta` from `anchor`
+    /// instead of from the Unix epoch.
+    ///
+    /// Only the position of `anchor` within an interval matters, so any Monday at midnight can be
+    /// used to round to weeks starting on Monday.
+    ///
+    /// # Example
+    /// ``` rust
+    /// # use chrono::{DurationRound, TimeDelta, NaiveDate, Weekday, Datelike};
+    /// let dt = NaiveDate::from_ymd_opt(2024, 1, 11).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    /// let week = TimeDelta::weeks(1);
+    ///
+    /// // Weeks counted from the Unix epoch start on Thursday.
+    /// assert_eq!(dt.duration_round(week).unwrap().weekday(), Weekday::Thu);
+    ///
+    /// // Count from a Monday instead.
+    /// let monday = NaiveDate::from_ymd_opt(1970, 1, 5).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// assert_eq!(
+    ///     dt.duration_round_anchored(week, monday).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2024, 1, 15).unwrap().and_hms_opt(0, 0, 0).unwrap()
+    /// );
+    /// ```
+    fn duration_round_anchored(
+        self,
+        duration: TimeDelta,
+        anchor: NaiveDateTime,
+    ) -> Result<Self, Self::Err>;
+
+    /// Return a copy truncated by TimeDelta, counting multiples of the `TimeDelta` from `anchor`
+    /// instead of from the Unix epoch.
+    ///
+    /// Only the position of `anchor` within an interval matters, so any Monday at midnight can be
+    /// used to truncate to weeks starting on Monday.
+    ///
+    /// # Example
+    /// ``` rust
+    /// # use chrono::{DurationRound, TimeDelta, NaiveDate};
+    /// let dt = NaiveDate::from_ymd_opt(2024, 1, 11).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    /// let monday = NaiveDate::from_ymd_opt(1970, 1, 5).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// assert_eq!(
+    ///     dt.duration_trunc_anchored(TimeDelta::weeks(1), monday).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2024, 1, 8).unwrap().and_hms_opt(0, 0, 0).unwrap()
+    /// );
+    /// ```
+    fn duration_trunc_anchored(
+        self,
+        duration: TimeDelta,
+        anchor: NaiveDateTime,
+    ) -> Result<Self, Self::Err>;
 }
 
 impl<Tz: TimeZone> DurationRound for DateTime<Tz> {
     type Err = RoundingError;
 
     fn duration_round(self, duration: TimeDelta) -> Result<Self, Self::Err> {
-        duration_round(self.naive_local(), self, duration)
+        duration_round(self.naive_local(), self, duration, NaiveDateTime::UNIX_EPOCH)
     }
 
     fn duration_trunc(self, duration: TimeDelta) -> Result<Self, Self::Err> {
-        duration_trunc(self.naive_local(), self, duration)
+        duration_trunc(self.naive_local(), self, duration, NaiveDateTime::UNIX_EPOCH)
+    }
+
+    fn duration_round_anchored(
+        self,
+        duration: TimeDelta,
+        anchor: NaiveDateTime,
+    ) -> Result<Self, Self::Err> {
+        duration_round(self.naive_local(), self, duration, anchor)
+    }
+
+    fn duration_trunc_anchored(
+        self,
+        duration: TimeDelta,
+        anchor: NaiveDateTime,
+    ) -> Result<Self, Self::Err> {
+        duration_trunc(self.naive_local(), self, duration, anchor)
     }
 }
 
@@ -171,18 +248,49 @@ impl DurationRound for NaiveDateTime {
     type Err = RoundingError;
 
     fn duration_round(self, duration: TimeDelta) -> Result<Self, Self::Err> {
-        duration_round(self, self, duration)
+        duration_round(self, self, duration, NaiveDateTime::UNIX_EPOCH)
     }
 
     fn duration_trunc(self, duration: TimeDelta) -> Result<Self, Self::Err> {
-        duration_trunc(self, self, duration)
+        duration_trunc(self, self, duration, NaiveDateTime::UNIX_EPOCH)
+    }
+
+    fn duration_round_anchored(
+        self,
+        duration: TimeDelta,
+        anchor: NaiveDateTime,
+    ) -> Result<Self, Self::Err> {
+        duration_round(self, self, duration, anchor)
+    }
+
+    fn duration_trunc_anchored(
+        self,
+        duration: TimeDelta,
+        anchor: NaiveDateTime,
+    ) -> Result<Self, Self::Err> {
+        duration_trunc(self, self, duration, anchor)
+    }
+}
+
+/// Returns the offset of `anchor` from the Unix epoch in nanoseconds, modulo `span`.
+fn anchor_shift_nanos(anchor: NaiveDateTime, span: i64) -> i64 {
+    match span > 0 {
+        true => anchor.and_utc().timestamp_nanos_i128().rem_euclid(span.into()) as i64,
+        false => 0,
     }
 }
 
+/// Returns the timestamp of `naive` in nanoseconds, shifted so multiples of `span` are counted
+/// from `anchor` instead of from the Unix epoch.
+fn anchored_stamp(naive: NaiveDateTime, span: i64, anchor: NaiveDateTime) -> Option<i64> {
+    naive.and_utc().timestamp_nanos_opt()?.checked_sub(anchor_shift_nanos(anchor, span))
+}
+
 fn duration_round<T>(
     naive: NaiveDateTime,
     original: T,
     duration: TimeDelta,
+    anchor: NaiveDateTime,
 ) -> Result<T, RoundingError>
 where
     T: Timelike + Add<TimeDelta, Output = T> + Sub<TimeDelta, Output = T>,
@@ -192,7 +300,7 @@ where
             return Err(RoundingError::DurationExceedsLimit);
         }
         let stamp =
-            naive.and_utc().timestamp_nanos_opt().ok_or(RoundingError::TimestampExceedsLimit)?;
+            anchored_stamp(naive, span, anchor).ok_or(RoundingError::TimestampExceedsLimit)?;
         if span == 0 {
             return Ok(original);
         }
@@ -220,6 +328,7 @@ fn duration_trunc<T>(
     naive: NaiveDateTime,
     original: T,
     duration: TimeDelta,
+    anchor: NaiveDateTime,
 ) -> Result<T, RoundingError>
 where
     T: Timelike + Add<TimeDelta, Output = T> + Sub<TimeDelta, Output = T>,
@@ -229,7 +338,7 @@ where
             return Err(RoundingError::DurationExceedsLimit);
         }
         let stamp =
-            naive.and_utc().timestamp_nanos_opt().ok_or(RoundingError::TimestampExceedsLimit)?;
+            anchored_stamp(naive, span, anchor).ok_or(RoundingError::TimestampExceedsLimit)?;
         let delta_down = stamp % span;
         match delta_down.cmp(&0) {
             Ordering::Equal => Ok(original),
@@ -241,6 +350,51 @@ where
     }
 }
 
+/// The direction in which [`round_to_interval`] moves a `DateTime`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum RoundDirection {
+    /// Towards the preceding multiple of the interval.
+    Floor,
+    /// Towards the following multiple of the interval.
+    Ceil,
+    /// Towards the closest multiple of the interval, with halfway values rounded up.
+    Nearest,
+}
+
+/// Round `dt` to a multiple of `interval`, counted from the Unix epoch.
+///
+/// The calculation is done on the UTC timestamp with `i128` nanoseconds, so it can't overflow and
+/// doesn't depend on the offset of `dt`.
+pub(crate) fn round_to_interval<Tz: TimeZone>(
+    dt: &DateTime<Tz>,
+    interval: TimeDelta,
+    direction: RoundDirection,
+) -> Result<DateTime<Tz>, RoundingError> {
+    const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+    if interval <= TimeDelta::zero() {
+        return Err(RoundingError::DurationExceedsLimit);
+    }
+    let span = interval.num_seconds() as i128 * NANOS_PER_SEC + interval.subsec_nanos() as i128;
+    let stamp = dt.timestamp() as i128 * NANOS_PER_SEC + dt.timestamp_subsec_nanos() as i128;
+
+    let delta_down = stamp.rem_euclid(span);
+    let rounded = match direction {
+        _ if delta_down == 0 => return Ok(dt.clone()),
+        RoundDirection::Floor => stamp - delta_down,
+        RoundDirection::Ceil => stamp - delta_down + span,
+        RoundDirection::Nearest if delta_down >= span - delta_down => stamp - delta_down + span,
+        RoundDirection::Nearest => stamp - delta_down,
+    };
+
+    let secs = i64::try_from(rounded.div_euclid(NANOS_PER_SEC))
+        .map_err(|_| RoundingError::TimestampExceedsLimit)?;
+    let nsecs = rounded.rem_euclid(NANOS_PER_SEC) as u32;
+    DateTime::from_timestamp(secs, nsecs)
+        .map(|utc| utc.with_timezone(&dt.timezone()))
+        .ok_or(RoundingError::TimestampExceedsLimit)
+}
+
 /// An error from rounding by `TimeDelta`
 ///
 /// See: [`DurationRound`]
@@ -253,6 +407,9 @@ pub enum RoundingError {
 
     /// Error when `TimeDelta.num_nanoseconds` exceeds the limit.
     ///
+    /// This is also returned when rounding to a zero or negative interval with
+    /// [`DateTime::round_to_nearest`](crate::DateTime::round_to_nearest) and related methods.
+    ///
     /// ``` rust
     /// # use chrono::{DurationRound, TimeDelta, RoundingError, NaiveDate};
     /// let dt = NaiveDate::from_ymd_opt(2260, 12, 31)
@@ -311,7 +468,7 @@ mod tests {
     use super::{DurationRound, RoundingError, SubsecRound, TimeDelta};
     use crate::offset::{FixedOffset, TimeZone, Utc};
     use crate::Timelike;
-    use crate::{DateTime, NaiveDate};
+    use crate::{DateTime, NaiveDate, NaiveDateTime};
 
     #[test]
     fn test_round_subsecs() {
@@ -824,4 +981,225 @@ mod tests {
         let dt = DateTime::from_timestamp_nanos(i64::MAX / 2 - 1);
         assert_eq!(dt.duration_round(span).unwrap().to_string(), "1970-01-01 00:00:00 UTC");
     }
+
+    #[test]
+    fn test_duration_round_weeks() {
+        let week = TimeDelta::weeks(1);
+        let ymd_hms = |y, m, d, h| Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap();
+        let monday = NaiveDate::from_ymd_opt(1970, 1, 5).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        let epoch = NaiveDateTime::UNIX_EPOCH;
+
+        // Anchored at the Unix epoch, weeks start on Thursday.
+        // Thursday 2024-01-11 00:00 is a multiple, the next one is 2024-01-18.
+        assert_eq!(ymd_hms(2024, 1, 14, 11).duration_round(week), Ok(ymd_hms(2024, 1, 11, 0)));
+        assert_eq!(ymd_hms(2024, 1, 14, 12).duration_round(week), Ok(ymd_hms(2024, 1, 18, 0)));
+        assert_eq!(ymd_hms(2024, 1, 17, 23).duration_trunc(week), Ok(ymd_hms(2024, 1, 11, 0)));
+        assert_eq!(
+            ymd_hms(2024, 1, 14, 12).duration_round_anchored(week, epoch),
+            Ok(ymd_hms(2024, 1, 18, 0))
+        );
+
+        // Anchored at a Monday, weeks start on Monday.
+        // Monday 2024-01-08 00:00 is a multiple, the next one is 2024-01-15.
+        let round = |dt: DateTime<Utc>| dt.duration_round_anchored(week, monday);
+        let trunc = |dt: DateTime<Utc>| dt.duration_trunc_anchored(week, monday);
+        assert_eq!(round(ymd_hms(2024, 1, 8, 0)), Ok(ymd_hms(2024, 1, 8, 0)));
+        assert_eq!(round(ymd_hms(2024, 1, 11, 11)), Ok(ymd_hms(2024, 1, 8, 0)));
+        assert_eq!(round(ymd_hms(2024, 1, 11, 12)), Ok(ymd_hms(2024, 1, 15, 0)));
+        assert_eq!(round(ymd_hms(2024, 1, 14, 23)), Ok(ymd_hms(2024, 1, 15, 0)));
+        assert_eq!(trunc(ymd_hms(2024, 1, 14, 23)), Ok(ymd_hms(2024, 1, 8, 0)));
+        assert_eq!(trunc(ymd_hms(2024, 1, 15, 0)), Ok(ymd_hms(2024, 1, 15, 0)));
+        // before the anchor and before the epoch
+        assert_eq!(round(ymd_hms(1969, 12, 31, 0)), Ok(ymd_hms(1969, 12, 29, 0)));
+        assert_eq!(trunc(ymd_hms(1969, 12, 28, 23)), Ok(ymd_hms(1969, 12, 22, 0)));
+
+        // Any Monday at midnight is the same anchor.
+        let other_monday =
+            NaiveDate::from_ymd_opt(2100, 3, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        assert_eq!(
+            ymd_hms(2024, 1, 11, 12).duration_round_anchored(week, other_monday),
+            Ok(ymd_hms(2024, 1, 15, 0))
+        );
+
+        // The rounding is done on the local time.
+        let tz = FixedOffset::east_opt(-5 * 3600).unwrap();
+        let dt = tz.with_ymd_and_hms(2024, 1, 14, 23, 0, 0).unwrap();
+        assert_eq!(
+            dt.duration_trunc_anchored(week, monday),
+            Ok(tz.with_ymd_and_hms(2024, 1, 8, 0, 0, 0).unwrap())
+        );
+
+        // NaiveDateTime
+        let naive = ymd_hms(2024, 1, 11, 12).naive_utc();
+        assert_eq!(
+            naive.duration_round_anchored(week, monday),
+            Ok(ymd_hms(2024, 1, 15, 0).naive_utc())
+        );
+        assert_eq!(
+            naive.duration_trunc_anchored(week, monday),
+            Ok(ymd_hms(2024, 1, 8, 0).naive_utc())
+        );
+
+        // Days anchored at noon
+        let noon = epoch + TimeDelta::hours(12);
+        let day = TimeDelta::days(1);
+        assert_eq!(naive.duration_trunc_anchored(day, noon), Ok(naive));
+        assert_eq!(
+            (naive - TimeDelta::minutes(1)).duration_trunc_anchored(day, noon),
+            Ok(naive - day)
+        );
+
+        // Errors are the same as without an anchor.
+        assert_eq!(
+            naive.duration_round_anchored(TimeDelta::nanoseconds(-1), monday),
+            Err(RoundingError::DurationExceedsLimit)
+        );
+        assert_eq!(
+            naive.duration_trunc_anchored(TimeDelta::days(300 * 365), monday),
+            Err(RoundingError::DurationExceedsLimit)
+        );
+        let far = Utc.with_ymd_and_hms(2300, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            far.duration_round_anchored(week, monday),
+            Err(RoundingError::TimestampExceedsLimit)
+        );
+    }
+
+    #[test]
+    fn test_duration_round_anchored_limits() {
+        let week = TimeDelta::weeks(1);
+        let monday = NaiveDate::from_ymd_opt(1970, 1, 5).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        for dt in [DateTime::<Utc>::MIN_UTC, DateTime::<Utc>::MAX_UTC] {
+            assert_eq!(
+                dt.duration_round_anchored(week, monday),
+                Err(RoundingError::TimestampExceedsLimit)
+            );
+            assert_eq!(
+                dt.duration_trunc_anchored(week, monday),
+                Err(RoundingError::TimestampExceedsLimit)
+            );
+            assert_eq!(
+                dt.naive_utc().duration_round_anchored(week, monday),
+                Err(RoundingError::TimestampExceedsLimit)
+            );
+            assert_eq!(
+                dt.naive_utc().duration_trunc_anchored(week, monday),
+                Err(RoundingError::TimestampExceedsLimit)
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_to_nearest() {
+        let dt = Utc.with_ymd_and_hms(2024, 3, 1, 12, 7, 30).unwrap();
+        let interval = TimeDelta::try_minutes(15).unwrap();
+        assert_eq!(dt.round_to_nearest(interval).unwrap().to_string(), "2024-03-01 12:15:00 UTC");
+        assert_eq!(dt.floor_to_nearest(interval).unwrap().to_string(), "2024-03-01 12:00:00 UTC");
+        assert_eq!(dt.ceil_to_nearest(interval).unwrap().to_string(), "2024-03-01 12:15:00 UTC");
+
+        let dt = Utc.with_ymd_and_hms(2024, 3, 1, 12, 15, 0).unwrap();
+        assert_eq!(dt.round_to_nearest(interval), Ok(dt));
+        assert_eq!(dt.floor_to_nearest(interval), Ok(dt));
+        assert_eq!(dt.ceil_to_nearest(interval), Ok(dt));
+
+        // 90 seconds
+        let interval = TimeDelta::try_seconds(90).unwrap();
+        let dt = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 44).unwrap();
+        assert_eq!(dt.round_to_nearest(interval).unwrap().to_string(), "2024-03-01 12:00:00 UTC");
+        let dt = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 45).unwrap();
+        assert_eq!(dt.round_to_nearest(interval).unwrap().to_string(), "2024-03-01 12:01:30 UTC");
+
+        // pre-epoch
+        let dt = Utc.with_ymd_and_hms(1969, 12, 31, 23, 0, 0).unwrap();
+        let interval = TimeDelta::try_minutes(7).unwrap();
+        assert_eq!(dt.floor_to_nearest(interval).unwrap().to_string(), "1969-12-31 22:57:00 UTC");
+        assert_eq!(dt.ceil_to_nearest(interval).unwrap().to_string(), "1969-12-31 23:04:00 UTC");
+    }
+
+    #[test]
+    fn test_round_to_nearest_subsec() {
+        let dt = NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_milli_opt(12, 0, 0, 125)
+            .unwrap()
+            .and_utc();
+        let interval = TimeDelta::try_milliseconds(250).unwrap();
+        assert_eq!(
+            dt.round_to_nearest(interval).unwrap().to_string(),
+            "2024-03-01 12:00:00.250 UTC"
+        );
+        assert_eq!(dt.floor_to_nearest(interval).unwrap().to_string(), "2024-03-01 12:00:00 UTC");
+        assert_eq!(
+            dt.ceil_to_nearest(interval).unwrap().to_string(),
+            "2024-03-01 12:00:00.250 UTC"
+        );
+
+        let interval = TimeDelta::nanoseconds(3);
+        let dt = DateTime::from_timestamp(0, 4).unwrap();
+        assert_eq!(dt.round_to_nearest(interval), Ok(DateTime::from_timestamp(0, 3).unwrap()));
+        let dt = DateTime::from_timestamp(-1, 999_999_998).unwrap();
+        assert_eq!(
+            dt.round_to_nearest(interval),
+            Ok(DateTime::from_timestamp(-1, 999_999_997).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_round_to_nearest_large_interval() {
+        let dt = Utc.with_ymd_and_hms(2024, 3, 1, 12, 7, 30).unwrap();
+        let interval = TimeDelta::try_hours(36).unwrap();
+        assert_eq!(dt.round_to_nearest(interval).unwrap().to_string(), "2024-03-01 12:00:00 UTC");
+        assert_eq!(dt.floor_to_nearest(interval).unwrap().to_string(), "2024-03-01 12:00:00 UTC");
+        assert_eq!(dt.ceil_to_nearest(interval).unwrap().to_string(), "2024-03-03 00:00:00 UTC");
+
+        // Intervals that don't divide a day are counted from the epoch, and don't drift.
+        let interval = TimeDelta::try_minutes(7).unwrap();
+        for day in 1..=31 {
+            let dt = Utc.with_ymd_and_hms(2024, 1, day, 0, 0, 0).unwrap();
+            let rounded = dt.round_to_nearest(interval).unwrap();
+            assert_eq!(rounded.timestamp() % (7 * 60), 0);
+            assert!((rounded - dt).abs() <= TimeDelta::try_seconds(210).unwrap());
+        }
+
+        // Rounding near the end of the representable range fails instead of panicking.
+        let interval = TimeDelta::try_days(365 * 1000).unwrap();
+        assert_eq!(
+            DateTime::<Utc>::MAX_UTC.ceil_to_nearest(interval),
+            Err(RoundingError::TimestampExceedsLimit)
+        );
+        assert_eq!(
+            DateTime::<Utc>::MIN_UTC.floor_to_nearest(interval),
+            Err(RoundingError::TimestampExceedsLimit)
+        );
+    }
+
+    #[test]
+    fn test_round_to_nearest_fixed_offset() {
+        let dt = FixedOffset::east_opt(5 * 3600 + 1800)
+            .unwrap()
+            .with_ymd_and_hms(2024, 3, 1, 12, 20, 0)
+            .unwrap();
+        let interval = TimeDelta::try_hours(1).unwrap();
+        // Multiples of an hour from the epoch fall on the half hour in +05:30.
+        assert_eq!(
+            dt.round_to_nearest(interval).unwrap().to_string(),
+            "2024-03-01 12:30:00 +05:30"
+        );
+        assert_eq!(
+            dt.floor_to_nearest(interval).unwrap().to_string(),
+            "2024-03-01 11:30:00 +05:30"
+        );
+    }
+
+    #[test]
+    fn test_round_to_nearest_invalid_interval() {
+        let dt = Utc.with_ymd_and_hms(2024, 3, 1, 12, 7, 30).unwrap();
+        for interval in
+            [TimeDelta::zero(), TimeDelta::nanoseconds(-1), -TimeDelta::try_hours(1).unwrap()]
+        {
+            assert_eq!(dt.round_to_nearest(interval), Err(RoundingError::DurationExceedsLimit));
+            assert_eq!(dt.floor_to_nearest(interval), Err(RoundingError::DurationExceedsLimit));
+            assert_eq!(dt.ceil_to_nearest(interval), Err(RoundingError::DurationExceedsLimit));
+        }
+    }
 }
@@ -10,12 +10,15 @@
 
 //! Temporal quantification
 
+#[cfg(all(feature = "alloc", not(feature = "std"), not(test)))]
+use alloc::string::String;
 use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
 use core::time::Duration;
 use core::{fmt, i64};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use crate::format::{ParseError, INVALID, OUT_OF_RANGE, TOO_SHORT};
 use crate::{expect, try_opt};
 
 #[cfg(any(feature = "rkyv", feature = "rkyv-16", feature = "rkyv-32", feature = "rkyv-64"))]
@@ -80,6 +83,18 @@ impl TimeDelta {
     /// # Errors
     ///
     /// Returns `None` when the duration is out of bounds, or if `nanos` ≥ 1,000,000,000.
+    ///
+    /// # Example
+    ///
+    /// This and the other constructors are `const fn`, so they can be used to define constants:
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// const TIMEOUT: TimeDelta = TimeDelta::seconds(30);
+    /// const PRECISE: Option<TimeDelta> = TimeDelta::new(30, 500_000_000);
+    /// assert_eq!(PRECISE, Some(TIMEOUT + TimeDelta::milliseconds(500)));
+    /// ```
     pub const fn new(secs: i64, nanos: u32) -> Option<TimeDelta> {
         if secs < MIN.secs
             || secs > MAX.secs
@@ -307,9 +322,44 @@ impl TimeDelta {
         }
     }
 
+    /// Returns the number of whole seconds in the `TimeDelta`, rounded towards zero.
+    ///
+    /// This is the same as [`num_seconds`](TimeDelta::num_seconds), and is the counterpart of
+    /// [`subsec_nanos`](TimeDelta::subsec_nanos) when splitting a `TimeDelta` into its components.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// let delta = TimeDelta::milliseconds(-1_500);
+    /// assert_eq!(delta.whole_seconds(), -1);
+    /// assert_eq!(delta.subsec_nanos(), -500_000_000);
+    /// assert_eq!(
+    ///     TimeDelta::seconds(delta.whole_seconds()) + TimeDelta::nanoseconds(delta.subsec_nanos().into()),
+    ///     delta
+    /// );
+    /// ```
+    #[inline]
+    pub const fn whole_seconds(&self) -> i64 {
+        self.num_seconds()
+    }
+
     /// Returns the number of nanoseconds such that
     /// `subsec_nanos() + num_seconds() * NANOS_PER_SEC` is the total number of
     /// nanoseconds in the `TimeDelta`.
+    ///
+    /// The result has the same sign as the `TimeDelta`, so it ranges from -999,999,999 to
+    /// 999,999,999. Unlike [`std::time::Duration::subsec_nanos`] it is not always positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::milliseconds(1_500).subsec_nanos(), 500_000_000);
+    /// assert_eq!(TimeDelta::milliseconds(-1_500).subsec_nanos(), -500_000_000);
+    /// ```
     pub const fn subsec_nanos(&self) -> i32 {
         if self.secs < 0 && self.nanos > 0 {
             self.nanos - NANOS_PER_SEC
@@ -318,13 +368,31 @@ impl TimeDelta {
         }
     }
 
+    /// Returns the number of whole microseconds in the fractional part of the `TimeDelta`.
+    ///
+    /// Like [`subsec_nanos`](TimeDelta::subsec_nanos), the result has the same sign as the
+    /// `TimeDelta`, so it ranges from -999,999 to 999,999.
+    #[inline]
+    pub const fn subsec_micros(&self) -> i32 {
+        self.subsec_nanos() / NANOS_PER_MICRO
+    }
+
+    /// Returns the number of whole milliseconds in the fractional part of the `TimeDelta`.
+    ///
+    /// Like [`subsec_nanos`](TimeDelta::subsec_nanos), the result has the same sign as the
+    /// `TimeDelta`, so it ranges from -999 to 999.
+    #[inline]
+    pub const fn subsec_millis(&self) -> i32 {
+        self.subsec_nanos() / NANOS_PER_MILLI
+    }
+
     /// Returns the total number of whole milliseconds in the `TimeDelta`.
     pub const fn num_milliseconds(&self) -> i64 {
         // A proper TimeDelta will not overflow, because MIN and MAX are defined such
         // that the range is within the bounds of an i64, from -i64::MAX through to
         // +i64::MAX inclusive. Notably, i64::MIN is excluded from this range.
         let secs_part = self.num_seconds() * MILLIS_PER_SEC;
-        let nanos_part = self.subsec_nanos() / NANOS_PER_MILLI;
+        let nanos_part = self.subsec_millis();
         secs_part + nanos_part as i64
     }
 
@@ -332,7 +400,7 @@ impl TimeDelta {
     /// or `None` on overflow (exceeding 2^63 microseconds in either direction).
     pub const fn num_microseconds(&self) -> Option<i64> {
         let secs_part = try_opt!(self.num_seconds().checked_mul(MICROS_PER_SEC));
-        let nanos_part = self.subsec_nanos() / NANOS_PER_MICRO;
+        let nanos_part = self.subsec_micros();
         secs_part.checked_add(nanos_part as i64)
     }
 
@@ -406,6 +474,169 @@ impl TimeDelta {
         Some(TimeDelta { secs, nanos })
     }
 
+    /// Returns how many whole times `rhs` fits in this `TimeDelta`.
+    ///
+    /// The division truncates towards zero like integer division, so the remainder from
+    /// [`rem_duration`](TimeDelta::rem_duration) has the same sign as `self`.
+    ///
+    /// Returns `None` if `rhs` is zero, or if the result doesn't fit in an `i64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// let total = TimeDelta::hours(7);
+    /// assert_eq!(total.div_duration(&TimeDelta::hours(2)), Some(3));
+    /// assert_eq!(total.rem_duration(&TimeDelta::hours(2)), Some(TimeDelta::hours(1)));
+    /// assert_eq!((-total).div_duration(&TimeDelta::hours(2)), Some(-3));
+    /// assert_eq!(total.div_duration(&TimeDelta::zero()), None);
+    /// ```
+    #[must_use]
+    pub const fn div_duration(&self, rhs: &TimeDelta) -> Option<i64> {
+        if rhs.is_zero() {
+            return None;
+        }
+        // Divide as i128 nanoseconds, which can't overflow for any `TimeDelta`.
+        let quotient = self.as_nanos_i128() / rhs.as_nanos_i128();
+        if quotient < i64::MIN as i128 || quotient > i64::MAX as i128 {
+            return None;
+        }
+        Some(quotient as i64)
+    }
+
+    /// Returns the part of this `TimeDelta` that is left after dividing it by `rhs`.
+    ///
+    /// The remainder has the same sign as `self`, and is smaller in magnitude than `rhs`. See
+    /// [`div_duration`](TimeDelta::div_duration).
+    ///
+    /// Returns `None` if `rhs` is zero.
+    #[must_use]
+    pub const fn rem_duration(&self, rhs: &TimeDelta) -> Option<TimeDelta> {
+        if rhs.is_zero() {
+            return None;
+        }
+        let rem = self.as_nanos_i128() % rhs.as_nanos_i128();
+        let secs = rem.div_euclid(NANOS_PER_SEC as i128) as i64;
+        let nanos = rem.rem_euclid(NANOS_PER_SEC as i128) as i32;
+        Some(TimeDelta { secs, nanos })
+    }
+
+    /// Multiply a `TimeDelta` by a floating point factor, rounding to the nearest nanosecond.
+    ///
+    /// Returns `None` if the result would be out of range, or if `factor` is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// let delta = TimeDelta::seconds(10);
+    /// assert_eq!(delta.checked_mul_f64(1.5), Some(TimeDelta::seconds(15)));
+    /// assert_eq!(delta.checked_mul_f64(-0.1), Some(TimeDelta::seconds(-1)));
+    /// assert_eq!(TimeDelta::max_value().checked_mul_f64(2.0), None);
+    /// assert_eq!(delta.checked_mul_f64(f64::NAN), None);
+    /// ```
+    #[must_use]
+    pub fn checked_mul_f64(self, factor: f64) -> Option<TimeDelta> {
+        TimeDelta::from_nanos_f64(self.as_nanos_i128() as f64 * factor)
+    }
+
+    /// Multiply a `TimeDelta` by a floating point factor, rounding to the nearest nanosecond.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result would be out of range, or if `factor` is not finite. See
+    /// [`checked_mul_f64`](TimeDelta::checked_mul_f64) for a version that doesn't panic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::seconds(10).mul_f64(1.5), TimeDelta::seconds(15));
+    /// assert_eq!(TimeDelta::seconds(10).mul_f64(0.1), TimeDelta::seconds(1));
+    /// ```
+    #[must_use]
+    pub fn mul_f64(self, factor: f64) -> TimeDelta {
+        self.checked_mul_f64(factor).expect("`TimeDelta::mul_f64` overflowed")
+    }
+
+    /// Divide a `TimeDelta` by a floating point divisor, rounding to the nearest nanosecond.
+    ///
+    /// Returns `None` if the result would be out of range, or if it is not finite, which
+    /// includes dividing by zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// let delta = TimeDelta::seconds(15);
+    /// assert_eq!(delta.checked_div_f64(1.5), Some(TimeDelta::seconds(10)));
+    /// assert_eq!(delta.checked_div_f64(0.0), None);
+    /// ```
+    #[must_use]
+    pub fn checked_div_f64(self, divisor: f64) -> Option<TimeDelta> {
+        TimeDelta::from_nanos_f64(self.as_nanos_i128() as f64 / divisor)
+    }
+
+    /// Divide a `TimeDelta` by a floating point divisor, rounding to the nearest nanosecond.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result would be out of range, or if it is not finite, which includes
+    /// dividing by zero. See [`checked_div_f64`](TimeDelta::checked_div_f64) for a version that
+    /// doesn't panic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::seconds(15).div_f64(1.5), TimeDelta::seconds(10));
+    /// assert_eq!(TimeDelta::seconds(1).div_f64(3.0), TimeDelta::nanoseconds(333_333_333));
+    /// ```
+    #[must_use]
+    pub fn div_f64(self, divisor: f64) -> TimeDelta {
+        self.checked_div_f64(divisor).expect("`TimeDelta::div_f64` overflowed")
+    }
+
+    /// Makes a `TimeDelta` from a floating point number of nanoseconds, rounding half away from
+    /// zero. Returns `None` if the value is out of range or not finite.
+    fn from_nanos_f64(nanos: f64) -> Option<TimeDelta> {
+        let max = MAX.as_nanos_i128();
+        // This comparison is also false for NaN.
+        if !(nanos >= -(max as f64) && nanos <= max as f64) {
+            return None;
+        }
+        // `f64::round` is not available in `core`.
+        let mut total = nanos as i128;
+        let frac = nanos - total as f64;
+        if frac >= 0.5 {
+            total += 1;
+        } else if frac <= -0.5 {
+            total -= 1;
+        }
+        TimeDelta::from_nanos_i128(total)
+    }
+
+    /// Makes a `TimeDelta` from a number of nanoseconds, or `None` if it is out of range.
+    fn from_nanos_i128(nanos: i128) -> Option<TimeDelta> {
+        let max = MAX.as_nanos_i128();
+        if nanos > max || nanos < -max {
+            return None;
+        }
+        let secs = nanos.div_euclid(NANOS_PER_SEC as i128) as i64;
+        let nanos = nanos.rem_euclid(NANOS_PER_SEC as i128) as i32;
+        Some(TimeDelta { secs, nanos })
+    }
+
+    /// Returns the total number of nanoseconds, which always fits in an `i128`.
+    const fn as_nanos_i128(&self) -> i128 {
+        self.secs as i128 * NANOS_PER_SEC as i128 + self.nanos as i128
+    }
+
     /// Returns the `TimeDelta` as an absolute (non-negative) value.
     #[inline]
     pub const fn abs(&self) -> TimeDelta {
@@ -416,6 +647,61 @@ impl TimeDelta {
         }
     }
 
+    /// Returns the absolute difference between `self` and `other`, which is never negative.
+    ///
+    /// Unlike `(self - other).abs()` this can't panic. If `self` and `other` have opposite signs
+    /// the difference may not fit in a `TimeDelta`, as for the minimum and the maximum value.
+    ///
+    /// # Warning
+    ///
+    /// A difference that doesn't fit is silently saturated at [`TimeDelta::max_value()`], which
+    /// is not the true difference. Nothing in the result tells the two cases apart. Use
+    /// [`TimeDelta::checked_abs_diff`] instead, unless the inputs are known to be close enough or
+    /// a saturated value is acceptable, for example as a "very large" sort key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// let a = TimeDelta::minutes(5);
+    /// let b = TimeDelta::seconds(-30);
+    /// assert_eq!(a.abs_diff(b), TimeDelta::seconds(330));
+    /// assert_eq!(b.abs_diff(a), TimeDelta::seconds(330));
+    /// let (min, max) = (TimeDelta::min_value(), TimeDelta::max_value());
+    /// assert_eq!(min.abs_diff(max), max);
+    /// ```
+    #[must_use]
+    pub const fn abs_diff(self, other: TimeDelta) -> TimeDelta {
+        match self.checked_abs_diff(other) {
+            Some(diff) => diff,
+            None => MAX,
+        }
+    }
+
+    /// Returns the absolute difference between `self` and `other`, or `None` if it does not fit
+    /// in a `TimeDelta`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// let a = TimeDelta::minutes(5);
+    /// assert_eq!(a.checked_abs_diff(TimeDelta::seconds(-30)), Some(TimeDelta::seconds(330)));
+    /// assert_eq!(TimeDelta::min_value().checked_abs_diff(TimeDelta::max_value()), None);
+    /// ```
+    #[must_use]
+    pub const fn checked_abs_diff(self, other: TimeDelta) -> Option<TimeDelta> {
+        let diff = (self.as_nanos_i128() - other.as_nanos_i128()).abs();
+        if diff > MAX.as_nanos_i128() {
+            return None;
+        }
+        let secs = (diff / NANOS_PER_SEC as i128) as i64;
+        let nanos = (diff % NANOS_PER_SEC as i128) as i32;
+        Some(TimeDelta { secs, nanos })
+    }
+
     /// The minimum possible `TimeDelta`: `-i64::MAX` milliseconds.
     #[inline]
     pub const fn min_value() -> TimeDelta {
@@ -440,10 +726,343 @@ impl TimeDelta {
         self.secs == 0 && self.nanos == 0
     }
 
+    /// Returns a value that formats the `TimeDelta` in the given [`TimeDeltaStyle`] with
+    /// [`Display`](fmt::Display).
+    ///
+    /// Hours are not rolled over into days unless enabled with [`TimeDeltaDisplay::with_days`].
+    /// A negative `TimeDelta` is written with a leading `-`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use chrono::{TimeDelta, TimeDeltaStyle};
+    ///
+    /// let delta = TimeDelta::new(3_723, 500_000_000).unwrap();
+    /// assert_eq!(delta.display_with(TimeDeltaStyle::Colonized).to_string(), "01:02:03");
+    /// assert_eq!(delta.display_with(TimeDeltaStyle::Verbose).to_string(), "1h 2m 3.5s");
+    /// assert_eq!(delta.display_with(TimeDeltaStyle::Compact).to_string(), "1:02:03.500");
+    /// assert_eq!((-delta).display_with(TimeDeltaStyle::Colonized).to_string(), "-01:02:03");
+    ///
+    /// let long = TimeDelta::hours(26);
+    /// assert_eq!(long.display_with(TimeDeltaStyle::Colonized).to_string(), "26:00:00");
+    /// assert_eq!(
+    ///     long.display_with(TimeDeltaStyle::Colonized).with_days(true).to_string(),
+    ///     "1d 02:00:00"
+    /// );
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn display_with(&self, style: TimeDeltaStyle) -> TimeDeltaDisplay {
+        TimeDeltaDisplay { delta: *self, style, days: false }
+    }
+
+    /// Describes the `TimeDelta` as an English phrase relative to now, such as `"in 5 minutes"`
+    /// or `"3 hours ago"`.
+    ///
+    /// Only the largest [`RelativeTimeUnit`](crate::RelativeTimeUnit) that fits at least once is
+    /// used, with months counted as 30 days and years as 365 days. A positive `TimeDelta` is
+    /// described as being in the future, a negative one as being in the past. A `TimeDelta` of less
+    /// than one second is described as `"just now"`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::minutes(5).humanize(), "in 5 minutes");
+    /// assert_eq!(TimeDelta::minutes(-200).humanize(), "3 hours ago");
+    /// assert_eq!(TimeDelta::days(1).humanize(), "in 1 day");
+    /// assert_eq!(TimeDelta::zero().humanize(), "just now");
+    /// ```
+    #[cfg(feature = "humanize")]
+    #[must_use]
+    pub fn humanize(&self) -> String {
+        crate::humanize::humanize(*self)
+    }
+
+    /// Formats the `TimeDelta` as an [ISO 8601] duration using days, hours, minutes and seconds,
+    /// such as `PT1H30M` or `P2DT3H`.
+    ///
+    /// Only the components that are not zero are included, and a zero `TimeDelta` is written as
+    /// `PT0S`. Fractional seconds are written with as many digits as needed. A negative
+    /// `TimeDelta` is written with a leading `-`, which is not part of ISO 8601 but is accepted by
+    /// [`TimeDelta::from_iso8601`].
+    ///
+    /// Unlike the [`Display`](fmt::Display) implementation, which only uses seconds, this splits
+    /// the duration into larger units. A day is always 24 hours.
+    ///
+    /// [ISO 8601]: https://en.wikipedia.org/wiki/ISO_8601#Durations
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::minutes(90).to_iso8601(), "PT1H30M");
+    /// assert_eq!(TimeDelta::hours(51).to_iso8601(), "P2DT3H");
+    /// assert_eq!(TimeDelta::milliseconds(-1_500).to_iso8601(), "-PT1.5S");
+    /// assert_eq!(TimeDelta::zero().to_iso8601(), "PT0S");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_iso8601(&self) -> String {
+        let mut result = String::new();
+        self.write_iso8601(&mut result).expect("writing to a string should never fail");
+        result
+    }
+
+    #[cfg(any(feature = "alloc", feature = "serde"))]
+    fn write_iso8601(&self, w: &mut impl fmt::Write) -> fmt::Result {
+        let (abs, sign) = if self.secs < 0 { (-*self, "-") } else { (*self, "") };
+        write!(w, "{}P", sign)?;
+        if abs.is_zero() {
+            return w.write_str("T0S");
+        }
+
+        let days = abs.secs / SECS_PER_DAY;
+        let hours = abs.secs % SECS_PER_DAY / SECS_PER_HOUR;
+        let minutes = abs.secs % SECS_PER_HOUR / SECS_PER_MINUTE;
+        let seconds = abs.secs % SECS_PER_MINUTE;
+        if days > 0 {
+            write!(w, "{}D", days)?;
+        }
+        if hours == 0 && minutes == 0 && seconds == 0 && abs.nanos == 0 {
+            return Ok(());
+        }
+        w.write_char('T')?;
+        if hours > 0 {
+            write!(w, "{}H", hours)?;
+        }
+        if minutes > 0 {
+            write!(w, "{}M", minutes)?;
+        }
+        if seconds > 0 || abs.nanos > 0 {
+            write!(w, "{}", seconds)?;
+            write_fraction(w, abs.nanos)?;
+            w.write_char('S')?;
+        }
+        Ok(())
+    }
+
+    /// Parses an [ISO 8601] duration, such as `PT1H30M` or `P2DT3H`.
+    ///
+    /// Because a `TimeDelta` has no calendar context, only the weeks (`W`), days (`D`), hours
+    /// (`H`), minutes (`M` after the `T`) and seconds (`S`) designators are accepted. A week is
+    /// always 7 days, and a day is always 24 hours. Seconds may have a fraction with up to nine
+    /// significant digits, using either `.` or `,` as the decimal sign. The duration may be
+    /// preceded by a `-` or `+` sign.
+    ///
+    /// This accepts the output of both [`TimeDelta::to_iso8601`] and the [`Display`](fmt::Display)
+    /// implementation.
+    ///
+    /// [ISO 8601]: https://en.wikipedia.org/wiki/ISO_8601#Durations
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The string is not a valid duration, or the designators are not in descending order.
+    /// - The string contains a years (`Y`) or months (`M` before the `T`) designator, because
+    ///   their length depends on the calendar.
+    /// - The duration is out of range for a `TimeDelta`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::from_iso8601("PT1H30M"), Ok(TimeDelta::minutes(90)));
+    /// assert_eq!(TimeDelta::from_iso8601("P1W2D"), Ok(TimeDelta::days(9)));
+    /// assert_eq!(TimeDelta::from_iso8601("-PT0.25S"), Ok(TimeDelta::milliseconds(-250)));
+    /// assert!(TimeDelta::from_iso8601("P1M").is_err());
+    /// assert!(TimeDelta::from_iso8601("P1Y").is_err());
+    /// ```
+    pub fn from_iso8601(s: &str) -> Result<TimeDelta, ParseError> {
+        let (negative, s) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let mut s = match s.as_bytes().first() {
+            Some(b'P') => &s[1..],
+            Some(_) => return Err(INVALID),
+            None => return Err(TOO_SHORT),
+        };
+
+        let mut total = TimeDelta::zero();
+        let mut in_time = false;
+        // Rank of the last designator, to ensure they are in order and appear only once.
+        let mut last_rank = 0;
+        while let Some(&c) = s.as_bytes().first() {
+            if c == b'T' {
+                if in_time {
+                    return Err(INVALID);
+                }
+                in_time = true;
+                s = &s[1..];
+                continue;
+            }
+
+            let digits = s.bytes().take_while(u8::is_ascii_digit).count();
+            if digits == 0 {
+                return Err(INVALID);
+            }
+            let mut value = 0i64;
+            for c in s[..digits].bytes() {
+                value = value
+                    .checked_mul(10)
+                    .and_then(|v| v.checked_add(i64::from(c - b'0')))
+                    .ok_or(OUT_OF_RANGE)?;
+            }
+            s = &s[digits..];
+
+            let mut nanos = 0;
+            let has_fraction = matches!(s.as_bytes().first(), Some(b'.' | b','));
+            if has_fraction {
+                let digits = s[1..].bytes().take_while(u8::is_ascii_digit).count();
+                if digits == 0 {
+                    return Err(INVALID);
+                }
+                // Digits beyond nanosecond precision are ignored.
+                let mut scale = NANOS_PER_SEC as u32;
+                for c in s[1..=digits].bytes().take(9) {
+                    scale /= 10;
+                    nanos += u32::from(c - b'0') * scale;
+                }
+                s = &s[1 + digits..];
+            }
+
+            let (rank, unit) = match (in_time, s.as_bytes().first()) {
+                (false, Some(b'W')) => (1, SECS_PER_WEEK),
+                (false, Some(b'D')) => (2, SECS_PER_DAY),
+                (true, Some(b'H')) => (3, SECS_PER_HOUR),
+                (true, Some(b'M')) => (4, SECS_PER_MINUTE),
+                (true, Some(b'S')) => (5, 1),
+                (_, None) => return Err(TOO_SHORT),
+                // This includes the calendar-dependent `Y` and `M` designators.
+                (_, Some(_)) => return Err(INVALID),
+            };
+            if rank <= last_rank || (has_fraction && rank != 5) {
+                return Err(INVALID);
+            }
+            last_rank = rank;
+            s = &s[1..];
+
+            let secs = value.checked_mul(unit).ok_or(OUT_OF_RANGE)?;
+            let delta = TimeDelta::new(secs, nanos).ok_or(OUT_OF_RANGE)?;
+            total = total.checked_add(&delta).ok_or(OUT_OF_RANGE)?;
+        }
+        // There must be at least one component, and at least one after the `T`.
+        if last_rank == 0 || (in_time && last_rank < 3) {
+            return Err(TOO_SHORT);
+        }
+
+        Ok(if negative { -total } else { total })
+    }
+
+    /// Parses a human-readable duration made of integer components with a unit suffix, such as
+    /// `1h30m` or `2d 12h`.
+    ///
+    /// The supported units are nanoseconds (`ns`), microseconds (`us` or `µs`), milliseconds
+    /// (`ms`), seconds (`s`), minutes (`m`), hours (`h`), days (`d`) and weeks (`w`). A day is
+    /// always 24 hours and a week always 7 days. Components may appear in any order, may be
+    /// repeated, and may be separated by spaces; their values are summed. The duration may be
+    /// preceded by a `-` or `+` sign, which applies to the total.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The string is empty, or a number is not followed by a unit.
+    /// - A component has no digits or an unknown unit, such as the calendar-dependent `y`.
+    /// - The duration is out of range for a `TimeDelta`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::from_human_str("1h30m"), Ok(TimeDelta::minutes(90)));
+    /// assert_eq!(TimeDelta::from_human_str("30m 1h"), Ok(TimeDelta::minutes(90)));
+    /// assert_eq!(TimeDelta::from_human_str("-500ms"), Ok(TimeDelta::milliseconds(-500)));
+    /// assert_eq!(TimeDelta::from_human_str("2w"), Ok(TimeDelta::weeks(2)));
+    /// assert!(TimeDelta::from_human_str("1y").is_err());
+    /// assert!(TimeDelta::from_human_str("abc").is_err());
+    /// ```
+    pub fn from_human_str(s: &str) -> Result<TimeDelta, ParseError> {
+        // Longer suffixes come first, so `ms` is not mistaken for `m`.
+        const UNITS: [(&str, i128); 10] = [
+            ("ns", 1),
+            ("us", NANOS_PER_MICRO as i128),
+            ("µs", NANOS_PER_MICRO as i128),
+            ("μs", NANOS_PER_MICRO as i128),
+            ("ms", NANOS_PER_MILLI as i128),
+            ("s", NANOS_PER_SEC as i128),
+            ("m", SECS_PER_MINUTE as i128 * NANOS_PER_SEC as i128),
+            ("h", SECS_PER_HOUR as i128 * NANOS_PER_SEC as i128),
+            ("d", SECS_PER_DAY as i128 * NANOS_PER_SEC as i128),
+            ("w", SECS_PER_WEEK as i128 * NANOS_PER_SEC as i128),
+        ];
+
+        let (negative, mut s) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        if s.is_empty() {
+            return Err(TOO_SHORT);
+        }
+
+        let mut total = 0i128;
+        while !s.is_empty() {
+            let digits = s.bytes().take_while(u8::is_ascii_digit).count();
+            if digits == 0 {
+                return Err(INVALID);
+            }
+            let mut value = 0i64;
+            for c in s[..digits].bytes() {
+                value = value
+                    .checked_mul(10)
+                    .and_then(|v| v.checked_add(i64::from(c - b'0')))
+                    .ok_or(OUT_OF_RANGE)?;
+            }
+            s = &s[digits..];
+            if s.is_empty() {
+                return Err(TOO_SHORT);
+            }
+
+            let (rest, unit) = UNITS
+                .iter()
+                .find_map(|&(suffix, unit)| s.strip_prefix(suffix).map(|rest| (rest, unit)))
+                .ok_or(INVALID)?;
+            s = rest.trim_start_matches(' ');
+
+            // Both factors are below 2^63, so the product fits in an `i128`.
+            total = total.checked_add(i128::from(value) * unit).ok_or(OUT_OF_RANGE)?;
+        }
+
+        let total = if negative { -total } else { total };
+        TimeDelta::from_nanos_i128(total).ok_or(OUT_OF_RANGE)
+    }
+
     /// Creates a `TimeDelta` object from `std::time::Duration`
     ///
-    /// This function errors when original duration is larger than the maximum
-    /// value supported for this type.
+    /// This is also available as `TimeDelta::try_from(duration)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OutOfRangeError`] if the duration is larger than
+    /// [`TimeDelta::max_value()`], which is `i64::MAX` milliseconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(TimeDelta::from_std(Duration::from_millis(1500)), Ok(TimeDelta::milliseconds(1500)));
+    /// assert!(TimeDelta::from_std(Duration::from_secs(i64::MAX as u64)).is_err());
+    /// ```
     pub const fn from_std(duration: Duration) -> Result<TimeDelta, OutOfRangeError> {
         // We need to check secs as u64 before coercing to i64
         if duration.as_secs() > MAX.secs as u64 {
@@ -457,8 +1076,24 @@ impl TimeDelta {
 
     /// Creates a `std::time::Duration` object from a `TimeDelta`.
     ///
-    /// This function errors when duration is less than zero. As standard
-    /// library implementation is limited to non-negative values.
+    /// This is also available as `Duration::try_from(delta)`. Use
+    /// [`abs_to_std`](TimeDelta::abs_to_std) to convert a negative `TimeDelta`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OutOfRangeError`] if the `TimeDelta` is negative, as the standard library
+    /// implementation is limited to non-negative values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(TimeDelta::milliseconds(1500).to_std(), Ok(Duration::from_millis(1500)));
+    /// assert_eq!(TimeDelta::zero().to_std(), Ok(Duration::ZERO));
+    /// assert!(TimeDelta::milliseconds(-1500).to_std().is_err());
+    /// ```
     pub const fn to_std(&self) -> Result<Duration, OutOfRangeError> {
         if self.secs < 0 {
             return Err(OutOfRangeError(()));
@@ -466,6 +1101,28 @@ impl TimeDelta {
         Ok(Duration::new(self.secs as u64, self.nanos as u32))
     }
 
+    /// Splits a `TimeDelta` into its sign and its magnitude as a `std::time::Duration`.
+    ///
+    /// Returns `(true, magnitude)` if the `TimeDelta` is negative, and `(false, magnitude)`
+    /// otherwise. Unlike [`to_std`](TimeDelta::to_std) this can't fail, because the magnitude of
+    /// every `TimeDelta` fits in a `Duration`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(TimeDelta::milliseconds(-1500).abs_to_std(), (true, Duration::from_millis(1500)));
+    /// assert_eq!(TimeDelta::milliseconds(1500).abs_to_std(), (false, Duration::from_millis(1500)));
+    /// assert_eq!(TimeDelta::zero().abs_to_std(), (false, Duration::ZERO));
+    /// ```
+    #[must_use]
+    pub const fn abs_to_std(&self) -> (bool, Duration) {
+        let abs = self.abs();
+        (self.secs < 0, Duration::new(abs.secs as u64, abs.nanos as u32))
+    }
+
     /// This duplicates `Neg::neg` because trait methods can't be const yet.
     pub(crate) const fn neg(self) -> TimeDelta {
         let (secs_diff, nanos) = match self.nanos {
@@ -563,24 +1220,158 @@ impl fmt::Display for TimeDelta {
         }
 
         f.write_fmt(format_args!("T{}", abs.secs))?;
+        write_fraction(f, abs.nanos)?;
+        f.write_str("S")?;
+        Ok(())
+    }
+}
+
+/// Writes `nanos` as a fraction of a second, without trailing zeros.
+///
+/// Nothing is written if `nanos` is zero.
+fn write_fraction(w: &mut impl fmt::Write, nanos: i32) -> fmt::Result {
+    if nanos > 0 {
+        // Count the number of significant digits, while removing all trailing zero's.
+        let mut figures = 9usize;
+        let mut fraction_digits = nanos;
+        loop {
+            let div = fraction_digits / 10;
+            let last_digit = fraction_digits % 10;
+            if last_digit != 0 {
+                break;
+            }
+            fraction_digits = div;
+            figures -= 1;
+        }
+        w.write_fmt(format_args!(".{:01$}", fraction_digits, figures))?;
+    }
+    Ok(())
+}
+
+/// The style used by [`TimeDelta::display_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeDeltaStyle {
+    /// Hours, minutes and seconds separated by colons and padded to two digits, such as
+    /// `01:02:03`.
+    ///
+    /// Fractional seconds are truncated.
+    Colonized,
+    /// Every component that is not zero followed by its unit, such as `1h 2m 3.5s`.
+    ///
+    /// Fractional seconds are written with as many digits as needed. A zero `TimeDelta` is
+    /// written as `0s`.
+    Verbose,
+    /// Hours without padding, then minutes and seconds separated by colons, such as
+    /// `1:02:03.500`.
+    ///
+    /// Fractional seconds are written as milli-, micro- or nanoseconds if they are not zero.
+    Compact,
+}
 
-        if abs.nanos > 0 {
-            // Count the number of significant digits, while removing all trailing zero's.
-            let mut figures = 9usize;
-            let mut fraction_digits = abs.nanos;
-            loop {
-                let div = fraction_digits / 10;
-                let last_digit = fraction_digits % 10;
-                if last_digit != 0 {
-                    break;
+/// Formats a [`TimeDelta`] in a [`TimeDeltaStyle`].
+///
+/// Created by [`TimeDelta::display_with`]. Width, fill, alignment and precision are supported
+/// with the `alloc` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeDeltaDisplay {
+    delta: TimeDelta,
+    style: TimeDeltaStyle,
+    days: bool,
+}
+
+impl TimeDeltaDisplay {
+    /// Sets whether 24 hours or more are written as a separate number of days, such as
+    /// `1d 02:00:00` instead of `26:00:00`.
+    ///
+    /// A day is always 24 hours. This is disabled by default.
+    #[inline]
+    #[must_use]
+    pub const fn with_days(mut self, days: bool) -> TimeDeltaDisplay {
+        self.days = days;
+        self
+    }
+}
+
+impl TimeDeltaDisplay {
+    fn write_to(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        let (abs, sign) = if self.delta.secs < 0 { (-self.delta, "-") } else { (self.delta, "") };
+        f.write_str(sign)?;
+
+        let (days, mut hours) = (abs.secs / SECS_PER_DAY, abs.secs / SECS_PER_HOUR);
+        let minutes = abs.secs % SECS_PER_HOUR / SECS_PER_MINUTE;
+        let seconds = abs.secs % SECS_PER_MINUTE;
+        if self.days {
+            hours %= 24;
+        }
+        let days = if self.days { days } else { 0 };
+
+        match self.style {
+            TimeDeltaStyle::Colonized | TimeDeltaStyle::Compact => {
+                if days > 0 {
+                    write!(f, "{}d ", days)?;
+                }
+                if self.style == TimeDeltaStyle::Colonized {
+                    write!(f, "{:02}", hours)?;
+                } else {
+                    write!(f, "{}", hours)?;
+                }
+                write!(f, ":{:02}:{:02}", minutes, seconds)?;
+                if self.style == TimeDeltaStyle::Compact {
+                    match abs.nanos {
+                        0 => {}
+                        n if n % 1_000_000 == 0 => write!(f, ".{:03}", n / 1_000_000)?,
+                        n if n % 1_000 == 0 => write!(f, ".{:06}", n / 1_000)?,
+                        n => write!(f, ".{:09}", n)?,
+                    }
                 }
-                fraction_digits = div;
-                figures -= 1;
+                Ok(())
+            }
+            TimeDeltaStyle::Verbose => {
+                let mut separator = "";
+                for (value, unit) in [(days, "d"), (hours, "h"), (minutes, "m")] {
+                    if value > 0 {
+                        write!(f, "{}{}{}", separator, value, unit)?;
+                        separator = " ";
+                    }
+                }
+                if seconds > 0 || abs.nanos > 0 || separator.is_empty() {
+                    write!(f, "{}{}", separator, seconds)?;
+                    write_fraction(f, abs.nanos)?;
+                    f.write_str("s")?;
+                }
+                Ok(())
             }
-            f.write_fmt(format_args!(".{:01$}", fraction_digits, figures))?;
         }
-        f.write_str("S")?;
-        Ok(())
+    }
+}
+
+impl fmt::Display for TimeDeltaDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "alloc")]
+        if f.width().is_some() || f.precision().is_some() {
+            let mut result = String::new();
+            self.write_to(&mut result)?;
+            return f.pad(&result);
+        }
+        self.write_to(f)
+    }
+}
+
+impl TryFrom<Duration> for TimeDelta {
+    type Error = OutOfRangeError;
+
+    /// Same as [`TimeDelta::from_std`].
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        TimeDelta::from_std(duration)
+    }
+}
+
+impl TryFrom<TimeDelta> for Duration {
+    type Error = OutOfRangeError;
+
+    /// Same as [`TimeDelta::to_std`].
+    fn try_from(delta: TimeDelta) -> Result<Self, Self::Error> {
+        delta.to_std()
     }
 }
 
@@ -615,17 +1406,380 @@ const fn div_mod_floor_64(this: i64, other: i64) -> (i64, i64) {
 #[cfg(all(feature = "arbitrary", feature = "std"))]
 impl arbitrary::Arbitrary<'_> for TimeDelta {
     fn arbitrary(u: &mut arbitrary::Unstructured) -> arbitrary::Result<TimeDelta> {
-        const MIN_SECS: i64 = -i64::MAX / MILLIS_PER_SEC - 1;
-        const MAX_SECS: i64 = i64::MAX / MILLIS_PER_SEC;
+        let secs: i64 = u.int_in_range(MIN.secs..=MAX.secs)?;
+        // Only sample the nanoseconds that keep the value within `MIN..=MAX`.
+        let nanos_range = match secs {
+            s if s == MIN.secs => MIN.nanos..=(NANOS_PER_SEC - 1),
+            s if s == MAX.secs => 0..=MAX.nanos,
+            _ => 0..=(NANOS_PER_SEC - 1),
+        };
+        let nanos: i32 = u.int_in_range(nanos_range)?;
+        Ok(TimeDelta { secs, nanos })
+    }
+}
 
-        let secs: i64 = u.int_in_range(MIN_SECS..=MAX_SECS)?;
-        let nanos: i32 = u.int_in_range(0..=(NANOS_PER_SEC - 1))?;
-        let duration = TimeDelta { secs, nanos };
+/// Serialization/Deserialization of `TimeDelta` in alternate formats.
+#[cfg(feature = "serde")]
+pub(crate) mod serde {
+    use super::{TimeDelta, NANOS_PER_SEC};
+    use core::fmt;
+    use serde::{de, ser};
 
-        if duration < MIN || duration > MAX {
-            Err(arbitrary::Error::IncorrectFormat)
-        } else {
-            Ok(duration)
+    /// Serialize a `TimeDelta` as a struct with the fields `secs` and `nanos`.
+    ///
+    /// This is the internal representation: `nanos` is always in the range `0..1_000_000_000`
+    /// and `secs` is rounded towards negative infinity, so -1.5 seconds are serialized as
+    /// `{"secs":-2,"nanos":500000000}`.
+    ///
+    /// See [the `serde` module](crate::serde) for alternate serializations.
+    impl ser::Serialize for TimeDelta {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            use ser::SerializeStruct;
+            let mut state = serializer.serialize_struct("TimeDelta", 2)?;
+            state.serialize_field("secs", &self.secs)?;
+            state.serialize_field("nanos", &self.nanos)?;
+            state.end()
+        }
+    }
+
+    const FIELDS: &[&str] = &["secs", "nanos"];
+
+    enum Field {
+        Secs,
+        Nanos,
+    }
+
+    impl<'de> de::Deserialize<'de> for Field {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            struct FieldVisitor;
+
+            impl<'de> de::Visitor<'de> for FieldVisitor {
+                type Value = Field;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.write_str("`secs` or `nanos`")
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Field, E>
+                where
+                    E: de::Error,
+                {
+                    match value {
+                        "secs" => Ok(Field::Secs),
+                        "nanos" => Ok(Field::Nanos),
+                        _ => Err(E::unknown_field(value, FIELDS)),
+                    }
+                }
+            }
+
+            deserializer.deserialize_identifier(FieldVisitor)
+        }
+    }
+
+    struct TimeDeltaVisitor;
+
+    fn from_parts<E: de::Error>(secs: i64, nanos: u32) -> Result<TimeDelta, E> {
+        if nanos >= NANOS_PER_SEC as u32 {
+            return Err(E::invalid_value(
+                de::Unexpected::Unsigned(nanos.into()),
+                &"nanos in the range 0..1_000_000_000",
+            ));
+        }
+        TimeDelta::new(secs, nanos).ok_or_else(|| E::custom("TimeDelta out of range"))
+    }
+
+    impl<'de> de::Visitor<'de> for TimeDeltaVisitor {
+        type Value = TimeDelta;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("struct TimeDelta")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<TimeDelta, A::Error>
+        where
+            A: de::SeqAccess<'de>,
+        {
+            let secs = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+            let nanos = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+            from_parts(secs, nanos)
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<TimeDelta, A::Error>
+        where
+            A: de::MapAccess<'de>,
+        {
+            let mut secs = None;
+            let mut nanos = None;
+            while let Some(key) = map.next_key()? {
+                match key {
+                    Field::Secs if secs.is_some() => {
+                        return Err(de::Error::duplicate_field("secs"))
+                    }
+                    Field::Secs => secs = Some(map.next_value()?),
+                    Field::Nanos if nanos.is_some() => {
+                        return Err(de::Error::duplicate_field("nanos"))
+                    }
+                    Field::Nanos => nanos = Some(map.next_value()?),
+                }
+            }
+            let secs = secs.ok_or_else(|| de::Error::missing_field("secs"))?;
+            let nanos = nanos.ok_or_else(|| de::Error::missing_field("nanos"))?;
+            from_parts(secs, nanos)
+        }
+    }
+
+    /// Deserialize a `TimeDelta` from a struct with the fields `secs` and `nanos`.
+    ///
+    /// `nanos` has to be in the range `0..1_000_000_000`.
+    ///
+    /// See [the `serde` module](crate::serde) for alternate deserialization formats.
+    impl<'de> de::Deserialize<'de> for TimeDelta {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            deserializer.deserialize_struct("TimeDelta", FIELDS, TimeDeltaVisitor)
+        }
+    }
+
+    /// Ser/de a `TimeDelta` to/from an integer number of seconds.
+    ///
+    /// Intended for use with `serde`'s `with` attribute. Serializing truncates any fractional
+    /// seconds towards zero.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::TimeDelta;
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::duration_seconds;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "duration_seconds")]
+    ///     timeout: TimeDelta,
+    /// }
+    ///
+    /// let my_s = S { timeout: TimeDelta::minutes(5) };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"timeout":300}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.timeout, TimeDelta::minutes(5));
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub mod duration_seconds {
+        use core::fmt;
+        use serde::{de, ser};
+
+        use crate::TimeDelta;
+
+        /// Serialize a `TimeDelta` as an integer number of seconds.
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        pub fn serialize<S>(delta: &TimeDelta, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_i64(delta.num_seconds())
+        }
+
+        /// Deserialize a `TimeDelta` from an integer number of seconds.
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D>(d: D) -> Result<TimeDelta, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_i64(SecondsVisitor)
+        }
+
+        struct SecondsVisitor;
+
+        impl<'de> de::Visitor<'de> for SecondsVisitor {
+            type Value = TimeDelta;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a duration in seconds")
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<TimeDelta, E>
+            where
+                E: de::Error,
+            {
+                TimeDelta::try_seconds(value).ok_or_else(|| E::custom("TimeDelta out of range"))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<TimeDelta, E>
+            where
+                E: de::Error,
+            {
+                i64::try_from(value)
+                    .ok()
+                    .and_then(TimeDelta::try_seconds)
+                    .ok_or_else(|| E::custom("TimeDelta out of range"))
+            }
+        }
+    }
+
+    /// Ser/de a `TimeDelta` to/from an integer number of milliseconds.
+    ///
+    /// Intended for use with `serde`'s `with` attribute. Serializing truncates any fractional
+    /// milliseconds towards zero.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::TimeDelta;
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::duration_milliseconds;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "duration_milliseconds")]
+    ///     timeout: TimeDelta,
+    /// }
+    ///
+    /// let my_s = S { timeout: TimeDelta::milliseconds(1_500) };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"timeout":1500}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.timeout, TimeDelta::milliseconds(1_500));
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub mod duration_milliseconds {
+        use core::fmt;
+        use serde::{de, ser};
+
+        use crate::TimeDelta;
+
+        /// Serialize a `TimeDelta` as an integer number of milliseconds.
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        pub fn serialize<S>(delta: &TimeDelta, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_i64(delta.num_milliseconds())
+        }
+
+        /// Deserialize a `TimeDelta` from an integer number of milliseconds.
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D>(d: D) -> Result<TimeDelta, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_i64(MillisecondsVisitor)
+        }
+
+        struct MillisecondsVisitor;
+
+        impl<'de> de::Visitor<'de> for MillisecondsVisitor {
+            type Value = TimeDelta;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a duration in milliseconds")
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<TimeDelta, E>
+            where
+                E: de::Error,
+            {
+                TimeDelta::try_milliseconds(value)
+                    .ok_or_else(|| E::custom("TimeDelta out of range"))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<TimeDelta, E>
+            where
+                E: de::Error,
+            {
+                i64::try_from(value)
+                    .ok()
+                    .and_then(TimeDelta::try_milliseconds)
+                    .ok_or_else(|| E::custom("TimeDelta out of range"))
+            }
+        }
+    }
+
+    /// Ser/de a `TimeDelta` to/from an [ISO 8601] duration string, such as `"PT1H30M"`.
+    ///
+    /// Intended for use with `serde`'s `with` attribute. See [`TimeDelta::to_iso8601`] and
+    /// [`TimeDelta::from_iso8601`] for the supported syntax.
+    ///
+    /// [ISO 8601]: https://en.wikipedia.org/wiki/ISO_8601#Durations
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::TimeDelta;
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::duration_iso8601;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "duration_iso8601")]
+    ///     timeout: TimeDelta,
+    /// }
+    ///
+    /// let my_s = S { timeout: TimeDelta::minutes(90) };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"timeout":"PT1H30M"}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.timeout, TimeDelta::minutes(90));
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub mod duration_iso8601 {
+        use core::fmt;
+        use serde::{de, ser};
+
+        use crate::TimeDelta;
+
+        /// Serialize a `TimeDelta` as an ISO 8601 duration string.
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        pub fn serialize<S>(delta: &TimeDelta, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            struct FormatIso8601<'a>(&'a TimeDelta);
+
+            impl fmt::Display for FormatIso8601<'_> {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    self.0.write_iso8601(f)
+                }
+            }
+
+            serializer.collect_str(&FormatIso8601(delta))
+        }
+
+        /// Deserialize a `TimeDelta` from an ISO 8601 duration string.
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D>(d: D) -> Result<TimeDelta, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_str(Iso8601Visitor)
+        }
+
+        struct Iso8601Visitor;
+
+        impl<'de> de::Visitor<'de> for Iso8601Visitor {
+            type Value = TimeDelta;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an ISO 8601 duration string")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<TimeDelta, E>
+            where
+                E: de::Error,
+            {
+                TimeDelta::from_iso8601(value).map_err(E::custom)
+            }
         }
     }
 }
@@ -635,6 +1789,7 @@ mod tests {
     use super::OutOfRangeError;
     use super::{TimeDelta, MAX, MIN};
     use crate::expect;
+    use crate::format::{INVALID, OUT_OF_RANGE, TOO_SHORT};
     use core::time::Duration;
 
     #[test]
@@ -726,6 +1881,35 @@ mod tests {
         let _ = TimeDelta::seconds(-i64::MAX / 1_000 - 1);
     }
 
+    #[test]
+    fn test_duration_components() {
+        let components = |d: TimeDelta| {
+            (d.whole_seconds(), d.subsec_millis(), d.subsec_micros(), d.subsec_nanos())
+        };
+        assert_eq!(components(TimeDelta::zero()), (0, 0, 0, 0));
+        assert_eq!(components(TimeDelta::milliseconds(1_500)), (1, 500, 500_000, 500_000_000));
+        assert_eq!(components(TimeDelta::milliseconds(-1_500)), (-1, -500, -500_000, -500_000_000));
+        assert_eq!(components(TimeDelta::nanoseconds(-1)), (0, 0, 0, -1));
+        assert_eq!(components(TimeDelta::nanoseconds(-1_001)), (0, 0, -1, -1_001));
+        assert_eq!(components(TimeDelta::seconds(-3)), (-3, 0, 0, 0));
+        assert_eq!(components(MAX), (i64::MAX / 1000, 807, 807_000, 807_000_000));
+        assert_eq!(components(MIN), (-i64::MAX / 1000, -807, -807_000, -807_000_000));
+
+        // The components add up to the original `TimeDelta`.
+        for delta in [
+            TimeDelta::milliseconds(-1_500),
+            TimeDelta::new(-5, 1).unwrap(),
+            TimeDelta::new(5, 999_999_999).unwrap(),
+            MAX,
+            MIN,
+        ] {
+            let rebuilt = TimeDelta::seconds(delta.whole_seconds())
+                + TimeDelta::nanoseconds(delta.subsec_nanos().into());
+            assert_eq!(rebuilt, delta);
+            assert_eq!(delta.whole_seconds(), delta.num_seconds());
+        }
+    }
+
     #[test]
     fn test_duration_num_milliseconds() {
         assert_eq!(TimeDelta::zero().num_milliseconds(), 0);
@@ -1097,6 +2281,41 @@ mod tests {
         assert_eq!(milliseconds(-i64::MAX).abs(), milliseconds(i64::MAX));
     }
 
+    #[test]
+    fn test_duration_abs_diff() {
+        let ns = TimeDelta::nanoseconds;
+        assert_eq!(ns(5).abs_diff(ns(3)), ns(2));
+        assert_eq!(ns(3).abs_diff(ns(5)), ns(2));
+        assert_eq!(ns(-1).abs_diff(ns(1)), ns(2));
+        assert_eq!(ns(1_500_000_000).abs_diff(ns(-1_700_000_000)), ns(3_200_000_000));
+        assert_eq!(ns(-1_700_000_000).abs_diff(ns(1_500_000_000)), ns(3_200_000_000));
+        assert_eq!(ns(-1_700_000_000).abs_diff(ns(-1_500_000_000)), ns(200_000_000));
+        assert_eq!(TimeDelta::zero().abs_diff(TimeDelta::zero()), TimeDelta::zero());
+
+        // `max - min` overflows, but `abs_diff` saturates
+        let (min, max) = (TimeDelta::min_value(), TimeDelta::max_value());
+        assert_eq!(max.checked_sub(&min), None);
+        assert_eq!(max.abs_diff(min), max);
+        assert_eq!(min.abs_diff(max), max);
+        assert_eq!(max.abs_diff(TimeDelta::zero()), max);
+        assert_eq!(min.abs_diff(TimeDelta::zero()), max);
+        assert_eq!(min.abs_diff(min), TimeDelta::zero());
+        assert_eq!(max.abs_diff(ns(-1)), max);
+        assert_eq!(max.abs_diff(ns(1)), max - ns(1));
+        assert_eq!(min.abs_diff(ns(-1)), max - ns(1));
+        for (a, b) in [(min, max), (max, ns(-7)), (min, ns(7)), (ns(-3), ns(4))] {
+            assert!(a.abs_diff(b) >= TimeDelta::zero());
+        }
+
+        // `checked_abs_diff` reports the overflow
+        assert_eq!(min.checked_abs_diff(max), None);
+        assert_eq!(max.checked_abs_diff(min), None);
+        assert_eq!(max.checked_abs_diff(ns(-1)), None);
+        assert_eq!(min.checked_abs_diff(TimeDelta::zero()), Some(max));
+        assert_eq!(max.checked_abs_diff(ns(1)), Some(max - ns(1)));
+        assert_eq!(ns(-1).checked_abs_diff(ns(1)), Some(ns(2)));
+    }
+
     #[test]
     #[allow(clippy::erasing_op)]
     fn test_duration_mul() {
@@ -1156,6 +2375,89 @@ mod tests {
         assert_eq!(TimeDelta::try_seconds(-4).unwrap() / -3, TimeDelta::nanoseconds(1_333_333_333));
     }
 
+    #[test]
+    fn test_duration_mul_div_f64() {
+        let seconds = |s| TimeDelta::try_seconds(s).unwrap();
+
+        assert_eq!(seconds(10).mul_f64(1.5), seconds(15));
+        assert_eq!(seconds(10).mul_f64(0.1), seconds(1));
+        assert_eq!(seconds(10).mul_f64(-0.1), seconds(-1));
+        assert_eq!(seconds(-10).mul_f64(0.25), TimeDelta::milliseconds(-2500));
+        assert_eq!(seconds(10).mul_f64(0.0), TimeDelta::zero());
+        assert_eq!(seconds(1).mul_f64(1e-9), TimeDelta::nanoseconds(1));
+        // rounds to the nearest nanosecond, half away from zero
+        assert_eq!(TimeDelta::nanoseconds(3).mul_f64(0.5), TimeDelta::nanoseconds(2));
+        assert_eq!(TimeDelta::nanoseconds(-3).mul_f64(0.5), TimeDelta::nanoseconds(-2));
+        assert_eq!(TimeDelta::nanoseconds(5).mul_f64(0.1), TimeDelta::nanoseconds(1));
+
+        assert_eq!(seconds(15).div_f64(1.5), seconds(10));
+        assert_eq!(seconds(1).div_f64(3.0), TimeDelta::nanoseconds(333_333_333));
+        assert_eq!(seconds(2).div_f64(3.0), TimeDelta::nanoseconds(666_666_667));
+        assert_eq!(seconds(1).div_f64(-4.0), TimeDelta::milliseconds(-250));
+
+        // overflow and non-finite values
+        assert_eq!(TimeDelta::max_value().checked_mul_f64(1.5), None);
+        assert_eq!(TimeDelta::min_value().checked_mul_f64(1.5), None);
+        assert_eq!(TimeDelta::max_value().checked_mul_f64(-1.5), None);
+        assert_eq!(seconds(i64::MAX / 1000).checked_mul_f64(1e6), None);
+        assert_eq!(seconds(1).checked_mul_f64(f64::INFINITY), None);
+        assert_eq!(seconds(1).checked_mul_f64(f64::NAN), None);
+        assert_eq!(TimeDelta::zero().checked_mul_f64(f64::INFINITY), None);
+        assert_eq!(TimeDelta::max_value().checked_div_f64(0.5), None);
+        assert_eq!(seconds(1).checked_div_f64(0.0), None);
+        assert_eq!(TimeDelta::zero().checked_div_f64(0.0), None);
+        assert_eq!(seconds(1 << 40).checked_mul_f64(0.5), Some(seconds(1 << 39)));
+    }
+
+    #[test]
+    #[should_panic(expected = "`TimeDelta::mul_f64` overflowed")]
+    fn test_duration_mul_f64_overflow() {
+        let _ = TimeDelta::max_value().mul_f64(2.0);
+    }
+
+    #[test]
+    fn test_duration_div_duration() {
+        let hour = TimeDelta::hours(1);
+        let two_hours = TimeDelta::hours(2);
+        let total = TimeDelta::hours(7);
+        assert_eq!(total.div_duration(&two_hours), Some(3));
+        assert_eq!(total.rem_duration(&two_hours), Some(hour));
+        assert_eq!((-total).div_duration(&two_hours), Some(-3));
+        assert_eq!((-total).rem_duration(&two_hours), Some(-hour));
+        assert_eq!(total.div_duration(&-two_hours), Some(-3));
+        assert_eq!(total.rem_duration(&-two_hours), Some(hour));
+        assert_eq!((-total).div_duration(&-two_hours), Some(3));
+        assert_eq!((-total).rem_duration(&-two_hours), Some(-hour));
+
+        assert_eq!(hour.div_duration(&two_hours), Some(0));
+        assert_eq!(hour.rem_duration(&two_hours), Some(hour));
+        assert_eq!(total.div_duration(&total), Some(1));
+        assert_eq!(total.rem_duration(&total), Some(TimeDelta::zero()));
+        let ns = TimeDelta::nanoseconds(1);
+        assert_eq!(TimeDelta::milliseconds(-1_500).div_duration(&TimeDelta::seconds(1)), Some(-1));
+        assert_eq!(
+            TimeDelta::milliseconds(-1_500).rem_duration(&TimeDelta::seconds(1)),
+            Some(TimeDelta::milliseconds(-500))
+        );
+        assert_eq!(TimeDelta::seconds(1).div_duration(&ns), Some(1_000_000_000));
+
+        // division by zero
+        assert_eq!(total.div_duration(&TimeDelta::zero()), None);
+        assert_eq!(total.rem_duration(&TimeDelta::zero()), None);
+
+        // no overflow in the intermediate values, but the quotient may not fit in an `i64`
+        let (min, max) = (TimeDelta::min_value(), TimeDelta::max_value());
+        assert_eq!(max.div_duration(&max), Some(1));
+        assert_eq!(min.div_duration(&max), Some(-1));
+        assert_eq!(max.div_duration(&ns), None);
+        assert_eq!(max.rem_duration(&ns), Some(TimeDelta::zero()));
+        assert_eq!(max.div_duration(&TimeDelta::milliseconds(1)), Some(max.num_milliseconds()));
+        assert_eq!(
+            max.rem_duration(&TimeDelta::seconds(1)),
+            Some(TimeDelta::nanoseconds(max.subsec_nanos().into()))
+        );
+    }
+
     #[test]
     fn test_duration_sum() {
         let duration_list_1 = [TimeDelta::zero(), TimeDelta::try_seconds(1).unwrap()];
@@ -1208,6 +2510,211 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_duration_display_with() {
+        use super::TimeDeltaStyle::{Colonized, Compact, Verbose};
+
+        let delta = TimeDelta::new(3_723, 500_000_000).unwrap();
+        assert_eq!(delta.display_with(Colonized).to_string(), "01:02:03");
+        assert_eq!(delta.display_with(Verbose).to_string(), "1h 2m 3.5s");
+        assert_eq!(delta.display_with(Compact).to_string(), "1:02:03.500");
+
+        // negative
+        assert_eq!((-delta).display_with(Colonized).to_string(), "-01:02:03");
+        assert_eq!((-delta).display_with(Verbose).to_string(), "-1h 2m 3.5s");
+        assert_eq!((-delta).display_with(Compact).to_string(), "-1:02:03.500");
+
+        // zero
+        assert_eq!(TimeDelta::zero().display_with(Colonized).to_string(), "00:00:00");
+        assert_eq!(TimeDelta::zero().display_with(Verbose).to_string(), "0s");
+        assert_eq!(TimeDelta::zero().display_with(Compact).to_string(), "0:00:00");
+
+        // sub-second fractions
+        let millis = TimeDelta::milliseconds(42);
+        assert_eq!(millis.display_with(Colonized).to_string(), "00:00:00");
+        assert_eq!(millis.display_with(Verbose).to_string(), "0.042s");
+        assert_eq!(millis.display_with(Compact).to_string(), "0:00:00.042");
+        let micros = TimeDelta::microseconds(1_000_042);
+        assert_eq!(micros.display_with(Verbose).to_string(), "1.000042s");
+        assert_eq!(micros.display_with(Compact).to_string(), "0:00:01.000042");
+        let nanos = TimeDelta::nanoseconds(-1);
+        assert_eq!(nanos.display_with(Verbose).to_string(), "-0.000000001s");
+        assert_eq!(nanos.display_with(Compact).to_string(), "-0:00:00.000000001");
+
+        // more than 24 hours
+        let long = TimeDelta::new(93_784, 5_000_000).unwrap();
+        assert_eq!(long.display_with(Colonized).to_string(), "26:03:04");
+        assert_eq!(long.display_with(Verbose).to_string(), "26h 3m 4.005s");
+        assert_eq!(long.display_with(Compact).to_string(), "26:03:04.005");
+        assert_eq!(long.display_with(Colonized).with_days(true).to_string(), "1d 02:03:04");
+        assert_eq!(long.display_with(Verbose).with_days(true).to_string(), "1d 2h 3m 4.005s");
+        assert_eq!(long.display_with(Compact).with_days(true).to_string(), "1d 2:03:04.005");
+        let days = TimeDelta::days(2);
+        assert_eq!(days.display_with(Colonized).with_days(true).to_string(), "2d 00:00:00");
+        assert_eq!(days.display_with(Verbose).with_days(true).to_string(), "2d");
+        assert_eq!(days.display_with(Verbose).to_string(), "48h");
+        assert_eq!(
+            TimeDelta::hours(23).display_with(Colonized).with_days(true).to_string(),
+            "23:00:00"
+        );
+
+        // extremes
+        assert_eq!(
+            TimeDelta::max_value().display_with(Colonized).to_string(),
+            "2562047788015:12:55"
+        );
+        assert_eq!(
+            TimeDelta::min_value().display_with(Compact).with_days(true).to_string(),
+            "-106751991167d 7:12:55.807"
+        );
+
+        // composes with `write!`
+        assert_eq!(format!("[{}]", delta.display_with(Colonized)), "[01:02:03]");
+
+        // width, fill and alignment
+        #[cfg(feature = "alloc")]
+        {
+            assert_eq!(format!("[{:>10}]", delta.display_with(Colonized)), "[  01:02:03]");
+            assert_eq!(format!("[{:<12}]", delta.display_with(Verbose)), "[1h 2m 3.5s  ]");
+            assert_eq!(format!("[{:*^13}]", delta.display_with(Compact)), "[*1:02:03.500*]");
+            assert_eq!(format!("[{:.5}]", delta.display_with(Verbose)), "[1h 2m]");
+            assert_eq!(format!("[{:4}]", delta.display_with(Colonized)), "[01:02:03]");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_duration_to_iso8601() {
+        assert_eq!(TimeDelta::zero().to_iso8601(), "PT0S");
+        assert_eq!(TimeDelta::minutes(90).to_iso8601(), "PT1H30M");
+        assert_eq!(TimeDelta::hours(51).to_iso8601(), "P2DT3H");
+        assert_eq!(TimeDelta::days(42).to_iso8601(), "P42D");
+        assert_eq!(TimeDelta::seconds(86_401).to_iso8601(), "P1DT1S");
+        assert_eq!(TimeDelta::seconds(3_601).to_iso8601(), "PT1H1S");
+        assert_eq!(TimeDelta::milliseconds(42).to_iso8601(), "PT0.042S");
+        assert_eq!(TimeDelta::nanoseconds(1).to_iso8601(), "PT0.000000001S");
+        assert_eq!(TimeDelta::new(93_784, 5_000_000).unwrap().to_iso8601(), "P1DT2H3M4.005S");
+        assert_eq!(TimeDelta::minutes(-90).to_iso8601(), "-PT1H30M");
+        assert_eq!(TimeDelta::nanoseconds(-1).to_iso8601(), "-PT0.000000001S");
+        assert_eq!(TimeDelta::max_value().to_iso8601(), "P106751991167DT7H12M55.807S");
+        assert_eq!(TimeDelta::min_value().to_iso8601(), "-P106751991167DT7H12M55.807S");
+    }
+
+    #[test]
+    fn test_duration_from_iso8601() {
+        let parse = TimeDelta::from_iso8601;
+        assert_eq!(parse("PT0S"), Ok(TimeDelta::zero()));
+        assert_eq!(parse("P0D"), Ok(TimeDelta::zero()));
+        assert_eq!(parse("PT1H30M"), Ok(TimeDelta::minutes(90)));
+        assert_eq!(parse("P2DT3H"), Ok(TimeDelta::hours(51)));
+        assert_eq!(parse("P1W"), Ok(TimeDelta::weeks(1)));
+        assert_eq!(parse("P1W1DT1H1M1S"), Ok(TimeDelta::seconds(694_861)));
+        assert_eq!(parse("PT90M"), Ok(TimeDelta::minutes(90)));
+        assert_eq!(parse("PT3628800S"), Ok(TimeDelta::days(42)));
+        assert_eq!(parse("+PT1S"), Ok(TimeDelta::seconds(1)));
+
+        // fractions
+        assert_eq!(parse("PT0.5S"), Ok(TimeDelta::milliseconds(500)));
+        assert_eq!(parse("PT0,5S"), Ok(TimeDelta::milliseconds(500)));
+        assert_eq!(parse("PT1.000000001S"), Ok(TimeDelta::new(1, 1).unwrap()));
+        assert_eq!(parse("PT1.0000000019S"), Ok(TimeDelta::new(1, 1).unwrap()));
+        assert_eq!(parse("PT1.S"), Err(INVALID));
+        assert_eq!(parse("PT1.5M"), Err(INVALID));
+
+        // negative
+        assert_eq!(parse("-PT1H30M"), Ok(TimeDelta::minutes(-90)));
+        assert_eq!(parse("-PT0.000000001S"), Ok(TimeDelta::nanoseconds(-1)));
+        assert_eq!(parse("-P1DT0.5S"), Ok(-TimeDelta::new(86_400, 500_000_000).unwrap()));
+
+        // calendar designators
+        assert_eq!(parse("P1Y"), Err(INVALID));
+        assert_eq!(parse("P1M"), Err(INVALID));
+        assert_eq!(parse("P1Y2M3D"), Err(INVALID));
+        assert_eq!(parse("PT1M"), Ok(TimeDelta::minutes(1)));
+
+        // malformed
+        assert_eq!(parse(""), Err(TOO_SHORT));
+        assert_eq!(parse("P"), Err(TOO_SHORT));
+        assert_eq!(parse("PT"), Err(TOO_SHORT));
+        assert_eq!(parse("P1DT"), Err(TOO_SHORT));
+        assert_eq!(parse("PT1"), Err(TOO_SHORT));
+        assert_eq!(parse("1D"), Err(INVALID));
+        assert_eq!(parse("P1H"), Err(INVALID));
+        assert_eq!(parse("PT1D"), Err(INVALID));
+        assert_eq!(parse("PT1S1M"), Err(INVALID));
+        assert_eq!(parse("PT1M1M"), Err(INVALID));
+        assert_eq!(parse("PTT1S"), Err(INVALID));
+        assert_eq!(parse("pt1s"), Err(INVALID));
+        assert_eq!(parse("PT-1S"), Err(INVALID));
+        assert_eq!(parse("PT1S "), Err(INVALID));
+
+        // range
+        assert_eq!(parse("PT9223372036854775S"), Ok(TimeDelta::seconds(i64::MAX / 1000)));
+        assert_eq!(parse("PT9223372036854775.807S"), Ok(TimeDelta::max_value()));
+        assert_eq!(parse("PT9223372036854775.808S"), Err(OUT_OF_RANGE));
+        assert_eq!(parse("P106751991167DT7H12M56S"), Err(OUT_OF_RANGE));
+        assert_eq!(parse("P99999999999999999999D"), Err(OUT_OF_RANGE));
+    }
+
+    #[test]
+    fn test_duration_from_human_str() {
+        let parse = TimeDelta::from_human_str;
+        assert_eq!(parse("1h30m"), Ok(TimeDelta::minutes(90)));
+        assert_eq!(parse("30m1h"), Ok(TimeDelta::minutes(90)));
+        assert_eq!(parse("1h 30m"), Ok(TimeDelta::minutes(90)));
+        assert_eq!(parse("-500ms"), Ok(TimeDelta::milliseconds(-500)));
+        assert_eq!(parse("+500ms"), Ok(TimeDelta::milliseconds(500)));
+        assert_eq!(parse("2w"), Ok(TimeDelta::weeks(2)));
+        assert_eq!(parse("1d"), Ok(TimeDelta::days(1)));
+        assert_eq!(parse("90s"), Ok(TimeDelta::seconds(90)));
+        assert_eq!(parse("1ns"), Ok(TimeDelta::nanoseconds(1)));
+        assert_eq!(parse("1us"), Ok(TimeDelta::microseconds(1)));
+        assert_eq!(parse("1µs"), Ok(TimeDelta::microseconds(1)));
+        assert_eq!(parse("1s1ms1us1ns"), Ok(TimeDelta::new(1, 1_001_001).unwrap()));
+        assert_eq!(parse("1m1m"), Ok(TimeDelta::minutes(2)));
+        assert_eq!(parse("-1h30m"), Ok(TimeDelta::minutes(-90)));
+        assert_eq!(parse("0s"), Ok(TimeDelta::zero()));
+
+        // malformed
+        assert_eq!(parse(""), Err(TOO_SHORT));
+        assert_eq!(parse("-"), Err(TOO_SHORT));
+        assert_eq!(parse("1"), Err(TOO_SHORT));
+        assert_eq!(parse("1h30"), Err(TOO_SHORT));
+        assert_eq!(parse("1y"), Err(INVALID));
+        assert_eq!(parse("abc"), Err(INVALID));
+        assert_eq!(parse("h"), Err(INVALID));
+        assert_eq!(parse("1H"), Err(INVALID));
+        assert_eq!(parse("1.5s"), Err(INVALID));
+        assert_eq!(parse("1h-30m"), Err(INVALID));
+        assert_eq!(parse(" 1h"), Err(INVALID));
+
+        // range
+        assert_eq!(parse("9223372036854775807ms"), Ok(TimeDelta::max_value()));
+        assert_eq!(parse("-9223372036854775807ms"), Ok(TimeDelta::min_value()));
+        assert_eq!(parse("9223372036854775807ms1ns"), Err(OUT_OF_RANGE));
+        assert_eq!(parse("9223372036854775807w"), Err(OUT_OF_RANGE));
+        assert_eq!(parse("99999999999999999999ns"), Err(OUT_OF_RANGE));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_duration_iso8601_roundtrip() {
+        let deltas = [
+            TimeDelta::zero(),
+            TimeDelta::nanoseconds(1),
+            TimeDelta::nanoseconds(-999_999_999),
+            TimeDelta::new(93_784, 5_000_000).unwrap(),
+            -TimeDelta::new(93_784, 5_000_000).unwrap(),
+            TimeDelta::weeks(3),
+            TimeDelta::max_value(),
+            TimeDelta::min_value(),
+        ];
+        for delta in deltas {
+            assert_eq!(TimeDelta::from_iso8601(&delta.to_iso8601()), Ok(delta));
+            assert_eq!(TimeDelta::from_iso8601(&delta.to_string()), Ok(delta));
+        }
+    }
+
     #[test]
     fn test_to_std() {
         assert_eq!(TimeDelta::try_seconds(1).unwrap().to_std(), Ok(Duration::new(1, 0)));
@@ -1256,6 +2763,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_std_conversions_sign_and_overflow() {
+        let ns = TimeDelta::nanoseconds;
+
+        // zero
+        assert_eq!(TimeDelta::zero().to_std(), Ok(Duration::ZERO));
+        assert_eq!(TimeDelta::from_std(Duration::ZERO), Ok(TimeDelta::zero()));
+        assert_eq!(TimeDelta::zero().abs_to_std(), (false, Duration::ZERO));
+
+        // negative
+        assert_eq!(ns(-1).to_std(), Err(OutOfRangeError(())));
+        assert_eq!(Duration::try_from(ns(-1)), Err(OutOfRangeError(())));
+        assert_eq!(ns(-1).abs_to_std(), (true, Duration::new(0, 1)));
+        assert_eq!(ns(-1_500_000_000).abs_to_std(), (true, Duration::new(1, 500_000_000)));
+        assert_eq!(ns(1_500_000_000).abs_to_std(), (false, Duration::new(1, 500_000_000)));
+        assert_eq!(MIN.abs_to_std(), (true, Duration::new(9_223_372_036_854_775, 807_000_000)));
+        assert_eq!(MAX.abs_to_std(), (false, Duration::new(9_223_372_036_854_775, 807_000_000)));
+
+        // overflow
+        assert_eq!(
+            TimeDelta::from_std(Duration::new(i64::MAX as u64, 0)),
+            Err(OutOfRangeError(()))
+        );
+        assert_eq!(
+            TimeDelta::try_from(Duration::new(i64::MAX as u64, 0)),
+            Err(OutOfRangeError(()))
+        );
+        assert_eq!(
+            TimeDelta::from_std(Duration::new(u64::MAX, 999_999_999)),
+            Err(OutOfRangeError(()))
+        );
+        assert_eq!(TimeDelta::try_from(Duration::from_secs(90)), Ok(TimeDelta::seconds(90)));
+
+        // round trips
+        for delta in [MIN, ns(-1_500_000_000), ns(-1), TimeDelta::zero(), ns(1), MAX] {
+            let (negative, abs) = delta.abs_to_std();
+            let magnitude = TimeDelta::try_from(abs).unwrap();
+            assert_eq!(if negative { -magnitude } else { magnitude }, delta);
+            if !negative {
+                assert_eq!(Duration::try_from(delta), Ok(abs));
+            }
+        }
+    }
+
+    #[test]
+    fn test_duration_const_constructors() {
+        const TIMEOUT: TimeDelta = TimeDelta::seconds(30);
+        const TABLE: [TimeDelta; 6] = [
+            TimeDelta::weeks(2),
+            TimeDelta::days(3),
+            TimeDelta::hours(-4),
+            TimeDelta::minutes(5),
+            TimeDelta::milliseconds(-6),
+            expect(TimeDelta::new(7, 8), ""),
+        ];
+        const SUM: TimeDelta = expect(TIMEOUT.checked_add(&TABLE[0]), "");
+        const INVALID: Option<TimeDelta> = TimeDelta::new(0, 1_000_000_000);
+
+        let runtime = |f: fn(i64) -> TimeDelta, v| f(v);
+        assert_eq!(TIMEOUT, runtime(TimeDelta::seconds, 30));
+        assert_eq!(TABLE[0], runtime(TimeDelta::weeks, 2));
+        assert_eq!(TABLE[1], runtime(TimeDelta::days, 3));
+        assert_eq!(TABLE[2], runtime(TimeDelta::hours, -4));
+        assert_eq!(TABLE[3], runtime(TimeDelta::minutes, 5));
+        assert_eq!(TABLE[4], runtime(TimeDelta::milliseconds, -6));
+        assert_eq!(TABLE[5], TimeDelta::seconds(7) + TimeDelta::nanoseconds(8));
+        assert_eq!(SUM, TimeDelta::seconds(30) + TimeDelta::weeks(2));
+        assert_eq!(INVALID, None);
+    }
+
     #[test]
     fn test_duration_const() {
         const ONE_WEEK: TimeDelta = expect(TimeDelta::try_weeks(1), "");
@@ -1297,4 +2874,77 @@ mod tests {
         let bytes = rkyv::to_bytes::<_, 16>(&duration).unwrap();
         assert_eq!(rkyv::from_bytes::<TimeDelta>(&bytes).unwrap(), duration);
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        use serde_json::{from_str, to_string};
+
+        let cases = [
+            (TimeDelta::zero(), r#"{"secs":0,"nanos":0}"#),
+            (TimeDelta::new(90, 5).unwrap(), r#"{"secs":90,"nanos":5}"#),
+            (TimeDelta::milliseconds(-1_500), r#"{"secs":-2,"nanos":500000000}"#),
+            (TimeDelta::nanoseconds(-1), r#"{"secs":-1,"nanos":999999999}"#),
+            (TimeDelta::max_value(), r#"{"secs":9223372036854775,"nanos":807000000}"#),
+            (TimeDelta::min_value(), r#"{"secs":-9223372036854776,"nanos":193000000}"#),
+        ];
+        for (delta, json) in cases {
+            assert_eq!(to_string(&delta).unwrap(), json);
+            assert_eq!(from_str::<TimeDelta>(json).unwrap(), delta);
+        }
+        assert_eq!(
+            from_str::<TimeDelta>(r#"{"nanos":5,"secs":-1}"#).unwrap(),
+            TimeDelta::new(-1, 5).unwrap()
+        );
+        assert_eq!(from_str::<TimeDelta>("[-1,5]").unwrap(), TimeDelta::new(-1, 5).unwrap());
+
+        assert!(from_str::<TimeDelta>(r#"{"secs":0,"nanos":1000000000}"#).is_err());
+        assert!(from_str::<TimeDelta>(r#"{"secs":0,"nanos":-1}"#).is_err());
+        assert!(from_str::<TimeDelta>(r#"{"secs":9223372036854775,"nanos":808000000}"#).is_err());
+        assert!(from_str::<TimeDelta>(r#"{"secs":-9223372036854777,"nanos":0}"#).is_err());
+        assert!(from_str::<TimeDelta>(r#"{"secs":0}"#).is_err());
+        assert!(from_str::<TimeDelta>(r#"{"secs":0,"nanos":0,"secs":1}"#).is_err());
+        assert!(from_str::<TimeDelta>(r#"{"secs":0,"nanos":0,"days":1}"#).is_err());
+
+        // bincode uses the sequence representation
+        let encoded = bincode::serialize(&TimeDelta::milliseconds(-1_500)).unwrap();
+        assert_eq!(
+            bincode::deserialize::<TimeDelta>(&encoded).unwrap(),
+            TimeDelta::milliseconds(-1_500)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_with() {
+        use serde_derive::{Deserialize, Serialize};
+        use serde_json::{from_str, to_string};
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct S {
+            #[serde(with = "crate::serde::duration_seconds")]
+            secs: TimeDelta,
+            #[serde(with = "crate::serde::duration_milliseconds")]
+            millis: TimeDelta,
+            #[serde(with = "crate::serde::duration_iso8601")]
+            iso: TimeDelta,
+        }
+
+        let delta = TimeDelta::new(5_400, 250_000_000).unwrap();
+        let s = S { secs: delta, millis: delta, iso: delta };
+        let json = to_string(&s).unwrap();
+        assert_eq!(json, r#"{"secs":5400,"millis":5400250,"iso":"PT1H30M0.25S"}"#);
+        let back: S = from_str(&json).unwrap();
+        assert_eq!(back, S { secs: TimeDelta::seconds(5_400), millis: delta, iso: delta });
+
+        let s = S { secs: -delta, millis: -delta, iso: -delta };
+        let json = to_string(&s).unwrap();
+        assert_eq!(json, r#"{"secs":-5400,"millis":-5400250,"iso":"-PT1H30M0.25S"}"#);
+        let back: S = from_str(&json).unwrap();
+        assert_eq!(back, S { secs: TimeDelta::seconds(-5_400), millis: -delta, iso: -delta });
+
+        assert!(from_str::<S>(r#"{"secs":9223372036854775807,"millis":0,"iso":"PT0S"}"#).is_err());
+        assert!(from_str::<S>(r#"{"secs":0,"millis":9223372036854775808,"iso":"PT0S"}"#).is_err());
+        assert!(from_str::<S>(r#"{"secs":0,"millis":0,"iso":"P1M"}"#).is_err());
+    }
 }
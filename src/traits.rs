@@ -1,4 +1,4 @@
-use crate::{IsoWeek, Weekday};
+use crate::{IsoWeek, Month, Weekday};
 
 /// The common set of methods for date component.
 ///
@@ -54,6 +54,31 @@ pub trait Datelike: Sized {
     /// The return value ranges from 0 to 11.
     fn month0(&self) -> u32;
 
+    /// Returns the quarter number starting from 1.
+    ///
+    /// The return value ranges from 1 to 4. January through March is the first quarter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd_opt(2024, 3, 31).unwrap().quarter(), 1);
+    /// assert_eq!(NaiveDate::from_ymd_opt(2024, 4, 1).unwrap().quarter(), 2);
+    /// ```
+    #[inline]
+    fn quarter(&self) -> u32 {
+        self.quarter0() + 1
+    }
+
+    /// Returns the quarter number starting from 0.
+    ///
+    /// The return value ranges from 0 to 3.
+    #[inline]
+    fn quarter0(&self) -> u32 {
+        self.month0() / 3
+    }
+
     /// Returns the day of month starting from 1.
     ///
     /// The return value ranges from 1 to 31. (The last day of month differs by months.)
@@ -80,6 +105,155 @@ pub trait Datelike: Sized {
     /// Returns the ISO week.
     fn iso_week(&self) -> IsoWeek;
 
+    /// Returns the ISO week-numbering year.
+    ///
+    /// This is a shorthand for `self.iso_week().year()`. It differs from [`year`](Self::year) for
+    /// the first and last few days of some years.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap().iso_year(), 2022);
+    /// assert_eq!(NaiveDate::from_ymd_opt(2024, 6, 1).unwrap().iso_year(), 2024);
+    /// assert_eq!(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap().iso_year(), 2025);
+    /// ```
+    #[inline]
+    fn iso_year(&self) -> i32 {
+        self.iso_week().year()
+    }
+
+    /// Returns the number of days in the month of this date.
+    ///
+    /// February has 29 days in leap years of the proleptic Gregorian calendar, and 28 otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd_opt(2024, 2, 10).unwrap().num_days_in_month(), 29);
+    /// assert_eq!(NaiveDate::from_ymd_opt(2023, 2, 10).unwrap().num_days_in_month(), 28);
+    /// assert_eq!(NaiveDate::from_ymd_opt(2023, 4, 30).unwrap().num_days_in_month(), 30);
+    /// ```
+    #[inline]
+    fn num_days_in_month(&self) -> u32 {
+        u32::from(Month::from_month0(self.month0()).num_days(self.year()))
+    }
+
+    /// Returns the number of days in the year of this date, 366 in a leap year and 365 otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd_opt(2024, 7, 1).unwrap().num_days_in_year(), 366);
+    /// assert_eq!(NaiveDate::from_ymd_opt(2100, 7, 1).unwrap().num_days_in_year(), 365);
+    /// ```
+    #[inline]
+    fn num_days_in_year(&self) -> u32 {
+        if Month::February.num_days(self.year()) == 29 {
+            366
+        } else {
+            365
+        }
+    }
+
+    /// Returns the week of the month starting from 1, with weeks starting on `start`.
+    ///
+    /// The first week of the month is the week containing the first day of the month, even if
+    /// that week started in the previous month. So a month can have up to six weeks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate, Weekday};
+    ///
+    /// // 2024-05-01 is a Wednesday.
+    /// let date = |day| NaiveDate::from_ymd_opt(2024, 5, day).unwrap();
+    /// assert_eq!(date(1).week_of_month(Weekday::Mon), 1);
+    /// assert_eq!(date(5).week_of_month(Weekday::Mon), 1);
+    /// assert_eq!(date(6).week_of_month(Weekday::Mon), 2);
+    /// assert_eq!(date(5).week_of_month(Weekday::Sun), 2);
+    /// assert_eq!(date(31).week_of_month(Weekday::Mon), 5);
+    /// ```
+    ///
+    /// To iterate over the first days of the following weeks, use [`NaiveDate::week`] and
+    /// [`NaiveDate::iter_weeks`]:
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+    /// let mut week_starts = date.week(Weekday::Mon).first_day().iter_weeks();
+    /// assert_eq!(week_starts.next(), NaiveDate::from_ymd_opt(2024, 4, 29));
+    /// assert_eq!(week_starts.next(), NaiveDate::from_ymd_opt(2024, 5, 6));
+    /// ```
+    ///
+    /// [`NaiveDate::week`]: crate::NaiveDate::week
+    /// [`NaiveDate::iter_weeks`]: crate::NaiveDate::iter_weeks
+    #[inline]
+    fn week_of_month(&self, start: Weekday) -> u32 {
+        // The number of days of this week that come before `self`. If this is more than `day0` the
+        // week started in the previous month.
+        let days_into_week = self.weekday().days_since(start);
+        (self.day0() + 6 - days_into_week) / 7 + 1
+    }
+
+    /// Returns `true` if the date falls on a Saturday or Sunday.
+    ///
+    /// See [`is_weekend_with`](Datelike::is_weekend_with) for regions with a different weekend.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    ///
+    /// // 2024-05-18 is a Saturday.
+    /// assert!(NaiveDate::from_ymd_opt(2024, 5, 18).unwrap().is_weekend());
+    /// assert!(!NaiveDate::from_ymd_opt(2024, 5, 17).unwrap().is_weekend());
+    /// ```
+    #[inline]
+    fn is_weekend(&self) -> bool {
+        self.is_weekend_with(&[Weekday::Sat, Weekday::Sun])
+    }
+
+    /// Returns `true` if the date falls on one of the days of `weekend`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate, Weekday};
+    ///
+    /// // 2024-05-17 is a Friday.
+    /// let friday = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+    /// assert!(friday.is_weekend_with(&[Weekday::Fri, Weekday::Sat]));
+    /// assert!(!friday.is_weekend_with(&[Weekday::Sat, Weekday::Sun]));
+    /// ```
+    #[inline]
+    fn is_weekend_with(&self, weekend: &[Weekday]) -> bool {
+        weekend.contains(&self.weekday())
+    }
+
+    /// Returns `true` if the date falls on Monday through Friday.
+    ///
+    /// This is the negation of [`is_weekend`](Datelike::is_weekend).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    ///
+    /// assert!(NaiveDate::from_ymd_opt(2024, 5, 17).unwrap().is_weekday());
+    /// assert!(!NaiveDate::from_ymd_opt(2024, 5, 19).unwrap().is_weekday());
+    /// ```
+    #[inline]
+    fn is_weekday(&self) -> bool {
+        !self.is_weekend()
+    }
+
     /// Makes a new value with the year number changed, while keeping the same month and day.
     ///
     /// This method assumes you want to work on the date as a year-month-day value. Don't use it if
@@ -235,6 +409,13 @@ pub trait Datelike: Sized {
 
     /// Counts the days in the proleptic Gregorian calendar, with January 1, Year 1 (CE) as day 1.
     ///
+    /// # Overflow
+    ///
+    /// The result fits in an `i32` for every date chrono can represent. The default
+    /// implementation is only correct for years within about ±1,400,000 however, and overflows
+    /// for an implementation of `Datelike` that supports larger years. Use
+    /// [`num_days_from_ce_i64`](Datelike::num_days_from_ce_i64) in that case.
+    ///
     /// # Examples
     ///
     /// ```
@@ -261,6 +442,29 @@ pub trait Datelike: Sized {
         ndays += ((year * 1461) >> 2) - div_100 + (div_100 >> 2);
         ndays + self.ordinal() as i32
     }
+
+    /// Counts the days in the proleptic Gregorian calendar, with January 1, Year 1 (CE) as day 1,
+    /// as an `i64`.
+    ///
+    /// This is the same as [`num_days_from_ce`](Datelike::num_days_from_ce), but can't overflow
+    /// for any year that fits in an `i32`. See also
+    /// [`NaiveDate::from_num_days_from_ce_i64`](crate::NaiveDate::from_num_days_from_ce_i64).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+    /// assert_eq!(date.num_days_from_ce_i64(), 719_163);
+    /// assert_eq!(NaiveDate::MIN.num_days_from_ce_i64(), NaiveDate::MIN.num_days_from_ce() as i64);
+    /// ```
+    fn num_days_from_ce_i64(&self) -> i64 {
+        // The number of days before January 1 of `year`, counted from January 1, 1 CE.
+        let year = self.year() as i64 - 1;
+        let ndays = year * 365 + year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400);
+        ndays + self.ordinal() as i64
+    }
 }
 
 /// The common set of methods for time component.
@@ -330,7 +534,203 @@ pub trait Timelike: Sized {
 #[cfg(test)]
 mod tests {
     use super::Datelike;
-    use crate::{Days, NaiveDate};
+    use crate::{Days, NaiveDate, Weekday};
+
+    #[test]
+    fn test_week_of_month() {
+        // 2023-02-01 is a Wednesday, 2024-02-01 is a Thursday.
+        let feb = |year, day| NaiveDate::from_ymd_opt(year, 2, day).unwrap();
+        let cases = [
+            (2023, Weekday::Mon, [1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 3, 3, 3, 3, 3, 3, 3, 4]),
+            (2023, Weekday::Sun, [1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 3, 3, 3, 3, 3, 3, 3, 4, 4]),
+            (2024, Weekday::Mon, [1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 3, 3, 3, 3, 3, 3, 3, 4, 4]),
+            (2024, Weekday::Sun, [1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 3, 3, 3, 3, 3, 3, 3, 4, 4, 4]),
+        ];
+        for (year, start, expected) in cases {
+            for (day0, &week) in expected.iter().enumerate() {
+                let date = feb(year, day0 as u32 + 1);
+                assert_eq!(date.week_of_month(start), week, "{} with {:?}", date, start);
+            }
+        }
+        assert_eq!(feb(2023, 28).week_of_month(Weekday::Mon), 5);
+        assert_eq!(feb(2023, 28).week_of_month(Weekday::Sun), 5);
+        assert_eq!(feb(2024, 29).week_of_month(Weekday::Mon), 5);
+        assert_eq!(feb(2024, 29).week_of_month(Weekday::Sun), 5);
+
+        // A month starting on the first day of the week: 2021-02-01 is a Monday.
+        let date = |day| NaiveDate::from_ymd_opt(2021, 2, day).unwrap();
+        assert_eq!(date(1).week_of_month(Weekday::Mon), 1);
+        assert_eq!(date(7).week_of_month(Weekday::Mon), 1);
+        assert_eq!(date(8).week_of_month(Weekday::Mon), 2);
+        assert_eq!(date(28).week_of_month(Weekday::Mon), 4);
+        assert_eq!(date(28).week_of_month(Weekday::Sun), 5);
+    }
+
+    #[test]
+    fn test_iso_year() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(ymd(2023, 1, 1).iso_year(), 2022);
+        assert_eq!(ymd(2023, 1, 2).iso_year(), 2023);
+        assert_eq!(ymd(2024, 12, 29).iso_year(), 2024);
+        assert_eq!(ymd(2024, 12, 30).iso_year(), 2025);
+        assert_eq!(ymd(2024, 12, 31).iso_year(), 2025);
+        assert_eq!(ymd(2020, 12, 31).iso_year(), 2020);
+        assert_eq!(ymd(2021, 1, 3).iso_year(), 2020);
+        assert_eq!(ymd(2023, 1, 1).and_hms_opt(12, 0, 0).unwrap().iso_year(), 2022);
+
+        let mut date = ymd(2000, 1, 1);
+        while date.year() < 2030 {
+            assert_eq!(date.iso_year(), date.iso_week().year());
+            date = date + Days::new(1);
+        }
+    }
+
+    #[test]
+    fn test_is_weekend() {
+        // 2024-05-13 is a Monday.
+        let days: Vec<_> =
+            (13..=19).map(|day| NaiveDate::from_ymd_opt(2024, 5, day).unwrap()).collect();
+        let weekend: Vec<_> = days.iter().map(|d| d.is_weekend()).collect();
+        assert_eq!(weekend, [false, false, false, false, false, true, true]);
+        assert!(days.iter().all(|d| d.is_weekday() != d.is_weekend()));
+
+        let fri_sat = [Weekday::Fri, Weekday::Sat];
+        let weekend: Vec<_> = days.iter().map(|d| d.is_weekend_with(&fri_sat)).collect();
+        assert_eq!(weekend, [false, false, false, false, true, true, false]);
+        assert!(days.iter().all(|d| !d.is_weekend_with(&[])));
+        assert!(days[3].is_weekend_with(&[Weekday::Thu]));
+
+        let dt = days[5].and_hms_opt(23, 59, 59).unwrap();
+        assert!(dt.is_weekend());
+        assert!(!dt.is_weekend_with(&[Weekday::Sun]));
+    }
+
+    #[test]
+    fn test_num_days_in_month_and_year() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(ymd(2024, 2, 1).num_days_in_month(), 29);
+        assert_eq!(ymd(2023, 2, 28).num_days_in_month(), 28);
+        assert_eq!(ymd(2000, 2, 1).num_days_in_month(), 29);
+        assert_eq!(ymd(1900, 2, 1).num_days_in_month(), 28);
+        assert_eq!(ymd(-4, 2, 1).num_days_in_month(), 29);
+        assert_eq!(ymd(-1, 2, 1).num_days_in_month(), 28);
+        assert_eq!(ymd(2024, 1, 31).num_days_in_month(), 31);
+        assert_eq!(ymd(2024, 11, 15).num_days_in_month(), 30);
+        assert_eq!(ymd(2024, 2, 1).num_days_in_year(), 366);
+        assert_eq!(ymd(2023, 12, 31).num_days_in_year(), 365);
+        assert_eq!(ymd(2000, 1, 1).num_days_in_year(), 366);
+        assert_eq!(ymd(1900, 1, 1).num_days_in_year(), 365);
+        assert_eq!(ymd(0, 1, 1).num_days_in_year(), 366);
+        assert_eq!(ymd(2024, 2, 1).and_hms_opt(12, 0, 0).unwrap().num_days_in_month(), 29);
+
+        // compare with the last day of every month and year
+        for year in [-401, -400, -100, -4, -1, 0, 1, 4, 100, 400, 1900, 2000, 2023, 2024] {
+            let last_day = ymd(year, 12, 31);
+            assert_eq!(last_day.num_days_in_year(), last_day.ordinal());
+            for month in 1..=12 {
+                let first = ymd(year, month, 1);
+                let last = first.with_day(first.num_days_in_month()).unwrap();
+                assert_eq!(last.succ_opt().unwrap().day(), 1);
+                assert_eq!(last.num_days_in_year(), last_day.ordinal());
+                let month = crate::Month::try_from(month as u8).unwrap();
+                assert_eq!(first.num_days_in_month(), u32::from(month.num_days(year)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_num_days_from_ce_i64() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        for date in [
+            ymd(1, 1, 1),
+            ymd(0, 12, 31),
+            ymd(0, 1, 1),
+            ymd(1970, 1, 1),
+            ymd(2024, 2, 29),
+            ymd(-401, 3, 1),
+            ymd(200_000, 6, 15),
+            ymd(-200_000, 6, 15),
+            NaiveDate::MIN,
+            NaiveDate::MAX,
+        ] {
+            let days = date.num_days_from_ce_i64();
+            assert_eq!(days, date.num_days_from_ce() as i64, "{:?}", date);
+            assert_eq!(NaiveDate::from_num_days_from_ce_i64(days), Some(date));
+        }
+        assert_eq!(ymd(200_000, 1, 1).num_days_from_ce_i64(), 73_048_135);
+        assert_eq!(ymd(-200_000, 1, 1).num_days_from_ce_i64(), -73_048_865);
+
+        assert_eq!(NaiveDate::from_num_days_from_ce_i64(i64::MAX), None);
+        assert_eq!(NaiveDate::from_num_days_from_ce_i64(i64::MIN), None);
+        assert_eq!(NaiveDate::from_num_days_from_ce_i64(i32::MAX as i64 + 1), None);
+        let max = NaiveDate::MAX.num_days_from_ce_i64();
+        assert_eq!(NaiveDate::from_num_days_from_ce_i64(max + 1), None);
+    }
+
+    #[test]
+    fn test_num_days_from_ce_i64_large_years() {
+        // A `Datelike` implementation with years beyond the range of `NaiveDate`, which only
+        // supports the methods used by `num_days_from_ce_i64`.
+        struct Jan1(i32);
+
+        impl Datelike for Jan1 {
+            fn year(&self) -> i32 {
+                self.0
+            }
+            fn month(&self) -> u32 {
+                1
+            }
+            fn month0(&self) -> u32 {
+                0
+            }
+            fn day(&self) -> u32 {
+                1
+            }
+            fn day0(&self) -> u32 {
+                0
+            }
+            fn ordinal(&self) -> u32 {
+                1
+            }
+            fn ordinal0(&self) -> u32 {
+                0
+            }
+            fn weekday(&self) -> Weekday {
+                unimplemented!()
+            }
+            fn iso_week(&self) -> crate::IsoWeek {
+                unimplemented!()
+            }
+            fn with_year(&self, _: i32) -> Option<Self> {
+                unimplemented!()
+            }
+            fn with_month(&self, _: u32) -> Option<Self> {
+                unimplemented!()
+            }
+            fn with_month0(&self, _: u32) -> Option<Self> {
+                unimplemented!()
+            }
+            fn with_day(&self, _: u32) -> Option<Self> {
+                unimplemented!()
+            }
+            fn with_day0(&self, _: u32) -> Option<Self> {
+                unimplemented!()
+            }
+            fn with_ordinal(&self, _: u32) -> Option<Self> {
+                unimplemented!()
+            }
+            fn with_ordinal0(&self, _: u32) -> Option<Self> {
+                unimplemented!()
+            }
+        }
+
+        // Every 400 years have 146,097 days.
+        assert_eq!(Jan1(401).num_days_from_ce_i64(), 146_098);
+        assert_eq!(Jan1(-399).num_days_from_ce_i64(), -146_096);
+        assert_eq!(Jan1(4_000_001).num_days_from_ce_i64(), 146_097 * 10_000 + 1);
+        assert_eq!(Jan1(i32::MAX).num_days_from_ce_i64(), 784_352_295_575);
+        assert_eq!(Jan1(i32::MIN).num_days_from_ce_i64(), -784_352_296_670);
+    }
 
     /// Tests `Datelike::num_days_from_ce` against an alternative implementation.
     ///
@@ -1,4 +1,5 @@
 use core::fmt;
+use core::iter::FusedIterator;
 
 #[cfg(any(feature = "rkyv", feature = "rkyv-16", feature = "rkyv-32", feature = "rkyv-64"))]
 use rkyv::{Archive, Deserialize, Serialize};
@@ -148,6 +149,75 @@ impl Weekday {
         self.days_since(Weekday::Sun)
     }
 
+    /// Makes a `Weekday` from a day-of-week number starting from Monday = 0.
+    ///
+    /// This is the inverse of [`num_days_from_monday`](Weekday::num_days_from_monday), and uses
+    /// the same numbering as the `TryFrom<u8>` and `FromPrimitive` implementations. Use
+    /// [`from_iso`](Weekday::from_iso) for the ISO 8601 numbering starting from Monday = 1.
+    ///
+    /// Returns `None` if `n` is not in the range `0..=6`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    ///
+    /// assert_eq!(Weekday::from_u32(0), Some(Weekday::Mon));
+    /// assert_eq!(Weekday::from_u32(6), Some(Weekday::Sun));
+    /// assert_eq!(Weekday::from_u32(7), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_u32(n: u32) -> Option<Weekday> {
+        match n {
+            0 => Some(Weekday::Mon),
+            1 => Some(Weekday::Tue),
+            2 => Some(Weekday::Wed),
+            3 => Some(Weekday::Thu),
+            4 => Some(Weekday::Fri),
+            5 => Some(Weekday::Sat),
+            6 => Some(Weekday::Sun),
+            _ => None,
+        }
+    }
+
+    /// Makes a `Weekday` from an ISO 8601 weekday number, where Monday = 1 and Sunday = 7.
+    ///
+    /// This is the inverse of [`number_from_monday`](Weekday::number_from_monday). Note that
+    /// [`from_u32`](Weekday::from_u32) and the `TryFrom<u8>` implementation instead count from
+    /// Monday = 0.
+    ///
+    /// Returns `None` if `n` is not in the range `1..=7`.
+    ///
+    /// `n`:                    | 1     | 2     | 3     | 4     | 5     | 6     | 7
+    /// ----------------------- | ----- | ----- | ----- | ----- | ----- | ----- | -----
+    /// `Weekday::from_iso(n)`: | `Mon` | `Tue` | `Wed` | `Thu` | `Fri` | `Sat` | `Sun`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    ///
+    /// assert_eq!(Weekday::from_iso(1), Some(Weekday::Mon));
+    /// assert_eq!(Weekday::from_iso(7), Some(Weekday::Sun));
+    /// assert_eq!(Weekday::from_iso(0), None);
+    /// assert_eq!(Weekday::from_iso(8), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_iso(n: u8) -> Option<Weekday> {
+        match n {
+            1 => Some(Weekday::Mon),
+            2 => Some(Weekday::Tue),
+            3 => Some(Weekday::Wed),
+            4 => Some(Weekday::Thu),
+            5 => Some(Weekday::Fri),
+            6 => Some(Weekday::Sat),
+            7 => Some(Weekday::Sun),
+            _ => None,
+        }
+    }
+
     /// The number of days since the given day.
     ///
     /// # Examples
@@ -167,8 +237,77 @@ impl Weekday {
             lhs - rhs
         }
     }
+
+    /// Returns an iterator over all seven days of the week, starting at `self`.
+    ///
+    /// This can be used to build the header of a calendar with a week that starts on an arbitrary
+    /// day.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::Weekday::{self, *};
+    ///
+    /// let week: Vec<Weekday> = Wed.iter_from().collect();
+    /// assert_eq!(week, [Wed, Thu, Fri, Sat, Sun, Mon, Tue]);
+    /// ```
+    #[inline]
+    pub const fn iter_from(&self) -> WeekdayIterator {
+        WeekdayIterator { first: *self, front: 0, back: 7 }
+    }
+}
+
+/// Iterator over the seven days of the week, starting at a given day.
+///
+/// Created by [`Weekday::iter_from`].
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct WeekdayIterator {
+    first: Weekday,
+    // Number of days since `first` of the next day to return from the front.
+    front: u8,
+    // One past the number of days since `first` of the next day to return from the back.
+    back: u8,
+}
+
+impl WeekdayIterator {
+    fn nth_day(&self, days: u8) -> Weekday {
+        let index = (self.first as u8 + days) % 7;
+        Weekday::try_from(index).expect("index is in range")
+    }
+}
+
+impl Iterator for WeekdayIterator {
+    type Item = Weekday;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let day = self.nth_day(self.front);
+        self.front += 1;
+        Some(day)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let exact_size = usize::from(self.back - self.front);
+        (exact_size, Some(exact_size))
+    }
 }
 
+impl ExactSizeIterator for WeekdayIterator {}
+
+impl DoubleEndedIterator for WeekdayIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.nth_day(self.back))
+    }
+}
+
+impl FusedIterator for WeekdayIterator {}
+
 impl fmt::Display for Weekday {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(match *self {
@@ -331,6 +470,85 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_weekday_from_str() {
+        let names = ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
+        for (weekday, name) in Weekday::Mon.iter_from().zip(names) {
+            assert_eq!(name.parse::<Weekday>(), Ok(weekday), "{}", name);
+            assert_eq!(name.to_uppercase().parse::<Weekday>(), Ok(weekday));
+            assert_eq!(name.to_lowercase().parse::<Weekday>(), Ok(weekday));
+            assert_eq!(name[..3].parse::<Weekday>(), Ok(weekday));
+            assert_eq!(name[..3].to_lowercase().parse::<Weekday>(), Ok(weekday));
+        }
+        assert_eq!("tue".parse::<Weekday>(), Ok(Weekday::Tue));
+        assert_eq!("Tuesday".parse::<Weekday>(), Ok(Weekday::Tue));
+
+        for s in ["Tues", "Tu", "", " tue", "tue ", "Tuesdays", "Mo"] {
+            assert!(s.parse::<Weekday>().is_err(), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn test_weekday_numbers() {
+        use Weekday::*;
+        assert_eq!(Sun.num_days_from_sunday(), 0);
+        assert_eq!(Sat.num_days_from_sunday(), 6);
+        assert_eq!(Weekday::from_iso(7), Some(Sun));
+        assert_eq!(Weekday::from_iso(0), None);
+        assert_eq!(Weekday::from_iso(8), None);
+        assert_eq!(Weekday::from_u32(0), Some(Mon));
+        assert_eq!(Weekday::from_u32(6), Some(Sun));
+        assert_eq!(Weekday::from_u32(7), None);
+        assert_eq!(Weekday::from_u32(u32::MAX), None);
+
+        for weekday in Mon.iter_from() {
+            let iso = weekday.number_from_monday();
+            assert_eq!(Weekday::from_iso(iso as u8), Some(weekday));
+            assert_eq!(Weekday::from_u32(weekday.num_days_from_monday()), Some(weekday));
+            assert_eq!(Weekday::try_from(weekday.num_days_from_monday() as u8), Ok(weekday));
+            assert_eq!(weekday.num_days_from_sunday(), weekday.number_from_sunday() - 1);
+        }
+    }
+
+    #[test]
+    fn test_days_since_succ() {
+        use Weekday::*;
+        assert_eq!(Mon.days_since(Sun), 1);
+        assert_eq!(Sun.days_since(Mon), 6);
+        for i in 0..7 {
+            let day = Weekday::try_from(i).unwrap();
+            assert_eq!(day.succ().days_since(day), 1);
+            assert_eq!(day.days_since(day.pred()), 1);
+        }
+    }
+
+    #[test]
+    fn test_iter_from() {
+        use Weekday::*;
+        let week: Vec<Weekday> = Wed.iter_from().collect();
+        assert_eq!(week, [Wed, Thu, Fri, Sat, Sun, Mon, Tue]);
+        let week: Vec<Weekday> = Wed.iter_from().rev().collect();
+        assert_eq!(week, [Tue, Mon, Sun, Sat, Fri, Thu, Wed]);
+
+        for i in 0..7 {
+            let start = Weekday::try_from(i).unwrap();
+            let mut iter = start.iter_from();
+            assert_eq!(iter.len(), 7);
+            for (days, day) in iter.by_ref().enumerate() {
+                assert_eq!(day.days_since(start), days as u32);
+            }
+            assert_eq!(iter.len(), 0);
+            assert_eq!(iter.next(), None);
+        }
+
+        // both ends meet in the middle
+        let mut iter = Sun.iter_from();
+        assert_eq!(iter.next(), Some(Sun));
+        assert_eq!(iter.next_back(), Some(Sat));
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.collect::<Vec<_>>(), [Mon, Tue, Wed, Thu, Fri]);
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn test_serde_serialize() {
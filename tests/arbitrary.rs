@@ -0,0 +1,91 @@
+#![cfg(all(feature = "arbitrary", feature = "std"))]
+
+use arbitrary::{Arbitrary, Unstructured};
+use chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Timelike, Utc,
+};
+
+/// Returns a deterministic buffer of pseudo-random bytes.
+fn bytes(len: usize) -> Vec<u8> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect()
+}
+
+/// Calls `check` on every value that can be generated from the buffer.
+fn for_each_arbitrary<'a, T: Arbitrary<'a>>(data: &'a [u8], mut check: impl FnMut(T)) {
+    let mut u = Unstructured::new(data);
+    while !u.is_empty() {
+        check(T::arbitrary(&mut u).expect("arbitrary should only produce valid values"));
+    }
+}
+
+fn assert_valid_date(date: NaiveDate) {
+    assert!((NaiveDate::MIN..=NaiveDate::MAX).contains(&date));
+    assert_eq!(NaiveDate::from_ymd_opt(date.year(), date.month(), date.day()), Some(date));
+}
+
+fn assert_valid_time(time: NaiveTime) {
+    let secs = time.num_seconds_from_midnight();
+    assert_eq!(NaiveTime::from_num_seconds_from_midnight_opt(secs, time.nanosecond()), Some(time));
+}
+
+#[test]
+fn test_arbitrary_naive_date() {
+    for_each_arbitrary(&bytes(8192), assert_valid_date);
+}
+
+#[test]
+fn test_arbitrary_naive_time() {
+    for_each_arbitrary(&bytes(8192), assert_valid_time);
+}
+
+#[test]
+fn test_arbitrary_naive_datetime() {
+    for_each_arbitrary(&bytes(8192), |dt: NaiveDateTime| {
+        assert_valid_date(dt.date());
+        assert_valid_time(dt.time());
+    });
+}
+
+#[test]
+fn test_arbitrary_datetime() {
+    for_each_arbitrary(&bytes(8192), |dt: DateTime<Utc>| {
+        assert!((DateTime::<Utc>::MIN_UTC..=DateTime::<Utc>::MAX_UTC).contains(&dt));
+        assert_valid_date(dt.date_naive());
+        assert_valid_time(dt.time());
+    });
+    for_each_arbitrary(&bytes(8192), |dt: DateTime<FixedOffset>| {
+        assert_valid_date(dt.naive_utc().date());
+        assert_valid_time(dt.naive_utc().time());
+        let secs = dt.offset().local_minus_utc();
+        assert_eq!(FixedOffset::east_opt(secs).as_ref(), Some(dt.offset()));
+    });
+}
+
+#[test]
+fn test_arbitrary_time_delta() {
+    for_each_arbitrary(&bytes(8192), |delta: TimeDelta| {
+        assert!((TimeDelta::min_value()..=TimeDelta::max_value()).contains(&delta));
+        assert!((0..1_000_000_000).contains(&delta.subsec_nanos().abs()));
+    });
+
+    // The lower bound is not rejected.
+    let zeros = [0u8; 16];
+    assert_eq!(TimeDelta::arbitrary(&mut Unstructured::new(&zeros)), Ok(TimeDelta::min_value()));
+}
+
+#[test]
+fn test_arbitrary_fixed_offset() {
+    for_each_arbitrary(&bytes(8192), |offset: FixedOffset| {
+        let secs = offset.local_minus_utc();
+        assert!((-86_399..=86_399).contains(&secs));
+        assert_eq!(FixedOffset::east_opt(secs), Some(offset));
+    });
+}